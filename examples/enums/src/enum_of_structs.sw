@@ -1,9 +1,9 @@
 library enum_of_structs;
 
 struct Item {
-    price: u64,
-    amount: u64,
-    id: u64,
+    pub price: u64,
+    pub amount: u64,
+    pub id: u64,
 }
 
 enum MyEnum {
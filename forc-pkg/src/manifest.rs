@@ -85,6 +85,10 @@ pub struct BuildProfile {
     pub print_intermediate_asm: bool,
     pub silent: bool,
     pub time_phases: bool,
+    /// `None` if omitted, in which case `Manifest::from_file` turns it on for all but the
+    /// `release` profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overflow_checks: Option<bool>,
 }
 
 impl Dependency {
@@ -344,6 +348,13 @@ impl Manifest {
         if build_profiles.get(BuildProfile::RELEASE).is_none() {
             build_profiles.insert(BuildProfile::RELEASE.into(), BuildProfile::release());
         }
+
+        // Serde can't pick a default based on the profile's name, so it's done here.
+        for (name, profile) in build_profiles.iter_mut() {
+            profile
+                .overflow_checks
+                .get_or_insert(name != BuildProfile::RELEASE);
+        }
     }
 
     /// Retrieve a reference to the dependency with the given name.
@@ -394,6 +405,7 @@ impl BuildProfile {
             print_intermediate_asm: false,
             silent: false,
             time_phases: false,
+            overflow_checks: Some(true),
         }
     }
 
@@ -404,6 +416,7 @@ impl BuildProfile {
             print_intermediate_asm: false,
             silent: false,
             time_phases: false,
+            overflow_checks: Some(false),
         }
     }
 }
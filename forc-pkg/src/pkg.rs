@@ -26,7 +26,7 @@ use sway_core::{
     semantic_analysis::namespace, source_map::SourceMap, types::*, BytecodeCompilationResult,
     CompileAstResult, CompileError, CompileResult, ParseProgram, TreeType,
 };
-use sway_types::JsonABI;
+use sway_types::{JsonABI, MethodEffects, StorageLayout};
 use sway_utils::constants;
 use tracing::info;
 use url::Url;
@@ -49,6 +49,8 @@ pub struct PinnedId(u64);
 pub struct Compiled {
     pub json_abi: JsonABI,
     pub storage_slots: Vec<StorageSlot>,
+    pub storage_layout: StorageLayout,
+    pub method_effects: Vec<MethodEffects>,
     pub bytecode: Vec<u8>,
     pub tree_type: TreeType,
 }
@@ -1472,7 +1474,9 @@ pub fn sway_build_config(
     )
     .print_finalized_asm(build_profile.print_finalized_asm)
     .print_intermediate_asm(build_profile.print_intermediate_asm)
-    .print_ir(build_profile.print_ir);
+    .print_ir(build_profile.print_ir)
+    .time_phases(build_profile.time_phases)
+    .overflow_checks(build_profile.overflow_checks.unwrap_or(true));
     Ok(build_config)
 }
 
@@ -1626,8 +1630,10 @@ pub fn compile(
             typed_program,
             warnings,
         } => {
-            let json_abi = time_expr!("generate JSON ABI", typed_program.kind.generate_json_abi());
+            let json_abi = time_expr!("generate JSON ABI", typed_program.generate_json_abi());
             let storage_slots = typed_program.storage_slots.clone();
+            let storage_layout = typed_program.storage_layout.clone();
+            let method_effects = typed_program.generate_method_effects();
             let tree_type = typed_program.kind.tree_type();
             match tree_type {
                 // If we're compiling a library, we don't need to compile any further.
@@ -1639,6 +1645,8 @@ pub fn compile(
                     let compiled = Compiled {
                         json_abi,
                         storage_slots,
+                        storage_layout,
+                        method_effects,
                         bytecode,
                         tree_type,
                     };
@@ -1662,6 +1670,8 @@ pub fn compile(
                             let compiled = Compiled {
                                 json_abi,
                                 storage_slots,
+                                storage_layout,
+                                method_effects,
                                 bytecode,
                                 tree_type,
                             };
@@ -1689,8 +1699,10 @@ pub fn compile(
 pub fn build(plan: &BuildPlan, profile: &BuildProfile) -> anyhow::Result<(Compiled, SourceMap)> {
     let mut namespace_map = Default::default();
     let mut source_map = SourceMap::new();
-    let mut json_abi = vec![];
+    let mut json_abi = JsonABI::default();
     let mut storage_slots = vec![];
+    let mut storage_layout = StorageLayout::default();
+    let mut method_effects = vec![];
     let mut bytecode = vec![];
     let mut tree_type = None;
     for &node in &plan.compilation_order {
@@ -1702,8 +1714,11 @@ pub fn build(plan: &BuildPlan, profile: &BuildProfile) -> anyhow::Result<(Compil
         if let Some(namespace) = maybe_namespace {
             namespace_map.insert(node, namespace.into());
         }
-        json_abi.extend(compiled.json_abi);
+        json_abi.functions.extend(compiled.json_abi.functions);
+        json_abi.logged_types.extend(compiled.json_abi.logged_types);
         storage_slots.extend(compiled.storage_slots);
+        storage_layout.fields.extend(compiled.storage_layout.fields);
+        method_effects.extend(compiled.method_effects);
         bytecode = compiled.bytecode;
         tree_type = Some(compiled.tree_type);
         source_map.insert_dependency(manifest.dir());
@@ -1714,6 +1729,8 @@ pub fn build(plan: &BuildPlan, profile: &BuildProfile) -> anyhow::Result<(Compil
         bytecode,
         json_abi,
         storage_slots,
+        storage_layout,
+        method_effects,
         tree_type,
     };
     Ok((compiled, source_map))
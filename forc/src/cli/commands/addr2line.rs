@@ -42,7 +42,13 @@ pub(crate) fn exec(command: Command) -> Result<()> {
         )
     })?;
 
-    if let Some((mut path, range)) = sm.addr_to_span(command.opcode_index) {
+    // The location of the opcode comes first, followed by the calls it was inlined at, if any.
+    let spans = sm.addr_to_spans(command.opcode_index);
+    if spans.is_empty() {
+        return Err(anyhow!("Address did not map to any source code location"));
+    }
+
+    for (idx, (mut path, range)) in spans.into_iter().enumerate() {
         if path.is_relative() {
             path = command.search_dir.join(path);
         }
@@ -60,7 +66,7 @@ pub(crate) fn exec(command: Command) -> Result<()> {
                 origin: Some(&path_str),
                 fold: false,
                 annotations: vec![SourceAnnotation {
-                    label: "here",
+                    label: if idx == 0 { "here" } else { "inlined at" },
                     annotation_type: AnnotationType::Note,
                     range: (rr.offset, rr.offset + rr.length),
                 }],
@@ -71,11 +77,9 @@ pub(crate) fn exec(command: Command) -> Result<()> {
             },
         };
         info!("{}", DisplayList::from(snippet));
-
-        Ok(())
-    } else {
-        Err(anyhow!("Address did not map to any source code location"))
     }
+
+    Ok(())
 }
 
 struct ReadRange {
@@ -16,6 +16,13 @@ use clap::Parser;
 ///
 /// - `contract` and `library` projects will also produce the public ABI in JSON format
 /// `<project-name>-abi.json`.
+///
+/// - `contract` projects will also produce a summary of the effects of each ABI method in JSON
+/// format `<project-name>-method_effects.json`: the storage fields it reads and writes, the
+/// methods of other contracts it calls and whether it can be re-entered.
+///
+/// - `contract` projects will also produce the layout of their storage in JSON format
+/// `<project-name>-storage_layout.json` if `--storage-layout` is passed.
 #[derive(Debug, Default, Parser)]
 pub struct Command {
     /// Path to the project, if not specified, current working directory will be used.
@@ -61,6 +68,10 @@ pub struct Command {
     /// this option JSON output will be "minified", i.e. all on one line without whitespace.
     #[clap(long)]
     pub minify_json_storage_slots: bool,
+    /// Output the storage layout of a contract: the name, type, slot keys, offset and size of each
+    /// storage field, and the key derivation scheme of each `StorageMap`.
+    #[clap(long)]
+    pub storage_layout: bool,
     /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
     /// needs to be updated, Forc will exit with an error
     #[clap(long)]
@@ -26,6 +26,7 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
         output_directory,
         minify_json_abi,
         minify_json_storage_slots,
+        storage_layout,
         locked,
         build_profile,
         release,
@@ -103,7 +104,7 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
         .join(&manifest.project.name)
         .with_extension("bin");
     fs::write(&bin_path, &compiled.bytecode)?;
-    if !compiled.json_abi.is_empty() {
+    if !compiled.json_abi.functions.is_empty() {
         let json_abi_stem = format!("{}-abi", manifest.project.name);
         let json_abi_path = output_dir.join(&json_abi_stem).with_extension("json");
         let file = File::create(json_abi_path)?;
@@ -132,6 +133,23 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
                 serde_json::to_writer_pretty(&file, &compiled.storage_slots)
             };
             res?;
+
+            // Also emit a summary of the effects of each ABI method.
+            let json_method_effects_stem = format!("{}-method_effects", manifest.project.name);
+            let json_method_effects_path = output_dir
+                .join(&json_method_effects_stem)
+                .with_extension("json");
+            let file = File::create(json_method_effects_path)?;
+            serde_json::to_writer_pretty(&file, &compiled.method_effects)?;
+
+            if storage_layout {
+                let json_storage_layout_stem = format!("{}-storage_layout", manifest.project.name);
+                let json_storage_layout_path = output_dir
+                    .join(&json_storage_layout_stem)
+                    .with_extension("json");
+                let file = File::create(json_storage_layout_path)?;
+                serde_json::to_writer_pretty(&file, &compiled.storage_layout)?;
+            }
         }
         TreeType::Predicate => {
             // get the root hash of the bytecode for predicates and store the result in a file in the output directory
@@ -53,6 +53,7 @@ pub async fn deploy(command: DeployCommand) -> Result<fuel_tx::ContractId> {
         output_directory,
         minify_json_abi,
         minify_json_storage_slots,
+        storage_layout: false,
         locked,
         build_profile,
         release,
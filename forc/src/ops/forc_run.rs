@@ -1,6 +1,7 @@
 use crate::cli::{BuildCommand, RunCommand};
 use crate::ops::forc_build;
 use crate::utils::defaults::NODE_URL;
+use crate::utils::logs::{decode_log_receipt, decode_revert_error};
 use crate::utils::parameters::TxParameters;
 use crate::utils::SWAY_GIT_TAG;
 use anyhow::{anyhow, bail, Result};
@@ -11,6 +12,7 @@ use futures::TryFutureExt;
 use std::path::PathBuf;
 use std::str::FromStr;
 use sway_core::TreeType;
+use sway_types::LoggedType;
 use tracing::info;
 
 pub async fn run(command: RunCommand) -> Result<Vec<fuel_tx::Receipt>> {
@@ -38,6 +40,7 @@ pub async fn run(command: RunCommand) -> Result<Vec<fuel_tx::Receipt>> {
         output_directory: command.output_directory,
         minify_json_abi: command.minify_json_abi,
         minify_json_storage_slots: command.minify_json_storage_slots,
+        storage_layout: false,
         locked: command.locked,
         build_profile: None,
         release: false,
@@ -65,20 +68,28 @@ pub async fn run(command: RunCommand) -> Result<Vec<fuel_tx::Receipt>> {
         info!("{:?}", tx);
         Ok(vec![])
     } else {
-        try_send_tx(&node_url, &tx, command.pretty_print, command.simulate).await
+        try_send_tx(
+            &node_url,
+            &tx,
+            &compiled.json_abi.logged_types,
+            command.pretty_print,
+            command.simulate,
+        )
+        .await
     }
 }
 
 async fn try_send_tx(
     node_url: &str,
     tx: &Transaction,
+    logged_types: &[LoggedType],
     pretty_print: bool,
     simulate: bool,
 ) -> Result<Vec<fuel_tx::Receipt>> {
     let client = FuelClient::new(node_url)?;
 
     match client.health().await {
-        Ok(_) => send_tx(&client, tx, pretty_print, simulate).await,
+        Ok(_) => send_tx(&client, tx, logged_types, pretty_print, simulate).await,
         Err(_) => Err(fuel_core_not_running(node_url)),
     }
 }
@@ -86,6 +97,7 @@ async fn try_send_tx(
 async fn send_tx(
     client: &FuelClient,
     tx: &Transaction,
+    logged_types: &[LoggedType],
     pretty_print: bool,
     simulate: bool,
 ) -> Result<Vec<fuel_tx::Receipt>> {
@@ -106,7 +118,7 @@ async fn send_tx(
 
     match outputs {
         Ok(logs) => {
-            print_receipt_output(&logs, pretty_print)?;
+            print_receipt_output(&logs, logged_types, pretty_print)?;
             Ok(logs)
         }
         Err(e) => bail!("{e}"),
@@ -178,7 +190,11 @@ fn get_tx_inputs_and_outputs(
     (inputs, outputs)
 }
 
-fn print_receipt_output(receipts: &Vec<fuel_tx::Receipt>, pretty_print: bool) -> Result<()> {
+fn print_receipt_output(
+    receipts: &Vec<fuel_tx::Receipt>,
+    logged_types: &[LoggedType],
+    pretty_print: bool,
+) -> Result<()> {
     let mut receipt_to_json_array = serde_json::to_value(&receipts)?;
     for (rec_index, receipt) in receipts.iter().enumerate() {
         let rec_value = receipt_to_json_array.get_mut(rec_index).ok_or_else(|| {
@@ -200,11 +216,24 @@ fn print_receipt_output(receipts: &Vec<fuel_tx::Receipt>, pretty_print: bool) ->
             }
             _ => {}
         }
+        // Show the value of log receipts from `std::logging::log` next to the raw registers.
+        if let Some(decoded) = decode_log_receipt(receipt, logged_types) {
+            if let Some(fields) = rec_value
+                .as_object_mut()
+                .and_then(|rec| rec.values_mut().next())
+                .and_then(|fields| fields.as_object_mut())
+            {
+                fields.insert("decoded".into(), decoded);
+            }
+        }
     }
     if pretty_print {
         info!("{}", serde_json::to_string_pretty(&receipt_to_json_array)?);
     } else {
         info!("{}", serde_json::to_string(&receipt_to_json_array)?);
     }
+    if let Some(error) = decode_revert_error(receipts, logged_types) {
+        info!("Reverted with error: {}", error);
+    }
     Ok(())
 }
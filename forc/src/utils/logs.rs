@@ -0,0 +1,158 @@
+//! Decoding of the `Log` and `LogData` receipts emitted by `std::logging::log`.
+//!
+//! Each call to `log` places its log ID in `rb` of the receipt. The JSON ABI maps these IDs to
+//! the logged types, which tells us how to read the logged word or data back.
+
+use fuel_tx::Receipt;
+use serde_json::{Map, Value};
+use sway_types::{LoggedType, Property};
+
+const WORD_SIZE: usize = 8;
+
+/// The revert code of `std::revert::revert_with_log`, and so of a failed `require`.
+pub const FAILED_REQUIRE_SIGNAL: u64 = 42;
+
+/// Decode the value logged by `receipt`, if it is a log receipt with a log ID found in
+/// `logged_types`.
+pub fn decode_log_receipt(receipt: &Receipt, logged_types: &[LoggedType]) -> Option<Value> {
+    let (log_id, data, is_log_data) = match receipt {
+        Receipt::Log { ra, rb, .. } => (*rb, ra.to_be_bytes().to_vec(), false),
+        Receipt::LogData { rb, data, .. } => (*rb, data.clone(), true),
+        _ => return None,
+    };
+    let logged_type = &logged_types
+        .iter()
+        .find(|logged_type| logged_type.log_id == log_id)?
+        .logged_type;
+
+    // Copy types are logged with `log`, everything else with `logd`.
+    if is_log_data == is_copy_type(logged_type) {
+        return None;
+    }
+    decode_value(logged_type, &data)
+}
+
+/// Decode the error value of a transaction that reverted through `std::revert::revert_with_log`,
+/// which logs the value right before reverting with `FAILED_REQUIRE_SIGNAL`.
+pub fn decode_revert_error(receipts: &[Receipt], logged_types: &[LoggedType]) -> Option<Value> {
+    let revert_idx = receipts.iter().position(
+        |receipt| matches!(receipt, Receipt::Revert { ra, .. } if *ra == FAILED_REQUIRE_SIGNAL),
+    )?;
+    decode_log_receipt(receipts.get(revert_idx.checked_sub(1)?)?, logged_types)
+}
+
+fn is_copy_type(ty: &Property) -> bool {
+    matches!(
+        ty.type_field.as_str(),
+        "()" | "bool" | "u8" | "u16" | "u32" | "u64"
+    )
+}
+
+/// The size in bytes of a value of type `ty` in memory.
+fn size_of(ty: &Property) -> Option<usize> {
+    let components = || ty.components.iter().flatten();
+    let type_field = ty.type_field.as_str();
+    match type_field {
+        "()" | "bool" | "u8" | "u16" | "u32" | "u64" => Some(WORD_SIZE),
+        "u128" => Some(16),
+        "u256" | "b256" => Some(32),
+        _ if type_field.starts_with("str[") => {
+            let len = str_len(type_field)?;
+            Some((len + WORD_SIZE - 1) / WORD_SIZE * WORD_SIZE)
+        }
+        _ if type_field.starts_with('[') => {
+            let (elem_ty, count) = array_elem_and_count(ty)?;
+            Some(size_of(elem_ty)? * count)
+        }
+        _ if type_field.starts_with("struct ") || type_field.starts_with('(') => {
+            components().map(size_of).sum()
+        }
+        // Enums whose variants are all unit are just a tag.
+        _ if type_field.starts_with("enum ") && components().all(|v| v.type_field == "()") => {
+            Some(WORD_SIZE)
+        }
+        _ if type_field.starts_with("enum ") => {
+            let variant_sizes = components().map(size_of).collect::<Option<Vec<_>>>()?;
+            Some(WORD_SIZE + variant_sizes.into_iter().max().unwrap_or(0))
+        }
+        _ => None,
+    }
+}
+
+/// Decode a value of type `ty` from the start of `bytes`.
+fn decode_value(ty: &Property, bytes: &[u8]) -> Option<Value> {
+    let bytes = bytes.get(..size_of(ty)?)?;
+    let type_field = ty.type_field.as_str();
+    let value = match type_field {
+        "()" => Value::Null,
+        "bool" => Value::Bool(read_word(bytes)? != 0),
+        "u8" | "u16" | "u32" | "u64" => Value::from(read_word(bytes)?),
+        "u128" | "u256" | "b256" => Value::String(format!("0x{}", hex::encode(bytes))),
+        _ if type_field.starts_with("str[") => {
+            let len = str_len(type_field)?;
+            Value::String(String::from_utf8_lossy(&bytes[..len]).into_owned())
+        }
+        _ if type_field.starts_with('[') => {
+            let (elem_ty, count) = array_elem_and_count(ty)?;
+            let elem_size = size_of(elem_ty)?;
+            let elems = (0..count)
+                .map(|idx| decode_value(elem_ty, &bytes[idx * elem_size..]))
+                .collect::<Option<Vec<_>>>()?;
+            Value::Array(elems)
+        }
+        _ if type_field.starts_with("struct ") => {
+            let mut fields = Map::new();
+            let mut offset = 0;
+            for field in ty.components.iter().flatten() {
+                fields.insert(field.name.clone(), decode_value(field, &bytes[offset..])?);
+                offset += size_of(field)?;
+            }
+            Value::Object(fields)
+        }
+        _ if type_field.starts_with('(') => {
+            let mut elems = vec![];
+            let mut offset = 0;
+            for elem in ty.components.iter().flatten() {
+                elems.push(decode_value(elem, &bytes[offset..])?);
+                offset += size_of(elem)?;
+            }
+            Value::Array(elems)
+        }
+        _ if type_field.starts_with("enum ") => {
+            let tag = read_word(bytes)? as usize;
+            let variant = ty.components.as_ref()?.get(tag)?;
+            // Variants smaller than the largest one are padded on the left.
+            let offset = bytes.len() - size_of(variant)?;
+            let mut value = Map::new();
+            value.insert(
+                variant.name.clone(),
+                decode_value(variant, &bytes[offset..])?,
+            );
+            Value::Object(value)
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn read_word(bytes: &[u8]) -> Option<u64> {
+    let word: [u8; WORD_SIZE] = bytes.get(..WORD_SIZE)?.try_into().ok()?;
+    Some(u64::from_be_bytes(word))
+}
+
+/// The length of a `str[N]` type.
+fn str_len(type_field: &str) -> Option<usize> {
+    type_field
+        .strip_prefix("str[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// The element type and element count of a `[T; N]` type.
+fn array_elem_and_count(ty: &Property) -> Option<(&Property, usize)> {
+    let (_, count) = ty.type_field.rsplit_once("; ")?;
+    let count = count.strip_suffix(']')?.parse().ok()?;
+    let elem_ty = ty.components.as_ref()?.first()?;
+    Some((elem_ty, count))
+}
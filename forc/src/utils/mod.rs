@@ -1,4 +1,5 @@
 pub mod defaults;
+pub mod logs;
 pub mod parameters;
 pub mod program_type;
 
@@ -108,6 +108,7 @@ impl AbstractInstructionSet {
             Op {
                 opcode,
                 owning_span,
+                inlined_at,
                 comment,
             },
         ) in self.ops.clone().into_iter().enumerate()
@@ -117,6 +118,7 @@ impl AbstractInstructionSet {
                 Either::Left(op) => realized_ops.push(RealizedOp {
                     opcode: op,
                     owning_span,
+                    inlined_at,
                     comment,
                     offset,
                 }),
@@ -129,6 +131,7 @@ impl AbstractInstructionSet {
                         realized_ops.push(RealizedOp {
                             opcode: VirtualOp::JI(imm),
                             owning_span,
+                            inlined_at,
                             comment,
                             offset,
                         });
@@ -141,6 +144,7 @@ impl AbstractInstructionSet {
                         realized_ops.push(RealizedOp {
                             opcode: VirtualOp::JNEI(r1, r2, imm),
                             owning_span,
+                            inlined_at,
                             comment,
                             offset,
                         });
@@ -153,6 +157,7 @@ impl AbstractInstructionSet {
                         realized_ops.push(RealizedOp {
                             opcode: VirtualOp::JNZI(r1, imm),
                             owning_span,
+                            inlined_at,
                             comment,
                             offset,
                         });
@@ -161,6 +166,7 @@ impl AbstractInstructionSet {
                         realized_ops.push(RealizedOp {
                             opcode: VirtualOp::DataSectionOffsetPlaceholder,
                             owning_span: None,
+                            inlined_at: Vec::new(),
                             comment: String::new(),
                             offset,
                         });
@@ -233,6 +239,7 @@ impl RealizedAbstractInstructionSet {
                 opcode: op.opcode.allocate_registers(&pool),
                 comment: op.comment.clone(),
                 owning_span: op.owning_span.clone(),
+                inlined_at: op.inlined_at.clone(),
             })
         }
 
@@ -7,7 +7,8 @@ use crate::asm_lang::*;
 use crate::error::*;
 
 /// Checks for disallowed opcodes in non-contract code.
-/// i.e., if this is a script or predicate, we can't use certain contract opcodes.
+/// i.e., if this is a script or predicate, we can't use certain contract opcodes.  Predicates are
+/// further restricted to opcodes which don't depend on the transaction executing.
 /// See https://github.com/FuelLabs/sway/issues/350 for details.
 pub fn check_invalid_opcodes(asm: &FinalizedAsm) -> CompileResult<()> {
     match asm {
@@ -17,7 +18,27 @@ pub fn check_invalid_opcodes(asm: &FinalizedAsm) -> CompileResult<()> {
         } => check_for_contract_opcodes(&program_section.ops[..]),
         FinalizedAsm::PredicateMain {
             program_section, ..
-        } => check_for_contract_opcodes(&program_section.ops[..]),
+        } => {
+            let mut warnings = vec![];
+            let mut errors = vec![];
+            check!(
+                check_for_contract_opcodes(&program_section.ops[..]),
+                (),
+                warnings,
+                errors
+            );
+            check!(
+                check_for_predicate_opcodes(&program_section.ops[..]),
+                (),
+                warnings,
+                errors
+            );
+            if errors.is_empty() {
+                ok((), warnings, errors)
+            } else {
+                err(warnings, errors)
+            }
+        }
     }
 }
 
@@ -72,3 +93,45 @@ fn check_for_contract_opcodes(ops: &[AllocatedOp]) -> CompileResult<()> {
         err(vec![], errors)
     }
 }
+
+/// Checks for opcodes which the VM won't execute in a predicate, beyond the contract opcodes
+/// above.  The ops of inlined std functions keep the spans of their Sway code, so the error points
+/// to where the opcode came from.
+fn check_for_predicate_opcodes(ops: &[AllocatedOp]) -> CompileResult<()> {
+    use AllocatedOpcode::*;
+    let default_span =
+        sway_types::span::Span::new("no span found for opcode".into(), 0, 1, None).unwrap();
+    let mut errors = vec![];
+    for op in ops {
+        let opcode = match op.opcode {
+            BAL(..) => "BAL",
+            BHEI(..) => "BHEI",
+            BHSH(..) => "BHSH",
+            CALL(..) => "CALL",
+            CB(..) => "CB",
+            CCP(..) => "CCP",
+            CROO(..) => "CROO",
+            CSIZ(..) => "CSIZ",
+            LDC(..) => "LDC",
+            LOG(..) => "LOG",
+            LOGD(..) => "LOGD",
+            SMO(..) => "SMO",
+            TR(..) => "TR",
+            TRO(..) => "TRO",
+            _ => continue,
+        };
+        errors.push(CompileError::DisallowedOpcodeInPredicate {
+            opcode: opcode.to_string(),
+            span: op
+                .owning_span
+                .clone()
+                .unwrap_or_else(|| default_span.clone()),
+        });
+    }
+
+    if errors.is_empty() {
+        ok((), vec![], errors)
+    } else {
+        err(vec![], errors)
+    }
+}
@@ -7,6 +7,13 @@ pub(crate) const EIGHTEEN_BITS: u64 = 0b11_1111_1111_1111_1111;
 pub(crate) const TWELVE_BITS: u64 = 0b1111_1111_1111;
 pub(crate) const SIX_BITS: u64 = 0b11_1111;
 
+/// The bit in `$flag` which makes arithmetic wrap rather than panic on overflow.
+pub(crate) const FLAG_WRAPPING: u16 = 0b10;
+
+/// The code used to revert when integer arithmetic overflows with overflow checks enabled.  This
+/// must match `ARITHMETIC_OVERFLOW_SIGNAL` in the standard library.
+pub(crate) const ARITHMETIC_OVERFLOW_SIGNAL: u32 = 43;
+
 /// This is the number of registers reserved by the compiler. Adjust this number if a new
 /// reservation must be made.
 /// So far, the compiler-reserved registers are:
@@ -1,5 +1,10 @@
-use crate::{parse_tree::*, type_engine::*};
-use std::fmt::{self, Write};
+use crate::{
+    asm_generation::compiler_constants, asm_lang::VirtualImmediate18, parse_tree::*, type_engine::*,
+};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Write},
+};
 
 type Data = Literal;
 
@@ -13,10 +18,22 @@ impl fmt::Display for DataId {
     }
 }
 
+/// How a constant is loaded into a register.
+pub(crate) enum ConstantLoad {
+    /// Small enough to be loaded with `MOVI`.
+    Immediate(VirtualImmediate18),
+    /// Loaded from the data section with `LW`.
+    Data(DataId),
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct DataSection {
     /// the data to be put in the data section of the asm
     pub value_pairs: Vec<Data>,
+    /// the data used by each function, by name, for the size report
+    function_usage: Vec<(String, BTreeSet<u32>)>,
+    /// whether data inserted now is used by the last function in `function_usage`
+    in_function: bool,
 }
 
 impl DataSection {
@@ -58,16 +75,115 @@ impl DataSection {
     /// Given any data in the form of a [Literal] (using this type mainly because it includes type
     /// information and debug spans), insert it into the data section and return its offset as a
     /// [DataId].
+    ///
+    /// Values are pooled by their bytes rather than their type, so e.g. a `b256` and a `str[32]`
+    /// with the same contents share an entry.  Copy types are loaded by value and the rest by
+    /// address, so they're never pooled with each other.
     pub(crate) fn insert_data_value(&mut self, data: &Literal) -> DataId {
-        // if there is an identical data value, use the same id
-        match self.value_pairs.iter().position(|x| x == data) {
+        let bytes = data.to_bytes();
+        let is_copy_type = data.as_type().is_copy_type();
+        let id = match self
+            .value_pairs
+            .iter()
+            .position(|x| x.as_type().is_copy_type() == is_copy_type && x.to_bytes() == bytes)
+        {
             Some(num) => DataId(num as u32),
             None => {
                 self.value_pairs.push(data.clone());
                 // the index of the data section where the value is stored
                 DataId((self.value_pairs.len() - 1) as u32)
             }
+        };
+        if self.in_function {
+            if let Some((_, used)) = self.function_usage.last_mut() {
+                used.insert(id.0);
+            }
+        }
+        id
+    }
+
+    /// Decide whether a constant is loaded as an immediate or from the data section, inserting
+    /// it into the data section if need be.  Copy types are loaded as an immediate if they fit
+    /// in 18 bits.
+    pub(crate) fn insert_constant(&mut self, data: &Literal) -> ConstantLoad {
+        if data.as_type().is_copy_type() {
+            let bytes = data.to_bytes();
+            let value = u64::from_be_bytes(bytes[..].try_into().unwrap());
+            if value <= compiler_constants::EIGHTEEN_BITS {
+                return ConstantLoad::Immediate(VirtualImmediate18 {
+                    value: value as u32,
+                });
+            }
+        }
+        ConstantLoad::Data(self.insert_data_value(data))
+    }
+
+    /// Attribute the data inserted from now on to the function `name`, until
+    /// [DataSection::end_function] is called.
+    pub(crate) fn begin_function(&mut self, name: &str) {
+        self.function_usage.push((name.to_owned(), BTreeSet::new()));
+        self.in_function = true;
+    }
+
+    pub(crate) fn end_function(&mut self) {
+        self.in_function = false;
+    }
+
+    /// The size of the data section and how much of it each function uses.
+    pub(crate) fn size_report(&self) -> DataSectionReport {
+        let entry_size = |id: &u32| self.value_pairs[*id as usize].to_bytes().len();
+        let functions = self
+            .function_usage
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, used))| {
+                let shared = used
+                    .iter()
+                    .filter(|id| {
+                        self.function_usage
+                            .iter()
+                            .enumerate()
+                            .any(|(other_idx, (_, other))| other_idx != idx && other.contains(*id))
+                    })
+                    .map(entry_size)
+                    .sum();
+                FunctionDataSize {
+                    name: name.clone(),
+                    size: used.iter().map(entry_size).sum(),
+                    shared,
+                }
+            })
+            .collect();
+        DataSectionReport {
+            size: self.value_pairs.iter().map(|x| x.to_bytes().len()).sum(),
+            functions,
+        }
+    }
+}
+
+/// The data section size in bytes, from [DataSection::size_report].
+pub(crate) struct DataSectionReport {
+    size: usize,
+    functions: Vec<FunctionDataSize>,
+}
+
+struct FunctionDataSize {
+    name: String,
+    size: usize,
+    /// how much of `size` is also used by other functions
+    shared: usize,
+}
+
+impl fmt::Display for DataSectionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  Data section: {} bytes", self.size)?;
+        for function in &self.functions {
+            write!(f, "\n    {}: {} bytes", function.name, function.size)?;
+            if function.shared > 0 {
+                write!(f, " ({} shared)", function.shared)?;
+            }
         }
+        Ok(())
     }
 }
 
@@ -80,6 +196,8 @@ impl fmt::Display for DataSection {
                 Literal::U16(num) => format!(".u16 {:#04x}", num),
                 Literal::U32(num) => format!(".u32 {:#04x}", num),
                 Literal::U64(num) => format!(".u64 {:#04x}", num),
+                Literal::U128(num) => format!(".u128 {:#04x}", num),
+                Literal::U256(_) => format!(".u256 {}", data),
                 Literal::Numeric(num) => format!(".u64 {:#04x}", num),
                 Literal::Boolean(b) => format!(".bool {}", if *b { "0x01" } else { "0x00" }),
                 Literal::String(st) => format!(".str \"{}\"", st.as_str()),
@@ -99,3 +217,72 @@ impl fmt::Display for DataSection {
         write!(f, ".data:\n{}", data_buf)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_types::Span;
+
+    fn string_literal(s: &str) -> Literal {
+        Literal::String(Span::new(std::sync::Arc::from(s), 0, s.len(), None).unwrap())
+    }
+
+    #[test]
+    fn pooled_by_bytes() {
+        let mut data_section = DataSection::default();
+        let b256 = data_section.insert_data_value(&Literal::B256([b'a'; 32]));
+        let string = data_section.insert_data_value(&string_literal(&"a".repeat(32)));
+        assert_eq!(b256.0, string.0);
+
+        let uint = data_section.insert_data_value(&Literal::U64(1));
+        let boolean = data_section.insert_data_value(&Literal::Boolean(true));
+        assert_eq!(uint.0, boolean.0);
+
+        // Loaded by value rather than by address, so not pooled with the string.
+        let word = data_section.insert_data_value(&Literal::U64(u64::from_be_bytes([b'b'; 8])));
+        let short_string = data_section.insert_data_value(&string_literal("bbbbbbbb"));
+        assert_ne!(word.0, short_string.0);
+
+        assert_eq!(data_section.value_pairs.len(), 4);
+    }
+
+    #[test]
+    fn immediate_or_data() {
+        let mut data_section = DataSection::default();
+        assert!(matches!(
+            data_section.insert_constant(&Literal::U64(compiler_constants::EIGHTEEN_BITS)),
+            ConstantLoad::Immediate(VirtualImmediate18 { value: 0x3ffff })
+        ));
+        assert!(matches!(
+            data_section.insert_constant(&Literal::Boolean(true)),
+            ConstantLoad::Immediate(VirtualImmediate18 { value: 1 })
+        ));
+        assert!(matches!(
+            data_section.insert_constant(&Literal::U64(compiler_constants::EIGHTEEN_BITS + 1)),
+            ConstantLoad::Data(DataId(0))
+        ));
+        assert!(matches!(
+            data_section.insert_constant(&Literal::B256([0; 32])),
+            ConstantLoad::Data(DataId(1))
+        ));
+        assert_eq!(data_section.value_pairs.len(), 2);
+    }
+
+    #[test]
+    fn size_report() {
+        let mut data_section = DataSection::default();
+        data_section.begin_function("foo");
+        data_section.insert_data_value(&Literal::B256([1; 32]));
+        data_section.insert_data_value(&Literal::U64(1 << 20));
+        data_section.end_function();
+        data_section.begin_function("bar");
+        data_section.insert_data_value(&Literal::B256([1; 32]));
+        data_section.end_function();
+        data_section.insert_data_value(&Literal::U32(0x12345678));
+
+        assert_eq!(
+            data_section.size_report().to_string(),
+            "  Data section: 48 bytes\n    foo: 40 bytes (32 shared)\n    bar: 32 bytes (32 shared)"
+        );
+    }
+}
@@ -115,6 +115,7 @@ fn to_bytecode_mut(
     let mut half_word_ix = 0;
     for op in program_section.ops.iter() {
         let span = op.owning_span.clone();
+        let inlined_at = op.inlined_at.clone();
         let op = op.to_fuel_asm(offset_to_data_section_in_bytes, data_section);
         match op {
             Either::Right(data) => {
@@ -130,6 +131,7 @@ fn to_bytecode_mut(
                 for mut op in ops {
                     if let Some(span) = &span {
                         source_map.insert(half_word_ix, span);
+                        source_map.insert_inlined_at(half_word_ix, &inlined_at);
                     }
                     op.read_exact(&mut buf[half_word_ix * 4..])
                         .expect("Failed to write to in-memory buffer.");
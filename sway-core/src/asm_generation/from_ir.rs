@@ -14,7 +14,7 @@ use crate::{
     asm_generation::{
         build_contract_abi_switch, build_preamble, checks::check_invalid_opcodes,
         compiler_constants, finalized_asm::FinalizedAsm, register_sequencer::RegisterSequencer,
-        AbstractInstructionSet, DataId, DataSection, SwayAsmSet,
+        AbstractInstructionSet, ConstantLoad, DataId, DataSection, PeepholeStats, SwayAsmSet,
     },
     asm_lang::{
         virtual_register::*, Label, Op, VirtualImmediate12, VirtualImmediate18, VirtualImmediate24,
@@ -45,24 +45,33 @@ pub fn compile_ir_to_asm(
     // of libraries and link against them, rather than recompile everything each time.
     assert!(ir.module_iter().count() == 1);
     let module = ir.module_iter().next().unwrap();
+    let overflow_checks = build_config.map(|cfg| cfg.overflow_checks).unwrap_or(false);
     let (data_section, mut ops, mut reg_seqr) = check!(
-        compile_module_to_asm(reg_seqr, ir, module),
+        compile_module_to_asm(reg_seqr, ir, module, overflow_checks),
         return err(warnings, errors),
         warnings,
         errors
     );
     bytecode.append(&mut ops);
 
+    if build_config.map(|cfg| cfg.time_phases).unwrap_or(false) {
+        tracing::info!("{}", data_section.size_report());
+    }
+
     let asm = match module.get_kind(ir) {
         Kind::Script => SwayAsmSet::ScriptMain {
             program_section: AbstractInstructionSet { ops: bytecode },
             data_section,
         },
+        Kind::Predicate => SwayAsmSet::PredicateMain {
+            program_section: AbstractInstructionSet { ops: bytecode },
+            data_section,
+        },
         Kind::Contract => SwayAsmSet::ContractAbi {
             program_section: AbstractInstructionSet { ops: bytecode },
             data_section,
         },
-        Kind::Library | Kind::Predicate => todo!("libraries and predicates coming soon!"),
+        Kind::Library => todo!("libraries coming soon!"),
     };
 
     if build_config
@@ -72,10 +81,15 @@ pub fn compile_ir_to_asm(
         tracing::info!("{}", asm);
     }
 
+    let mut peephole_stats = PeepholeStats::default();
     let finalized_asm = asm
         .remove_unnecessary_jumps()
         .allocate_registers(&mut reg_seqr)
-        .optimize();
+        .optimize(&mut peephole_stats);
+
+    if build_config.map(|cfg| cfg.time_phases).unwrap_or(false) {
+        tracing::info!("{}", peephole_stats);
+    }
 
     if build_config
         .map(|cfg| cfg.print_finalized_asm)
@@ -98,10 +112,11 @@ fn compile_module_to_asm(
     reg_seqr: RegisterSequencer,
     context: &Context,
     module: Module,
+    overflow_checks: bool,
 ) -> CompileResult<(DataSection, Vec<Op>, RegisterSequencer)> {
-    let mut builder = AsmBuilder::new(DataSection::default(), reg_seqr, context);
+    let mut builder = AsmBuilder::new(DataSection::default(), reg_seqr, context, overflow_checks);
     match module.get_kind(context) {
-        Kind::Script => {
+        Kind::Script | Kind::Predicate => {
             // We can't do function calls yet, so we expect everything to be inlined into `main`.
             let function = module
                 .function_iter(context)
@@ -147,7 +162,7 @@ fn compile_module_to_asm(
                 errors,
             )
         }
-        Kind::Library | Kind::Predicate => todo!("libraries and predicates coming soon!"),
+        Kind::Library => todo!("libraries coming soon!"),
     }
 }
 
@@ -184,9 +199,19 @@ struct AsmBuilder<'ir> {
     // Stack base register, copied from $SP at the start, but only if we have stack storage.
     stack_base_reg: Option<VirtualRegister>,
 
+    // Word offsets of the stack space reserved for the results of wide integer arithmetic.
+    scratch_map: HashMap<Value, u64>,
+
+    // Array element offsets which are kept up to date along with the loop induction variables
+    // used as indices, for the function being compiled.  See `find_induction_offsets()`.
+    induction_offsets: Vec<InductionOffset>,
+
     // IR context we're compiling.
     context: &'ir Context,
 
+    // Whether integer arithmetic should revert with `ARITHMETIC_OVERFLOW_SIGNAL` on overflow.
+    overflow_checks: bool,
+
     // Final resulting VM bytecode ops.
     bytecode: Vec<Op>,
 }
@@ -201,13 +226,25 @@ pub(super) enum Storage {
     Stack(u64), // Storage in the runtime stack starting at an absolute word offset.  Essentially a global.
 }
 
+// A register holding the induction variable `phi_val` multiplied by `elem_size`.
+struct InductionOffset {
+    phi_val: Value,
+    elem_size: u64,
+    reg: VirtualRegister,
+}
+
 pub enum StateAccessType {
     Read,
     Write,
 }
 
 impl<'ir> AsmBuilder<'ir> {
-    fn new(data_section: DataSection, reg_seqr: RegisterSequencer, context: &'ir Context) -> Self {
+    fn new(
+        data_section: DataSection,
+        reg_seqr: RegisterSequencer,
+        context: &'ir Context,
+        overflow_checks: bool,
+    ) -> Self {
         AsmBuilder {
             data_section,
             reg_seqr,
@@ -215,7 +252,10 @@ impl<'ir> AsmBuilder<'ir> {
             reg_map: HashMap::new(),
             ptr_map: HashMap::new(),
             stack_base_reg: None,
+            scratch_map: HashMap::new(),
+            induction_offsets: Vec::new(),
             context,
+            overflow_checks,
             bytecode: Vec::new(),
         }
     }
@@ -272,6 +312,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: format!("Get offset for arg {}", name),
                                 owning_span: None,
+                                inlined_at: Vec::new(),
                             });
                             self.bytecode.push(Op {
                                 opcode: Either::Left(VirtualOp::LW(
@@ -281,6 +322,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: format!("Get arg {}", name),
                                 owning_span: None,
+                                inlined_at: Vec::new(),
                             });
                         } else {
                             self.bytecode.push(Op {
@@ -293,6 +335,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: format!("Get arg {}", name),
                                 owning_span: None,
+                                inlined_at: Vec::new(),
                             });
                         }
                     } else if arg_word_offset * 8 > compiler_constants::TWELVE_BITS {
@@ -306,6 +349,7 @@ impl<'ir> AsmBuilder<'ir> {
                             )),
                             comment: format!("Get offset or arg {}", name),
                             owning_span: None,
+                            inlined_at: Vec::new(),
                         });
                     } else {
                         self.bytecode.push(Op {
@@ -318,6 +362,7 @@ impl<'ir> AsmBuilder<'ir> {
                             )),
                             comment: format!("Get address for arg {}", name),
                             owning_span: None,
+                            inlined_at: Vec::new(),
                         });
                     }
 
@@ -338,6 +383,7 @@ impl<'ir> AsmBuilder<'ir> {
             )),
             comment: "Base register for method parameter".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         });
     }
 
@@ -365,6 +411,10 @@ impl<'ir> AsmBuilder<'ir> {
                         self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
                         stack_base += 4;
                     }
+                    Type::BigUint(nbits) => {
+                        self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
+                        stack_base += nbits as u64 / 64;
+                    }
                     Type::String(n) => {
                         // Strings are always constant and used by reference, so we only store the
                         // pointer on the stack.
@@ -385,8 +435,27 @@ impl<'ir> AsmBuilder<'ir> {
             }
         }
 
+        // Wide integer arithmetic needs somewhere to write its result, and division also needs
+        // somewhere for its remainder, so reserve that space along with the locals.
+        for block in function.block_iter(self.context) {
+            for instr_val in block.instruction_iter(self.context) {
+                if let ValueDatum::Instruction(Instruction::BinaryOp { op, arg1, .. }) =
+                    &self.context.values[instr_val.0].value
+                {
+                    if let Some(Type::BigUint(nbits)) = arg1.get_type(self.context) {
+                        let nwords = nbits as u64 / 64;
+                        self.scratch_map.insert(instr_val, stack_base);
+                        stack_base += match op {
+                            BinaryOpKind::Div => nwords * 2,
+                            _otherwise => nwords,
+                        };
+                    }
+                }
+            }
+        }
+
         // Reserve space on the stack for ALL our locals which require it.
-        if !self.ptr_map.is_empty() {
+        if !self.ptr_map.is_empty() || !self.scratch_map.is_empty() {
             let base_reg = self.reg_seqr.next();
             self.bytecode.push(Op::unowned_register_move_comment(
                 base_reg.clone(),
@@ -447,8 +516,11 @@ impl<'ir> AsmBuilder<'ir> {
         }
 
         // Compile instructions.
+        self.data_section
+            .begin_function(function.get_name(self.context));
         self.add_locals(function);
         self.compile_fn_args(function);
+        self.find_induction_offsets(function);
         let mut warnings = Vec::new();
         let mut errors = Vec::new();
         for block in function.block_iter(self.context) {
@@ -462,12 +534,88 @@ impl<'ir> AsmBuilder<'ir> {
                 );
             }
         }
+        self.data_section.end_function();
         ok((), warnings, errors)
     }
 
+    // Array elements are addressed by multiplying their index by the element size.  When the
+    // index is a loop induction variable, i.e., a loop header's phi which is incremented by a
+    // constant on every branch back to the header, the multiplied index can instead be kept in a
+    // register and incremented along with it.  This is done for the induction variables used for
+    // more than one access to elements of the same size.
+    fn find_induction_offsets(&mut self, function: Function) {
+        // The register allocator can't spill yet, so each offset takes a register away from
+        // everything else for the whole of its loop.
+        const MAX_INDUCTION_OFFSETS: usize = 4;
+
+        // The number of accesses with each index value and element size.
+        let mut accesses: Vec<((Value, u64), usize)> = Vec::new();
+        for (_block, instr_val) in function.instruction_iter(self.context) {
+            let (ty, index_val) = match &self.context.values[instr_val.0].value {
+                ValueDatum::Instruction(Instruction::ExtractElement { ty, index_val, .. })
+                | ValueDatum::Instruction(Instruction::InsertElement { ty, index_val, .. }) => {
+                    (ty, index_val)
+                }
+                _otherwise => continue,
+            };
+            let elem_size =
+                ir_type_size_in_bytes(self.context, &ty.get_elem_type(self.context).unwrap());
+            let elem_size = match elem_size {
+                0..=8 => 8,
+                _ if elem_size <= compiler_constants::TWELVE_BITS => elem_size,
+                _otherwise => continue,
+            };
+            match accesses
+                .iter_mut()
+                .find(|(key, _)| *key == (*index_val, elem_size))
+            {
+                Some((_, count)) => *count += 1,
+                None => accesses.push(((*index_val, elem_size), 1)),
+            }
+        }
+
+        let loop_nest = function.get_analysis::<LoopNest>(self.context);
+        self.induction_offsets = Vec::new();
+        for ((index_val, elem_size), count) in accesses {
+            if count < 2 || self.induction_offsets.len() == MAX_INDUCTION_OFFSETS {
+                continue;
+            }
+            let is_induction_var = loop_nest.loops().any(|(_, a_loop)| {
+                let header = a_loop.header();
+                header != function.get_entry_block(self.context)
+                    && header.get_phi(self.context) == index_val
+                    && match &self.context.values[index_val.0].value {
+                        ValueDatum::Instruction(Instruction::Phi(pairs)) => {
+                            pairs.len() == header.num_predecessors(self.context)
+                                && pairs.iter().all(|(from_block, value)| {
+                                    // A conditional branch would update the offset even when it
+                                    // doesn't branch to the header.
+                                    matches!(
+                                        from_block.get_term_inst(self.context),
+                                        Some(Instruction::Branch(_))
+                                    ) && (!a_loop.contains(from_block)
+                                        || self
+                                            .induction_step_offset(&index_val, value, elem_size)
+                                            .is_some())
+                                })
+                        }
+                        _otherwise => false,
+                    }
+            });
+            if is_induction_var {
+                self.induction_offsets.push(InductionOffset {
+                    phi_val: index_val,
+                    elem_size,
+                    reg: self.reg_seqr.next(),
+                });
+            }
+        }
+    }
+
     fn compile_instruction(&mut self, block: &Block, instr_val: &Value) -> CompileResult<()> {
         let mut warnings = Vec::new();
         let mut errors = Vec::new();
+        let first_op_idx = self.bytecode.len();
         if let ValueDatum::Instruction(instruction) = &self.context.values[instr_val.0].value {
             match instruction {
                 Instruction::AsmBlock(asm, args) => {
@@ -478,6 +626,9 @@ impl<'ir> AsmBuilder<'ir> {
                         errors
                     )
                 }
+                Instruction::BinaryOp { op, arg1, arg2 } => {
+                    self.compile_binary_op(instr_val, op, arg1, arg2)
+                }
                 Instruction::BitCast(val, ty) => self.compile_bitcast(instr_val, val, ty),
                 Instruction::Branch(to_block) => self.compile_branch(block, to_block),
                 Instruction::Call(..) => {
@@ -543,6 +694,11 @@ impl<'ir> AsmBuilder<'ir> {
                     warnings,
                     errors
                 ),
+                Instruction::Log {
+                    log_val,
+                    log_ty,
+                    log_id,
+                } => self.compile_log(instr_val, log_val, log_ty, log_id),
                 Instruction::Nop => (),
                 Instruction::Phi(_) => (), // Managing the phi value is done in br and cbr compilation.
                 Instruction::ReadRegister(reg) => self.compile_read_register(instr_val, reg),
@@ -599,9 +755,30 @@ impl<'ir> AsmBuilder<'ir> {
                     .unwrap_or_else(Self::empty_span),
             ));
         }
+        self.mark_inlined_ops(instr_val, first_op_idx);
         ok((), warnings, errors)
     }
 
+    // If the instruction was inlined then record the calls it was inlined at in each of its ops,
+    // for the source map.  Only the ops owned by the instruction itself are marked, not those
+    // which were emitted for another value, e.g., the phi moves before a branch.
+    fn mark_inlined_ops(&mut self, instr_val: &Value, first_op_idx: usize) {
+        let mut spans = match self.context.values[instr_val.0]
+            .span_md_idx
+            .map(|md_idx| md_idx.to_inlined_spans(self.context))
+        {
+            Some(Ok(spans)) if spans.len() > 1 => spans,
+            _otherwise => return,
+        };
+        let inlined_at = spans.split_off(1);
+        let instr_span = spans.pop();
+        for op in &mut self.bytecode[first_op_idx..] {
+            if op.owning_span == instr_span {
+                op.inlined_at = inlined_at.clone();
+            }
+        }
+    }
+
     // OK, I began by trying to translate the IR ASM block data structures back into AST data
     // structures which I could feed to the code in asm_generation/expression/mod.rs where it
     // compiles the inline ASM.  But it's more work to do that than to just re-implement that
@@ -706,6 +883,7 @@ impl<'ir> AsmBuilder<'ir> {
                 opcode: either::Either::Left(opcode),
                 comment: "asm block".into(),
                 owning_span: Some(op_span),
+                inlined_at: Vec::new(),
             });
         }
 
@@ -732,6 +910,7 @@ impl<'ir> AsmBuilder<'ir> {
                 opcode: Either::Left(VirtualOp::MOVE(instr_reg.clone(), ret_reg)),
                 comment: "return value from inline asm".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
             self.reg_map.insert(*instr_val, instr_reg);
         }
@@ -741,6 +920,46 @@ impl<'ir> AsmBuilder<'ir> {
         ok((), warnings, errors)
     }
 
+    fn compile_binary_op(
+        &mut self,
+        instr_val: &Value,
+        op: &BinaryOpKind,
+        arg1: &Value,
+        arg2: &Value,
+    ) {
+        let val1_reg = self.value_to_register(arg1);
+        let val2_reg = self.value_to_register(arg2);
+        let reg = match arg1.get_type(self.context) {
+            Some(Type::BigUint(nbits)) => self.compile_big_uint_binary_op(
+                instr_val,
+                op,
+                val1_reg,
+                val2_reg,
+                nbits as u64 / 64,
+            ),
+            Some(Type::Uint(nbits)) if self.overflow_checks => {
+                self.compile_checked_uint_binary_op(instr_val, op, val1_reg, val2_reg, nbits)
+            }
+            _otherwise => {
+                let res_reg = self.reg_seqr.next();
+                let opcode = match op {
+                    BinaryOpKind::Add => VirtualOp::ADD(res_reg.clone(), val1_reg, val2_reg),
+                    BinaryOpKind::Sub => VirtualOp::SUB(res_reg.clone(), val1_reg, val2_reg),
+                    BinaryOpKind::Mul => VirtualOp::MUL(res_reg.clone(), val1_reg, val2_reg),
+                    BinaryOpKind::Div => VirtualOp::DIV(res_reg.clone(), val1_reg, val2_reg),
+                };
+                self.bytecode.push(Op {
+                    opcode: Either::Left(opcode),
+                    comment: String::new(),
+                    owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
+                });
+                res_reg
+            }
+        };
+        self.reg_map.insert(*instr_val, reg);
+    }
+
     fn compile_bitcast(&mut self, instr_val: &Value, bitcast_val: &Value, to_type: &Type) {
         let val_reg = self.value_to_register(bitcast_val);
         let reg = if let Type::Bool = to_type {
@@ -754,6 +973,7 @@ impl<'ir> AsmBuilder<'ir> {
                 )),
                 comment: "convert to inversed boolean".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
             self.bytecode.push(Op {
                 opcode: Either::Left(VirtualOp::XORI(
@@ -763,6 +983,7 @@ impl<'ir> AsmBuilder<'ir> {
                 )),
                 comment: "invert boolean".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
             res_reg
         } else {
@@ -789,17 +1010,768 @@ impl<'ir> AsmBuilder<'ir> {
     ) {
         let lhs_reg = self.value_to_register(lhs_value);
         let rhs_reg = self.value_to_register(rhs_value);
+        let span = instr_val.get_span(self.context);
+        let res_reg = match lhs_value.get_type(self.context) {
+            Some(Type::BigUint(nbits)) => {
+                let nwords = nbits as u64 / 64;
+                match pred {
+                    Predicate::Equal => {
+                        let res_reg = self.reg_seqr.next();
+                        let len_reg = self.reg_seqr.next();
+                        self.push_op(
+                            VirtualOp::MOVI(
+                                len_reg.clone(),
+                                VirtualImmediate18 {
+                                    value: (nwords * 8) as u32,
+                                },
+                            ),
+                            "wide integer size in bytes",
+                            &span,
+                        );
+                        self.push_op(
+                            VirtualOp::MEQ(res_reg.clone(), lhs_reg, rhs_reg, len_reg),
+                            "",
+                            &span,
+                        );
+                        res_reg
+                    }
+                    Predicate::LessThan => {
+                        self.compile_big_uint_lt(&lhs_reg, &rhs_reg, nwords, &span)
+                    }
+                    Predicate::GreaterThan => {
+                        self.compile_big_uint_lt(&rhs_reg, &lhs_reg, nwords, &span)
+                    }
+                }
+            }
+            _otherwise => {
+                let res_reg = self.reg_seqr.next();
+                let opcode = match pred {
+                    Predicate::Equal => VirtualOp::EQ(res_reg.clone(), lhs_reg, rhs_reg),
+                    Predicate::LessThan => VirtualOp::LT(res_reg.clone(), lhs_reg, rhs_reg),
+                    Predicate::GreaterThan => VirtualOp::GT(res_reg.clone(), lhs_reg, rhs_reg),
+                };
+                self.push_op(opcode, "", &span);
+                res_reg
+            }
+        };
+        self.reg_map.insert(*instr_val, res_reg);
+    }
+
+    fn push_op(&mut self, opcode: VirtualOp, comment: &str, span: &Option<Span>) {
+        self.bytecode.push(Op {
+            opcode: Either::Left(opcode),
+            comment: comment.into(),
+            owning_span: span.clone(),
+            inlined_at: Vec::new(),
+        });
+    }
+
+    // With overflow checks enabled an operation whose result doesn't fit in `nbits` reverts with
+    // `ARITHMETIC_OVERFLOW_SIGNAL`.  Narrower integers are held in full words, so their sums and
+    // products can't overflow the word and are checked by looking for bits above `nbits`.  Full
+    // words are added and multiplied in wrapping mode and checked via `$of` instead.
+    fn compile_checked_uint_binary_op(
+        &mut self,
+        instr_val: &Value,
+        op: &BinaryOpKind,
+        lhs_reg: VirtualRegister,
+        rhs_reg: VirtualRegister,
+        nbits: u8,
+    ) -> VirtualRegister {
+        let span = instr_val.get_span(self.context);
         let res_reg = self.reg_seqr.next();
-        match pred {
-            Predicate::Equal => {
-                self.bytecode.push(Op {
-                    opcode: Either::Left(VirtualOp::EQ(res_reg.clone(), lhs_reg, rhs_reg)),
-                    comment: String::new(),
-                    owning_span: instr_val.get_span(self.context),
-                });
+        let overflow_reg = self.reg_seqr.next();
+        match op {
+            BinaryOpKind::Sub => {
+                self.push_op(
+                    VirtualOp::LT(overflow_reg.clone(), lhs_reg.clone(), rhs_reg.clone()),
+                    "check for underflow",
+                    &span,
+                );
+                self.compile_revert_on_overflow(overflow_reg, &span);
+                self.push_op(VirtualOp::SUB(res_reg.clone(), lhs_reg, rhs_reg), "", &span);
+            }
+            BinaryOpKind::Div => {
+                self.push_op(VirtualOp::DIV(res_reg.clone(), lhs_reg, rhs_reg), "", &span);
+            }
+            BinaryOpKind::Add | BinaryOpKind::Mul => {
+                let opcode = if let BinaryOpKind::Add = op {
+                    VirtualOp::ADD(res_reg.clone(), lhs_reg, rhs_reg)
+                } else {
+                    VirtualOp::MUL(res_reg.clone(), lhs_reg, rhs_reg)
+                };
+                if nbits < 64 {
+                    self.push_op(opcode, "", &span);
+                    self.push_op(
+                        VirtualOp::SRLI(
+                            overflow_reg.clone(),
+                            res_reg.clone(),
+                            VirtualImmediate12 {
+                                value: nbits as u16,
+                            },
+                        ),
+                        "check for overflow",
+                        &span,
+                    );
+                } else {
+                    let saved_flags_reg = self.compile_enable_wrapping(&span);
+                    self.push_op(opcode, "", &span);
+                    self.push_op(
+                        VirtualOp::MOVE(
+                            overflow_reg.clone(),
+                            VirtualRegister::Constant(ConstantRegister::Overflow),
+                        ),
+                        "check for overflow",
+                        &span,
+                    );
+                    self.push_op(VirtualOp::FLAG(saved_flags_reg), "restore flags", &span);
+                }
+                self.compile_revert_on_overflow(overflow_reg, &span);
             }
         }
-        self.reg_map.insert(*instr_val, res_reg);
+        res_reg
+    }
+
+    // Returns a register holding the current flags, which should be restored once the wrapping
+    // arithmetic is done.
+    fn compile_enable_wrapping(&mut self, span: &Option<Span>) -> VirtualRegister {
+        let saved_flags_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::MOVE(
+                saved_flags_reg.clone(),
+                VirtualRegister::Constant(ConstantRegister::Flags),
+            ),
+            "save flags",
+            span,
+        );
+        let wrapping_flags_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::ORI(
+                wrapping_flags_reg.clone(),
+                saved_flags_reg.clone(),
+                VirtualImmediate12 {
+                    value: compiler_constants::FLAG_WRAPPING,
+                },
+            ),
+            "",
+            span,
+        );
+        self.push_op(
+            VirtualOp::FLAG(wrapping_flags_reg),
+            "enable wrapping arithmetic",
+            span,
+        );
+        saved_flags_reg
+    }
+
+    // Reverts with `ARITHMETIC_OVERFLOW_SIGNAL` if `overflow_reg` is non-zero.
+    fn compile_revert_on_overflow(&mut self, overflow_reg: VirtualRegister, span: &Option<Span>) {
+        let no_overflow_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::EQ(
+                no_overflow_reg.clone(),
+                overflow_reg,
+                VirtualRegister::Constant(ConstantRegister::Zero),
+            ),
+            "",
+            span,
+        );
+        let ok_label = self.reg_seqr.get_label();
+        self.bytecode
+            .push(Op::jump_if_not_zero(no_overflow_reg, ok_label.clone()));
+        let signal_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::MOVI(
+                signal_reg.clone(),
+                VirtualImmediate18 {
+                    value: compiler_constants::ARITHMETIC_OVERFLOW_SIGNAL,
+                },
+            ),
+            "",
+            span,
+        );
+        self.push_op(VirtualOp::RVRT(signal_reg), "revert on overflow", span);
+        self.bytecode.push(Op::unowned_jump_label(ok_label));
+    }
+
+    // Wide integers are handled by reference and are stored big-endian, so the most significant
+    // word comes first.  The result of each operation is written to stack space reserved by
+    // `add_locals()`.
+    //
+    // The words are combined with the VM in wrapping mode so that carries and borrows can be
+    // tracked explicitly.  The original flags are restored before the final overflow check, which
+    // panics in exactly the same way as native `u64` arithmetic would, or reverts with
+    // `ARITHMETIC_OVERFLOW_SIGNAL` if overflow checks are enabled.
+    fn compile_big_uint_binary_op(
+        &mut self,
+        instr_val: &Value,
+        op: &BinaryOpKind,
+        lhs_ptr: VirtualRegister,
+        rhs_ptr: VirtualRegister,
+        nwords: u64,
+    ) -> VirtualRegister {
+        let span = instr_val.get_span(self.context);
+        let zero = VirtualRegister::Constant(ConstantRegister::Zero);
+        let scratch_offs = *self
+            .scratch_map
+            .get(instr_val)
+            .expect("Wide integer operations always have scratch space reserved.");
+        let base_reg = self.stack_base_reg.as_ref().unwrap().clone();
+        let res_ptr = self.offset_reg(&base_reg, scratch_offs * 8, span.clone());
+
+        if let BinaryOpKind::Div = op {
+            // Check the divisor before the flags are changed, so that division by zero behaves
+            // just like it does for `u64`.
+            let any_bits_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::MOVE(any_bits_reg.clone(), zero.clone()),
+                "",
+                &span,
+            );
+            for word in 0..nwords {
+                let word_reg = self.reg_seqr.next();
+                self.push_op(
+                    VirtualOp::LW(
+                        word_reg.clone(),
+                        rhs_ptr.clone(),
+                        VirtualImmediate12 { value: word as u16 },
+                    ),
+                    "",
+                    &span,
+                );
+                self.push_op(
+                    VirtualOp::OR(any_bits_reg.clone(), any_bits_reg.clone(), word_reg),
+                    "",
+                    &span,
+                );
+            }
+            let tmp_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::DIV(
+                    tmp_reg,
+                    VirtualRegister::Constant(ConstantRegister::One),
+                    any_bits_reg,
+                ),
+                "panic if dividing by zero",
+                &span,
+            );
+        }
+
+        let saved_flags_reg = self.compile_enable_wrapping(&span);
+
+        let overflow_reg = match op {
+            BinaryOpKind::Add => {
+                Some(self.compile_big_uint_add(&lhs_ptr, &rhs_ptr, &res_ptr, nwords, &span))
+            }
+            BinaryOpKind::Sub => {
+                Some(self.compile_big_uint_sub(&lhs_ptr, &rhs_ptr, &res_ptr, nwords, &span))
+            }
+            BinaryOpKind::Mul => {
+                Some(self.compile_big_uint_mul(&lhs_ptr, &rhs_ptr, &res_ptr, nwords, &span))
+            }
+            BinaryOpKind::Div => {
+                let rem_ptr = self.offset_reg(&base_reg, (scratch_offs + nwords) * 8, span.clone());
+                self.compile_big_uint_div(&lhs_ptr, &rhs_ptr, &res_ptr, &rem_ptr, nwords, &span);
+                None
+            }
+        };
+
+        self.push_op(VirtualOp::FLAG(saved_flags_reg), "restore flags", &span);
+        match overflow_reg {
+            Some(overflow_reg) if self.overflow_checks => {
+                self.compile_revert_on_overflow(overflow_reg, &span);
+            }
+            Some(overflow_reg) => {
+                // `overflow_reg` is either 0 or 1, so adding it to u64::MAX will panic on overflow.
+                let max_reg = self.reg_seqr.next();
+                self.push_op(VirtualOp::NOT(max_reg.clone(), zero), "", &span);
+                self.push_op(
+                    VirtualOp::ADD(max_reg.clone(), max_reg, overflow_reg),
+                    "panic on overflow",
+                    &span,
+                );
+            }
+            None => (),
+        }
+
+        res_ptr
+    }
+
+    // Returns a register which is 1 if the sum overflowed, otherwise 0.
+    fn compile_big_uint_add(
+        &mut self,
+        lhs_ptr: &VirtualRegister,
+        rhs_ptr: &VirtualRegister,
+        res_ptr: &VirtualRegister,
+        nwords: u64,
+        span: &Option<Span>,
+    ) -> VirtualRegister {
+        let overflow = VirtualRegister::Constant(ConstantRegister::Overflow);
+        let carry_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::MOVE(
+                carry_reg.clone(),
+                VirtualRegister::Constant(ConstantRegister::Zero),
+            ),
+            "",
+            span,
+        );
+        for word in (0..nwords).rev() {
+            let imm = VirtualImmediate12 { value: word as u16 };
+            let lhs_reg = self.reg_seqr.next();
+            let rhs_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::LW(lhs_reg.clone(), lhs_ptr.clone(), imm.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::LW(rhs_reg.clone(), rhs_ptr.clone(), imm.clone()),
+                "",
+                span,
+            );
+            let sum_reg = self.reg_seqr.next();
+            let carry_a_reg = self.reg_seqr.next();
+            let carry_b_reg = self.reg_seqr.next();
+            self.push_op(VirtualOp::ADD(sum_reg.clone(), lhs_reg, rhs_reg), "", span);
+            self.push_op(
+                VirtualOp::MOVE(carry_a_reg.clone(), overflow.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::ADD(sum_reg.clone(), sum_reg.clone(), carry_reg.clone()),
+                "add carry",
+                span,
+            );
+            self.push_op(
+                VirtualOp::MOVE(carry_b_reg.clone(), overflow.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::ADD(carry_reg.clone(), carry_a_reg, carry_b_reg),
+                "",
+                span,
+            );
+            self.push_op(VirtualOp::SW(res_ptr.clone(), sum_reg, imm), "", span);
+        }
+        carry_reg
+    }
+
+    // Returns a register which is 1 if the difference underflowed, otherwise 0.  `res_ptr` may be
+    // the same as `lhs_ptr`.
+    fn compile_big_uint_sub(
+        &mut self,
+        lhs_ptr: &VirtualRegister,
+        rhs_ptr: &VirtualRegister,
+        res_ptr: &VirtualRegister,
+        nwords: u64,
+        span: &Option<Span>,
+    ) -> VirtualRegister {
+        let borrow_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::MOVE(
+                borrow_reg.clone(),
+                VirtualRegister::Constant(ConstantRegister::Zero),
+            ),
+            "",
+            span,
+        );
+        for word in (0..nwords).rev() {
+            let imm = VirtualImmediate12 { value: word as u16 };
+            let lhs_reg = self.reg_seqr.next();
+            let rhs_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::LW(lhs_reg.clone(), lhs_ptr.clone(), imm.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::LW(rhs_reg.clone(), rhs_ptr.clone(), imm.clone()),
+                "",
+                span,
+            );
+            // Only one of these borrows can be set: if the first is then the difference is at
+            // least 1.
+            let borrow_a_reg = self.reg_seqr.next();
+            let borrow_b_reg = self.reg_seqr.next();
+            let diff_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::LT(borrow_a_reg.clone(), lhs_reg.clone(), rhs_reg.clone()),
+                "",
+                span,
+            );
+            self.push_op(VirtualOp::SUB(diff_reg.clone(), lhs_reg, rhs_reg), "", span);
+            self.push_op(
+                VirtualOp::LT(borrow_b_reg.clone(), diff_reg.clone(), borrow_reg.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::SUB(diff_reg.clone(), diff_reg.clone(), borrow_reg.clone()),
+                "subtract borrow",
+                span,
+            );
+            self.push_op(
+                VirtualOp::ADD(borrow_reg.clone(), borrow_a_reg, borrow_b_reg),
+                "",
+                span,
+            );
+            self.push_op(VirtualOp::SW(res_ptr.clone(), diff_reg, imm), "", span);
+        }
+        borrow_reg
+    }
+
+    // Long multiplication, keeping only the low `nwords` words.  Returns a register which is 1 if
+    // any of the discarded words would have been non-zero, otherwise 0.
+    fn compile_big_uint_mul(
+        &mut self,
+        lhs_ptr: &VirtualRegister,
+        rhs_ptr: &VirtualRegister,
+        res_ptr: &VirtualRegister,
+        nwords: u64,
+        span: &Option<Span>,
+    ) -> VirtualRegister {
+        let zero = VirtualRegister::Constant(ConstantRegister::Zero);
+        let overflow = VirtualRegister::Constant(ConstantRegister::Overflow);
+
+        // Words are counted from the least significant here, so convert them to memory offsets.
+        let word_imm = |idx: u64| VirtualImmediate12 {
+            value: (nwords - 1 - idx) as u16,
+        };
+
+        for word in 0..nwords {
+            self.push_op(
+                VirtualOp::SW(res_ptr.clone(), zero.clone(), word_imm(word)),
+                "",
+                span,
+            );
+        }
+        let discarded_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::MOVE(discarded_reg.clone(), zero.clone()),
+            "",
+            span,
+        );
+
+        for lhs_idx in 0..nwords {
+            let lhs_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::LW(lhs_reg.clone(), lhs_ptr.clone(), word_imm(lhs_idx)),
+                "",
+                span,
+            );
+            let carry_reg = self.reg_seqr.next();
+            self.push_op(VirtualOp::MOVE(carry_reg.clone(), zero.clone()), "", span);
+            for rhs_idx in 0..nwords {
+                let rhs_reg = self.reg_seqr.next();
+                self.push_op(
+                    VirtualOp::LW(rhs_reg.clone(), rhs_ptr.clone(), word_imm(rhs_idx)),
+                    "",
+                    span,
+                );
+                let lo_reg = self.reg_seqr.next();
+                self.push_op(
+                    VirtualOp::MUL(lo_reg.clone(), lhs_reg.clone(), rhs_reg),
+                    "",
+                    span,
+                );
+                if lhs_idx + rhs_idx < nwords {
+                    // res[i + j] += lo + carry, with the high word and any carries going into the
+                    // next word.  This can't overflow the carry: (2^64 - 1)^2 + 2(2^64 - 1) is
+                    // exactly 2^128 - 1.
+                    let hi_reg = self.reg_seqr.next();
+                    let acc_reg = self.reg_seqr.next();
+                    let carry_a_reg = self.reg_seqr.next();
+                    let carry_b_reg = self.reg_seqr.next();
+                    let acc_imm = word_imm(lhs_idx + rhs_idx);
+                    self.push_op(VirtualOp::MOVE(hi_reg.clone(), overflow.clone()), "", span);
+                    self.push_op(
+                        VirtualOp::LW(acc_reg.clone(), res_ptr.clone(), acc_imm.clone()),
+                        "",
+                        span,
+                    );
+                    self.push_op(
+                        VirtualOp::ADD(acc_reg.clone(), acc_reg.clone(), lo_reg),
+                        "",
+                        span,
+                    );
+                    self.push_op(
+                        VirtualOp::MOVE(carry_a_reg.clone(), overflow.clone()),
+                        "",
+                        span,
+                    );
+                    self.push_op(
+                        VirtualOp::ADD(acc_reg.clone(), acc_reg.clone(), carry_reg.clone()),
+                        "",
+                        span,
+                    );
+                    self.push_op(
+                        VirtualOp::MOVE(carry_b_reg.clone(), overflow.clone()),
+                        "",
+                        span,
+                    );
+                    self.push_op(VirtualOp::SW(res_ptr.clone(), acc_reg, acc_imm), "", span);
+                    self.push_op(
+                        VirtualOp::ADD(carry_reg.clone(), hi_reg, carry_a_reg),
+                        "",
+                        span,
+                    );
+                    self.push_op(
+                        VirtualOp::ADD(carry_reg.clone(), carry_reg.clone(), carry_b_reg),
+                        "",
+                        span,
+                    );
+                } else {
+                    // This product lands entirely outside of the result.  The high word must be
+                    // copied out of `$of` before the next ALU op clears it.
+                    let hi_reg = self.reg_seqr.next();
+                    self.push_op(VirtualOp::MOVE(hi_reg.clone(), overflow.clone()), "", span);
+                    self.push_op(
+                        VirtualOp::OR(discarded_reg.clone(), discarded_reg.clone(), lo_reg),
+                        "",
+                        span,
+                    );
+                    self.push_op(
+                        VirtualOp::OR(discarded_reg.clone(), discarded_reg.clone(), hi_reg),
+                        "",
+                        span,
+                    );
+                }
+            }
+            // The final carry for this row is always beyond the most significant word.
+            self.push_op(
+                VirtualOp::OR(discarded_reg.clone(), discarded_reg.clone(), carry_reg),
+                "",
+                span,
+            );
+        }
+
+        let overflow_bit_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::GT(overflow_bit_reg.clone(), discarded_reg, zero),
+            "",
+            span,
+        );
+        overflow_bit_reg
+    }
+
+    // Restoring shift-subtract division.  The dividend is copied to the quotient and then shifted,
+    // a bit at a time, into the remainder.  Whenever the remainder is at least the divisor it is
+    // reduced and the new low bit of the quotient is set.
+    fn compile_big_uint_div(
+        &mut self,
+        lhs_ptr: &VirtualRegister,
+        rhs_ptr: &VirtualRegister,
+        quot_ptr: &VirtualRegister,
+        rem_ptr: &VirtualRegister,
+        nwords: u64,
+        span: &Option<Span>,
+    ) {
+        let zero = VirtualRegister::Constant(ConstantRegister::Zero);
+        let low_word_imm = VirtualImmediate12 {
+            value: (nwords - 1) as u16,
+        };
+
+        self.push_op(
+            VirtualOp::MCPI(
+                quot_ptr.clone(),
+                lhs_ptr.clone(),
+                VirtualImmediate12 {
+                    value: (nwords * 8) as u16,
+                },
+            ),
+            "copy dividend to quotient",
+            span,
+        );
+        for word in 0..nwords {
+            self.push_op(
+                VirtualOp::SW(
+                    rem_ptr.clone(),
+                    zero.clone(),
+                    VirtualImmediate12 { value: word as u16 },
+                ),
+                "",
+                span,
+            );
+        }
+        let count_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::MOVI(
+                count_reg.clone(),
+                VirtualImmediate18 {
+                    value: (nwords * 64) as u32,
+                },
+            ),
+            "",
+            span,
+        );
+
+        let loop_label = self.add_label();
+
+        // Shift the remainder and quotient left by one bit as a single number.
+        let carry_reg = self.reg_seqr.next();
+        self.push_op(VirtualOp::MOVE(carry_reg.clone(), zero.clone()), "", span);
+        for ptr in [quot_ptr, rem_ptr] {
+            for word in (0..nwords).rev() {
+                let imm = VirtualImmediate12 { value: word as u16 };
+                let word_reg = self.reg_seqr.next();
+                let top_bit_reg = self.reg_seqr.next();
+                self.push_op(
+                    VirtualOp::LW(word_reg.clone(), ptr.clone(), imm.clone()),
+                    "",
+                    span,
+                );
+                self.push_op(
+                    VirtualOp::SRLI(
+                        top_bit_reg.clone(),
+                        word_reg.clone(),
+                        VirtualImmediate12 { value: 63 },
+                    ),
+                    "",
+                    span,
+                );
+                self.push_op(
+                    VirtualOp::SLLI(
+                        word_reg.clone(),
+                        word_reg.clone(),
+                        VirtualImmediate12 { value: 1 },
+                    ),
+                    "",
+                    span,
+                );
+                self.push_op(
+                    VirtualOp::OR(word_reg.clone(), word_reg.clone(), carry_reg.clone()),
+                    "",
+                    span,
+                );
+                self.push_op(VirtualOp::SW(ptr.clone(), word_reg, imm), "", span);
+                self.push_op(VirtualOp::MOVE(carry_reg.clone(), top_bit_reg), "", span);
+            }
+        }
+
+        // If a bit was shifted out of the remainder then it's definitely larger than the divisor,
+        // otherwise compare them.
+        let skip_label = self.reg_seqr.get_label();
+        let lt_reg = self.compile_big_uint_lt(rem_ptr, rhs_ptr, nwords, span);
+        let no_carry_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::EQ(no_carry_reg.clone(), carry_reg, zero),
+            "",
+            span,
+        );
+        self.push_op(
+            VirtualOp::AND(lt_reg.clone(), lt_reg.clone(), no_carry_reg),
+            "",
+            span,
+        );
+        self.bytecode
+            .push(Op::jump_if_not_zero(lt_reg, skip_label.clone()));
+        self.compile_big_uint_sub(rem_ptr, rhs_ptr, rem_ptr, nwords, span);
+        let low_word_reg = self.reg_seqr.next();
+        self.push_op(
+            VirtualOp::LW(low_word_reg.clone(), quot_ptr.clone(), low_word_imm.clone()),
+            "",
+            span,
+        );
+        self.push_op(
+            VirtualOp::ORI(
+                low_word_reg.clone(),
+                low_word_reg.clone(),
+                VirtualImmediate12 { value: 1 },
+            ),
+            "set quotient bit",
+            span,
+        );
+        self.push_op(
+            VirtualOp::SW(quot_ptr.clone(), low_word_reg, low_word_imm),
+            "",
+            span,
+        );
+        self.bytecode.push(Op::unowned_jump_label(skip_label));
+
+        self.push_op(
+            VirtualOp::SUBI(
+                count_reg.clone(),
+                count_reg.clone(),
+                VirtualImmediate12 { value: 1 },
+            ),
+            "",
+            span,
+        );
+        self.bytecode
+            .push(Op::jump_if_not_zero(count_reg, loop_label));
+    }
+
+    // Returns a register which is 1 if `lhs` is less than `rhs`, otherwise 0.  The words are
+    // compared from the most significant and the first which differs decides the result.
+    fn compile_big_uint_lt(
+        &mut self,
+        lhs_ptr: &VirtualRegister,
+        rhs_ptr: &VirtualRegister,
+        nwords: u64,
+        span: &Option<Span>,
+    ) -> VirtualRegister {
+        let zero = VirtualRegister::Constant(ConstantRegister::Zero);
+        let lt_reg = self.reg_seqr.next();
+        let decided_reg = self.reg_seqr.next();
+        self.push_op(VirtualOp::MOVE(lt_reg.clone(), zero.clone()), "", span);
+        self.push_op(VirtualOp::MOVE(decided_reg.clone(), zero.clone()), "", span);
+        for word in 0..nwords {
+            let imm = VirtualImmediate12 { value: word as u16 };
+            let lhs_reg = self.reg_seqr.next();
+            let rhs_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::LW(lhs_reg.clone(), lhs_ptr.clone(), imm.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::LW(rhs_reg.clone(), rhs_ptr.clone(), imm),
+                "",
+                span,
+            );
+            let word_lt_reg = self.reg_seqr.next();
+            let word_gt_reg = self.reg_seqr.next();
+            let undecided_reg = self.reg_seqr.next();
+            self.push_op(
+                VirtualOp::LT(word_lt_reg.clone(), lhs_reg.clone(), rhs_reg.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::GT(word_gt_reg.clone(), lhs_reg, rhs_reg),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::EQ(undecided_reg.clone(), decided_reg.clone(), zero.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::AND(word_lt_reg.clone(), word_lt_reg.clone(), undecided_reg),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::OR(lt_reg.clone(), lt_reg.clone(), word_lt_reg.clone()),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::OR(decided_reg.clone(), decided_reg.clone(), word_lt_reg),
+                "",
+                span,
+            );
+            self.push_op(
+                VirtualOp::OR(decided_reg.clone(), decided_reg.clone(), word_gt_reg),
+                "",
+                span,
+            );
+        }
+        lt_reg
     }
 
     fn compile_conditional_branch(
@@ -835,6 +1807,84 @@ impl<'ir> AsmBuilder<'ir> {
                 ));
             }
         }
+        self.compile_induction_offset_updates(from_block, to_block);
+    }
+
+    // Keep the element offsets for the induction variables of `to_block` up to date.  From inside
+    // the loop an induction variable is incremented by a constant, and so is its offset.  From
+    // outside the loop the offset is calculated from the initial value.
+    fn compile_induction_offset_updates(&mut self, from_block: &Block, to_block: &Block) {
+        let phi_val = to_block.get_phi(self.context);
+        let offsets = self
+            .induction_offsets
+            .iter()
+            .filter(|offset| offset.phi_val == phi_val)
+            .map(|offset| (offset.elem_size, offset.reg.clone()))
+            .collect::<Vec<_>>();
+        if offsets.is_empty() {
+            return;
+        }
+        let incoming_val = match to_block.get_phi_val_coming_from(self.context, from_block) {
+            Some(incoming_val) => incoming_val,
+            None => return,
+        };
+        let span = incoming_val.get_span(self.context);
+        for (elem_size, offs_reg) in offsets {
+            match self.induction_step_offset(&phi_val, &incoming_val, elem_size) {
+                Some(step_offset) => self.push_op(
+                    VirtualOp::ADDI(
+                        offs_reg.clone(),
+                        offs_reg,
+                        VirtualImmediate12 {
+                            value: step_offset as u16,
+                        },
+                    ),
+                    "increment element offset",
+                    &span,
+                ),
+                None => {
+                    let incoming_reg = self.value_to_register(&incoming_val);
+                    self.push_op(
+                        VirtualOp::MULI(
+                            offs_reg,
+                            incoming_reg,
+                            VirtualImmediate12 {
+                                value: elem_size as u16,
+                            },
+                        ),
+                        "initial element offset",
+                        &span,
+                    );
+                }
+            }
+        }
+    }
+
+    // If `value` is `phi_val` incremented by a constant, return how much its offset into an array
+    // of `elem_size` byte elements increases, as long as that fits in 12 bits.
+    fn induction_step_offset(&self, phi_val: &Value, value: &Value, elem_size: u64) -> Option<u64> {
+        let step = match &self.context.values[value.0].value {
+            ValueDatum::Instruction(Instruction::BinaryOp {
+                op: BinaryOpKind::Add,
+                arg1,
+                arg2,
+            }) if arg1 == phi_val => arg2,
+            ValueDatum::Instruction(Instruction::BinaryOp {
+                op: BinaryOpKind::Add,
+                arg1,
+                arg2,
+            }) if arg2 == phi_val => arg1,
+            _otherwise => return None,
+        };
+        match &self.context.values[step.0].value {
+            ValueDatum::Constant(Constant {
+                value: ConstantValue::Uint(step),
+                ..
+            }) => step
+                .checked_mul(elem_size)
+                .filter(|step_offset| *step_offset <= compiler_constants::TWELVE_BITS),
+            _otherwise => None,
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -860,6 +1910,7 @@ impl<'ir> AsmBuilder<'ir> {
             )),
             comment: "call external contract".into(),
             owning_span: instr_val.get_span(self.context),
+            inlined_at: Vec::new(),
         });
 
         // now, move the return value of the contract call to the return register.
@@ -892,20 +1943,23 @@ impl<'ir> AsmBuilder<'ir> {
         let elem_size =
             ir_type_size_in_bytes(self.context, &ty.get_elem_type(self.context).unwrap());
         if elem_size <= 8 {
-            self.bytecode.push(Op {
-                opcode: Either::Left(VirtualOp::MULI(
-                    index_reg.clone(),
-                    index_reg.clone(),
-                    VirtualImmediate12 { value: 8 },
-                )),
-                comment: "extract_element relative offset".into(),
-                owning_span: instr_val.get_span(self.context),
-            });
+            let rel_offs_reg = self.compile_element_offset(
+                instr_val,
+                index_val,
+                index_reg,
+                8,
+                "extract_element relative offset",
+            );
             let elem_offs_reg = self.reg_seqr.next();
             self.bytecode.push(Op {
-                opcode: Either::Left(VirtualOp::ADD(elem_offs_reg.clone(), base_reg, index_reg)),
+                opcode: Either::Left(VirtualOp::ADD(
+                    elem_offs_reg.clone(),
+                    base_reg,
+                    rel_offs_reg,
+                )),
                 comment: "extract_element absolute offset".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
             self.bytecode.push(Op {
                 opcode: Either::Left(VirtualOp::LW(
@@ -915,51 +1969,84 @@ impl<'ir> AsmBuilder<'ir> {
                 )),
                 comment: "extract_element".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
         } else {
             // Value too big for a register, so we return the memory offset.
-            if elem_size > compiler_constants::TWELVE_BITS {
-                let size_data_id = self
-                    .data_section
-                    .insert_data_value(&Literal::U64(elem_size));
+            let rel_offs_reg = if elem_size > compiler_constants::TWELVE_BITS {
                 let size_reg = self.reg_seqr.next();
-                self.bytecode.push(Op {
-                    opcode: Either::Left(VirtualOp::LWDataId(size_reg.clone(), size_data_id)),
-                    owning_span: instr_val.get_span(self.context),
-                    comment: "loading element size for relative offset".into(),
-                });
+                self.load_constant(
+                    &size_reg,
+                    &Literal::U64(elem_size),
+                    "loading element size for relative offset",
+                    instr_val.get_span(self.context),
+                );
                 self.bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::MUL(instr_reg.clone(), index_reg, size_reg)),
                     comment: "extract_element relative offset".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
+                instr_reg.clone()
             } else {
-                self.bytecode.push(Op {
-                    opcode: Either::Left(VirtualOp::MULI(
-                        instr_reg.clone(),
-                        index_reg,
-                        VirtualImmediate12 {
-                            value: elem_size as u16,
-                        },
-                    )),
-                    comment: "extract_element relative offset".into(),
-                    owning_span: instr_val.get_span(self.context),
-                });
-            }
+                self.compile_element_offset(
+                    instr_val,
+                    index_val,
+                    index_reg,
+                    elem_size,
+                    "extract_element relative offset",
+                )
+            };
             self.bytecode.push(Op {
-                opcode: Either::Left(VirtualOp::ADD(
-                    instr_reg.clone(),
-                    base_reg,
-                    instr_reg.clone(),
-                )),
+                opcode: Either::Left(VirtualOp::ADD(instr_reg.clone(), base_reg, rel_offs_reg)),
                 comment: "extract_element absolute offset".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
         }
 
         self.reg_map.insert(*instr_val, instr_reg);
     }
 
+    // Return a register holding the byte offset of element `index_val`, which is in `index_reg`,
+    // in an array whose elements are `elem_size` bytes each, which must fit in 12 bits.
+    fn compile_element_offset(
+        &mut self,
+        instr_val: &Value,
+        index_val: &Value,
+        index_reg: VirtualRegister,
+        elem_size: u64,
+        comment: &str,
+    ) -> VirtualRegister {
+        if let Some(offset) = self
+            .induction_offsets
+            .iter()
+            .find(|offset| offset.phi_val == *index_val && offset.elem_size == elem_size)
+        {
+            return offset.reg.clone();
+        }
+
+        // A constant index is loaded into a new register for each use, so it can be scaled in
+        // place, but any other index may still be needed.
+        let offs_reg = if index_val.is_constant(self.context) {
+            index_reg.clone()
+        } else {
+            self.reg_seqr.next()
+        };
+        self.push_op(
+            VirtualOp::MULI(
+                offs_reg.clone(),
+                index_reg,
+                VirtualImmediate12 {
+                    value: elem_size as u16,
+                },
+            ),
+            comment,
+            &instr_val.get_span(self.context),
+        );
+        offs_reg
+    }
+
     fn compile_extract_value(&mut self, instr_val: &Value, aggregate_val: &Value, indices: &[u64]) {
         // Base register should pointer to some stack allocated memory.
         let base_reg = self.value_to_register(aggregate_val);
@@ -986,6 +2073,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: "add array base to offset".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
                 self.bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::LW(
@@ -1002,6 +2090,7 @@ impl<'ir> AsmBuilder<'ir> {
                             .join(",")
                     ),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             } else {
                 self.bytecode.push(Op {
@@ -1021,6 +2110,7 @@ impl<'ir> AsmBuilder<'ir> {
                             .join(",")
                     ),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
         } else {
@@ -1040,6 +2130,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: "extract address".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             } else {
                 self.bytecode.push(Op {
@@ -1052,6 +2143,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: "extract address".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
         }
@@ -1108,6 +2200,7 @@ impl<'ir> AsmBuilder<'ir> {
             opcode: either::Either::Left(VirtualOp::LWDataId(reg.clone(), data_id)),
             comment: "literal instantiation".into(),
             owning_span: instr_span,
+            inlined_at: Vec::new(),
         });
         self.reg_map.insert(*instr_val, reg);
         ok((), warnings, errors)
@@ -1147,6 +2240,7 @@ impl<'ir> AsmBuilder<'ir> {
                             )),
                             comment: "get offset reg for get_ptr".into(),
                             owning_span: instr_val.get_span(self.context),
+                            inlined_at: Vec::new(),
                         });
                     } else {
                         self.bytecode.push(Op {
@@ -1159,6 +2253,7 @@ impl<'ir> AsmBuilder<'ir> {
                             )),
                             comment: "get offset reg for get_ptr".into(),
                             owning_span: instr_val.get_span(self.context),
+                            inlined_at: Vec::new(),
                         });
                     }
                     self.reg_map.insert(*instr_val, instr_reg);
@@ -1185,24 +2280,23 @@ impl<'ir> AsmBuilder<'ir> {
         let elem_size =
             ir_type_size_in_bytes(self.context, &ty.get_elem_type(self.context).unwrap());
         if elem_size <= 8 {
-            self.bytecode.push(Op {
-                opcode: Either::Left(VirtualOp::MULI(
-                    index_reg.clone(),
-                    index_reg.clone(),
-                    VirtualImmediate12 { value: 8 },
-                )),
-                comment: "insert_element relative offset".into(),
-                owning_span: instr_val.get_span(self.context),
-            });
+            let rel_offs_reg = self.compile_element_offset(
+                instr_val,
+                index_val,
+                index_reg,
+                8,
+                "insert_element relative offset",
+            );
             let elem_offs_reg = self.reg_seqr.next();
             self.bytecode.push(Op {
                 opcode: Either::Left(VirtualOp::ADD(
                     elem_offs_reg.clone(),
                     base_reg.clone(),
-                    index_reg,
+                    rel_offs_reg,
                 )),
                 comment: "insert_element absolute offset".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
             self.bytecode.push(Op {
                 opcode: Either::Left(VirtualOp::SW(
@@ -1212,32 +2306,30 @@ impl<'ir> AsmBuilder<'ir> {
                 )),
                 comment: "insert_element".into(),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
             });
         } else {
             // Element size is larger than 8; we switch to bytewise offsets and sizes and use MCP.
             if elem_size > compiler_constants::TWELVE_BITS {
                 todo!("array element size bigger than 4k")
             } else {
+                let rel_offs_reg = self.compile_element_offset(
+                    instr_val,
+                    index_val,
+                    index_reg,
+                    elem_size,
+                    "insert_element relative offset",
+                );
                 let elem_index_offs_reg = self.reg_seqr.next();
-                self.bytecode.push(Op {
-                    opcode: Either::Left(VirtualOp::MULI(
-                        elem_index_offs_reg.clone(),
-                        index_reg,
-                        VirtualImmediate12 {
-                            value: elem_size as u16,
-                        },
-                    )),
-                    comment: "insert_element relative offset".into(),
-                    owning_span: instr_val.get_span(self.context),
-                });
                 self.bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::ADD(
                         elem_index_offs_reg.clone(),
                         base_reg.clone(),
-                        elem_index_offs_reg.clone(),
+                        rel_offs_reg,
                     )),
                     comment: "insert_element absolute offset".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
                 self.bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::MCPI(
@@ -1249,6 +2341,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: "insert_element store value".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
         }
@@ -1308,6 +2401,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: "insert_value absolute offset".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
                 self.bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::SW(
@@ -1317,6 +2411,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: format!("insert_value @ {}", indices_str),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             } else {
                 self.bytecode.push(Op {
@@ -1329,6 +2424,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: format!("insert_value @ {}", indices_str),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
         } else {
@@ -1346,6 +2442,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: format!("get struct field(s) {} offset", indices_str),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
             if value_size_in_bytes > compiler_constants::TWELVE_BITS {
@@ -1359,6 +2456,7 @@ impl<'ir> AsmBuilder<'ir> {
                     opcode: Either::Left(VirtualOp::MCP(offs_reg, insert_reg, size_reg)),
                     comment: "store struct field value".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             } else {
                 self.bytecode.push(Op {
@@ -1371,6 +2469,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: "store struct field value".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
         }
@@ -1395,6 +2494,7 @@ impl<'ir> AsmBuilder<'ir> {
                         opcode: Either::Left(VirtualOp::LWDataId(instr_reg.clone(), data_id)),
                         comment: "load constant".into(),
                         owning_span: instr_val.get_span(self.context),
+                        inlined_at: Vec::new(),
                     });
                 }
                 Storage::Stack(word_offs) => {
@@ -1416,6 +2516,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "absolute offset for load".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                             self.bytecode.push(Op {
                                 opcode: Either::Left(VirtualOp::LW(
@@ -1425,6 +2526,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "load value".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         } else {
                             self.bytecode.push(Op {
@@ -1437,6 +2539,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "load value".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         }
                     } else {
@@ -1458,6 +2561,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "load address".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         } else {
                             self.bytecode.push(Op {
@@ -1470,6 +2574,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "load address".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         }
                     }
@@ -1480,6 +2585,45 @@ impl<'ir> AsmBuilder<'ir> {
         ok((), Vec::new(), Vec::new())
     }
 
+    fn compile_log(&mut self, instr_val: &Value, log_val: &Value, log_ty: &Type, log_id: &Value) {
+        let log_val_reg = self.value_to_register(log_val);
+        let log_id_reg = self.value_to_register(log_id);
+        let owning_span = instr_val.get_span(self.context);
+
+        if log_ty.is_copy_type() {
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::LOG(
+                    log_val_reg,
+                    log_id_reg,
+                    VirtualRegister::Constant(ConstantRegister::Zero),
+                    VirtualRegister::Constant(ConstantRegister::Zero),
+                )),
+                comment: "log value".into(),
+                owning_span,
+                inlined_at: Vec::new(),
+            });
+        } else {
+            // Reference types are logged as the data at their address.
+            let size_reg = self.reg_seqr.next();
+            self.number_to_reg(
+                ir_type_size_in_bytes(self.context, log_ty),
+                &size_reg,
+                owning_span.clone(),
+            );
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::LOGD(
+                    VirtualRegister::Constant(ConstantRegister::Zero),
+                    log_id_reg,
+                    log_val_reg,
+                    size_reg,
+                )),
+                comment: "log data".into(),
+                owning_span,
+                inlined_at: Vec::new(),
+            });
+        }
+    }
+
     fn compile_read_register(&mut self, instr_val: &Value, reg: &sway_ir::Register) {
         let instr_reg = self.reg_seqr.next();
         self.bytecode.push(Op {
@@ -1504,6 +2648,7 @@ impl<'ir> AsmBuilder<'ir> {
             )),
             comment: "move register into abi function".to_owned(),
             owning_span: instr_val.get_span(self.context),
+            inlined_at: Vec::new(),
         });
 
         self.reg_map.insert(*instr_val, instr_reg);
@@ -1519,6 +2664,7 @@ impl<'ir> AsmBuilder<'ir> {
                     ConstantRegister::Zero,
                 ))),
                 owning_span: instr_val.get_span(self.context),
+                inlined_at: Vec::new(),
                 comment: "returning unit as zero".into(),
             });
         } else {
@@ -1527,6 +2673,7 @@ impl<'ir> AsmBuilder<'ir> {
             if ret_type.is_copy_type() {
                 self.bytecode.push(Op {
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                     opcode: Either::Left(VirtualOp::RET(ret_reg)),
                     comment: "".into(),
                 });
@@ -1536,17 +2683,15 @@ impl<'ir> AsmBuilder<'ir> {
                 // it.
                 let size_reg = self.reg_seqr.next();
                 let size_in_bytes = ir_type_size_in_bytes(self.context, ret_type);
-                let size_data_id = self
-                    .data_section
-                    .insert_data_value(&Literal::U64(size_in_bytes));
-
-                self.bytecode.push(Op {
-                    opcode: Either::Left(VirtualOp::LWDataId(size_reg.clone(), size_data_id)),
-                    owning_span: instr_val.get_span(self.context),
-                    comment: "loading size for RETD".into(),
-                });
+                self.load_constant(
+                    &size_reg,
+                    &Literal::U64(size_in_bytes),
+                    "loading size for RETD",
+                    instr_val.get_span(self.context),
+                );
                 self.bytecode.push(Op {
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                     opcode: Either::Left(VirtualOp::RETD(ret_reg, size_reg)),
                     comment: "".into(),
                 });
@@ -1572,6 +2717,7 @@ impl<'ir> AsmBuilder<'ir> {
                 )),
                 comment: "get offset".into(),
                 owning_span: span,
+                inlined_at: Vec::new(),
             });
         } else {
             self.bytecode.push(Op {
@@ -1584,6 +2730,7 @@ impl<'ir> AsmBuilder<'ir> {
                 )),
                 comment: "get offset".into(),
                 owning_span: span,
+                inlined_at: Vec::new(),
             });
         }
 
@@ -1646,6 +2793,7 @@ impl<'ir> AsmBuilder<'ir> {
                     }),
                     comment: "quad word state access".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
             _ => unreachable!("Unexpected storage locations for key and val"),
@@ -1684,6 +2832,7 @@ impl<'ir> AsmBuilder<'ir> {
                     opcode: Either::Left(VirtualOp::SRW(load_reg.clone(), key_reg)),
                     comment: "single word state access".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
             _ => unreachable!("Unexpected storage location for key"),
@@ -1735,6 +2884,7 @@ impl<'ir> AsmBuilder<'ir> {
                     opcode: Either::Left(VirtualOp::SWW(key_reg, store_reg)),
                     comment: "single word state access".into(),
                     owning_span: instr_val.get_span(self.context),
+                    inlined_at: Vec::new(),
                 });
             }
             _ => unreachable!("Unexpected storage locations for key and store_val"),
@@ -1783,6 +2933,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "load for store".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                             tmp_reg
                         };
@@ -1801,6 +2952,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "store absolute offset".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                             self.bytecode.push(Op {
                                 opcode: Either::Left(VirtualOp::SW(
@@ -1810,6 +2962,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "store value".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         } else {
                             self.bytecode.push(Op {
@@ -1822,6 +2975,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "store value".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         }
                     } else {
@@ -1843,6 +2997,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "get store offset".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         } else {
                             self.bytecode.push(Op {
@@ -1855,6 +3010,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "get store offset".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         }
 
@@ -1873,6 +3029,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "store value".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         } else {
                             self.bytecode.push(Op {
@@ -1885,6 +3042,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 )),
                                 comment: "store value".into(),
                                 owning_span: instr_val.get_span(self.context),
+                                inlined_at: Vec::new(),
                             });
                         }
                     }
@@ -1918,30 +3076,23 @@ impl<'ir> AsmBuilder<'ir> {
         constant: &Constant,
         span: Option<Span>,
     ) -> VirtualRegister {
-        // Get the constant into the namespace.
+        // Allocate a register for the constant, and an instruction to load it.
         let lit = ir_constant_to_ast_literal(constant);
-        let data_id = self.data_section.insert_data_value(&lit);
-
-        // Allocate a register for it, and a load instruction.
         let reg = self.reg_seqr.next();
-        self.bytecode.push(Op {
-            opcode: either::Either::Left(VirtualOp::LWDataId(reg.clone(), data_id)),
-            comment: "literal instantiation".into(),
-            owning_span: span,
-        });
+        self.load_constant(&reg, &lit, "literal instantiation", span);
 
         // Insert the value into the map.
         //self.reg_map.insert(*value, reg.clone());
         //
         // Actually, no, don't.  It's possible for constant values to be
         // reused in the IR, especially with transforms which copy blocks
-        // around, like inlining.  The `LW`/`LWDataId` instruction above
+        // around, like inlining.  The `MOVI`/`LWDataId` instruction above
         // initialises that constant value but it may be in a conditional
         // block and not actually get evaluated for every possible
         // execution. So using the register later on by pulling it from
         // `self.reg_map` will have a potentially uninitialised register.
         //
-        // By not putting it in the map we recreate the `LW` each time it's
+        // By not putting it in the map we recreate the load each time it's
         // used, which also isn't ideal.  A better solution is to put this
         // initialisation into the IR itself, and allow for analysis there
         // to determine when it may be initialised and/or reused.
@@ -2012,21 +3163,26 @@ impl<'ir> AsmBuilder<'ir> {
             None => {
                 match &self.context.values[value.0].value {
                     // Handle constants.
-                    ValueDatum::Constant(constant) => match &value_type {
-                        Type::Unit | Type::Bool | Type::Uint(_) | Type::B256 | Type::String(_) => {
-                            Some(self.initialise_non_aggregate_type(
+                    ValueDatum::Constant(constant) => {
+                        match &value_type {
+                            Type::Unit
+                            | Type::Bool
+                            | Type::Uint(_)
+                            | Type::BigUint(_)
+                            | Type::B256
+                            | Type::String(_) => Some(self.initialise_non_aggregate_type(
                                 constant,
                                 value.get_span(self.context),
-                            ))
-                        }
-                        Type::Array(_) | Type::Struct(_) | Type::Union(_) => {
-                            Some(self.initialise_aggregate_type(
-                                constant,
-                                &value_type,
-                                value.get_span(self.context),
-                            ))
+                            )),
+                            Type::Array(_) | Type::Struct(_) | Type::Union(_) => {
+                                Some(self.initialise_aggregate_type(
+                                    constant,
+                                    &value_type,
+                                    value.get_span(self.context),
+                                ))
+                            }
                         }
-                    },
+                    }
                     _otherwise => None,
                 }
             }
@@ -2047,6 +3203,27 @@ impl<'ir> AsmBuilder<'ir> {
         }
     }
 
+    // Load the constant `lit` into `reg`, as an immediate if it's small enough or otherwise from
+    // the data section.
+    fn load_constant(
+        &mut self,
+        reg: &VirtualRegister,
+        lit: &Literal,
+        comment: &str,
+        span: Option<Span>,
+    ) {
+        let opcode = match self.data_section.insert_constant(lit) {
+            ConstantLoad::Immediate(imm) => VirtualOp::MOVI(reg.clone(), imm),
+            ConstantLoad::Data(data_id) => VirtualOp::LWDataId(reg.clone(), data_id),
+        };
+        self.bytecode.push(Op {
+            opcode: Either::Left(opcode),
+            comment: comment.into(),
+            owning_span: span,
+            inlined_at: Vec::new(),
+        });
+    }
+
     fn number_to_reg(&mut self, offset: u64, offset_reg: &VirtualRegister, span: Option<Span>) {
         if offset > compiler_constants::TWENTY_FOUR_BITS {
             todo!("Absolutely giant arrays.");
@@ -2063,6 +3240,7 @@ impl<'ir> AsmBuilder<'ir> {
             )),
             comment: "get extract offset high bits".into(),
             owning_span: span.clone(),
+            inlined_at: Vec::new(),
         });
         self.bytecode.push(Op {
             opcode: either::Either::Left(VirtualOp::SLLI(
@@ -2072,6 +3250,7 @@ impl<'ir> AsmBuilder<'ir> {
             )),
             comment: "shift extract offset high bits".into(),
             owning_span: span.clone(),
+            inlined_at: Vec::new(),
         });
         self.bytecode.push(Op {
             opcode: either::Either::Left(VirtualOp::ORI(
@@ -2083,6 +3262,7 @@ impl<'ir> AsmBuilder<'ir> {
             )),
             comment: "get extract offset low bits".into(),
             owning_span: span,
+            inlined_at: Vec::new(),
         });
     }
 
@@ -2111,17 +3291,15 @@ impl<'ir> AsmBuilder<'ir> {
 
         match &value_type {
             Type::Unit | Type::Bool | Type::Uint(_) => {
-                // Get the constant into the namespace.
-                let lit = ir_constant_to_ast_literal(constant);
-                let data_id = self.data_section.insert_data_value(&lit);
-
                 // Load the initialiser value.
+                let lit = ir_constant_to_ast_literal(constant);
                 let init_reg = self.reg_seqr.next();
-                self.bytecode.push(Op {
-                    opcode: either::Either::Left(VirtualOp::LWDataId(init_reg.clone(), data_id)),
-                    comment: "literal instantiation for aggregate field".into(),
-                    owning_span: span.clone(),
-                });
+                self.load_constant(
+                    &init_reg,
+                    &lit,
+                    "literal instantiation for aggregate field",
+                    span.clone(),
+                );
 
                 if offs_in_words > compiler_constants::TWELVE_BITS {
                     let offs_reg = self.reg_seqr.next();
@@ -2134,6 +3312,7 @@ impl<'ir> AsmBuilder<'ir> {
                         )),
                         comment: "calculate byte offset to aggregate field".into(),
                         owning_span: span.clone(),
+                        inlined_at: Vec::new(),
                     });
                     self.bytecode.push(Op {
                         opcode: Either::Left(VirtualOp::SW(
@@ -2143,6 +3322,7 @@ impl<'ir> AsmBuilder<'ir> {
                         )),
                         comment: "initialise aggregate field".into(),
                         owning_span: span,
+                        inlined_at: Vec::new(),
                     });
                 } else {
                     self.bytecode.push(Op {
@@ -2155,12 +3335,13 @@ impl<'ir> AsmBuilder<'ir> {
                         )),
                         comment: "initialise aggregate field".into(),
                         owning_span: span,
+                        inlined_at: Vec::new(),
                     });
                 }
 
                 1
             }
-            Type::B256 | Type::String(_) => {
+            Type::BigUint(_) | Type::B256 | Type::String(_) => {
                 // Get the constant into the namespace.
                 let lit = ir_constant_to_ast_literal(constant);
                 let data_id = self.data_section.insert_data_value(&lit);
@@ -2171,10 +3352,11 @@ impl<'ir> AsmBuilder<'ir> {
                     opcode: either::Either::Left(VirtualOp::LWDataId(init_reg.clone(), data_id)),
                     comment: "literal instantiation for aggregate field".into(),
                     owning_span: span.clone(),
+                    inlined_at: Vec::new(),
                 });
 
-                // Write the initialiser to memory.  Most Literals are 1 word, B256 is 32 bytes and
-                // needs to use a MCP instruction.
+                // Write the initialiser to memory.  Most Literals are 1 word, B256 and wide
+                // integers are 16 or 32 bytes and need to use a MCP instruction.
                 let offs_reg = self.reg_seqr.next();
                 if offs_in_words * 8 > compiler_constants::TWELVE_BITS {
                     self.number_to_reg(offs_in_words * 8, &offs_reg, span.clone());
@@ -2186,6 +3368,7 @@ impl<'ir> AsmBuilder<'ir> {
                         )),
                         comment: "calculate byte offset to aggregate field".into(),
                         owning_span: span.clone(),
+                        inlined_at: Vec::new(),
                     });
                 } else {
                     self.bytecode.push(Op {
@@ -2198,6 +3381,7 @@ impl<'ir> AsmBuilder<'ir> {
                         )),
                         comment: "calculate byte offset to aggregate field".into(),
                         owning_span: span.clone(),
+                        inlined_at: Vec::new(),
                     });
                 }
                 self.bytecode.push(Op {
@@ -2210,6 +3394,7 @@ impl<'ir> AsmBuilder<'ir> {
                     )),
                     comment: "initialise aggregate field".into(),
                     owning_span: span,
+                    inlined_at: Vec::new(),
                 });
 
                 value_size_in_words
@@ -2276,6 +3461,7 @@ impl<'ir> AsmBuilder<'ir> {
                             )),
                             comment: "get base pointer for union".into(),
                             owning_span: span.clone(),
+                            inlined_at: Vec::new(),
                         });
                     } else {
                         self.bytecode.push(Op {
@@ -2288,6 +3474,7 @@ impl<'ir> AsmBuilder<'ir> {
                             )),
                             comment: "get base pointer for union".into(),
                             owning_span: span.clone(),
+                            inlined_at: Vec::new(),
                         });
                     }
                     self.bytecode.push(Op {
@@ -2299,6 +3486,7 @@ impl<'ir> AsmBuilder<'ir> {
                         )),
                         comment: "clear padding for union initialisation".into(),
                         owning_span: span.clone(),
+                        inlined_at: Vec::new(),
                     });
                 }
 
@@ -2334,6 +3522,11 @@ fn ir_constant_to_ast_literal(constant: &Constant) -> Literal {
         ConstantValue::Unit => Literal::U64(0), // No unit.
         ConstantValue::Bool(b) => Literal::Boolean(*b),
         ConstantValue::Uint(n) => Literal::U64(*n),
+        ConstantValue::BigUint(bs) => match bs.len() {
+            16 => Literal::U128(u128::from_be_bytes(bs[..].try_into().unwrap())),
+            32 => Literal::U256(bs[..].try_into().unwrap()),
+            _ => unreachable!("Unsupported wide integer width."),
+        },
         ConstantValue::B256(bs) => Literal::B256(*bs),
         ConstantValue::String(bs) => {
             // ConstantValue::String bytes are guaranteed to be valid UTF8.
@@ -2351,6 +3544,7 @@ fn ir_constant_to_ast_literal(constant: &Constant) -> Literal {
 pub fn ir_type_size_in_bytes(context: &Context, ty: &Type) -> u64 {
     match ty {
         Type::Unit | Type::Bool | Type::Uint(_) => 8,
+        Type::BigUint(nbits) => *nbits as u64 / 8,
         Type::B256 => 32,
         Type::String(n) => size_bytes_round_up_to_word_alignment!(n),
         Type::Array(aggregate) => {
@@ -2467,6 +3661,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn predicate_disallowed_opcodes() {
+        let input = r#"predicate {
+    fn main() -> bool {
+        entry:
+        v0 = asm(r1) -> bool r1 {
+            bhei   r1
+        }
+        ret bool v0
+    }
+}"#;
+        let ir = parse(input).expect("parsed ir");
+        let asm_result = compile_ir_to_asm(&ir, None);
+        assert!(asm_result.value.is_none());
+        assert!(matches!(
+            asm_result.errors.as_slice(),
+            [CompileError::DisallowedOpcodeInPredicate { opcode, .. }] if opcode == "BHEI"
+        ));
+    }
+
     fn test_ir_to_asm(mut path: PathBuf) {
         let input_bytes = std::fs::read(&path).unwrap();
         let input = String::from_utf8_lossy(&input_bytes);
@@ -17,6 +17,7 @@ mod finalized_asm;
 pub mod from_ir;
 mod instruction_set;
 mod jump_optimized_asm_set;
+mod peephole;
 mod register_allocated_asm_set;
 pub(crate) mod register_allocator;
 mod register_sequencer;
@@ -27,6 +28,7 @@ use abstract_instruction_set::*;
 pub(crate) use data_section::*;
 use instruction_set::*;
 use jump_optimized_asm_set::*;
+pub(crate) use peephole::PeepholeStats;
 use register_allocated_asm_set::*;
 use register_sequencer::*;
 
@@ -72,7 +74,6 @@ pub enum SwayAsmSet {
         data_section: DataSection,
         program_section: AbstractInstructionSet,
     },
-    #[allow(dead_code)]
     PredicateMain {
         data_section: DataSection,
         program_section: AbstractInstructionSet,
@@ -194,12 +195,14 @@ fn build_preamble(register_sequencer: &mut RegisterSequencer) -> [Op; 6] {
             opcode: Either::Left(VirtualOp::NOOP),
             comment: "".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         },
         // word 2 -- full word u64 placeholder
         Op {
             opcode: Either::Right(OrganizationalOp::DataSectionOffsetPlaceholder),
             comment: "data section offset".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         },
         Op::unowned_jump_label_comment(label, "end of metadata"),
         // word 3 -- load the data offset into $ds
@@ -207,6 +210,7 @@ fn build_preamble(register_sequencer: &mut RegisterSequencer) -> [Op; 6] {
             opcode: Either::Left(VirtualOp::DataSectionRegisterLoadPlaceholder),
             comment: "".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         },
         // word 3.5 -- add $ds $ds $is
         Op {
@@ -217,6 +221,7 @@ fn build_preamble(register_sequencer: &mut RegisterSequencer) -> [Op; 6] {
             )),
             comment: "".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         },
     ]
 }
@@ -234,6 +239,7 @@ fn build_contract_abi_switch(
         opcode: Either::Right(OrganizationalOp::Comment),
         comment: "Begin contract ABI selector switch".into(),
         owning_span: None,
+        inlined_at: Vec::new(),
     }];
     // load the selector from the call frame
     asm_buf.push(Op {
@@ -247,6 +253,7 @@ fn build_contract_abi_switch(
         )),
         comment: "load input function selector".into(),
         owning_span: None,
+        inlined_at: Vec::new(),
     });
 
     for (selector, label) in selectors_and_labels {
@@ -262,6 +269,7 @@ fn build_contract_abi_switch(
             )),
             comment: "load fn selector for comparison".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         });
         // compare with the input selector
         let comparison_result_register = register_sequencer.next();
@@ -273,6 +281,7 @@ fn build_contract_abi_switch(
             )),
             comment: "function selector comparison".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         });
 
         // jump to the function label if the selector was equal
@@ -284,6 +293,7 @@ fn build_contract_abi_switch(
             )),
             comment: "jump to selected function".into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         });
     }
 
@@ -296,6 +306,7 @@ fn build_contract_abi_switch(
         ))),
         comment: "revert if no selectors matched".into(),
         owning_span: None,
+        inlined_at: Vec::new(),
     });
 
     asm_buf
@@ -0,0 +1,595 @@
+//! A peephole optimiser over the allocated ops.
+//!
+//! Register allocation leaves behind some redundant ops, such as moves to self, moves straight
+//! back again and constants which are loaded twice in a row.  Each rule in [RULES] matches a short
+//! sequence of ops and returns what to replace them with.  The rules are applied over the whole
+//! program repeatedly until none of them match.
+//!
+//! Jumps are to absolute offsets, so after each sweep they're updated to follow the ops which
+//! were removed.  A rule may only remove an op from the middle of a sequence if it isn't a jump
+//! target, since the ops before it may not have run.  The preamble is left untouched as its layout
+//! is fixed.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::asm_lang::{
+    allocated_ops::{AllocatedOp, AllocatedOpcode, AllocatedRegister},
+    ConstantRegister, VirtualImmediate12, VirtualImmediate18, VirtualImmediate24,
+};
+
+use super::DataSection;
+
+/// A rewrite rule, which is given the ops from the current one onwards.
+struct Rule {
+    name: &'static str,
+    apply: fn(&Window) -> Option<Rewrite>,
+}
+
+/// The rules, in the order they're tried at each op.
+const RULES: &[Rule] = &[
+    Rule {
+        name: "move to self",
+        apply: move_to_self,
+    },
+    Rule {
+        name: "move chain",
+        apply: move_chain,
+    },
+    Rule {
+        name: "no-op arithmetic",
+        apply: no_op_arithmetic,
+    },
+    Rule {
+        name: "reloaded constant",
+        apply: reloaded_constant,
+    },
+    Rule {
+        name: "jump to next",
+        apply: jump_to_next,
+    },
+    Rule {
+        name: "overwritten result",
+        apply: overwritten_result,
+    },
+];
+
+/// The number of times each rule was applied.
+pub(crate) struct PeepholeStats {
+    rule_counts: Vec<usize>,
+    ops_before: usize,
+    ops_after: usize,
+}
+
+impl Default for PeepholeStats {
+    fn default() -> Self {
+        PeepholeStats {
+            rule_counts: vec![0; RULES.len()],
+            ops_before: 0,
+            ops_after: 0,
+        }
+    }
+}
+
+impl fmt::Display for PeepholeStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "  Peephole optimisation removed {} of {} ops",
+            self.ops_before - self.ops_after,
+            self.ops_before
+        )?;
+        for (rule, count) in RULES.iter().zip(self.rule_counts.iter()) {
+            if *count > 0 {
+                write!(f, "\n    {}: {}", rule.name, count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Apply the rules to `ops` until none of them match.
+pub(crate) fn optimize(
+    ops: &mut Vec<AllocatedOp>,
+    data_section: &DataSection,
+    stats: &mut PeepholeStats,
+) {
+    let first_idx = ops
+        .iter()
+        .position(|op| {
+            matches!(
+                op.opcode,
+                AllocatedOpcode::DataSectionRegisterLoadPlaceholder
+            )
+        })
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+
+    stats.ops_before += ops.len();
+    while apply_rules(ops, first_idx, data_section, stats) {}
+    stats.ops_after += ops.len();
+}
+
+/// Make a single sweep over `ops`, returning whether any were rewritten.
+fn apply_rules(
+    ops: &mut Vec<AllocatedOp>,
+    first_idx: usize,
+    data_section: &DataSection,
+    stats: &mut PeepholeStats,
+) -> bool {
+    let mut offsets = Vec::with_capacity(ops.len() + 1);
+    let mut offset = 0;
+    for op in ops.iter() {
+        offsets.push(offset);
+        offset += op_size(op, data_section);
+    }
+    offsets.push(offset);
+    let jump_targets = ops
+        .iter()
+        .filter_map(|op| jump_target(&op.opcode))
+        .collect::<HashSet<_>>();
+
+    let mut new_ops = Vec::with_capacity(ops.len());
+    let mut offset_map = HashMap::new();
+    let mut new_offset = 0;
+    let mut modified = false;
+    let mut idx = 0;
+    while idx < ops.len() {
+        let window = Window {
+            ops: &ops[idx..],
+            offsets: &offsets[idx..],
+            jump_targets: &jump_targets,
+        };
+        let rewrite = if idx < first_idx {
+            None
+        } else {
+            RULES
+                .iter()
+                .enumerate()
+                .find_map(|(rule_idx, rule)| (rule.apply)(&window).map(|rw| (rule_idx, rw)))
+        };
+        let (len, replacement) = match rewrite {
+            Some((rule_idx, rewrite)) => {
+                stats.rule_counts[rule_idx] += 1;
+                modified = true;
+                (rewrite.len, rewrite.ops)
+            }
+            None => (1, vec![ops[idx].clone()]),
+        };
+
+        // Anything jumping to the replaced ops now jumps to the replacement, or whatever follows
+        // it if there is none.
+        for old_offset in &offsets[idx..idx + len] {
+            offset_map.insert(*old_offset, new_offset);
+        }
+        for op in replacement {
+            new_offset += op_size(&op, data_section);
+            new_ops.push(op);
+        }
+        idx += len;
+    }
+    offset_map.insert(offsets[ops.len()], new_offset);
+
+    if modified {
+        for op in &mut new_ops {
+            retarget_jump(&mut op.opcode, &offset_map);
+        }
+        *ops = new_ops;
+    }
+    modified
+}
+
+/// The ops from the current one onwards, with their offsets.  There's an extra offset at the end,
+/// for the end of the program.
+struct Window<'a> {
+    ops: &'a [AllocatedOp],
+    offsets: &'a [u64],
+    jump_targets: &'a HashSet<u64>,
+}
+
+impl<'a> Window<'a> {
+    fn is_jump_target(&self, idx: usize) -> bool {
+        self.jump_targets.contains(&self.offsets[idx])
+    }
+
+    /// The opcode at `idx`, if it's there and may be rewritten along with the current op.
+    fn following(&self, idx: usize) -> Option<&'a AllocatedOpcode> {
+        self.ops
+            .get(idx)
+            .filter(|_| !self.is_jump_target(idx))
+            .map(|op| &op.opcode)
+    }
+
+    /// Whether the op at `idx` reads `$of` or `$err`, which every ALU op sets.
+    fn reads_status(&self, idx: usize) -> bool {
+        self.ops.get(idx).map_or(false, |op| {
+            op.opcode.use_registers().iter().any(|reg| {
+                matches!(
+                    reg,
+                    AllocatedRegister::Constant(
+                        ConstantRegister::Overflow | ConstantRegister::Error
+                    )
+                )
+            })
+        })
+    }
+}
+
+/// Replace the first `len` ops with `ops`.
+struct Rewrite {
+    len: usize,
+    ops: Vec<AllocatedOp>,
+}
+
+impl Rewrite {
+    fn remove(len: usize) -> Rewrite {
+        Rewrite {
+            len,
+            ops: Vec::new(),
+        }
+    }
+}
+
+// `move a a`
+fn move_to_self(window: &Window) -> Option<Rewrite> {
+    match &window.ops[0].opcode {
+        AllocatedOpcode::MOVE(a, b) if a == b => Some(Rewrite::remove(1)),
+        _ => None,
+    }
+}
+
+// `move b a; move a b` -> `move b a`
+fn move_chain(window: &Window) -> Option<Rewrite> {
+    match (&window.ops[0].opcode, window.following(1)) {
+        (AllocatedOpcode::MOVE(b, a), Some(AllocatedOpcode::MOVE(a2, b2)))
+            if a == a2 && b == b2 =>
+        {
+            Some(Rewrite {
+                len: 2,
+                ops: vec![window.ops[0].clone()],
+            })
+        }
+        _ => None,
+    }
+}
+
+// `addi a a i0`, `muli a a i1`, etc.
+fn no_op_arithmetic(window: &Window) -> Option<Rewrite> {
+    use AllocatedOpcode::*;
+    let is_no_op = match &window.ops[0].opcode {
+        ADDI(a, b, imm)
+        | SUBI(a, b, imm)
+        | ORI(a, b, imm)
+        | XORI(a, b, imm)
+        | SLLI(a, b, imm)
+        | SRLI(a, b, imm) => a == b && imm.value == 0,
+        MULI(a, b, imm) | DIVI(a, b, imm) => a == b && imm.value == 1,
+        _ => false,
+    };
+    if is_no_op && !window.reads_status(1) {
+        Some(Rewrite::remove(1))
+    } else {
+        None
+    }
+}
+
+// `lw a data_0; lw b data_0` -> `lw a data_0; move b a`, and the same for `movi`.
+fn reloaded_constant(window: &Window) -> Option<Rewrite> {
+    use AllocatedOpcode::*;
+    let (a, b) = match (&window.ops[0].opcode, window.following(1)?) {
+        (LWDataId(a, id_a), LWDataId(b, id_b)) if id_a.0 == id_b.0 => (a, b),
+        (MOVI(a, imm_a), MOVI(b, imm_b)) if imm_a.value == imm_b.value => (a, b),
+        _ => return None,
+    };
+    let mut ops = vec![window.ops[0].clone()];
+    if a != b {
+        ops.push(AllocatedOp {
+            opcode: MOVE(b.clone(), a.clone()),
+            ..window.ops[1].clone()
+        });
+    }
+    Some(Rewrite { len: 2, ops })
+}
+
+// `ji`, `jnei` or `jnzi` to the following op.
+fn jump_to_next(window: &Window) -> Option<Rewrite> {
+    match jump_target(&window.ops[0].opcode) {
+        Some(target) if target == window.offsets[1] => Some(Rewrite::remove(1)),
+        _ => None,
+    }
+}
+
+// `addi a b i0; lw a data_0` -> `lw a data_0`, where the first op can't fail and the result is
+// written again without being read.
+fn overwritten_result(window: &Window) -> Option<Rewrite> {
+    use AllocatedOpcode::*;
+    let first = &window.ops[0].opcode;
+    let can_remove = match first {
+        MOVE(..) | MOVI(..) | LWDataId(..) | NOT(..) => true,
+        AND(..) | ANDI(..) | OR(..) | ORI(..) | XOR(..) | XORI(..) => true,
+        EQ(..) | GT(..) | LT(..) => true,
+        ADDI(_, _, imm) | SUBI(_, _, imm) => imm.value == 0,
+        _ => false,
+    };
+    if !can_remove {
+        return None;
+    }
+    let defs = first.def_registers();
+    let reg = match defs.as_slice() {
+        [reg] if matches!(reg, AllocatedRegister::Allocated(_)) => *reg,
+        _ => return None,
+    };
+    let second = window.following(1)?;
+    if second.def_registers() == vec![reg]
+        && !second.use_registers().contains(&reg)
+        && !window.reads_status(1)
+    {
+        Some(Rewrite::remove(1))
+    } else {
+        None
+    }
+}
+
+fn op_size(op: &AllocatedOp, data_section: &DataSection) -> u64 {
+    match &op.opcode {
+        AllocatedOpcode::LWDataId(_, data_id) => {
+            let type_of_data = data_section.type_of_data(data_id).expect(
+                "Internal miscalculation in data section -- data id did not match up to any actual data",
+            );
+            if type_of_data.is_copy_type() {
+                1
+            } else {
+                2
+            }
+        }
+        AllocatedOpcode::DataSectionOffsetPlaceholder => 2,
+        _ => 1,
+    }
+}
+
+fn jump_target(opcode: &AllocatedOpcode) -> Option<u64> {
+    match opcode {
+        AllocatedOpcode::JI(imm) => Some(imm.value as u64),
+        AllocatedOpcode::JNEI(_, _, imm) => Some(imm.value as u64),
+        AllocatedOpcode::JNZI(_, imm) => Some(imm.value as u64),
+        _ => None,
+    }
+}
+
+fn retarget_jump(opcode: &mut AllocatedOpcode, offset_map: &HashMap<u64, u64>) {
+    let new_target = match jump_target(opcode).and_then(|target| offset_map.get(&target)) {
+        Some(new_target) => *new_target,
+        None => return,
+    };
+    match opcode {
+        AllocatedOpcode::JI(imm) => {
+            *imm = VirtualImmediate24::new_unchecked(
+                new_target,
+                "removing ops only moves jump targets back",
+            )
+        }
+        AllocatedOpcode::JNEI(_, _, imm) => {
+            *imm = VirtualImmediate12::new_unchecked(
+                new_target,
+                "removing ops only moves jump targets back",
+            )
+        }
+        AllocatedOpcode::JNZI(_, imm) => {
+            *imm = VirtualImmediate18::new_unchecked(
+                new_target,
+                "removing ops only moves jump targets back",
+            )
+        }
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_tree::Literal;
+    use AllocatedOpcode::*;
+
+    fn op(opcode: AllocatedOpcode) -> AllocatedOp {
+        AllocatedOp {
+            opcode,
+            comment: String::new(),
+            owning_span: None,
+            inlined_at: Vec::new(),
+        }
+    }
+
+    fn reg(idx: u8) -> AllocatedRegister {
+        AllocatedRegister::Allocated(idx)
+    }
+
+    fn imm12(value: u16) -> VirtualImmediate12 {
+        VirtualImmediate12 { value }
+    }
+
+    fn run(ops: Vec<AllocatedOpcode>, data_section: &DataSection) -> (Vec<String>, PeepholeStats) {
+        let mut ops: Vec<AllocatedOp> = ops.into_iter().map(op).collect();
+        let mut stats = PeepholeStats::default();
+        optimize(&mut ops, data_section, &mut stats);
+        (ops.iter().map(|op| op.to_string()).collect(), stats)
+    }
+
+    fn count(stats: &PeepholeStats, rule_name: &str) -> usize {
+        let idx = RULES
+            .iter()
+            .position(|rule| rule.name == rule_name)
+            .unwrap();
+        stats.rule_counts[idx]
+    }
+
+    #[test]
+    fn move_to_self() {
+        let (ops, stats) = run(
+            vec![MOVE(reg(0), reg(0)), RET(reg(0))],
+            &DataSection::default(),
+        );
+        assert_eq!(ops, vec!["ret  $r0"]);
+        assert_eq!(count(&stats, "move to self"), 1);
+    }
+
+    #[test]
+    fn move_chain() {
+        let (ops, stats) = run(
+            vec![MOVE(reg(1), reg(0)), MOVE(reg(0), reg(1)), RET(reg(1))],
+            &DataSection::default(),
+        );
+        assert_eq!(ops, vec!["move $r1 $r0", "ret  $r1"]);
+        assert_eq!(count(&stats, "move chain"), 1);
+    }
+
+    #[test]
+    fn move_chain_into_jump_target() {
+        // The second move may be reached without the first, so it must stay.
+        let opcodes = vec![
+            MOVE(reg(1), reg(0)),
+            MOVE(reg(0), reg(1)),
+            RET(reg(0)),
+            JNZI(reg(1), VirtualImmediate18 { value: 1 }),
+        ];
+        let (ops, stats) = run(opcodes, &DataSection::default());
+        assert_eq!(
+            ops,
+            vec!["move $r1 $r0", "move $r0 $r1", "ret  $r0", "jnzi $r1 i1"]
+        );
+        assert_eq!(stats.ops_before, stats.ops_after);
+    }
+
+    #[test]
+    fn no_op_arithmetic() {
+        let opcodes = vec![
+            ADDI(reg(0), reg(0), imm12(0)),
+            MULI(reg(1), reg(1), imm12(1)),
+            ADD(reg(0), reg(0), reg(1)),
+            RET(reg(0)),
+        ];
+        let (ops, stats) = run(opcodes, &DataSection::default());
+        assert_eq!(ops, vec!["add  $r0 $r0 $r1", "ret  $r0"]);
+        assert_eq!(count(&stats, "no-op arithmetic"), 2);
+    }
+
+    #[test]
+    fn no_op_arithmetic_before_overflow_check() {
+        // The `addi` clears `$of`, which is read next.
+        let opcodes = vec![
+            ADDI(reg(0), reg(0), imm12(0)),
+            MOVE(
+                reg(1),
+                AllocatedRegister::Constant(ConstantRegister::Overflow),
+            ),
+            RET(reg(1)),
+        ];
+        let (ops, _) = run(opcodes, &DataSection::default());
+        assert_eq!(ops.len(), 3);
+    }
+
+    #[test]
+    fn reloaded_constant() {
+        let mut data_section = DataSection::default();
+        let data_id = data_section.insert_data_value(&Literal::U64(42));
+        let opcodes = vec![
+            LWDataId(reg(0), data_id.clone()),
+            LWDataId(reg(1), data_id.clone()),
+            MOVI(reg(2), VirtualImmediate18 { value: 7 }),
+            MOVI(reg(2), VirtualImmediate18 { value: 7 }),
+            ADD(reg(0), reg(0), reg(1)),
+            ADD(reg(0), reg(0), reg(2)),
+            RET(reg(0)),
+        ];
+        let (ops, stats) = run(opcodes, &data_section);
+        assert_eq!(
+            ops,
+            vec![
+                "lw   $r0 data_0",
+                "move $r1 $r0",
+                "movi $r2 i7",
+                "add  $r0 $r0 $r1",
+                "add  $r0 $r0 $r2",
+                "ret  $r0"
+            ]
+        );
+        assert_eq!(count(&stats, "reloaded constant"), 2);
+    }
+
+    #[test]
+    fn jump_to_next() {
+        let opcodes = vec![
+            JNZI(reg(0), VirtualImmediate18 { value: 1 }),
+            JI(VirtualImmediate24 { value: 2 }),
+            RET(reg(0)),
+        ];
+        let (ops, stats) = run(opcodes, &DataSection::default());
+        assert_eq!(ops, vec!["ret  $r0"]);
+        assert_eq!(count(&stats, "jump to next"), 2);
+    }
+
+    #[test]
+    fn jumps_retargeted() {
+        // The `b256` is loaded by address, which takes two ops.
+        let mut data_section = DataSection::default();
+        let data_id = data_section.insert_data_value(&Literal::B256([0; 32]));
+        let opcodes = vec![
+            LWDataId(reg(0), data_id),
+            MOVE(reg(1), reg(1)),
+            JNZI(reg(1), VirtualImmediate18 { value: 5 }),
+            RET(reg(0)),
+            RET(reg(1)),
+        ];
+        let (ops, _) = run(opcodes, &data_section);
+        assert_eq!(
+            ops,
+            vec!["lw   $r0 data_0", "jnzi $r1 i4", "ret  $r0", "ret  $r1"]
+        );
+    }
+
+    #[test]
+    fn overwritten_result() {
+        let opcodes = vec![
+            ADDI(reg(0), reg(2), imm12(0)),
+            LW(reg(0), reg(2), imm12(0)),
+            ADDI(reg(1), reg(2), imm12(0)),
+            ADDI(reg(1), reg(1), imm12(8)),
+            ADD(reg(0), reg(0), reg(1)),
+            RET(reg(0)),
+        ];
+        let (ops, stats) = run(opcodes, &DataSection::default());
+        assert_eq!(
+            ops,
+            vec![
+                "lw   $r0 $r2 i0",
+                "addi $r1 $r2 i0",
+                "addi $r1 $r1 i8",
+                "add  $r0 $r0 $r1",
+                "ret  $r0"
+            ]
+        );
+        assert_eq!(count(&stats, "overwritten result"), 1);
+    }
+
+    #[test]
+    fn preamble_untouched() {
+        let opcodes = vec![
+            JI(VirtualImmediate24 { value: 4 }),
+            NOOP,
+            DataSectionOffsetPlaceholder,
+            DataSectionRegisterLoadPlaceholder,
+            ADD(
+                AllocatedRegister::Constant(ConstantRegister::DataSectionStart),
+                AllocatedRegister::Constant(ConstantRegister::DataSectionStart),
+                AllocatedRegister::Constant(ConstantRegister::InstructionStart),
+            ),
+            MOVE(reg(0), reg(0)),
+            RET(reg(0)),
+        ];
+        let (ops, stats) = run(opcodes, &DataSection::default());
+        assert_eq!(ops[0], "ji   i4");
+        assert_eq!(ops.len(), 6);
+        assert_eq!(stats.ops_before - stats.ops_after, 1);
+    }
+}
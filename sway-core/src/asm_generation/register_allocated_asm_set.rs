@@ -1,5 +1,5 @@
 use crate::{
-    asm_generation::{DataSection, FinalizedAsm, InstructionSet},
+    asm_generation::{peephole, DataSection, FinalizedAsm, InstructionSet, PeepholeStats},
     asm_lang::allocated_ops::AllocatedOp,
 };
 use std::fmt;
@@ -23,14 +23,15 @@ pub enum RegisterAllocatedAsmSet {
 }
 
 impl RegisterAllocatedAsmSet {
-    pub(crate) fn optimize(self) -> FinalizedAsm {
-        // TODO implement this -- noop for now
+    pub(crate) fn optimize(self, stats: &mut PeepholeStats) -> FinalizedAsm {
         match self {
             RegisterAllocatedAsmSet::Library => FinalizedAsm::Library,
             RegisterAllocatedAsmSet::ScriptMain {
                 mut program_section,
                 data_section,
             } => {
+                peephole::optimize(&mut program_section.ops, &data_section, stats);
+
                 // ensure there's an even number of ops so the
                 // data section offset is valid
                 if program_section.ops.len() & 1 != 0 {
@@ -38,6 +39,7 @@ impl RegisterAllocatedAsmSet {
                         opcode: crate::asm_lang::allocated_ops::AllocatedOpcode::NOOP,
                         comment: "word-alignment of data section".into(),
                         owning_span: None,
+                        inlined_at: Vec::new(),
                     });
                 }
                 FinalizedAsm::ScriptMain {
@@ -49,6 +51,8 @@ impl RegisterAllocatedAsmSet {
                 mut program_section,
                 data_section,
             } => {
+                peephole::optimize(&mut program_section.ops, &data_section, stats);
+
                 // ensure there's an even number of ops so the
                 // data section offset is valid
                 if program_section.ops.len() & 1 != 0 {
@@ -56,6 +60,7 @@ impl RegisterAllocatedAsmSet {
                         opcode: crate::asm_lang::allocated_ops::AllocatedOpcode::NOOP,
                         comment: "word-alignment of data section".into(),
                         owning_span: None,
+                        inlined_at: Vec::new(),
                     });
                 }
                 FinalizedAsm::PredicateMain {
@@ -67,6 +72,8 @@ impl RegisterAllocatedAsmSet {
                 mut program_section,
                 data_section,
             } => {
+                peephole::optimize(&mut program_section.ops, &data_section, stats);
+
                 // ensure there's an even number of ops so the
                 // data section offset is valid
                 if program_section.ops.len() & 1 != 0 {
@@ -74,6 +81,7 @@ impl RegisterAllocatedAsmSet {
                         opcode: crate::asm_lang::allocated_ops::AllocatedOpcode::NOOP,
                         comment: "word-alignment of data section".into(),
                         owning_span: None,
+                        inlined_at: Vec::new(),
                     });
                 }
                 FinalizedAsm::ContractAbi {
@@ -223,6 +223,7 @@ pub(crate) fn coalesce_registers(
         let new_op = RealizedOp {
             opcode: op.opcode.clone(),
             owning_span: op.owning_span.clone(),
+            inlined_at: op.inlined_at.clone(),
             comment: op.comment.clone(),
             offset: op.offset - num_moves_removed,
         };
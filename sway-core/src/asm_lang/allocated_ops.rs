@@ -182,6 +182,7 @@ pub(crate) struct AllocatedOp {
     /// A descriptive comment for ASM readability
     pub(crate) comment: String,
     pub(crate) owning_span: Option<Span>,
+    pub(crate) inlined_at: Vec<Span>,
 }
 
 impl fmt::Display for AllocatedOp {
@@ -290,6 +291,192 @@ impl fmt::Display for AllocatedOp {
 
 type DoubleWideData = [u8; 8];
 
+impl AllocatedOpcode {
+    /// Returns a list of all registers *read* by instruction `self`.
+    pub(crate) fn use_registers(&self) -> Vec<&AllocatedRegister> {
+        use AllocatedOpcode::*;
+        match self {
+            ADD(_r1, r2, r3) => vec![r2, r3],
+            ADDI(_r1, r2, _i) => vec![r2],
+            AND(_r1, r2, r3) => vec![r2, r3],
+            ANDI(_r1, r2, _i) => vec![r2],
+            DIV(_r1, r2, r3) => vec![r2, r3],
+            DIVI(_r1, r2, _i) => vec![r2],
+            EQ(_r1, r2, r3) => vec![r2, r3],
+            EXP(_r1, r2, r3) => vec![r2, r3],
+            EXPI(_r1, r2, _i) => vec![r2],
+            GT(_r1, r2, r3) => vec![r2, r3],
+            LT(_r1, r2, r3) => vec![r2, r3],
+            MLOG(_r1, r2, r3) => vec![r2, r3],
+            MROO(_r1, r2, r3) => vec![r2, r3],
+            MOD(_r1, r2, r3) => vec![r2, r3],
+            MODI(_r1, r2, _i) => vec![r2],
+            MOVE(_r1, r2) => vec![r2],
+            MOVI(_r1, _i) => vec![],
+            MUL(_r1, r2, r3) => vec![r2, r3],
+            MULI(_r1, r2, _i) => vec![r2],
+            NOT(_r1, r2) => vec![r2],
+            OR(_r1, r2, r3) => vec![r2, r3],
+            ORI(_r1, r2, _i) => vec![r2],
+            SLL(_r1, r2, r3) => vec![r2, r3],
+            SLLI(_r1, r2, _i) => vec![r2],
+            SMO(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
+            SRL(_r1, r2, r3) => vec![r2, r3],
+            SRLI(_r1, r2, _i) => vec![r2],
+            SUB(_r1, r2, r3) => vec![r2, r3],
+            SUBI(_r1, r2, _i) => vec![r2],
+            XOR(_r1, r2, r3) => vec![r2, r3],
+            XORI(_r1, r2, _i) => vec![r2],
+            JI(_im) => vec![],
+            JNEI(r1, r2, _i) => vec![r1, r2],
+            JNZI(r1, _i) => vec![r1],
+            RET(r1) => vec![r1],
+            RETD(r1, r2) => vec![r1, r2],
+            CFEI(_imm) => vec![],
+            CFSI(_imm) => vec![],
+            LB(_r1, r2, _i) => vec![r2],
+            LWDataId(_r1, _i) => vec![],
+            LW(_r1, r2, _i) => vec![r2],
+            ALOC(r1) => vec![r1],
+            MCL(r1, r2) => vec![r1, r2],
+            MCLI(r1, _imm) => vec![r1],
+            MCP(r1, r2, r3) => vec![r1, r2, r3],
+            MEQ(_r1, r2, r3, r4) => vec![r2, r3, r4],
+            MCPI(r1, r2, _imm) => vec![r1, r2],
+            SB(r1, r2, _i) => vec![r1, r2],
+            SW(r1, r2, _i) => vec![r1, r2],
+            BAL(_r1, r2, r3) => vec![r2, r3],
+            BHSH(r1, r2) => vec![r1, r2],
+            BHEI(_r1) => vec![],
+            BURN(r1) => vec![r1],
+            CALL(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
+            CCP(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
+            CROO(r1, r2) => vec![r1, r2],
+            CSIZ(_r1, r2) => vec![r2],
+            CB(r1) => vec![r1],
+            LDC(r1, r2, r3) => vec![r1, r2, r3],
+            LOG(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
+            LOGD(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
+            MINT(r1) => vec![r1],
+            RVRT(r1) => vec![r1],
+            SRW(_r1, r2) => vec![r2],
+            SRWQ(r1, r2) => vec![r1, r2],
+            SWW(r1, r2) => vec![r1, r2],
+            SWWQ(r1, r2) => vec![r1, r2],
+            TR(r1, r2, r3) => vec![r1, r2, r3],
+            TRO(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
+            ECR(r1, r2, r3) => vec![r1, r2, r3],
+            K256(r1, r2, r3) => vec![r1, r2, r3],
+            S256(r1, r2, r3) => vec![r1, r2, r3],
+            XIL(_r1, r2) => vec![r2],
+            XIS(_r1, r2) => vec![r2],
+            XOL(_r1, r2) => vec![r2],
+            XOS(_r1, r2) => vec![r2],
+            XWL(_r1, r2) => vec![r2],
+            XWS(_r1, r2) => vec![r2],
+            NOOP => vec![],
+            FLAG(r1) => vec![r1],
+            GM(_r1, _imm) => vec![],
+            Undefined | DataSectionOffsetPlaceholder => vec![],
+            DataSectionRegisterLoadPlaceholder => vec![&AllocatedRegister::Constant(
+                ConstantRegister::InstructionStart,
+            )],
+        }
+    }
+
+    /// Returns a list of all registers *written* by instruction `self`.
+    pub(crate) fn def_registers(&self) -> Vec<&AllocatedRegister> {
+        use AllocatedOpcode::*;
+        match self {
+            ADD(r1, _r2, _r3) => vec![r1],
+            ADDI(r1, _r2, _i) => vec![r1],
+            AND(r1, _r2, _r3) => vec![r1],
+            ANDI(r1, _r2, _i) => vec![r1],
+            DIV(r1, _r2, _r3) => vec![r1],
+            DIVI(r1, _r2, _i) => vec![r1],
+            EQ(r1, _r2, _r3) => vec![r1],
+            EXP(r1, _r2, _r3) => vec![r1],
+            EXPI(r1, _r2, _i) => vec![r1],
+            GT(r1, _r2, _r3) => vec![r1],
+            LT(r1, _r2, _r3) => vec![r1],
+            MLOG(r1, _r2, _r3) => vec![r1],
+            MROO(r1, _r2, _r3) => vec![r1],
+            MOD(r1, _r2, _r3) => vec![r1],
+            MODI(r1, _r2, _i) => vec![r1],
+            MOVE(r1, _r2) => vec![r1],
+            MOVI(r1, _i) => vec![r1],
+            MUL(r1, _r2, _r3) => vec![r1],
+            MULI(r1, _r2, _i) => vec![r1],
+            NOT(r1, _r2) => vec![r1],
+            OR(r1, _r2, _r3) => vec![r1],
+            ORI(r1, _r2, _i) => vec![r1],
+            SLL(r1, _r2, _r3) => vec![r1],
+            SLLI(r1, _r2, _i) => vec![r1],
+            SMO(_r1, _r2, _r3, _r4) => vec![],
+            SRL(r1, _r2, _r3) => vec![r1],
+            SRLI(r1, _r2, _i) => vec![r1],
+            SUB(r1, _r2, _r3) => vec![r1],
+            SUBI(r1, _r2, _i) => vec![r1],
+            XOR(r1, _r2, _r3) => vec![r1],
+            XORI(r1, _r2, _i) => vec![r1],
+            JI(_im) => vec![],
+            JNEI(_r1, _r2, _i) => vec![],
+            JNZI(_r1, _i) => vec![],
+            RET(_r1) => vec![],
+            RETD(_r1, _r2) => vec![],
+            CFEI(_imm) => vec![],
+            CFSI(_imm) => vec![],
+            LB(r1, _r2, _i) => vec![r1],
+            LWDataId(r1, _i) => vec![r1],
+            LW(r1, _r2, _i) => vec![r1],
+            ALOC(_r1) => vec![],
+            MCL(_r1, _r2) => vec![],
+            MCLI(_r1, _imm) => vec![],
+            MCP(_r1, _r2, _r3) => vec![],
+            MEQ(r1, _r2, _r3, _r4) => vec![r1],
+            MCPI(_r1, _r2, _imm) => vec![],
+            SB(_r1, _r2, _i) => vec![],
+            SW(_r1, _r2, _i) => vec![],
+            BAL(r1, _r2, _r3) => vec![r1],
+            BHSH(_r1, _r2) => vec![],
+            BHEI(r1) => vec![r1],
+            BURN(_r1) => vec![],
+            CALL(_r1, _r2, _r3, _r4) => vec![],
+            CCP(_r1, _r2, _r3, _r4) => vec![],
+            CROO(_r1, _r2) => vec![],
+            CSIZ(r1, _r2) => vec![r1],
+            CB(_r1) => vec![],
+            LDC(_r1, _r2, _r3) => vec![],
+            LOG(_r1, _r2, _r3, _r4) => vec![],
+            LOGD(_r1, _r2, _r3, _r4) => vec![],
+            MINT(_r1) => vec![],
+            RVRT(_r1) => vec![],
+            SRW(r1, _r2) => vec![r1],
+            SRWQ(_r1, _r2) => vec![],
+            SWW(_r1, _r2) => vec![],
+            SWWQ(_r1, _r2) => vec![],
+            TR(_r1, _r2, _r3) => vec![],
+            TRO(_r1, _r2, _r3, _r4) => vec![],
+            ECR(_r1, _r2, _r3) => vec![],
+            K256(_r1, _r2, _r3) => vec![],
+            S256(_r1, _r2, _r3) => vec![],
+            XIL(r1, _r2) => vec![r1],
+            XIS(r1, _r2) => vec![r1],
+            XOL(r1, _r2) => vec![r1],
+            XOS(r1, _r2) => vec![r1],
+            XWL(r1, _r2) => vec![r1],
+            XWS(r1, _r2) => vec![r1],
+            NOOP => vec![],
+            FLAG(_r1) => vec![],
+            GM(r1, _imm) => vec![r1],
+            Undefined | DataSectionOffsetPlaceholder => vec![],
+            DataSectionRegisterLoadPlaceholder => vec![&AllocatedRegister::Constant(
+                ConstantRegister::DataSectionStart,
+            )],
+        }
+    }
+}
+
 impl AllocatedOp {
     pub(crate) fn to_fuel_asm(
         &self,
@@ -38,6 +38,9 @@ pub(crate) struct Op {
     /// A descriptive comment for ASM readability
     pub(crate) comment: String,
     pub(crate) owning_span: Option<Span>,
+    /// If the owning span is within an inlined function then the spans of the calls it was
+    /// inlined at, innermost first.
+    pub(crate) inlined_at: Vec<Span>,
 }
 
 #[derive(Clone, Debug)]
@@ -46,6 +49,7 @@ pub(crate) struct RealizedOp {
     /// A descriptive comment for ASM readability
     pub(crate) comment: String,
     pub(crate) owning_span: Option<Span>,
+    pub(crate) inlined_at: Vec<Span>,
     pub(crate) offset: u64,
 }
 
@@ -62,6 +66,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::SW(destination_address, value_to_write, offset)),
             comment: String::new(),
             owning_span: Some(span),
+            inlined_at: Vec::new(),
         }
     }
     /// Write value in given [VirtualRegister] `value_to_write` to given memory address that is held within the
@@ -77,6 +82,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::SW(destination_address, value_to_write, offset)),
             comment: comment.into(),
             owning_span: Some(span),
+            inlined_at: Vec::new(),
         }
     }
     /// Moves the stack pointer by the given amount (i.e. allocates stack memory)
@@ -87,6 +93,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::CFEI(size_to_allocate_in_bytes)),
             comment: String::new(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
     pub(crate) fn unowned_new_with_comment(opcode: VirtualOp, comment: impl Into<String>) -> Self {
@@ -94,6 +101,7 @@ impl Op {
             opcode: Either::Left(opcode),
             comment: comment.into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
     pub(crate) fn new(opcode: VirtualOp, owning_span: Span) -> Self {
@@ -101,6 +109,7 @@ impl Op {
             opcode: Either::Left(opcode),
             comment: String::new(),
             owning_span: Some(owning_span),
+            inlined_at: Vec::new(),
         }
     }
     pub(crate) fn new_with_comment(
@@ -113,6 +122,7 @@ impl Op {
             opcode: Either::Left(opcode),
             comment,
             owning_span: Some(owning_span),
+            inlined_at: Vec::new(),
         }
     }
 
@@ -122,6 +132,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::Label(label)),
             comment: String::new(),
             owning_span: Some(owning_span),
+            inlined_at: Vec::new(),
         }
     }
     /// Loads the data from [DataId] `data` into [VirtualRegister] `reg`.
@@ -134,6 +145,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::LWDataId(reg, data)),
             comment: comment.into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -144,6 +156,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::Label(label)),
             comment: comment.into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -158,6 +171,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::Label(label)),
             comment: comment.into(),
             owning_span: Some(owning_span),
+            inlined_at: Vec::new(),
         }
     }
 
@@ -167,6 +181,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::Label(label)),
             comment: String::new(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -180,6 +195,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::MOVE(r1, r2)),
             comment: String::new(),
             owning_span: Some(owning_span),
+            inlined_at: Vec::new(),
         }
     }
 
@@ -189,6 +205,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::MOVE(r1, r2)),
             comment: String::new(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -202,6 +219,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::MOVE(r1, r2)),
             comment: comment.into(),
             owning_span: Some(owning_span),
+            inlined_at: Vec::new(),
         }
     }
 
@@ -215,6 +233,7 @@ impl Op {
             opcode: Either::Left(VirtualOp::MOVE(r1, r2)),
             comment: comment.into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -223,6 +242,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::Comment),
             comment: comm.into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -231,6 +251,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::Jump(label)),
             comment: String::new(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -239,6 +260,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::Jump(label)),
             comment: comment.into(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -252,6 +274,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::JumpIfNotEq(reg0, reg1, label)),
             comment: String::new(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
@@ -261,6 +284,7 @@ impl Op {
             opcode: Either::Right(OrganizationalOp::JumpIfNotZero(reg0, label)),
             comment: String::new(),
             owning_span: None,
+            inlined_at: Vec::new(),
         }
     }
 
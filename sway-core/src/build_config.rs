@@ -9,6 +9,8 @@ pub struct BuildConfig {
     pub(crate) print_intermediate_asm: bool,
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
+    pub(crate) time_phases: bool,
+    pub(crate) overflow_checks: bool,
 }
 
 impl BuildConfig {
@@ -46,6 +48,8 @@ impl BuildConfig {
             print_intermediate_asm: false,
             print_finalized_asm: false,
             print_ir: false,
+            time_phases: false,
+            overflow_checks: false,
         }
     }
 
@@ -70,6 +74,20 @@ impl BuildConfig {
         }
     }
 
+    pub fn time_phases(self, a: bool) -> Self {
+        Self {
+            time_phases: a,
+            ..self
+        }
+    }
+
+    pub fn overflow_checks(self, a: bool) -> Self {
+        Self {
+            overflow_checks: a,
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
@@ -1058,6 +1058,33 @@ fn connect_expression(
             tree_type,
             exp.span.clone(),
         ),
+        FunctionValue { function } => match graph.namespace.get_function(&function.name).cloned() {
+            // a named function used as a value is reachable from here
+            Some(FunctionNamespaceEntry { entry_point, .. }) => {
+                for leaf in leaves {
+                    graph.add_edge(*leaf, entry_point, label.into());
+                }
+                Ok(leaves.to_vec())
+            }
+            // a closure's body is connected in place
+            None => connect_code_block(&function.body, graph, leaves, exit_node, tree_type),
+        },
+        IndirectFunctionApplication { arguments, .. } => {
+            let mut current_leaf = leaves.to_vec();
+            for arg in arguments {
+                current_leaf = connect_expression(
+                    &arg.expression,
+                    graph,
+                    &current_leaf,
+                    exit_node,
+                    "arg eval",
+                    tree_type,
+                    arg.span.clone(),
+                )?;
+            }
+            Ok(current_leaf)
+        }
+        VariableReference { .. } => Ok(leaves.to_vec()),
     }
 }
 
@@ -3,18 +3,19 @@ use crate::type_engine::{TraitConstraint, TypeArgument, TypeParameter};
 use {
     crate::{
         constants::{
-            STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
+            DERIVE_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME,
+            STORAGE_PURITY_WRITE_NAME,
         },
         error::{err, ok, CompileError, CompileResult, CompileWarning},
-        type_engine::{insert_type, AbiName, IntegerBits},
+        type_engine::{insert_type, AbiName, ArrayLength, IntegerBits},
         AbiDeclaration, AsmExpression, AsmOp, AsmRegister, AsmRegisterDeclaration, AstNode,
-        AstNodeContent, CallPath, CodeBlock, ConstantDeclaration, Declaration, EnumDeclaration,
-        EnumVariant, Expression, FunctionDeclaration, FunctionParameter, ImplSelf, ImplTrait,
-        ImportType, IncludeStatement, LazyOp, Literal, MatchBranch, MethodName, ParseTree, Purity,
-        Reassignment, ReassignmentTarget, ReturnStatement, Scrutinee, StorageDeclaration,
-        StorageField, StructDeclaration, StructExpressionField, StructField, StructScrutineeField,
-        Supertrait, TraitDeclaration, TraitFn, TreeType, TypeInfo, UseStatement,
-        VariableDeclaration, Visibility, WhileLoop,
+        AstNodeContent, CallPath, CodeBlock, ConstantDeclaration, Declaration, DerivableTrait,
+        Derive, EnumDeclaration, EnumVariant, Expression, FunctionDeclaration, FunctionParameter,
+        ImplSelf, ImplTrait, ImportType, IncludeStatement, LazyOp, Literal, MatchBranch,
+        MethodName, ParseTree, Purity, Reassignment, ReassignmentTarget, ReturnStatement,
+        Scrutinee, StorageDeclaration, StorageField, StructDeclaration, StructExpressionField,
+        StructField, StructScrutineeField, Supertrait, TraitDeclaration, TraitFn, TreeType,
+        TypeInfo, UseStatement, VariableDeclaration, Visibility, WhileLoop,
     },
     std::{
         collections::HashMap,
@@ -26,15 +27,17 @@ use {
     },
     sway_parse::{
         expr::{ReassignmentOp, ReassignmentOpVariant},
+        keywords::CommaToken,
+        punctuated::Punctuated,
         ty::TyTupleDescriptor,
-        AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
-        Dependency, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition,
-        IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst, ItemEnum, ItemFn, ItemImpl,
-        ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemUse, LitInt, LitIntType, MatchBranchKind,
-        Module, ModuleKind, PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern,
-        PatternStructField, PubToken, QualifiedPathRoot, Statement, StatementLet, Traits, Ty,
-        TypeField, UseTree, WhereClause,
+        AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, ClosureParam,
+        ClosureParams, CodeBlockContents, Dependency, DoubleColonToken, Expr, ExprArrayDescriptor,
+        ExprClosure, ExprStructField, ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs,
+        GenericParams, IfCondition, IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst,
+        ItemEnum, ItemFn, ItemImpl, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemUse, LitInt,
+        LitIntType, MatchBranchKind, Module, ModuleKind, PathExpr, PathExprSegment, PathType,
+        PathTypeSegment, Pattern, PatternStructField, PubToken, QualifiedPathRoot, Statement,
+        StatementLet, Traits, Ty, TypeField, UseTree, WhereClause,
     },
     sway_types::{Ident, Span, Spanned},
     thiserror::Error,
@@ -136,6 +139,10 @@ pub enum ConvertParseTreeError {
     U32LiteralOutOfRange { span: Span },
     #[error("u64 literal out of range")]
     U64LiteralOutOfRange { span: Span },
+    #[error("u128 literal out of range")]
+    U128LiteralOutOfRange { span: Span },
+    #[error("u256 literal out of range")]
+    U256LiteralOutOfRange { span: Span },
     #[error("signed integers are not supported")]
     SignedIntegersNotSupported { span: Span },
     #[error("literal patterns not supported in this position")]
@@ -154,6 +161,10 @@ pub enum ConvertParseTreeError {
     ConstructorPatternOneArg { span: Span },
     #[error("mutable bindings are not supported in this position")]
     MutableBindingsNotSupportedHere { span: Span },
+    #[error("ref bindings are not supported in this position")]
+    RefBindingsNotSupportedHere { span: Span },
+    #[error("ref parameters must be mutable, as in \"ref mut\"")]
+    RefParameterNotMutable { span: Span },
     #[error("constructor patterns cannot contain sub-patterns")]
     ConstructorPatternSubPatterns { span: Span },
     #[error("paths are not supported in this position")]
@@ -178,6 +189,8 @@ pub enum ConvertParseTreeError {
     DuplicateStorageField { name: Ident, span: Span },
     #[error("struct field \"{name}\" already declared")]
     DuplicateStructField { name: Ident, span: Span },
+    #[error("enum variants are always public and cannot be marked \"pub\"")]
+    PubEnumVariant { span: Span },
 }
 
 impl Spanned for ConvertParseTreeError {
@@ -206,6 +219,8 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::U16LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U32LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U64LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::U128LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::U256LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::SignedIntegersNotSupported { span } => span.clone(),
             ConvertParseTreeError::LiteralPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstantPatternsNotSupportedHere { span } => span.clone(),
@@ -215,6 +230,8 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::TuplePatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternOneArg { span } => span.clone(),
             ConvertParseTreeError::MutableBindingsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::RefBindingsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::RefParameterNotMutable { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternSubPatterns { span } => span.clone(),
             ConvertParseTreeError::PathsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::FullySpecifiedTypesNotSupported { span } => span.clone(),
@@ -227,6 +244,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::DuplicateEnumVariant { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateStorageField { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateStructField { span, .. } => span.clone(),
+            ConvertParseTreeError::PubEnumVariant { span } => span.clone(),
         }
     }
 }
@@ -290,13 +308,14 @@ fn item_to_ast_nodes(ec: &mut ErrorContext, item: Item) -> Result<Vec<AstNode>,
                 .collect()
         }
         ItemKind::Struct(item_struct) => {
-            let struct_declaration = item_struct_to_struct_declaration(ec, item_struct)?;
+            let struct_declaration =
+                item_struct_to_struct_declaration(ec, item_struct, &attributes)?;
             vec![AstNodeContent::Declaration(Declaration::StructDeclaration(
                 struct_declaration,
             ))]
         }
         ItemKind::Enum(item_enum) => {
-            let enum_declaration = item_enum_to_enum_declaration(ec, item_enum)?;
+            let enum_declaration = item_enum_to_enum_declaration(ec, item_enum, &attributes)?;
             vec![AstNodeContent::Declaration(Declaration::EnumDeclaration(
                 enum_declaration,
             ))]
@@ -480,6 +499,7 @@ fn use_tree_to_use_statements(
 fn item_struct_to_struct_declaration(
     ec: &mut ErrorContext,
     item_struct: ItemStruct,
+    attributes: &AttributesMap,
 ) -> Result<StructDeclaration, ErrorEmitted> {
     let mut errors = Vec::new();
     let span = item_struct.span();
@@ -520,6 +540,7 @@ fn item_struct_to_struct_declaration(
             item_struct.where_clause_opt,
         )?,
         visibility: pub_token_opt_to_visibility(item_struct.visibility),
+        derives: get_attributed_derives(ec, attributes)?,
         span,
     };
     Ok(struct_declaration)
@@ -528,6 +549,7 @@ fn item_struct_to_struct_declaration(
 fn item_enum_to_enum_declaration(
     ec: &mut ErrorContext,
     item_enum: ItemEnum,
+    attributes: &AttributesMap,
 ) -> Result<EnumDeclaration, ErrorEmitted> {
     let mut errors = Vec::new();
     let span = item_enum.span();
@@ -570,6 +592,7 @@ fn item_enum_to_enum_declaration(
         variants,
         span,
         visibility: pub_token_opt_to_visibility(item_enum.visibility),
+        derives: get_attributed_derives(ec, attributes)?,
     };
     Ok(enum_declaration)
 }
@@ -584,15 +607,16 @@ fn item_fn_to_function_declaration(
         Some((_right_arrow_token, ty)) => ty.span(),
         None => item_fn.fn_signature.span(),
     };
+    let (fn_args, destructured_args) =
+        destructure_fn_args(ec, item_fn.fn_signature.arguments.into_inner())?;
+    let mut body = braced_code_block_contents_to_code_block(ec, item_fn.body, false)?;
+    body.contents.splice(0..0, destructured_args);
     Ok(FunctionDeclaration {
         purity: get_attributed_purity(ec, attributes)?,
         name: item_fn.fn_signature.name,
         visibility: pub_token_opt_to_visibility(item_fn.fn_signature.visibility),
-        body: braced_code_block_contents_to_code_block(ec, item_fn.body, false)?,
-        parameters: fn_args_to_function_parameters(
-            ec,
-            item_fn.fn_signature.arguments.into_inner(),
-        )?,
+        body,
+        parameters: fn_args_to_function_parameters(ec, fn_args)?,
         span,
         return_type: match item_fn.fn_signature.return_type_opt {
             Some((_right_arrow, ty)) => ty_to_type_info(ec, ty)?,
@@ -639,6 +663,33 @@ fn get_attributed_purity(
     }
 }
 
+// Each trait may only be derived once, so repeated names are ignored.
+fn get_attributed_derives(
+    ec: &mut ErrorContext,
+    attributes: &AttributesMap,
+) -> Result<Vec<Derive>, ErrorEmitted> {
+    let mut derives: Vec<Derive> = Vec::new();
+    for arg in attributes.get(DERIVE_ATTRIBUTE_NAME).into_iter().flatten() {
+        match DerivableTrait::from_name(arg.as_str()) {
+            Some(derived_trait) => {
+                if derives.iter().all(|d| d.derived_trait != derived_trait) {
+                    derives.push(Derive {
+                        derived_trait,
+                        span: arg.span(),
+                    });
+                }
+            }
+            None => {
+                return Err(ec.error(ConvertParseTreeError::InvalidAttributeArgument {
+                    attribute: DERIVE_ATTRIBUTE_NAME.to_owned(),
+                    span: arg.span(),
+                }));
+            }
+        }
+    }
+    Ok(derives)
+}
+
 fn item_trait_to_trait_declaration(
     ec: &mut ErrorContext,
     item_trait: ItemTrait,
@@ -671,8 +722,10 @@ fn item_trait_to_trait_declaration(
         Some((_colon_token, traits)) => traits_to_supertraits(ec, traits)?,
     };
     let visibility = pub_token_opt_to_visibility(item_trait.visibility);
+    let type_parameters = generic_params_opt_to_type_parameters(ec, item_trait.generics, None)?;
     Ok(TraitDeclaration {
         name,
+        type_parameters,
         interface_surface,
         methods,
         supertraits,
@@ -707,8 +760,11 @@ fn item_impl_to_declaration(
 
     match item_impl.trait_opt {
         Some((path_type, _for_token)) => {
+            let (trait_name, trait_type_arguments) =
+                path_type_to_call_path_and_type_arguments(ec, path_type)?;
             let impl_trait = ImplTrait {
-                trait_name: path_type_to_call_path(ec, path_type)?,
+                trait_name,
+                trait_type_arguments,
                 type_implementing_for,
                 type_implementing_for_span,
                 type_parameters,
@@ -817,6 +873,7 @@ fn type_field_to_struct_field(
     let span = type_field.span();
     let type_span = type_field.ty.span();
     let struct_field = StructField {
+        visibility: pub_token_opt_to_visibility(type_field.visibility),
         name: type_field.name,
         type_info: ty_to_type_info(ec, type_field.ty)?,
         span,
@@ -902,6 +959,12 @@ fn type_field_to_enum_variant(
     tag: usize,
 ) -> Result<EnumVariant, ErrorEmitted> {
     let span = type_field.span();
+    if let Some(pub_token) = type_field.visibility {
+        let error = ConvertParseTreeError::PubEnumVariant {
+            span: pub_token.span(),
+        };
+        return Err(ec.error(error));
+    }
     let enum_variant = EnumVariant {
         name: type_field.name,
         type_info: ty_to_type_info(ec, type_field.ty)?,
@@ -940,6 +1003,87 @@ fn braced_code_block_contents_to_code_block(
     })
 }
 
+/// Replaces every destructuring pattern among `fn_args` with a new parameter
+/// name. Returns the new arguments along with the declarations which
+/// destructure those parameters, to be prepended to the function body.
+fn destructure_fn_args(
+    ec: &mut ErrorContext,
+    fn_args: FnArgs,
+) -> Result<(FnArgs, Vec<AstNode>), ErrorEmitted> {
+    fn destructure_fn_arg(
+        ec: &mut ErrorContext,
+        fn_arg: FnArg,
+        ast_nodes: &mut Vec<AstNode>,
+    ) -> Result<FnArg, ErrorEmitted> {
+        match fn_arg.pattern {
+            pattern @ (Pattern::Wildcard { .. }
+            | Pattern::Constructor { .. }
+            | Pattern::Struct { .. }
+            | Pattern::Tuple(..)) => {
+                let span = pattern.span();
+                let name = generate_destructured_name(span.clone());
+                let expression = Expression::VariableExpression {
+                    name: name.clone(),
+                    span: span.clone(),
+                };
+                ast_nodes.extend(pattern_to_ast_nodes(ec, pattern, None, expression, span)?);
+                Ok(FnArg {
+                    pattern: Pattern::Var {
+                        reference: None,
+                        mutable: None,
+                        name,
+                    },
+                    ..fn_arg
+                })
+            }
+            pattern => Ok(FnArg { pattern, ..fn_arg }),
+        }
+    }
+
+    fn destructure_punctuated(
+        ec: &mut ErrorContext,
+        args: Punctuated<FnArg, CommaToken>,
+        ast_nodes: &mut Vec<AstNode>,
+    ) -> Result<Punctuated<FnArg, CommaToken>, ErrorEmitted> {
+        let value_separator_pairs = args
+            .value_separator_pairs
+            .into_iter()
+            .map(|(fn_arg, comma_token)| {
+                destructure_fn_arg(ec, fn_arg, ast_nodes).map(|fn_arg| (fn_arg, comma_token))
+            })
+            .collect::<Result<_, _>>()?;
+        let final_value_opt = args
+            .final_value_opt
+            .map(|fn_arg| destructure_fn_arg(ec, *fn_arg, ast_nodes).map(Box::new))
+            .transpose()?;
+        Ok(Punctuated {
+            value_separator_pairs,
+            final_value_opt,
+        })
+    }
+
+    let mut ast_nodes = Vec::new();
+    let fn_args = match fn_args {
+        FnArgs::Static(args) => FnArgs::Static(destructure_punctuated(ec, args, &mut ast_nodes)?),
+        FnArgs::NonStatic {
+            self_token,
+            mutable_self,
+            args_opt,
+        } => FnArgs::NonStatic {
+            self_token,
+            mutable_self,
+            args_opt: match args_opt {
+                Some((comma_token, args)) => Some((
+                    comma_token,
+                    destructure_punctuated(ec, args, &mut ast_nodes)?,
+                )),
+                None => None,
+            },
+        },
+    };
+    Ok((fn_args, ast_nodes))
+}
+
 fn fn_args_to_function_parameters(
     ec: &mut ErrorContext,
     fn_args: FnArgs,
@@ -956,6 +1100,7 @@ fn fn_args_to_function_parameters(
         } => {
             let mut function_parameters = vec![FunctionParameter {
                 name: Ident::new(self_token.span()),
+                is_reference: false,
                 is_mutable: mutable_self.is_some(),
                 type_id: insert_type(TypeInfo::SelfType),
                 type_span: self_token.span(),
@@ -978,6 +1123,8 @@ fn type_name_to_type_info_opt(name: &Ident) -> Option<TypeInfo> {
         "u16" => Some(TypeInfo::UnsignedInteger(IntegerBits::Sixteen)),
         "u32" => Some(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
         "u64" => Some(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+        "u128" => Some(TypeInfo::UnsignedInteger(IntegerBits::OneTwentyEight)),
+        "u256" => Some(TypeInfo::UnsignedInteger(IntegerBits::TwoFiftySix)),
         "bool" => Some(TypeInfo::Boolean),
         "unit" => Some(TypeInfo::Tuple(Vec::new())),
         "byte" => Some(TypeInfo::Byte),
@@ -999,13 +1146,44 @@ fn ty_to_type_info(ec: &mut ErrorContext, ty: Ty) -> Result<TypeInfo, ErrorEmitt
         }
         Ty::Array(bracketed_ty_array_descriptor) => {
             let ty_array_descriptor = bracketed_ty_array_descriptor.into_inner();
-            TypeInfo::Array(
-                crate::type_engine::insert_type(ty_to_type_info(ec, *ty_array_descriptor.ty)?),
-                expr_to_usize(ec, *ty_array_descriptor.length)?,
-            )
+            let elem_type_id =
+                crate::type_engine::insert_type(ty_to_type_info(ec, *ty_array_descriptor.ty)?);
+            match *ty_array_descriptor.length {
+                length @ Expr::Literal(..) => {
+                    TypeInfo::Array(elem_type_id, expr_to_usize(ec, length)?)
+                }
+                // Any other length refers to constants, so it can't be evaluated until they are in
+                // scope during type checking.
+                length => TypeInfo::UnresolvedArray(
+                    elem_type_id,
+                    ArrayLength(expr_to_expression(ec, length)?),
+                ),
+            }
         }
         Ty::Str { length, .. } => TypeInfo::Str(expr_to_u64(ec, *length.into_inner())?),
         Ty::Infer { .. } => TypeInfo::Unknown,
+        Ty::Fn {
+            fn_token,
+            arguments,
+            return_type_opt,
+        } => {
+            let parameters = arguments
+                .into_inner()
+                .into_iter()
+                .map(|ty| ty_to_type_argument(ec, ty))
+                .collect::<Result<Vec<_>, _>>()?;
+            let return_type = match return_type_opt {
+                Some((_right_arrow_token, ty)) => ty_to_type_argument(ec, *ty)?,
+                None => TypeArgument {
+                    type_id: insert_type(TypeInfo::Tuple(Vec::new())),
+                    span: fn_token.span(),
+                },
+            };
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            }
+        }
     };
     Ok(type_info)
 }
@@ -1097,6 +1275,38 @@ fn path_type_to_call_path(
     Ok(call_path)
 }
 
+/// Like [path_type_to_call_path], but allows generic arguments on the last segment of the path,
+/// e.g., the `<u64>` in `impl From<u64> for MyType`.
+fn path_type_to_call_path_and_type_arguments(
+    ec: &mut ErrorContext,
+    path_type: PathType,
+) -> Result<(CallPath, Vec<TypeArgument>), ErrorEmitted> {
+    let PathType {
+        root_opt,
+        mut prefix,
+        mut suffix,
+    } = path_type;
+    let last_segment = match suffix.last_mut() {
+        Some((_double_colon_token, call_path_suffix)) => call_path_suffix,
+        None => &mut prefix,
+    };
+    let type_arguments = match last_segment.generics_opt.take() {
+        Some((_double_colon_token, generic_args)) => {
+            generic_args_to_type_arguments(ec, generic_args)?
+        }
+        None => Vec::new(),
+    };
+    let call_path = path_type_to_call_path(
+        ec,
+        PathType {
+            root_opt,
+            prefix,
+            suffix,
+        },
+    )?;
+    Ok((call_path, type_arguments))
+}
+
 fn expr_to_ast_node(
     ec: &mut ErrorContext,
     expr: Expr,
@@ -1727,6 +1937,14 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
             rhs: Box::new(expr_to_expression(ec, *rhs)?),
             span,
         },
+        Expr::Closure(expr_closure) => Expression::Closure {
+            function: Box::new(expr_closure_to_function_declaration(
+                ec,
+                expr_closure,
+                span.clone(),
+            )?),
+            span,
+        },
         Expr::Reassignment { .. } => {
             let error = ConvertParseTreeError::ReassignmentOutsideOfBlock { span };
             return Err(ec.error(error));
@@ -1735,6 +1953,59 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
     Ok(expression)
 }
 
+/// Desugars a closure into an anonymous function declaration. Parameters without a type
+/// annotation, and the return type, are left as `Unknown` to be inferred during type checking.
+fn expr_closure_to_function_declaration(
+    ec: &mut ErrorContext,
+    expr_closure: ExprClosure,
+    span: Span,
+) -> Result<FunctionDeclaration, ErrorEmitted> {
+    let parameters = match expr_closure.params {
+        ClosureParams::Empty { .. } => Vec::new(),
+        ClosureParams::List { params, .. } => params
+            .into_iter()
+            .map(|ClosureParam { name, ty_opt }| {
+                let (type_info, type_span) = match ty_opt {
+                    Some((_colon_token, ty)) => {
+                        let type_span = ty.span();
+                        (ty_to_type_info(ec, ty)?, type_span)
+                    }
+                    None => (TypeInfo::Unknown, name.span()),
+                };
+                Ok(FunctionParameter {
+                    name,
+                    is_reference: false,
+                    is_mutable: false,
+                    type_id: insert_type(type_info),
+                    type_span,
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    };
+    let body_span = expr_closure.body.span();
+    let body = CodeBlock {
+        contents: vec![AstNode {
+            content: AstNodeContent::ImplicitReturnExpression(expr_to_expression(
+                ec,
+                *expr_closure.body,
+            )?),
+            span: body_span.clone(),
+        }],
+        whole_block_span: body_span.clone(),
+    };
+    Ok(FunctionDeclaration {
+        purity: Purity::Pure,
+        name: Ident::new_with_override("__closure", span.clone()),
+        visibility: Visibility::Private,
+        body,
+        parameters,
+        span,
+        return_type: TypeInfo::Unknown,
+        type_parameters: Vec::new(),
+        return_type_span: body_span,
+    })
+}
+
 fn unary_op_call(
     ec: &mut ErrorContext,
     name: &'static str,
@@ -1815,20 +2086,31 @@ fn fn_arg_to_function_parameter(
 ) -> Result<FunctionParameter, ErrorEmitted> {
     let type_span = fn_arg.ty.span();
     let pat_span = fn_arg.pattern.span();
-    let name = match fn_arg.pattern {
+    let (name, is_reference) = match fn_arg.pattern {
         Pattern::Wildcard { .. } => {
             let error = ConvertParseTreeError::WildcardPatternsNotSupportedHere { span: pat_span };
             return Err(ec.error(error));
         }
-        Pattern::Var { mutable, name } => {
-            if let Some(mut_token) = mutable {
+        Pattern::Var {
+            reference,
+            mutable,
+            name,
+        } => match (reference, mutable) {
+            (Some(_ref_token), Some(_mut_token)) => (name, true),
+            (Some(ref_token), None) => {
+                let error = ConvertParseTreeError::RefParameterNotMutable {
+                    span: ref_token.span(),
+                };
+                return Err(ec.error(error));
+            }
+            (None, Some(mut_token)) => {
                 let error = ConvertParseTreeError::MutableBindingsNotSupportedHere {
                     span: mut_token.span(),
                 };
                 return Err(ec.error(error));
             }
-            name
-        }
+            (None, None) => (name, false),
+        },
         Pattern::Literal(..) => {
             let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span: pat_span };
             return Err(ec.error(error));
@@ -1853,7 +2135,8 @@ fn fn_arg_to_function_parameter(
     };
     let function_parameter = FunctionParameter {
         name,
-        is_mutable: false,
+        is_reference,
+        is_mutable: is_reference,
         type_id: insert_type(ty_to_type_info(ec, fn_arg.ty)?),
         type_span,
     };
@@ -2275,6 +2558,26 @@ fn literal_to_literal(
                         };
                         Literal::U64(value)
                     }
+                    LitIntType::U128 => {
+                        let value = match u128::try_from(parsed) {
+                            Ok(value) => value,
+                            Err(..) => {
+                                let error = ConvertParseTreeError::U128LiteralOutOfRange { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        Literal::U128(value)
+                    }
+                    LitIntType::U256 => {
+                        let bytes = parsed.to_bytes_be();
+                        if bytes.len() > 32 {
+                            let error = ConvertParseTreeError::U256LiteralOutOfRange { span };
+                            return Err(ec.error(error));
+                        }
+                        let mut full_bytes = [0u8; 32];
+                        full_bytes[(32 - bytes.len())..].copy_from_slice(&bytes);
+                        Literal::U256(full_bytes)
+                    }
                     LitIntType::I8 | LitIntType::I16 | LitIntType::I32 | LitIntType::I64 => {
                         let error = ConvertParseTreeError::SignedIntegersNotSupported { span };
                         return Err(ec.error(error));
@@ -2483,235 +2786,391 @@ fn statement_let_to_ast_nodes(
     ec: &mut ErrorContext,
     statement_let: StatementLet,
 ) -> Result<Vec<AstNode>, ErrorEmitted> {
-    fn unfold(
-        ec: &mut ErrorContext,
-        pattern: Pattern,
-        ty_opt: Option<Ty>,
-        expression: Expression,
-        span: Span,
-    ) -> Result<Vec<AstNode>, ErrorEmitted> {
-        let ast_nodes = match pattern {
-            Pattern::Wildcard { .. } | Pattern::Var { .. } => {
-                let (mutable, name) = match pattern {
-                    Pattern::Var { mutable, name } => (mutable, name),
-                    Pattern::Wildcard { .. } => (None, Ident::new_no_span("_")),
-                    _ => unreachable!(),
-                };
-                let (type_ascription, type_ascription_span) = match ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(ec, ty)?;
-                        (type_ascription, Some(type_ascription_span))
+    let span = statement_let.span();
+    let initial_expression = expr_to_expression(ec, statement_let.expr)?;
+    pattern_to_ast_nodes(
+        ec,
+        statement_let.pattern,
+        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
+        initial_expression,
+        span,
+    )
+}
+
+/// Creates the variable declarations which bind the variables of `pattern` to
+/// the corresponding parts of `expression`.
+fn pattern_to_ast_nodes(
+    ec: &mut ErrorContext,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let ast_nodes = match pattern {
+        Pattern::Wildcard { .. } | Pattern::Var { .. } => {
+            let (mutable, name) = match pattern {
+                Pattern::Var {
+                    reference: Some(ref_token),
+                    ..
+                } => {
+                    let error = ConvertParseTreeError::RefBindingsNotSupportedHere {
+                        span: ref_token.span(),
+                    };
+                    return Err(ec.error(error));
+                }
+                Pattern::Var { mutable, name, .. } => (mutable, name),
+                Pattern::Wildcard { .. } => (None, Ident::new_no_span("_")),
+                _ => unreachable!(),
+            };
+            let (type_ascription, type_ascription_span) = match ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(ec, ty)?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
+            let ast_node = AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    VariableDeclaration {
+                        name,
+                        type_ascription,
+                        type_ascription_span,
+                        body: expression,
+                        is_mutable: mutable.is_some(),
+                    },
+                )),
+                span,
+            };
+            vec![ast_node]
+        }
+        Pattern::Literal(..) | Pattern::Constant(..) | Pattern::Constructor { .. } => {
+            irrefutable_pattern_to_ast_nodes(ec, pattern, ty_opt, expression, span)?
+        }
+        Pattern::Struct { fields, .. } => {
+            let mut ast_nodes = Vec::new();
+
+            // Generate a deterministic name for the destructured struct
+            let destructure_name = generate_destructured_name(span.clone());
+
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to TypeInfo::Unknown and the span to None.
+            let (type_ascription, type_ascription_span) = match &ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(ec, ty.clone())?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
+
+            // Save the destructure to the new name as a new variable declaration
+            let save_body_first = VariableDeclaration {
+                name: destructure_name.clone(),
+                type_ascription,
+                type_ascription_span,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
+
+            // create a new variable expression that points to the new destructured struct name that we just created
+            let new_expr = Expression::VariableExpression {
+                name: destructure_name,
+                span: span.clone(),
+            };
+
+            // for all of the fields of the struct destructuring on the LHS,
+            // recursively create variable declarations
+            for pattern_struct_field in fields.into_inner().into_iter() {
+                let (field, recursive_pattern) = match pattern_struct_field {
+                    PatternStructField::Field {
+                        field_name,
+                        pattern_opt,
+                    } => {
+                        let recursive_pattern = match pattern_opt {
+                            Some((_colon_token, box_pattern)) => *box_pattern,
+                            None => Pattern::Var {
+                                reference: None,
+                                mutable: None,
+                                name: field_name.clone(),
+                            },
+                        };
+                        (field_name, recursive_pattern)
                     }
-                    None => (TypeInfo::Unknown, None),
-                };
-                let ast_node = AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        VariableDeclaration {
-                            name,
-                            type_ascription,
-                            type_ascription_span,
-                            body: expression,
-                            is_mutable: mutable.is_some(),
-                        },
-                    )),
-                    span,
-                };
-                vec![ast_node]
-            }
-            Pattern::Literal(..) => {
-                let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Constant(..) => {
-                let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Constructor { .. } => {
-                let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Struct { fields, .. } => {
-                let mut ast_nodes = Vec::new();
-
-                // Generate a deterministic name for the destructured struct
-                // Because the parser is single threaded, the name generated below will be stable.
-                static COUNTER: AtomicUsize = AtomicUsize::new(0);
-                let destructured_name = format!(
-                    "{}{}",
-                    crate::constants::DESTRUCTURE_PREFIX,
-                    COUNTER.load(Ordering::SeqCst)
-                );
-                COUNTER.fetch_add(1, Ordering::SeqCst);
-                let destructure_name = Ident::new_with_override(
-                    Box::leak(destructured_name.into_boxed_str()),
-                    span.clone(),
-                );
-
-                // Parse the type ascription and the type ascription span.
-                // In the event that the user did not provide a type ascription,
-                // it is set to TypeInfo::Unknown and the span to None.
-                let (type_ascription, type_ascription_span) = match &ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(ec, ty.clone())?;
-                        (type_ascription, Some(type_ascription_span))
+                    PatternStructField::Rest { .. } => {
+                        continue;
                     }
-                    None => (TypeInfo::Unknown, None),
                 };
 
-                // Save the destructure to the new name as a new variable declaration
-                let save_body_first = VariableDeclaration {
-                    name: destructure_name.clone(),
-                    type_ascription,
-                    type_ascription_span,
-                    body: expression,
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
+                // recursively create variable declarations for the subpatterns on the LHS
+                // and add them to the ast nodes
+                ast_nodes.extend(pattern_to_ast_nodes(
+                    ec,
+                    recursive_pattern,
+                    None,
+                    Expression::SubfieldExpression {
+                        prefix: Box::new(new_expr.clone()),
+                        span: span.clone(),
+                        field_to_access: field,
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+        Pattern::Tuple(pat_tuple) => {
+            let mut ast_nodes = Vec::new();
+
+            // Generate a deterministic name for the tuple.
+            let tuple_name = generate_tuple_var_name(span.clone());
+
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to TypeInfo::Unknown and the span to None.
+            let (type_ascription, type_ascription_span) = match &ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(ec, ty.clone())?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
 
-                // create a new variable expression that points to the new destructured struct name that we just created
-                let new_expr = Expression::VariableExpression {
-                    name: destructure_name,
-                    span: span.clone(),
-                };
+            // Save the tuple to the new name as a new variable declaration.
+            let save_body_first = VariableDeclaration {
+                name: tuple_name.clone(),
+                type_ascription,
+                type_ascription_span,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
 
-                // for all of the fields of the struct destructuring on the LHS,
-                // recursively create variable declarations
-                for pattern_struct_field in fields.into_inner().into_iter() {
-                    let (field, recursive_pattern) = match pattern_struct_field {
-                        PatternStructField::Field {
-                            field_name,
-                            pattern_opt,
-                        } => {
-                            let recursive_pattern = match pattern_opt {
-                                Some((_colon_token, box_pattern)) => *box_pattern,
-                                None => Pattern::Var {
-                                    mutable: None,
-                                    name: field_name.clone(),
-                                },
-                            };
-                            (field_name, recursive_pattern)
-                        }
-                        PatternStructField::Rest { .. } => {
-                            continue;
-                        }
-                    };
+            // create a variable expression that points to the new tuple name that we just created
+            let new_expr = Expression::VariableExpression {
+                name: tuple_name,
+                span: span.clone(),
+            };
 
-                    // recursively create variable declarations for the subpatterns on the LHS
-                    // and add them to the ast nodes
-                    ast_nodes.extend(unfold(
-                        ec,
-                        recursive_pattern,
-                        None,
-                        Expression::SubfieldExpression {
-                            prefix: Box::new(new_expr.clone()),
-                            span: span.clone(),
-                            field_to_access: field,
-                        },
-                        span.clone(),
-                    )?);
-                }
-                ast_nodes
-            }
-            Pattern::Tuple(pat_tuple) => {
-                let mut ast_nodes = Vec::new();
-
-                // Generate a deterministic name for the tuple.
-                // Because the parser is single threaded, the name generated below will be stable.
-                static COUNTER: AtomicUsize = AtomicUsize::new(0);
-                let tuple_name = format!(
-                    "{}{}",
-                    crate::constants::TUPLE_NAME_PREFIX,
-                    COUNTER.load(Ordering::SeqCst)
-                );
-                COUNTER.fetch_add(1, Ordering::SeqCst);
-                let tuple_name =
-                    Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span.clone());
-
-                // Parse the type ascription and the type ascription span.
-                // In the event that the user did not provide a type ascription,
-                // it is set to TypeInfo::Unknown and the span to None.
-                let (type_ascription, type_ascription_span) = match &ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(ec, ty.clone())?;
-                        (type_ascription, Some(type_ascription_span))
-                    }
-                    None => (TypeInfo::Unknown, None),
-                };
+            // from the possible type annotation, if the annotation was a tuple annotation,
+            // extract the internal types of the annotation
+            let tuple_tys_opt = match ty_opt {
+                Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
+                _ => None,
+            };
 
-                // Save the tuple to the new name as a new variable declaration.
-                let save_body_first = VariableDeclaration {
-                    name: tuple_name.clone(),
-                    type_ascription,
-                    type_ascription_span,
-                    body: expression,
-                    is_mutable: false,
+            // for all of the elements in the tuple destructuring on the LHS,
+            // recursively create variable declarations
+            for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
+                // from the possible type annotation, grab the type at the index of the current element
+                // we are processing
+                let ty_opt = match &tuple_tys_opt {
+                    Some(tys) => tys.get(index).cloned(),
+                    None => None,
                 };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
+                // recursively create variable declarations for the subpatterns on the LHS
+                // and add them to the ast nodes
+                ast_nodes.extend(pattern_to_ast_nodes(
+                    ec,
+                    pattern,
+                    ty_opt,
+                    Expression::TupleIndex {
+                        prefix: Box::new(new_expr.clone()),
+                        index,
+                        index_span: span.clone(),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+    };
+    Ok(ast_nodes)
+}
 
-                // create a variable expression that points to the new tuple name that we just created
-                let new_expr = Expression::VariableExpression {
-                    name: tuple_name,
-                    span: span.clone(),
-                };
+/// Creates the variable declarations for a pattern that can only be
+/// destructured once the type of `expression` is known, i.e. one containing
+/// enum constructors, literals or constants.
+///
+/// The expression is saved to a new variable which is then matched against
+/// the pattern in an [Expression::IrrefutableMatchExp]. The single arm of that
+/// match returns the variables bound by the pattern, which are then declared
+/// with the mutability given in the pattern.
+fn irrefutable_pattern_to_ast_nodes(
+    ec: &mut ErrorContext,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let mut ast_nodes = Vec::new();
+
+    // Generate a deterministic name for the destructured value
+    let destructure_name = generate_destructured_name(span.clone());
+
+    // Parse the type ascription and the type ascription span.
+    // In the event that the user did not provide a type ascription,
+    // it is set to TypeInfo::Unknown and the span to None.
+    let (type_ascription, type_ascription_span) = match ty_opt {
+        Some(ty) => {
+            let type_ascription_span = ty.span();
+            let type_ascription = ty_to_type_info(ec, ty)?;
+            (type_ascription, Some(type_ascription_span))
+        }
+        None => (TypeInfo::Unknown, None),
+    };
+
+    // Save the value to the new name as a new variable declaration, so that
+    // it is only evaluated once
+    ast_nodes.push(AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name: destructure_name.clone(),
+                type_ascription,
+                type_ascription_span,
+                body: expression,
+                is_mutable: false,
+            },
+        )),
+        span: span.clone(),
+    });
 
-                // from the possible type annotation, if the annotation was a tuple annotation,
-                // extract the internal types of the annotation
-                let tuple_tys_opt = match ty_opt {
-                    Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
-                    _ => None,
+    // the single match arm returns the bound variables, as a tuple if there
+    // are more than one of them
+    let mut bindings = Vec::new();
+    collect_pattern_bindings(&pattern, &mut bindings);
+    let mut binding_exprs = bindings
+        .iter()
+        .map(|(_is_mutable, name)| Expression::VariableExpression {
+            name: name.clone(),
+            span: name.span(),
+        })
+        .collect::<Vec<_>>();
+    let result = if binding_exprs.len() == 1 {
+        binding_exprs.remove(0)
+    } else {
+        Expression::Tuple {
+            fields: binding_exprs,
+            span: span.clone(),
+        }
+    };
+    let match_exp = Expression::IrrefutableMatchExp {
+        value: Box::new(Expression::VariableExpression {
+            name: destructure_name,
+            span: span.clone(),
+        }),
+        scrutinee: pattern_to_scrutinee(ec, pattern)?,
+        result: Box::new(result),
+        span: span.clone(),
+    };
+
+    let declare = |name: Ident, is_mutable: bool, body: Expression| AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name,
+                type_ascription: TypeInfo::Unknown,
+                type_ascription_span: None,
+                body,
+                is_mutable,
+            },
+        )),
+        span: span.clone(),
+    };
+    match bindings.len() {
+        0 => ast_nodes.push(declare(Ident::new_no_span("_"), false, match_exp)),
+        1 => {
+            let (is_mutable, name) = bindings.remove(0);
+            ast_nodes.push(declare(name, is_mutable, match_exp));
+        }
+        _ => {
+            let tuple_name = generate_tuple_var_name(span.clone());
+            ast_nodes.push(declare(tuple_name.clone(), false, match_exp));
+            for (index, (is_mutable, name)) in bindings.into_iter().enumerate() {
+                let body = Expression::TupleIndex {
+                    prefix: Box::new(Expression::VariableExpression {
+                        name: tuple_name.clone(),
+                        span: span.clone(),
+                    }),
+                    index,
+                    index_span: span.clone(),
+                    span: span.clone(),
                 };
+                ast_nodes.push(declare(name, is_mutable, body));
+            }
+        }
+    }
+    Ok(ast_nodes)
+}
 
-                // for all of the elements in the tuple destructuring on the LHS,
-                // recursively create variable declarations
-                for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
-                    // from the possible type annotation, grab the type at the index of the current element
-                    // we are processing
-                    let ty_opt = match &tuple_tys_opt {
-                        Some(tys) => tys.get(index).cloned(),
-                        None => None,
-                    };
-                    // recursively create variable declarations for the subpatterns on the LHS
-                    // and add them to the ast nodes
-                    ast_nodes.extend(unfold(
-                        ec,
-                        pattern,
-                        ty_opt,
-                        Expression::TupleIndex {
-                            prefix: Box::new(new_expr.clone()),
-                            index,
-                            index_span: span.clone(),
-                            span: span.clone(),
-                        },
-                        span.clone(),
-                    )?);
+/// Collects the variables bound by `pattern`, in order of appearance, along
+/// with whether they are declared as mutable.
+fn collect_pattern_bindings(pattern: &Pattern, bindings: &mut Vec<(bool, Ident)>) {
+    match pattern {
+        Pattern::Var { mutable, name, .. } => bindings.push((mutable.is_some(), name.clone())),
+        Pattern::Wildcard { .. } | Pattern::Literal(..) | Pattern::Constant(..) => (),
+        Pattern::Constructor { args, .. } => {
+            for arg in args.get() {
+                collect_pattern_bindings(arg, bindings);
+            }
+        }
+        Pattern::Struct { fields, .. } => {
+            for field in fields.get() {
+                match field {
+                    PatternStructField::Field {
+                        pattern_opt: Some((_colon_token, pattern)),
+                        ..
+                    } => collect_pattern_bindings(pattern, bindings),
+                    PatternStructField::Field {
+                        field_name,
+                        pattern_opt: None,
+                    } => bindings.push((false, field_name.clone())),
+                    PatternStructField::Rest { .. } => (),
                 }
-                ast_nodes
             }
-        };
-        Ok(ast_nodes)
+        }
+        Pattern::Tuple(pat_tuple) => {
+            for pattern in pat_tuple.get() {
+                collect_pattern_bindings(pattern, bindings);
+            }
+        }
     }
-    let span = statement_let.span();
-    let initial_expression = expr_to_expression(ec, statement_let.expr)?;
-    unfold(
-        ec,
-        statement_let.pattern,
-        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
-        initial_expression,
-        span,
-    )
+}
+
+/// Generates a deterministic name for a destructured value.
+/// Because the parser is single threaded, the name generated below will be stable.
+fn generate_destructured_name(span: Span) -> Ident {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let destructured_name = format!(
+        "{}{}",
+        crate::constants::DESTRUCTURE_PREFIX,
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    );
+    Ident::new_with_override(Box::leak(destructured_name.into_boxed_str()), span)
+}
+
+/// Generates a deterministic name for a destructured tuple.
+/// Because the parser is single threaded, the name generated below will be stable.
+fn generate_tuple_var_name(span: Span) -> Ident {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let tuple_name = format!(
+        "{}{}",
+        crate::constants::TUPLE_NAME_PREFIX,
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    );
+    Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span)
 }
 
 fn dependency_to_include_statement(dependency: &Dependency) -> IncludeStatement {
@@ -2766,6 +3225,15 @@ fn pattern_to_scrutinee(
         Pattern::Wildcard { underscore_token } => Scrutinee::CatchAll {
             span: underscore_token.span(),
         },
+        Pattern::Var {
+            reference: Some(ref_token),
+            ..
+        } => {
+            let error = ConvertParseTreeError::RefBindingsNotSupportedHere {
+                span: ref_token.span(),
+            };
+            return Err(ec.error(error));
+        }
         Pattern::Var { name, .. } => Scrutinee::Variable { name, span },
         Pattern::Literal(literal) => Scrutinee::Literal {
             value: literal_to_literal(ec, literal)?,
@@ -2860,6 +3328,7 @@ fn ty_to_type_parameter(ec: &mut ErrorContext, ty: Ty) -> Result<TypeParameter,
         Ty::Tuple(..) => panic!("tuple types are not allowed in this position"),
         Ty::Array(..) => panic!("array types are not allowed in this position"),
         Ty::Str { .. } => panic!("str types are not allowed in this position"),
+        Ty::Fn { .. } => panic!("function types are not allowed in this position"),
     };
     Ok(TypeParameter {
         type_id: insert_type(TypeInfo::Custom {
@@ -294,6 +294,10 @@ pub enum Warning {
         unneeded_attrib: String,
     },
     MatchExpressionUnreachableArm,
+    StorageWriteAfterContractCall {
+        method_name: String,
+        contract_method: String,
+    },
 }
 
 impl fmt::Display for Warning {
@@ -417,6 +421,17 @@ impl fmt::Display for Warning {
                 and can be removed."
             ),
             MatchExpressionUnreachableArm => write!(f, "This match arm is unreachable."),
+            StorageWriteAfterContractCall {
+                method_name,
+                contract_method,
+            } => write!(
+                f,
+                "Storage may be written after this call to \"{contract_method}\", so the called \
+                contract could re-enter \"{method_name}\" before storage is updated. Consider \
+                writing storage before making external calls (the checks-effects-interactions \
+                pattern) or calling `std::reentrancy::reentrancy_guard` at the start of \
+                \"{method_name}\"."
+            ),
         }
     }
 }
@@ -514,6 +529,39 @@ pub enum CompileError {
         variable_name: Ident,
         span: Span,
     },
+    #[error("The argument to \"ref mut\" parameter \"{param_name}\" must be a mutable variable.")]
+    RefMutableArgumentNotVariable { param_name: Ident, span: Span },
+    #[error(
+        "Cannot pass variable \"{name}\" to \"ref mut\" parameter \"{param_name}\" because \
+            \"{name}\" is not declared as mutable."
+    )]
+    RefMutableArgumentNotMutable {
+        name: Ident,
+        param_name: Ident,
+        span: Span,
+    },
+    #[error(
+        "Variable \"{name}\" is passed to a \"ref mut\" parameter, so it cannot also be used by \
+            another argument of the same call."
+    )]
+    RefMutableArgumentAliased { name: Ident, span: Span },
+    #[error(
+        "Parameter \"{param_name}\" cannot be \"ref mut\". The \"main\" function and ABI methods \
+            take their arguments by value."
+    )]
+    RefMutableParameterInEntryPoint { param_name: Ident, span: Span },
+    #[error("Function \"{name}\" has \"ref mut\" parameters and so cannot be used as a value.")]
+    RefMutableFunctionAsValue { name: Ident, span: Span },
+    #[error(
+        "Parameter \"{param_name}\" of \"{fn_name}\" must be \"ref mut\" exactly when it is \
+            \"ref mut\" in the declaration of trait \"{trait_name}\"."
+    )]
+    RefMutableParameterMismatchInTrait {
+        param_name: Ident,
+        fn_name: Ident,
+        trait_name: Ident,
+        span: Span,
+    },
     #[error(
         "Cannot call associated function \"{fn_name}\" as a method. Use associated function \
         syntax instead."
@@ -659,6 +707,12 @@ pub enum CompileError {
         available_fields: String,
         struct_name: Ident,
     },
+    #[error("Field \"{field_name}\" of struct \"{struct_name}\" is private.")]
+    StructFieldIsPrivate {
+        field_name: Ident,
+        struct_name: Ident,
+        span: Span,
+    },
     #[error("Could not find symbol \"{name}\" in this scope.")]
     SymbolNotFound { name: Ident },
     #[error("Symbol \"{name}\" is private.")]
@@ -829,6 +883,14 @@ pub enum CompileError {
         should_be: String,
         provided: String,
     },
+    #[error("Function values must be known at compile time. They can be passed as arguments, bound with \"let\" and called, but not returned, stored in data structures or reassigned.")]
+    FunctionValueNotKnown { span: Span },
+    #[error("Generic function \"{name}\" cannot be used as a value.")]
+    GenericFunctionAsValue { name: Ident, span: Span },
+    #[error(
+        "Closures cannot capture variables, but \"{name}\" is declared outside of this closure."
+    )]
+    ClosureCapturesVariable { name: Ident, span: Span },
     #[error("Function {fn_name} is recursive, which is unsupported at this time.")]
     RecursiveCall { fn_name: Ident, span: Span },
     #[error(
@@ -861,6 +923,15 @@ pub enum CompileError {
     BurnFromExternalContext { span: Span },
     #[error("Contract storage cannot be used in an external context.")]
     ContractStorageFromExternalContext { span: Span },
+    #[error("Contract calls cannot be made from a predicate.")]
+    ContractCallInPredicate { span: Span },
+    #[error(
+        "Loops cannot be used in a predicate. Predicates must not jump backwards, so that they \
+        always finish in a bounded amount of time."
+    )]
+    LoopInPredicate { span: Span },
+    #[error("The {opcode} opcode cannot be used in a predicate.")]
+    DisallowedOpcodeInPredicate { opcode: String, span: Span },
     #[error("Array index out of bounds; the length is {count} but the index is {index}.")]
     ArrayOutOfBounds { index: u64, count: u64, span: Span },
     #[error("Tuple index out of bounds; the arity is {count} but the index is {index}.")]
@@ -886,6 +957,15 @@ pub enum CompileError {
         missing_patterns: String,
         span: Span,
     },
+    #[error(
+        "Refutable pattern in `let` binding. Missing patterns {missing_patterns}\n\
+         help: `let` bindings require a pattern that matches every possible value; \
+         use a `match` or `if let` expression to handle the remaining cases."
+    )]
+    RefutablePatternInLet {
+        missing_patterns: String,
+        span: Span,
+    },
     #[error("Pattern does not mention {}: {}",
         if missing_fields.len() == 1 { "field" } else { "fields" },
         missing_fields.join(", "))]
@@ -996,6 +1076,12 @@ pub enum CompileError {
     WhereClauseNotYetSupported { span: Span },
     #[error("Could not evaluate initializer to a const declaration.")]
     NonConstantDeclValue { span: Span },
+    #[error("Could not evaluate initializer to a const declaration within {limit} steps.")]
+    ConstEvalStepLimitExceeded { limit: u64, span: Span },
+    #[error("Evaluating this initializer would panic at runtime, e.g., due to an overflow or a division by zero.")]
+    ConstEvalPanic { span: Span },
+    #[error("The array length \"{length}\" must be a `u64` constant.")]
+    ArrayLengthNotConstU64 { length: String, span: Span },
     #[error("Declaring storage in a {program_kind} is not allowed.")]
     StorageDeclarationInNonContract { program_kind: String, span: Span },
     #[error("Unsupported argument type to intrinsic \"{name}\".")]
@@ -1016,6 +1102,26 @@ pub enum CompileError {
     BreakOutsideLoop { span: Span },
     #[error("\"continue\" used outside of a loop")]
     ContinueOutsideLoop { span: Span },
+    #[error(
+        "Cannot derive \"{trait_name}\" for \"{type_name}\" because the type of \"{member}\", \
+         \"{member_type}\", does not implement \"{trait_name}\"."
+    )]
+    DeriveMemberMissingTrait {
+        trait_name: String,
+        type_name: Ident,
+        member: Ident,
+        member_type: String,
+        span: Span,
+    },
+    #[error(
+        "Cannot derive \"{trait_name}\" for \"{type_name}\". Deriving traits for generic types \
+         is not supported."
+    )]
+    DeriveOnGenericType {
+        trait_name: String,
+        type_name: Ident,
+        span: Span,
+    },
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -1051,6 +1157,12 @@ impl Spanned for CompileError {
             ReassignmentToNonVariable { span, .. } => span.clone(),
             AssignmentToNonMutable { name } => name.span(),
             MethodRequiresMutableSelf { span, .. } => span.clone(),
+            RefMutableArgumentNotVariable { span, .. } => span.clone(),
+            RefMutableArgumentNotMutable { span, .. } => span.clone(),
+            RefMutableArgumentAliased { span, .. } => span.clone(),
+            RefMutableParameterInEntryPoint { span, .. } => span.clone(),
+            RefMutableFunctionAsValue { span, .. } => span.clone(),
+            RefMutableParameterMismatchInTrait { span, .. } => span.clone(),
             AssociatedFunctionCalledAsMethod { span, .. } => span.clone(),
             TypeParameterNotInTypeScope { span, .. } => span.clone(),
             MultipleImmediates(span) => span.clone(),
@@ -1075,6 +1187,7 @@ impl Spanned for CompileError {
             NotAStruct { span, .. } => span.clone(),
             FieldAccessOnNonStruct { span, .. } => span.clone(),
             FieldNotFound { field_name, .. } => field_name.span(),
+            StructFieldIsPrivate { span, .. } => span.clone(),
             SymbolNotFound { name, .. } => name.span(),
             ImportPrivateSymbol { name } => name.span(),
             NoElseBranch { span, .. } => span.clone(),
@@ -1121,6 +1234,9 @@ impl Spanned for CompileError {
             ImplAbiForNonContract { span, .. } => span.clone(),
             IncorrectNumberOfInterfaceSurfaceFunctionParameters { span, .. } => span.clone(),
             ArgumentParameterTypeMismatch { span, .. } => span.clone(),
+            FunctionValueNotKnown { span } => span.clone(),
+            GenericFunctionAsValue { span, .. } => span.clone(),
+            ClosureCapturesVariable { span, .. } => span.clone(),
             RecursiveCall { span, .. } => span.clone(),
             RecursiveCallChain { span, .. } => span.clone(),
             RecursiveType { span, .. } => span.clone(),
@@ -1131,12 +1247,16 @@ impl Spanned for CompileError {
             MintFromExternalContext { span, .. } => span.clone(),
             BurnFromExternalContext { span, .. } => span.clone(),
             ContractStorageFromExternalContext { span, .. } => span.clone(),
+            ContractCallInPredicate { span } => span.clone(),
+            LoopInPredicate { span } => span.clone(),
+            DisallowedOpcodeInPredicate { span, .. } => span.clone(),
             ArrayOutOfBounds { span, .. } => span.clone(),
             ShadowsOtherSymbol { name } => name.span(),
             GenericShadowsGeneric { name } => name.span(),
             StarImportShadowsOtherSymbol { name } => name.span(),
             MatchWrongType { span, .. } => span.clone(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
+            RefutablePatternInLet { span, .. } => span.clone(),
             MatchStructPatternMissingFields { span, .. } => span.clone(),
             NotAnEnum { span, .. } => span.clone(),
             StorageAccessMismatch { span, .. } => span.clone(),
@@ -1173,12 +1293,17 @@ impl Spanned for CompileError {
             EnumNotFound { span, .. } => span.clone(),
             TupleIndexOutOfBounds { span, .. } => span.clone(),
             NonConstantDeclValue { span } => span.clone(),
+            ConstEvalStepLimitExceeded { span, .. } => span.clone(),
+            ConstEvalPanic { span } => span.clone(),
+            ArrayLengthNotConstU64 { span, .. } => span.clone(),
             StorageDeclarationInNonContract { span, .. } => span.clone(),
             IntrinsicUnsupportedArgType { span, .. } => span.clone(),
             IntrinsicIncorrectNumArgs { span, .. } => span.clone(),
             IntrinsicIncorrectNumTArgs { span, .. } => span.clone(),
             BreakOutsideLoop { span } => span.clone(),
             ContinueOutsideLoop { span } => span.clone(),
+            DeriveMemberMissingTrait { span, .. } => span.clone(),
+            DeriveOnGenericType { span, .. } => span.clone(),
         }
     }
 }
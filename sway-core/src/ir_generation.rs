@@ -4,6 +4,7 @@ mod convert;
 mod function;
 mod lexical_map;
 mod purity;
+mod reentrancy;
 pub mod storage;
 mod types;
 
@@ -12,10 +13,11 @@ use crate::{
     semantic_analysis::{TypedProgram, TypedProgramKind},
 };
 
-use sway_ir::Context;
+use sway_ir::{Context, Kind};
 use sway_types::span::Span;
 
 pub(crate) use purity::PurityChecker;
+pub(crate) use reentrancy::check_reentrancy;
 
 pub fn compile_program(program: TypedProgram) -> Result<Context, CompileError> {
     let TypedProgram { kind, root, .. } = program;
@@ -25,13 +27,25 @@ pub fn compile_program(program: TypedProgram) -> Result<Context, CompileError> {
         TypedProgramKind::Script {
             main_function,
             declarations,
-        }
-        | TypedProgramKind::Predicate {
+        } => compile::compile_script(
+            &mut ctx,
+            Kind::Script,
             main_function,
+            &root.namespace,
             declarations,
-            // predicates and scripts have the same codegen, their only difference is static
-            // type-check time checks.
-        } => compile::compile_script(&mut ctx, main_function, &root.namespace, declarations),
+        ),
+        // Predicates have the same codegen as scripts, but the module kind is kept so that the
+        // backend can check for opcodes which predicates may not use.
+        TypedProgramKind::Predicate {
+            main_function,
+            declarations,
+        } => compile::compile_script(
+            &mut ctx,
+            Kind::Predicate,
+            main_function,
+            &root.namespace,
+            declarations,
+        ),
         TypedProgramKind::Contract {
             abi_entries,
             declarations,
@@ -7,19 +7,23 @@ use crate::{
 use super::{
     const_eval::{compile_const_decl, LookupEnv},
     convert::convert_resolved_typeid,
-    function::FnCompiler,
+    function::{FnCompiler, FunctionValues},
 };
 
 use sway_ir::*;
 use sway_types::{span::Span, Spanned};
 
+use std::collections::HashSet;
+
+/// Compile a script or predicate, depending on `kind`.
 pub(super) fn compile_script(
     context: &mut Context,
+    kind: Kind,
     main_function: TypedFunctionDeclaration,
     namespace: &namespace::Module,
     declarations: Vec<TypedDeclaration>,
 ) -> Result<Module, CompileError> {
-    let module = Module::new(context, Kind::Script);
+    let module = Module::new(context, kind);
 
     compile_constants(context, module, namespace, false)?;
     compile_declarations(context, module, namespace, declarations)?;
@@ -141,6 +145,16 @@ pub(super) fn compile_function(
     context: &mut Context,
     module: Module,
     ast_fn_decl: TypedFunctionDeclaration,
+) -> Result<Option<Function>, CompileError> {
+    compile_function_with_values(context, module, ast_fn_decl, FunctionValues::default())
+}
+
+/// Compile a function specialized for the functions passed to its parameters of function type.
+pub(super) fn compile_function_with_values(
+    context: &mut Context,
+    module: Module,
+    ast_fn_decl: TypedFunctionDeclaration,
+    function_values: FunctionValues,
 ) -> Result<Option<Function>, CompileError> {
     // Currently monomorphisation of generics is inlined into main() and the functions with generic
     // args are still present in the AST declarations, but they can be ignored.
@@ -156,7 +170,7 @@ pub(super) fn compile_function(
             })
             .collect::<Result<Vec<(String, Type, Span)>, CompileError>>()?;
 
-        compile_fn_with_args(context, module, ast_fn_decl, args, None).map(&Some)
+        compile_fn_with_args(context, module, ast_fn_decl, args, None, function_values).map(&Some)
     }
 }
 
@@ -166,10 +180,12 @@ fn compile_fn_with_args(
     ast_fn_decl: TypedFunctionDeclaration,
     args: Vec<(String, Type, Span)>,
     selector: Option<[u8; 4]>,
+    function_values: FunctionValues,
 ) -> Result<Function, CompileError> {
     let TypedFunctionDeclaration {
         name,
         body,
+        parameters,
         return_type,
         return_type_span,
         visibility,
@@ -178,9 +194,23 @@ fn compile_fn_with_args(
         ..
     } = ast_fn_decl;
 
+    // Arguments to `ref mut` parameters are boxed in a single field struct, so that they're
+    // passed by address like any other aggregate.
+    let reference_args = parameters
+        .iter()
+        .filter(|param| param.is_reference)
+        .map(|param| param.name.as_str().to_owned())
+        .collect::<HashSet<_>>();
     let args = args
         .into_iter()
-        .map(|(name, ty, span)| (name, ty, MetadataIndex::from_span(context, &span)))
+        .map(|(name, ty, span)| {
+            let ty = if reference_args.contains(&name) {
+                Type::Struct(Aggregate::new_struct(context, vec![ty]))
+            } else {
+                ty
+            };
+            (name, ty, MetadataIndex::from_span(context, &span))
+        })
         .collect();
     let ret_type = convert_resolved_typeid(context, &return_type, &return_type_span)?;
     let span_md_idx = MetadataIndex::from_span(context, &span);
@@ -211,7 +241,7 @@ fn compile_fn_with_args(
 
     // We clone the struct symbols here, as they contain the globals; any new local declarations
     // may remain within the function scope.
-    let mut compiler = FnCompiler::new(context, module, func);
+    let mut compiler = FnCompiler::new(context, module, func, function_values, reference_args);
 
     let mut ret_val = compiler.compile_code_block(context, body)?;
 
@@ -273,7 +303,7 @@ fn compile_impl(
             })
             .collect::<Result<Vec<(String, Type, Span)>, CompileError>>()?;
 
-        compile_fn_with_args(context, module, method, args, None)?;
+        compile_fn_with_args(context, module, method, args, None, FunctionValues::default())?;
     }
     Ok(())
 }
@@ -314,5 +344,12 @@ fn compile_abi_method(
         })
         .collect::<Result<Vec<(String, Type, Span)>, CompileError>>()?;
 
-    compile_fn_with_args(context, module, ast_fn_decl, args, Some(selector))
+    compile_fn_with_args(
+        context,
+        module,
+        ast_fn_decl,
+        args,
+        Some(selector),
+        FunctionValues::default(),
+    )
 }
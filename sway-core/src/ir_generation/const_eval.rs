@@ -1,24 +1,33 @@
 use crate::{
     error::CompileError,
+    parse_tree::{CallPath, LazyOp},
     semantic_analysis::{
-        declaration::ProjectionKind, namespace, TypedAstNode, TypedAstNodeContent,
+        declaration::ProjectionKind, namespace, TypedAstNode, TypedAstNodeContent, TypedCodeBlock,
         TypedConstantDeclaration, TypedDeclaration, TypedExpression, TypedExpressionVariant,
-        TypedStructExpressionField,
+        TypedIntrinsicFunctionKind, TypedReassignment, TypedStructExpressionField,
+        TypedVariableDeclaration, TypedWhileLoop,
     },
 };
 
-use super::{convert::convert_literal_to_constant, types::*};
+use super::{
+    convert::{convert_literal_to_constant, narrow_uint_bits},
+    types::*,
+};
 
 use sway_ir::{
     constant::{Constant, ConstantValue},
     context::Context,
+    irtype::Type,
     metadata::MetadataIndex,
     module::Module,
     value::Value,
 };
 
+use sway_parse::intrinsics::Intrinsic;
 use sway_types::{ident::Ident, span::Spanned};
 
+use uint::construct_uint;
+
 use std::collections::HashMap;
 
 pub struct LookupEnv<'a> {
@@ -95,21 +104,65 @@ pub(crate) fn compile_constant_expression_to_constant(
         lookup: compile_const_decl,
     };
 
-    let err = match &const_expr.expression {
+    let span = match &const_expr.expression {
         // Special case functions because the span in `const_expr` is to the inlined function
         // definition, rather than the actual call site.
-        TypedExpressionVariant::FunctionApplication { call_path, .. } => {
-            Err(CompileError::NonConstantDeclValue {
-                span: call_path.span(),
-            })
-        }
-        _otherwise => Err(CompileError::NonConstantDeclValue {
-            span: const_expr.span.clone(),
-        }),
+        TypedExpressionVariant::FunctionApplication { call_path, .. } => call_path.span(),
+        _otherwise => const_expr.span.clone(),
     };
-    let mut known_consts = MappedStack::<Ident, Constant>::new();
+    let mut state = ConstEvalState::new();
+    match const_eval_typed_expr(lookup, &mut state, const_expr) {
+        Ok(constant) => Ok(constant),
+        Err(ConstEvalStop::StepLimitExceeded) => Err(CompileError::ConstEvalStepLimitExceeded {
+            limit: CONST_EVAL_STEP_LIMIT,
+            span,
+        }),
+        Err(ConstEvalStop::Panic) => Err(CompileError::ConstEvalPanic { span }),
+        Err(_) => Err(CompileError::NonConstantDeclValue { span }),
+    }
+}
+
+/// The maximum number of expressions which may be evaluated for a single constant.  This bounds
+/// the time spent evaluating loops which never terminate.
+const CONST_EVAL_STEP_LIMIT: u64 = 1_000_000;
+
+/// The reasons evaluation of an expression may stop without producing a constant.
+enum ConstEvalStop {
+    /// The expression can't be evaluated at compile time.
+    NotConstant,
+    /// The expression would panic at runtime, e.g., due to overflow or division by zero.
+    Panic,
+    /// More than `CONST_EVAL_STEP_LIMIT` expressions were evaluated.
+    StepLimitExceeded,
+    /// A `return` statement was reached.  This unwinds to the enclosing function application.
+    Return(Constant),
+    /// A `break` or `continue` statement was reached.  These unwind to the enclosing loop.
+    Break,
+    Continue,
+}
+
+type ConstEvalResult = Result<Constant, ConstEvalStop>;
+
+struct ConstEvalState {
+    known_consts: MappedStack<Ident, Constant>,
+    steps_remaining: u64,
+}
+
+impl ConstEvalState {
+    fn new() -> Self {
+        ConstEvalState {
+            known_consts: MappedStack::new(),
+            steps_remaining: CONST_EVAL_STEP_LIMIT,
+        }
+    }
 
-    const_eval_typed_expr(lookup, &mut known_consts, const_expr).map_or(err, Ok)
+    fn step(&mut self) -> Result<(), ConstEvalStop> {
+        if self.steps_remaining == 0 {
+            return Err(ConstEvalStop::StepLimitExceeded);
+        }
+        self.steps_remaining -= 1;
+        Ok(())
+    }
 }
 
 // A HashMap that can hold multiple values and
@@ -138,6 +191,19 @@ impl<K: std::cmp::Eq + std::hash::Hash, V> MappedStack<K, V> {
     fn get(&self, k: &K) -> Option<&V> {
         self.container.get(k).and_then(|val_vec| val_vec.last())
     }
+    fn set(&mut self, k: &K, v: V) -> bool {
+        match self
+            .container
+            .get_mut(k)
+            .and_then(|val_vec| val_vec.last_mut())
+        {
+            Some(val) => {
+                *val = v;
+                true
+            }
+            None => false,
+        }
+    }
     fn pop(&mut self, k: &K) {
         match self.container.get_mut(k) {
             Some(val_vec) => {
@@ -161,100 +227,115 @@ impl<K: std::cmp::Eq + std::hash::Hash, V> Default for MappedStack<K, V> {
 /// attempt to evaluate a typed expression to a constant.
 fn const_eval_typed_expr(
     lookup: &mut LookupEnv,
-    known_consts: &mut MappedStack<Ident, Constant>,
+    state: &mut ConstEvalState,
     expr: &TypedExpression,
-) -> Option<Constant> {
+) -> ConstEvalResult {
+    state.step()?;
     match &expr.expression {
-        TypedExpressionVariant::Literal(l) => Some(convert_literal_to_constant(l)),
+        TypedExpressionVariant::Literal(l) => Ok(convert_literal_to_constant(l)),
         TypedExpressionVariant::FunctionApplication {
+            call_path,
             arguments,
             function_body,
+            selector,
             ..
         } => {
+            if selector.is_some() {
+                // Contract calls can't be evaluated at compile time.
+                return Err(ConstEvalStop::NotConstant);
+            }
             let actuals_const = arguments
                 .iter()
-                .filter_map(|(name, sub_expr)| {
-                    const_eval_typed_expr(lookup, known_consts, sub_expr)
-                        .map(|sub_const| (name, sub_const))
+                .map(|(name, sub_expr)| {
+                    const_eval_typed_expr(lookup, state, sub_expr)
+                        .map(|sub_const| (name.clone(), sub_const))
                 })
-                .collect::<Vec<_>>();
-            // If all actual arguments don't evaluate a constant, bail out.
-            // TODO: Explore if we could continue here and if it'll be useful.
-            if actuals_const.len() < arguments.len() {
-                return None;
-            }
-            for (name, cval) in actuals_const.into_iter() {
-                known_consts.push(name.clone(), cval);
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // The operators are implemented with `asm` blocks for the primitive types, so
+            // evaluate them directly.
+            if is_core_ops_call_path(call_path) {
+                let arg_consts = actuals_const
+                    .iter()
+                    .map(|(_, cval)| cval)
+                    .collect::<Vec<_>>();
+                let nbits = arguments
+                    .first()
+                    .and_then(|(_, arg)| narrow_uint_bits(&arg.return_type));
+                if let Some(res) =
+                    const_eval_primitive_op(call_path.suffix.as_str(), &arg_consts, nbits)
+                {
+                    return res;
+                }
             }
 
-            // TODO: Handle more than one statement in the block.
-            if function_body.contents.len() > 1 {
-                return None;
+            // The body may only refer to its own parameters, so evaluate it in a fresh scope.
+            let caller_consts = std::mem::take(&mut state.known_consts);
+            for (name, cval) in actuals_const.into_iter() {
+                state.known_consts.push(name, cval);
             }
-            let res = function_body
-                .contents
-                .last()
-                .and_then(|first_expr| const_eval_typed_ast_node(lookup, known_consts, first_expr));
-            for (name, _) in arguments {
-                known_consts.pop(name);
+            let res = const_eval_typed_code_block(lookup, state, function_body);
+            state.known_consts = caller_consts;
+            match res {
+                Err(ConstEvalStop::Return(cval)) => Ok(cval),
+                Err(ConstEvalStop::Break) | Err(ConstEvalStop::Continue) => {
+                    Err(ConstEvalStop::NotConstant)
+                }
+                res => res,
             }
-            res
         }
-        TypedExpressionVariant::VariableExpression { name } => match known_consts.get(name) {
+        TypedExpressionVariant::VariableExpression { name } => match state.known_consts.get(name) {
             // 1. Check if name is in known_consts.
-            Some(cvs) => Some(cvs.clone()),
+            Some(cvs) => Ok(cvs.clone()),
             None => {
                 // 2. Check if name is a global constant.
                 use sway_ir::value::ValueDatum::Constant;
-                (lookup.lookup)(lookup, name).ok().flatten().and_then(|v| {
-                    match &lookup.context.values[(v.0)].value {
+                (lookup.lookup)(lookup, name)
+                    .ok()
+                    .flatten()
+                    .and_then(|v| match &lookup.context.values[(v.0)].value {
                         Constant(cv) => Some(cv.clone()),
                         _ => None,
-                    }
-                })
+                    })
+                    .ok_or(ConstEvalStop::NotConstant)
             }
         },
         TypedExpressionVariant::StructExpression { fields, .. } => {
             let (field_typs, field_vals): (Vec<_>, Vec<_>) = fields
                 .iter()
-                .filter_map(|TypedStructExpressionField { name: _, value, .. }| {
-                    const_eval_typed_expr(lookup, known_consts, value)
-                        .map(|cv| (value.return_type, cv))
+                .map(|TypedStructExpressionField { name: _, value, .. }| {
+                    const_eval_typed_expr(lookup, state, value).map(|cv| (value.return_type, cv))
                 })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
                 .unzip();
-            if field_vals.len() < fields.len() {
-                // We couldn't evaluate all fields to a constant.
-                return None;
-            }
             let aggregate = get_aggregate_for_types(lookup.context, &field_typs).unwrap();
-            Some(Constant::new_struct(&aggregate, field_vals))
+            Ok(Constant::new_struct(&aggregate, field_vals))
         }
         TypedExpressionVariant::Tuple { fields } => {
             let (field_typs, field_vals): (Vec<_>, Vec<_>) = fields
                 .iter()
-                .filter_map(|value| {
-                    const_eval_typed_expr(lookup, known_consts, value)
-                        .map(|cv| (value.return_type, cv))
+                .map(|value| {
+                    const_eval_typed_expr(lookup, state, value).map(|cv| (value.return_type, cv))
                 })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
                 .unzip();
-            if field_vals.len() < fields.len() {
-                // We couldn't evaluate all fields to a constant.
-                return None;
-            }
             let aggregate = create_tuple_aggregate(lookup.context, field_typs).unwrap();
-            Some(Constant::new_struct(&aggregate, field_vals))
+            Ok(Constant::new_struct(&aggregate, field_vals))
         }
         TypedExpressionVariant::Array { contents } => {
             let (element_typs, element_vals): (Vec<_>, Vec<_>) = contents
                 .iter()
-                .filter_map(|value| {
-                    const_eval_typed_expr(lookup, known_consts, value)
-                        .map(|cv| (value.return_type, cv))
+                .map(|value| {
+                    const_eval_typed_expr(lookup, state, value).map(|cv| (value.return_type, cv))
                 })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
                 .unzip();
-            if element_vals.len() < contents.len() || element_typs.is_empty() {
-                // We couldn't evaluate all fields to a constant or cannot determine element type.
-                return None;
+            if element_typs.is_empty() {
+                // We cannot determine the element type.
+                return Err(ConstEvalStop::NotConstant);
             }
             let mut element_iter = element_typs.iter();
             let element_type_id = *element_iter.next().unwrap();
@@ -263,7 +344,7 @@ fn const_eval_typed_expr(
                     == crate::type_engine::look_up_type_id(element_type_id)
             }) {
                 // This shouldn't happen if the type checker did its job.
-                return None;
+                return Err(ConstEvalStop::NotConstant);
             }
             let aggregate = create_array_aggregate(
                 lookup.context,
@@ -271,7 +352,7 @@ fn const_eval_typed_expr(
                 element_typs.len().try_into().unwrap(),
             )
             .unwrap();
-            Some(Constant::new_array(&aggregate, element_vals))
+            Ok(Constant::new_array(&aggregate, element_vals))
         }
         TypedExpressionVariant::EnumInstantiation {
             enum_decl,
@@ -283,24 +364,20 @@ fn const_eval_typed_expr(
                 create_enum_aggregate(lookup.context, enum_decl.variants.clone()).unwrap();
             let tag_value = Constant::new_uint(64, *tag as u64);
             let mut fields: Vec<Constant> = vec![tag_value];
-            contents.iter().for_each(|subexpr| {
-                const_eval_typed_expr(lookup, known_consts, &*subexpr)
-                    .into_iter()
-                    .for_each(|enum_val| {
-                        fields.push(enum_val);
-                    })
-            });
-            Some(Constant::new_struct(&aggregate, fields))
+            if let Some(subexpr) = contents {
+                fields.push(const_eval_typed_expr(lookup, state, subexpr)?);
+            }
+            Ok(Constant::new_struct(&aggregate, fields))
         }
         TypedExpressionVariant::StructFieldAccess {
             prefix,
             field_to_access,
             resolved_type_of_parent,
-        } => match const_eval_typed_expr(lookup, known_consts, &*prefix) {
-            Some(Constant {
+        } => match const_eval_typed_expr(lookup, state, prefix)? {
+            Constant {
                 value: ConstantValue::Struct(fields),
                 ..
-            }) => {
+            } => {
                 let field_kind = ProjectionKind::StructField {
                     name: field_to_access.name.clone(),
                 };
@@ -309,51 +386,349 @@ fn const_eval_typed_expr(
                         field_idx_and_type_opt.map(|(field_idx, _field_type)| field_idx)
                     })
                     .and_then(|field_idx| fields.get(field_idx as usize).cloned())
+                    .ok_or(ConstEvalStop::NotConstant)
             }
-            _ => None,
+            _ => Err(ConstEvalStop::NotConstant),
         },
         TypedExpressionVariant::TupleElemAccess {
             prefix,
             elem_to_access_num,
             ..
-        } => match const_eval_typed_expr(lookup, known_consts, &*prefix) {
-            Some(Constant {
+        } => match const_eval_typed_expr(lookup, state, prefix)? {
+            Constant {
                 value: ConstantValue::Struct(fields),
                 ..
-            }) => fields.get(*elem_to_access_num).cloned(),
-            _ => None,
+            } => fields
+                .get(*elem_to_access_num)
+                .cloned()
+                .ok_or(ConstEvalStop::NotConstant),
+            _ => Err(ConstEvalStop::NotConstant),
+        },
+        TypedExpressionVariant::ArrayIndex { prefix, index } => {
+            let array = const_eval_typed_expr(lookup, state, prefix)?;
+            let index = const_eval_typed_expr(lookup, state, index)?;
+            match (array.value, index.value) {
+                (ConstantValue::Array(elems), ConstantValue::Uint(idx)) => {
+                    elems.get(idx as usize).cloned().ok_or(ConstEvalStop::Panic)
+                }
+                _ => Err(ConstEvalStop::NotConstant),
+            }
+        }
+        TypedExpressionVariant::IntrinsicFunction(TypedIntrinsicFunctionKind {
+            kind,
+            arguments,
+            ..
+        }) => {
+            let op_name = match kind {
+                Intrinsic::Eq => "eq",
+                Intrinsic::Gt => "gt",
+                Intrinsic::Lt => "lt",
+                Intrinsic::Add => "add",
+                Intrinsic::Sub => "subtract",
+                Intrinsic::Mul => "multiply",
+                Intrinsic::Div => "divide",
+                _otherwise => return Err(ConstEvalStop::NotConstant),
+            };
+            let arg_consts = arguments
+                .iter()
+                .map(|arg| const_eval_typed_expr(lookup, state, arg))
+                .collect::<Result<Vec<_>, _>>()?;
+            let nbits = arguments
+                .first()
+                .and_then(|arg| narrow_uint_bits(&arg.return_type));
+            const_eval_primitive_op(op_name, &arg_consts.iter().collect::<Vec<_>>(), nbits)
+                .unwrap_or(Err(ConstEvalStop::NotConstant))
+        }
+        TypedExpressionVariant::CodeBlock(block) => {
+            const_eval_typed_code_block(lookup, state, block)
+        }
+        TypedExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => match const_eval_typed_expr(lookup, state, condition)?.value {
+            ConstantValue::Bool(true) => const_eval_typed_expr(lookup, state, then),
+            ConstantValue::Bool(false) => match r#else {
+                Some(r#else) => const_eval_typed_expr(lookup, state, r#else),
+                None => Ok(Constant::new_unit()),
+            },
+            _ => Err(ConstEvalStop::NotConstant),
+        },
+        TypedExpressionVariant::LazyOperator { op, lhs, rhs } => {
+            match (op, const_eval_typed_expr(lookup, state, lhs)?.value) {
+                (LazyOp::And, ConstantValue::Bool(false)) => Ok(Constant::new_bool(false)),
+                (LazyOp::Or, ConstantValue::Bool(true)) => Ok(Constant::new_bool(true)),
+                (_, ConstantValue::Bool(_)) => const_eval_typed_expr(lookup, state, rhs),
+                _ => Err(ConstEvalStop::NotConstant),
+            }
+        }
+        TypedExpressionVariant::EnumTag { exp } => match const_eval_typed_expr(lookup, state, exp)?
+        {
+            Constant {
+                value: ConstantValue::Struct(fields),
+                ..
+            } => fields.first().cloned().ok_or(ConstEvalStop::NotConstant),
+            _ => Err(ConstEvalStop::NotConstant),
         },
-        TypedExpressionVariant::ArrayIndex { .. }
-        | TypedExpressionVariant::IntrinsicFunction(_)
-        | TypedExpressionVariant::CodeBlock(_)
-        | TypedExpressionVariant::FunctionParameter
-        | TypedExpressionVariant::IfExp { .. }
+        TypedExpressionVariant::UnsafeDowncast { exp, .. } => {
+            match const_eval_typed_expr(lookup, state, exp)? {
+                // Variants without contents only have a tag.
+                Constant {
+                    value: ConstantValue::Struct(fields),
+                    ..
+                } => Ok(fields.get(1).cloned().unwrap_or_else(Constant::new_unit)),
+                _ => Err(ConstEvalStop::NotConstant),
+            }
+        }
+        TypedExpressionVariant::FunctionParameter
         | TypedExpressionVariant::AsmExpression { .. }
-        | TypedExpressionVariant::LazyOperator { .. }
         | TypedExpressionVariant::AbiCast { .. }
         | TypedExpressionVariant::StorageAccess(_)
         | TypedExpressionVariant::AbiName(_)
-        | TypedExpressionVariant::EnumTag { .. }
-        | TypedExpressionVariant::UnsafeDowncast { .. } => None,
+        | TypedExpressionVariant::FunctionValue { .. }
+        | TypedExpressionVariant::IndirectFunctionApplication { .. }
+        | TypedExpressionVariant::VariableReference { .. } => Err(ConstEvalStop::NotConstant),
+    }
+}
+
+fn const_eval_typed_code_block(
+    lookup: &mut LookupEnv,
+    state: &mut ConstEvalState,
+    block: &TypedCodeBlock,
+) -> ConstEvalResult {
+    let mut bindings = Vec::new();
+    let mut res = Ok(Constant::new_unit());
+    for node in &block.contents {
+        match const_eval_typed_ast_node(lookup, state, &mut bindings, node) {
+            Ok(cval) => {
+                // Only the implicit return determines the value of the block.
+                if matches!(
+                    node.content,
+                    TypedAstNodeContent::ImplicitReturnExpression(_)
+                ) {
+                    res = Ok(cval);
+                }
+            }
+            Err(stop) => {
+                res = Err(stop);
+                break;
+            }
+        }
     }
+    for name in bindings {
+        state.known_consts.pop(&name);
+    }
+    res
 }
 
 fn const_eval_typed_ast_node(
     lookup: &mut LookupEnv,
-    known_consts: &mut MappedStack<Ident, Constant>,
+    state: &mut ConstEvalState,
+    bindings: &mut Vec<Ident>,
     expr: &TypedAstNode,
-) -> Option<Constant> {
+) -> ConstEvalResult {
     match &expr.content {
-        TypedAstNodeContent::ReturnStatement(trs) => {
-            const_eval_typed_expr(lookup, known_consts, &trs.expr)
+        TypedAstNodeContent::ReturnStatement(trs) => Err(ConstEvalStop::Return(
+            const_eval_typed_expr(lookup, state, &trs.expr)?,
+        )),
+        TypedAstNodeContent::Declaration(TypedDeclaration::VariableDeclaration(
+            TypedVariableDeclaration { name, body, .. },
+        ))
+        | TypedAstNodeContent::Declaration(TypedDeclaration::ConstantDeclaration(
+            TypedConstantDeclaration {
+                name, value: body, ..
+            },
+        )) => {
+            let cval = const_eval_typed_expr(lookup, state, body)?;
+            state.known_consts.push(name.clone(), cval);
+            bindings.push(name.clone());
+            Ok(Constant::new_unit())
+        }
+        TypedAstNodeContent::Declaration(TypedDeclaration::Reassignment(TypedReassignment {
+            lhs_base_name,
+            lhs_indices,
+            rhs,
+            ..
+        })) if lhs_indices.is_empty() => {
+            let cval = const_eval_typed_expr(lookup, state, rhs)?;
+            match state.known_consts.set(lhs_base_name, cval) {
+                true => Ok(Constant::new_unit()),
+                false => Err(ConstEvalStop::NotConstant),
+            }
+        }
+        TypedAstNodeContent::Declaration(TypedDeclaration::Break { .. }) => {
+            Err(ConstEvalStop::Break)
         }
-        TypedAstNodeContent::Declaration(_) => {
-            // TODO: add the binding to known_consts (if it's a const) and proceed.
-            None
+        TypedAstNodeContent::Declaration(TypedDeclaration::Continue { .. }) => {
+            Err(ConstEvalStop::Continue)
         }
+        TypedAstNodeContent::Declaration(_) => Err(ConstEvalStop::NotConstant),
         TypedAstNodeContent::Expression(e) | TypedAstNodeContent::ImplicitReturnExpression(e) => {
-            const_eval_typed_expr(lookup, known_consts, e)
+            const_eval_typed_expr(lookup, state, e)
+        }
+        TypedAstNodeContent::WhileLoop(TypedWhileLoop { condition, body }) => {
+            loop {
+                match const_eval_typed_expr(lookup, state, condition)?.value {
+                    ConstantValue::Bool(true) => (),
+                    ConstantValue::Bool(false) => break,
+                    _ => return Err(ConstEvalStop::NotConstant),
+                }
+                match const_eval_typed_code_block(lookup, state, body) {
+                    Ok(_) | Err(ConstEvalStop::Continue) => (),
+                    Err(ConstEvalStop::Break) => break,
+                    Err(stop) => return Err(stop),
+                }
+            }
+            Ok(Constant::new_unit())
         }
-        TypedAstNodeContent::WhileLoop(_) | TypedAstNodeContent::SideEffect => None,
+        TypedAstNodeContent::SideEffect => Ok(Constant::new_unit()),
+    }
+}
+
+fn is_core_ops_call_path(call_path: &CallPath) -> bool {
+    call_path.prefixes.len() == 2
+        && call_path.prefixes[0].as_str() == "core"
+        && call_path.prefixes[1].as_str() == "ops"
+}
+
+/// Evaluate one of the `core::ops` functions, or an intrinsic, for primitive arguments.  Returns
+/// `None` if `op_name` isn't implemented for the argument types, in which case the function body
+/// should be evaluated instead.
+///
+/// The arithmetic matches the VM: word sized integers are not truncated to their width and any
+/// overflow of a word, or of `nbits` for an integer type narrower than a word, is a panic.
+fn const_eval_primitive_op(
+    op_name: &str,
+    args: &[&Constant],
+    nbits: Option<u8>,
+) -> Option<ConstEvalResult> {
+    use ConstantValue::*;
+    let bool_res = |b: bool| -> Option<ConstEvalResult> { Some(Ok(Constant::new_bool(b))) };
+    let uint_res = |ty: &Type, n: Option<u64>| match ty {
+        Type::Uint(nbits) => Some(
+            n.map(|n| Constant::new_uint(*nbits, n))
+                .ok_or(ConstEvalStop::Panic),
+        ),
+        _ => None,
+    };
+    // Sums and products which don't fit in a narrower integer type overflow just like `u64` would.
+    let checked_uint_res = |ty: &Type, n: Option<u64>| {
+        uint_res(
+            ty,
+            n.filter(|n| !matches!(nbits, Some(nbits) if n >> nbits != 0)),
+        )
+    };
+    match args {
+        [arg] => match (op_name, &arg.value) {
+            ("not", Bool(b)) => bool_res(!b),
+            _ => None,
+        },
+        [lhs, rhs] => match (op_name, &lhs.value, &rhs.value) {
+            ("eq", Bool(l), Bool(r)) => bool_res(l == r),
+            ("neq", Bool(l), Bool(r)) => bool_res(l != r),
+
+            ("eq", Uint(l), Uint(r)) => bool_res(l == r),
+            ("neq", Uint(l), Uint(r)) => bool_res(l != r),
+            ("gt", Uint(l), Uint(r)) => bool_res(l > r),
+            ("lt", Uint(l), Uint(r)) => bool_res(l < r),
+            ("ge", Uint(l), Uint(r)) => bool_res(l >= r),
+            ("le", Uint(l), Uint(r)) => bool_res(l <= r),
+            ("add", Uint(l), Uint(r)) => checked_uint_res(&lhs.ty, l.checked_add(*r)),
+            ("subtract", Uint(l), Uint(r)) => uint_res(&lhs.ty, l.checked_sub(*r)),
+            ("multiply", Uint(l), Uint(r)) => checked_uint_res(&lhs.ty, l.checked_mul(*r)),
+            ("divide", Uint(l), Uint(r)) => uint_res(&lhs.ty, l.checked_div(*r)),
+            ("modulo", Uint(l), Uint(r)) => uint_res(&lhs.ty, l.checked_rem(*r)),
+            ("binary_and", Uint(l), Uint(r)) => uint_res(&lhs.ty, Some(l & r)),
+            ("binary_or", Uint(l), Uint(r)) => uint_res(&lhs.ty, Some(l | r)),
+            ("binary_xor", Uint(l), Uint(r)) => uint_res(&lhs.ty, Some(l ^ r)),
+            ("lsh", Uint(l), Uint(r)) => uint_res(
+                &lhs.ty,
+                Some(
+                    u32::try_from(*r)
+                        .ok()
+                        .and_then(|r| l.checked_shl(r))
+                        .unwrap_or(0),
+                ),
+            ),
+            ("rsh", Uint(l), Uint(r)) => uint_res(
+                &lhs.ty,
+                Some(
+                    u32::try_from(*r)
+                        .ok()
+                        .and_then(|r| l.checked_shr(r))
+                        .unwrap_or(0),
+                ),
+            ),
+
+            // Big-endian bytes of the same length compare in the same order as the numbers.
+            ("eq", BigUint(l), BigUint(r)) => bool_res(l == r),
+            ("neq", BigUint(l), BigUint(r)) => bool_res(l != r),
+            ("gt", BigUint(l), BigUint(r)) => bool_res(l > r),
+            ("lt", BigUint(l), BigUint(r)) => bool_res(l < r),
+            ("ge", BigUint(l), BigUint(r)) => bool_res(l >= r),
+            ("le", BigUint(l), BigUint(r)) => bool_res(l <= r),
+            ("add" | "subtract" | "multiply" | "divide", BigUint(l), BigUint(r)) => {
+                const_eval_big_uint_op(op_name, &lhs.ty, l, r)
+            }
+
+            ("eq", B256(l), B256(r)) => bool_res(l == r),
+            ("neq", B256(l), B256(r)) => bool_res(l != r),
+            ("gt", B256(l), B256(r)) => bool_res(l > r),
+            ("lt", B256(l), B256(r)) => bool_res(l < r),
+            ("ge", B256(l), B256(r)) => bool_res(l >= r),
+            ("le", B256(l), B256(r)) => bool_res(l <= r),
+            ("binary_and" | "binary_or" | "binary_xor", B256(l), B256(r)) => {
+                let mut res = [0; 32];
+                for (byte, (l, r)) in res.iter_mut().zip(l.iter().zip(r.iter())) {
+                    *byte = match op_name {
+                        "binary_and" => l & r,
+                        "binary_or" => l | r,
+                        _binary_xor => l ^ r,
+                    };
+                }
+                Some(Ok(Constant::new_b256(res)))
+            }
+
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[allow(
+// These two warnings are generated by the `construct_uint!()` macro below.
+    clippy::assign_op_pattern,
+    clippy::ptr_offset_with_cast
+)]
+fn const_eval_big_uint_op(
+    op_name: &str,
+    ty: &Type,
+    lhs: &[u8],
+    rhs: &[u8],
+) -> Option<ConstEvalResult> {
+    construct_uint! {
+        struct U256(4);
+    }
+    let nbits = match ty {
+        Type::BigUint(nbits) => *nbits,
+        _ => return None,
+    };
+    let l = U256::from_big_endian(lhs);
+    let r = U256::from_big_endian(rhs);
+    let res = match op_name {
+        "add" => l.checked_add(r),
+        "subtract" => l.checked_sub(r),
+        "multiply" => l.checked_mul(r),
+        "divide" => l.checked_div(r),
+        _ => return None,
     }
+    .filter(|res| res.bits() <= nbits as usize);
+    Some(
+        res.map(|res| {
+            let mut bytes = [0; 32];
+            res.to_big_endian(&mut bytes);
+            Constant::new_big_uint(nbits, bytes[(32 - nbits as usize / 8)..].to_vec())
+        })
+        .ok_or(ConstEvalStop::Panic),
+    )
 }
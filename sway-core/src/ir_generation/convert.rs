@@ -1,7 +1,7 @@
 use crate::{
     error::CompileError,
     parse_tree::Literal,
-    type_engine::{resolve_type, TypeId, TypeInfo},
+    type_engine::{look_up_type_id, resolve_type, TypeId, TypeInfo},
 };
 
 use super::types::{create_enum_aggregate, create_tuple_aggregate};
@@ -29,6 +29,10 @@ pub(super) fn convert_literal_to_value(
         Literal::U16(n) => Constant::get_uint(context, 64, *n as u64, span_id_idx),
         Literal::U32(n) => Constant::get_uint(context, 64, *n as u64, span_id_idx),
         Literal::U64(n) => Constant::get_uint(context, 64, *n, span_id_idx),
+        Literal::U128(n) => {
+            Constant::get_big_uint(context, 128, n.to_be_bytes().to_vec(), span_id_idx)
+        }
+        Literal::U256(bs) => Constant::get_big_uint(context, 256, bs.to_vec(), span_id_idx),
         Literal::Numeric(n) => Constant::get_uint(context, 64, *n, span_id_idx),
         Literal::String(s) => {
             Constant::get_string(context, s.as_str().as_bytes().to_vec(), span_id_idx)
@@ -45,6 +49,8 @@ pub(super) fn convert_literal_to_constant(ast_literal: &Literal) -> Constant {
         Literal::U16(n) => Constant::new_uint(64, *n as u64),
         Literal::U32(n) => Constant::new_uint(64, *n as u64),
         Literal::U64(n) => Constant::new_uint(64, *n),
+        Literal::U128(n) => Constant::new_big_uint(128, n.to_be_bytes().to_vec()),
+        Literal::U256(bs) => Constant::new_big_uint(256, bs.to_vec()),
         Literal::Numeric(n) => Constant::new_uint(64, *n),
         Literal::String(s) => Constant::new_string(s.as_str().as_bytes().to_vec()),
         Literal::Boolean(b) => Constant::new_bool(*b),
@@ -76,6 +82,15 @@ pub(super) fn convert_resolved_typeid_no_span(
     convert_resolved_typeid(context, ast_type, &span)
 }
 
+/// The width of an integer type which is narrower than the `u64` it's converted to.  Arithmetic on
+/// it must still overflow at its own width.
+pub(super) fn narrow_uint_bits(ast_type: &TypeId) -> Option<u8> {
+    match look_up_type_id(*ast_type) {
+        TypeInfo::UnsignedInteger(bits) if bits.num_bits() < 64 => Some(bits.num_bits() as u8),
+        _otherwise => None,
+    }
+}
+
 fn convert_resolved_type(
     context: &mut Context,
     ast_type: &TypeInfo,
@@ -93,6 +108,7 @@ fn convert_resolved_type(
 
     Ok(match ast_type {
         // All integers are `u64`, see comment in convert_literal_to_value() above.
+        TypeInfo::UnsignedInteger(bits) if !bits.fits_in_word() => Type::BigUint(bits.num_bits()),
         TypeInfo::UnsignedInteger(_) => Type::Uint(64),
         TypeInfo::Numeric => Type::Uint(64),
         TypeInfo::Boolean => Type::Bool,
@@ -138,5 +154,12 @@ fn convert_resolved_type(
         TypeInfo::Ref(..) => reject_type!("Ref"),
         TypeInfo::ErrorRecovery => reject_type!("Error recovery"),
         TypeInfo::Storage { .. } => reject_type!("Storage"),
+        TypeInfo::UnresolvedArray(..) => reject_type!("Unresolved array"),
+
+        // Function values have no runtime representation; every use must be resolved to a known
+        // function while compiling the call sites.
+        TypeInfo::Function { .. } => {
+            return Err(CompileError::FunctionValueNotKnown { span: span.clone() })
+        }
     })
 }
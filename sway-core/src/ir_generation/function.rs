@@ -1,5 +1,5 @@
 use super::{
-    compile::compile_function,
+    compile::compile_function_with_values,
     convert::*,
     lexical_map::LexicalMap,
     storage::{add_to_b256, get_storage_key},
@@ -22,7 +22,10 @@ use sway_types::{
     state::StateIndex,
 };
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 pub(super) struct FnCompiler {
     module: Module,
@@ -31,6 +34,22 @@ pub(super) struct FnCompiler {
     pub(super) block_to_break_to: Option<Block>,
     pub(super) block_to_continue_to: Option<Block>,
     lexical_map: LexicalMap,
+    function_values: FunctionValues,
+    /// The arguments passed to `ref mut` parameters.  Each is boxed in a single field struct so
+    /// that it is passed by address, whatever its type.
+    reference_args: HashSet<String>,
+}
+
+/// Function values have no runtime representation.  Instead, a function with parameters of
+/// function type is specialized for each call, with the functions passed to it bound to the
+/// parameter names.
+#[derive(Clone, Default)]
+pub(super) struct FunctionValues {
+    /// The known functions, keyed by their local names.
+    bindings: HashMap<String, TypedFunctionDeclaration>,
+    /// The spans of the functions currently being called through function values, used to
+    /// reject recursion.
+    active: Vec<Span>,
 }
 
 pub(super) enum StateAccessType {
@@ -39,12 +58,23 @@ pub(super) enum StateAccessType {
 }
 
 impl FnCompiler {
-    pub(super) fn new(context: &mut Context, module: Module, function: Function) -> Self {
-        let lexical_map = LexicalMap::from_iter(
+    pub(super) fn new(
+        context: &mut Context,
+        module: Module,
+        function: Function,
+        function_values: FunctionValues,
+        reference_args: HashSet<String>,
+    ) -> Self {
+        let mut lexical_map = LexicalMap::from_iter(
             function
                 .args_iter(context)
                 .map(|(name, _value)| name.clone()),
         );
+        let bindings = function_values
+            .bindings
+            .into_iter()
+            .map(|(name, function)| (lexical_map.insert(name), function))
+            .collect();
         FnCompiler {
             module,
             function,
@@ -52,6 +82,11 @@ impl FnCompiler {
             block_to_break_to: None,
             block_to_continue_to: None,
             lexical_map,
+            function_values: FunctionValues {
+                bindings,
+                active: function_values.active,
+            },
+            reference_args,
         }
     }
 
@@ -359,6 +394,17 @@ impl FnCompiler {
                 self.compile_unsafe_downcast(context, exp, variant)
             }
             TypedExpressionVariant::EnumTag { exp } => self.compile_enum_tag(context, exp),
+            // Function values are only known at compile time, so there's nothing to compile for
+            // them on their own.
+            TypedExpressionVariant::FunctionValue { .. } => {
+                Ok(Constant::get_unit(context, span_md_idx))
+            }
+            TypedExpressionVariant::IndirectFunctionApplication { callee, arguments } => {
+                self.compile_indirect_fn_call(context, callee, arguments, span_md_idx)
+            }
+            TypedExpressionVariant::VariableReference { name } => {
+                self.compile_var_ref(context, &name, span_md_idx)
+            }
         }
     }
 
@@ -370,6 +416,7 @@ impl FnCompiler {
             arguments,
             type_arguments,
             span: _,
+            log_id,
         }: TypedIntrinsicFunctionKind,
         span: Span,
     ) -> Result<Value, CompileError> {
@@ -410,17 +457,82 @@ impl FnCompiler {
                     .ins(context)
                     .get_storage_key(span_md_idx, None))
             }
-            Intrinsic::Eq => {
+            Intrinsic::Eq | Intrinsic::Gt | Intrinsic::Lt => {
                 let lhs = arguments[0].clone();
                 let rhs = arguments[1].clone();
                 let lhs_value = self.compile_expression(context, lhs)?;
                 let rhs_value = self.compile_expression(context, rhs)?;
-                Ok(self.current_block.ins(context).cmp(
-                    Predicate::Equal,
-                    lhs_value,
-                    rhs_value,
-                    None,
-                ))
+                let pred = match kind {
+                    Intrinsic::Eq => Predicate::Equal,
+                    Intrinsic::Gt => Predicate::GreaterThan,
+                    _lt => Predicate::LessThan,
+                };
+                Ok(self
+                    .current_block
+                    .ins(context)
+                    .cmp(pred, lhs_value, rhs_value, None))
+            }
+            Intrinsic::Add | Intrinsic::Sub | Intrinsic::Mul | Intrinsic::Div => {
+                let lhs = arguments[0].clone();
+                let rhs = arguments[1].clone();
+                let nbits = narrow_uint_bits(&lhs.return_type);
+                let lhs_value = self.compile_expression(context, lhs)?;
+                let rhs_value = self.compile_expression(context, rhs)?;
+                let op = match kind {
+                    Intrinsic::Add => BinaryOpKind::Add,
+                    Intrinsic::Sub => BinaryOpKind::Sub,
+                    Intrinsic::Mul => BinaryOpKind::Mul,
+                    _div => BinaryOpKind::Div,
+                };
+                let span_md_idx = MetadataIndex::from_span(context, &span);
+                match nbits {
+                    // The operands are `u64` like any other integer, so they're cast to their
+                    // real width for the duration of the operation, which then overflows at that
+                    // width.
+                    Some(nbits) => {
+                        let lhs_value = self.current_block.ins(context).bitcast(
+                            lhs_value,
+                            Type::Uint(nbits),
+                            span_md_idx,
+                        );
+                        let rhs_value = self.current_block.ins(context).bitcast(
+                            rhs_value,
+                            Type::Uint(nbits),
+                            span_md_idx,
+                        );
+                        let res_value = self.current_block.ins(context).binary_op(
+                            op,
+                            lhs_value,
+                            rhs_value,
+                            span_md_idx,
+                        );
+                        Ok(self.current_block.ins(context).bitcast(
+                            res_value,
+                            Type::Uint(64),
+                            span_md_idx,
+                        ))
+                    }
+                    None => Ok(self.current_block.ins(context).binary_op(
+                        op,
+                        lhs_value,
+                        rhs_value,
+                        span_md_idx,
+                    )),
+                }
+            }
+            Intrinsic::Log => {
+                let log_id = log_id.ok_or_else(|| {
+                    CompileError::Internal("Log call has no log ID.", span.clone())
+                })?;
+                let exp = arguments[0].clone();
+                let log_ty = convert_resolved_typeid(context, &exp.return_type, &exp.span)?;
+                let log_val = self.compile_expression(context, exp)?;
+                let log_id = Constant::get_uint(context, 64, log_id, None);
+                let span_md_idx = MetadataIndex::from_span(context, &span);
+                self.current_block
+                    .ins(context)
+                    .log(log_val, log_ty, log_id, span_md_idx);
+                Ok(Constant::get_unit(context, span_md_idx))
             }
         }
     }
@@ -714,6 +826,31 @@ impl FnCompiler {
         callee_purity: Purity,
         self_state_idx: Option<StateIndex>,
         span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let active = self.function_values.active.clone();
+        self.compile_fn_call_with_active(
+            context,
+            ast_args,
+            callee_body,
+            callee_span,
+            callee_purity,
+            self_state_idx,
+            span_md_idx,
+            active,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn compile_fn_call_with_active(
+        &mut self,
+        context: &mut Context,
+        ast_args: Vec<(Ident, TypedExpression)>,
+        callee_body: TypedCodeBlock,
+        callee_span: Span,
+        callee_purity: Purity,
+        self_state_idx: Option<StateIndex>,
+        span_md_idx: Option<MetadataIndex>,
+        active: Vec<Span>,
     ) -> Result<Value, CompileError> {
         // XXX OK, now, the old compiler inlines everything very lazily.  Function calls include
         // the body of the callee (i.e., the callee_body arg above) and so codegen just pulled it
@@ -736,6 +873,20 @@ impl FnCompiler {
                 crate::span::Span::new(Arc::from(callee_name), 0, callee_name_len, None).unwrap(),
             );
 
+            // Arguments of function type aren't passed at runtime.  The callee is specialized for
+            // the known functions instead.
+            let mut bindings = HashMap::new();
+            let mut value_args = Vec::new();
+            for (name, expr) in ast_args {
+                if Self::is_function_type(expr.return_type, &expr.span)? {
+                    let function = self.resolve_function_value(expr)?;
+                    bindings.insert(name.as_str().to_owned(), function);
+                } else {
+                    value_args.push((name, expr));
+                }
+            }
+            let ast_args = value_args;
+
             // TODO: `is_mutable` below is set to `false` regardless of the actual mutability of
             // each arg. This is hacky but not too important at the moment. Mutability is only
             // relevant (currently) during type checking and so this just works. Long term, we need
@@ -745,6 +896,10 @@ impl FnCompiler {
                 .iter()
                 .map(|(name, expr)| TypedFunctionParameter {
                     name: name.clone(),
+                    is_reference: matches!(
+                        expr.expression,
+                        TypedExpressionVariant::VariableReference { .. }
+                    ),
                     is_mutable: false,
                     type_id: expr.return_type,
                     type_span: crate::span::Span::new(" ".into(), 0, 0, None).unwrap(),
@@ -772,7 +927,12 @@ impl FnCompiler {
                 purity: callee_purity,
             };
 
-            let callee = compile_function(context, self.module, callee_fn_decl)?;
+            let callee = compile_function_with_values(
+                context,
+                self.module,
+                callee_fn_decl,
+                FunctionValues { bindings, active },
+            )?;
 
             // Now actually call the new function.
             let args = ast_args
@@ -794,6 +954,77 @@ impl FnCompiler {
         }
     }
 
+    fn compile_indirect_fn_call(
+        &mut self,
+        context: &mut Context,
+        callee: Ident,
+        ast_args: Vec<TypedExpression>,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let function = self.lookup_function_value(&callee)?;
+
+        // Each call through a function value is specialized in place, so a function which
+        // reaches itself again through one would never finish compiling.
+        if self.function_values.active.contains(&function.span) {
+            return Err(CompileError::RecursiveCall {
+                fn_name: function.name,
+                span: callee.span(),
+            });
+        }
+        let mut active = self.function_values.active.clone();
+        active.push(function.span.clone());
+
+        let ast_args = function
+            .parameters
+            .iter()
+            .map(|param| param.name.clone())
+            .zip(ast_args)
+            .collect();
+        self.compile_fn_call_with_active(
+            context,
+            ast_args,
+            function.body,
+            function.span,
+            function.purity,
+            None,
+            span_md_idx,
+            active,
+        )
+    }
+
+    /// Find the function a function value refers to, which must be known at compile time.
+    fn resolve_function_value(
+        &self,
+        ast_expr: TypedExpression,
+    ) -> Result<TypedFunctionDeclaration, CompileError> {
+        match ast_expr.expression {
+            TypedExpressionVariant::FunctionValue { function } => Ok(*function),
+            TypedExpressionVariant::VariableExpression { name } => {
+                self.lookup_function_value(&name)
+            }
+            _otherwise => Err(CompileError::FunctionValueNotKnown {
+                span: ast_expr.span,
+            }),
+        }
+    }
+
+    fn lookup_function_value(
+        &self,
+        name: &Ident,
+    ) -> Result<TypedFunctionDeclaration, CompileError> {
+        self.lexical_map
+            .get(name.as_str())
+            .and_then(|local_name| self.function_values.bindings.get(local_name))
+            .cloned()
+            .ok_or_else(|| CompileError::FunctionValueNotKnown { span: name.span() })
+    }
+
+    fn is_function_type(type_id: TypeId, span: &Span) -> Result<bool, CompileError> {
+        resolve_type(type_id, span)
+            .map(|type_info| matches!(type_info, TypeInfo::Function { .. }))
+            .map_err(|ty_err| CompileError::InternalOwned(format!("{:?}", ty_err), span.clone()))
+    }
+
     fn get_codeblock_return_type(codeblock: &TypedCodeBlock) -> Option<TypeId> {
         if codeblock.contents.is_empty() {
             Some(insert_type(TypeInfo::Tuple(Vec::new())))
@@ -1023,9 +1254,22 @@ impl FnCompiler {
                 self.current_block.ins(context).load(ptr_val, span_md_idx)
             })
         } else if let Some(val) = self.function.get_arg(context, name) {
-            Ok(val)
+            if self.reference_args.contains(name) {
+                let box_ty = Self::get_reference_box(context, val)?;
+                Ok(self
+                    .current_block
+                    .ins(context)
+                    .extract_value(val, box_ty, vec![0], span_md_idx))
+            } else {
+                Ok(val)
+            }
         } else if let Some(const_val) = self.module.get_global_constant(context, name) {
             Ok(const_val)
+        } else if self.lexical_map.get(name).map_or(false, |local_name| {
+            self.function_values.bindings.contains_key(local_name)
+        }) {
+            // Function values are only known at compile time.
+            Ok(Constant::get_unit(context, span_md_idx))
         } else {
             Err(CompileError::InternalOwned(
                 format!("Unable to resolve variable '{name}'."),
@@ -1034,6 +1278,53 @@ impl FnCompiler {
         }
     }
 
+    fn compile_var_ref(
+        &mut self,
+        context: &mut Context,
+        name: &Ident,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        if let Some(ptr) = self
+            .lexical_map
+            .get(name.as_str())
+            .and_then(|local_name| self.function.get_local_ptr(context, local_name))
+        {
+            // The callee sees the local through a single field struct.
+            let ptr_ty = *ptr.get_type(context);
+            let box_ty = Type::Struct(Aggregate::new_struct(context, vec![ptr_ty]));
+            Ok(self
+                .current_block
+                .ins(context)
+                .get_ptr(ptr, box_ty, 0, span_md_idx))
+        } else if self.reference_args.contains(name.as_str()) {
+            // Already boxed, so the reference is passed on as is.
+            self.function
+                .get_arg(context, name.as_str())
+                .ok_or_else(|| {
+                    CompileError::InternalOwned(
+                        format!("Unable to resolve argument '{name}'."),
+                        name.span(),
+                    )
+                })
+        } else {
+            Err(CompileError::Unimplemented(
+                "Only local variables and \"ref mut\" parameters can be passed to \"ref mut\" \
+                parameters.",
+                name.span(),
+            ))
+        }
+    }
+
+    fn get_reference_box(context: &Context, arg: Value) -> Result<Aggregate, CompileError> {
+        match arg.get_type(context) {
+            Some(Type::Struct(box_ty)) => Ok(box_ty),
+            _ => Err(CompileError::Internal(
+                "Argument to a \"ref mut\" parameter is not boxed.",
+                Span::dummy(),
+            )),
+        }
+    }
+
     fn compile_var_decl(
         &mut self,
         context: &mut Context,
@@ -1046,6 +1337,14 @@ impl FnCompiler {
             is_mutable,
             ..
         } = ast_var_decl;
+        // Function values are bound to the variable name, to be called directly through it.
+        if Self::is_function_type(body.return_type, &body.span)? {
+            let function = self.resolve_function_value(body)?;
+            let local_name = self.lexical_map.insert(name.as_str().to_owned());
+            self.function_values.bindings.insert(local_name, function);
+            return Ok(Constant::get_unit(context, span_md_idx));
+        }
+
         // Nothing to do for an abi cast declarations. The address specified in them is already
         // provided in each contract call node in the AST.
         if matches!(
@@ -1133,6 +1432,16 @@ impl FnCompiler {
             .get(ast_reassignment.lhs_base_name.as_str())
             .expect("All local symbols must be in the lexical symbol map.");
 
+        // Function values can't change at runtime as they are only known at compile time.
+        if self.function_values.bindings.contains_key(name) {
+            return Err(CompileError::FunctionValueNotKnown {
+                span: ast_reassignment.rhs.span,
+            });
+        }
+
+        let is_reference_arg = self.function.get_local_ptr(context, name).is_none()
+            && self.reference_args.contains(name);
+
         // First look for a local ptr with the required name
         let val = match self.function.get_local_ptr(context, name) {
             Some(ptr) => {
@@ -1158,7 +1467,22 @@ impl FnCompiler {
 
         let reassign_val = self.compile_expression(context, ast_reassignment.rhs)?;
 
-        if ast_reassignment.lhs_indices.is_empty() {
+        if is_reference_arg {
+            // Whatever is being reassigned is within the box of the `ref mut` argument.
+            let box_ty = Self::get_reference_box(context, val)?;
+            let mut field_idcs = vec![0];
+            field_idcs.extend(get_indices_for_struct_access(
+                ast_reassignment.lhs_type,
+                &ast_reassignment.lhs_indices,
+            )?);
+            self.current_block.ins(context).insert_value(
+                val,
+                box_ty,
+                reassign_val,
+                field_idcs,
+                span_md_idx,
+            );
+        } else if ast_reassignment.lhs_indices.is_empty() {
             // A non-aggregate; use a `store`.
             self.current_block
                 .ins(context)
@@ -1771,18 +2095,19 @@ impl FnCompiler {
                         rhs,
                         span_md_idx,
                     ),
-                    Type::String(_) | Type::Union(_) => self.compile_union_or_string_storage(
-                        context,
-                        access_type,
-                        ix,
-                        &indices,
-                        &mut key_ptr_val,
-                        &key_ptr,
-                        &storage_key,
-                        r#type,
-                        rhs,
-                        span_md_idx,
-                    ),
+                    Type::String(_) | Type::Union(_) | Type::BigUint(_) => self
+                        .compile_union_or_string_storage(
+                            context,
+                            access_type,
+                            ix,
+                            &indices,
+                            &mut key_ptr_val,
+                            &key_ptr,
+                            &storage_key,
+                            r#type,
+                            rhs,
+                            span_md_idx,
+                        ),
                     Type::Struct(_) => unreachable!("structs are already handled!"),
                     Type::Unit => Ok(Constant::get_unit(context, span_md_idx)),
                 }
@@ -0,0 +1,100 @@
+use crate::error::*;
+
+use sway_ir::{Block, Context, Function, Instruction, Value, ValueDatum};
+use sway_types::span::Span;
+
+use std::collections::{HashMap, HashSet};
+
+/// Designed to be called for each contract entry point _after_ inlining, so that everything the
+/// method executes is within its own body.
+///
+/// Warns about each contract call which may be followed by a storage write on some path through
+/// the method.  The called contract may call back into this one before the write is made, seeing
+/// (and acting on) storage which is about to change.
+pub(crate) fn check_reentrancy(context: &Context, function: &Function) -> Vec<CompileWarning> {
+    let method_name = function.get_name(context);
+    let mut writing_blocks = HashMap::new();
+    let mut warnings = Vec::new();
+    for block in function.block_iter(context) {
+        let instructions = block.instruction_iter(context).collect::<Vec<_>>();
+        for (idx, ins_value) in instructions.iter().enumerate() {
+            let contract_method = match &context.values[ins_value.0].value {
+                ValueDatum::Instruction(Instruction::ContractCall { name, .. }) => name,
+                _otherwise => continue,
+            };
+            let writes_later = instructions[idx + 1..]
+                .iter()
+                .any(|later_value| is_storage_write(context, later_value))
+                || successors(context, &block)
+                    .into_iter()
+                    .any(|succ| reaches_write(context, succ, &mut writing_blocks));
+            if writes_later {
+                let span = context.values[ins_value.0]
+                    .span_md_idx
+                    .and_then(|idx| idx.to_span(context).ok())
+                    .unwrap_or_else(Span::dummy);
+                warnings.push(CompileWarning {
+                    warning_content: Warning::StorageWriteAfterContractCall {
+                        method_name: method_name.to_string(),
+                        contract_method: contract_method.clone(),
+                    },
+                    span,
+                });
+            }
+        }
+    }
+    warnings
+}
+
+/// Whether a storage write is reachable from the start of `block`, including via loops back to
+/// it.  `writing_blocks` memoises whether each block itself contains a write.
+fn reaches_write(
+    context: &Context,
+    block: Block,
+    writing_blocks: &mut HashMap<Block, bool>,
+) -> bool {
+    let mut visited = HashSet::new();
+    let mut worklist = vec![block];
+    while let Some(block) = worklist.pop() {
+        if !visited.insert(block) {
+            continue;
+        }
+        let writes = *writing_blocks.entry(block).or_insert_with(|| {
+            block
+                .instruction_iter(context)
+                .any(|ins_value| is_storage_write(context, &ins_value))
+        });
+        if writes {
+            return true;
+        }
+        worklist.append(&mut successors(context, &block));
+    }
+    false
+}
+
+/// Whether the instruction writes storage, either via the storage IR instructions or via an ASM
+/// block with storage VM instructions.
+fn is_storage_write(context: &Context, ins_value: &Value) -> bool {
+    match &context.values[ins_value.0].value {
+        ValueDatum::Instruction(Instruction::StateStoreQuadWord { .. })
+        | ValueDatum::Instruction(Instruction::StateStoreWord { .. }) => true,
+        ValueDatum::Instruction(Instruction::AsmBlock(asm_block, _args)) => context.asm_blocks
+            [asm_block.0]
+            .body
+            .iter()
+            .any(|asm_op| matches!(asm_op.name.as_str(), "sww" | "swwq")),
+        _otherwise => false,
+    }
+}
+
+fn successors(context: &Context, block: &Block) -> Vec<Block> {
+    match block.get_term_inst(context) {
+        Some(Instruction::Branch(to_block)) => vec![*to_block],
+        Some(Instruction::ConditionalBranch {
+            true_block,
+            false_block,
+            ..
+        }) => vec![*true_block, *false_block],
+        _otherwise => Vec::new(),
+    }
+}
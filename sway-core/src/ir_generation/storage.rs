@@ -1,4 +1,10 @@
-use crate::asm_generation::from_ir::ir_type_size_in_bytes;
+use super::convert::convert_resolved_typeid;
+use crate::{
+    asm_generation::from_ir::ir_type_size_in_bytes,
+    error::CompileError,
+    type_engine::{resolve_type, TypeId, TypeInfo},
+    types::JsonAbiString,
+};
 use fuel_crypto::Hasher;
 use fuel_tx::StorageSlot;
 use fuel_types::{Bytes32, Bytes8};
@@ -7,7 +13,7 @@ use sway_ir::{
     context::Context,
     irtype::{AggregateContent, Type},
 };
-use sway_types::state::StateIndex;
+use sway_types::{state::StateIndex, Span, StorageFieldLayout};
 
 /// Hands out storage keys using a state index and a list of subfield indices.
 /// Basically returns sha256("storage_<state_index>_<idx1>_<idx2>_..")
@@ -126,7 +132,7 @@ pub fn serialize_to_storage_slots(
                 _ => unreachable!("Wrong content for struct."),
             }
         }
-        (Type::Union(_), _) | (Type::String(_), _) => {
+        (Type::Union(_), _) | (Type::String(_), _) | (Type::BigUint(_), _) => {
             // Serialize the constant data in words and add zero words until the number of words
             // is a multiple of 4. This is useful because each storage slot is 4 words.
             let mut packed = serialize_to_words(constant, context, ty);
@@ -177,6 +183,10 @@ pub fn serialize_to_words(constant: &Constant, context: &Context, ty: &Type) ->
         (Type::Uint(_), ConstantValue::Uint(n)) => {
             vec![Bytes8::new(n.to_be_bytes())]
         }
+        (Type::BigUint(_), ConstantValue::BigUint(bs)) => Vec::from_iter(
+            bs.chunks(8)
+                .map(|word| Bytes8::new(word.try_into().unwrap())),
+        ),
         (Type::B256, ConstantValue::B256(b)) => Vec::from_iter(
             (0..4)
                 .into_iter()
@@ -232,3 +242,147 @@ pub fn serialize_to_words(constant: &Constant, context: &Context, ty: &Type) ->
         _ => vec![],
     }
 }
+
+/// Lay out the storage field `name` of type `type_id`, stored under the state index `ix` and the
+/// subfield indices `indices`.
+///
+/// This matches how storage slots are assigned for storage reads and writes: each field of a
+/// struct or tuple is stored separately, as are the tag and the variant value of an enum, and any
+/// other value starts at the beginning of its own slot and continues in the successive slots if it
+/// doesn't fit.  The values of a `StorageMap` are stored
+/// by `std::storage` under keys derived from the key of the map's own slot.
+pub(crate) fn get_storage_field_layout(
+    context: &mut Context,
+    ix: &StateIndex,
+    indices: &[usize],
+    name: String,
+    type_id: TypeId,
+    span: &Span,
+) -> Result<Vec<StorageFieldLayout>, CompileError> {
+    let resolve = |type_id| {
+        resolve_type(type_id, span)
+            .map_err(|ty_err| CompileError::InternalOwned(format!("{ty_err:?}"), span.clone()))
+    };
+    let storage_key = get_storage_key(ix, indices);
+    match resolve(type_id)? {
+        TypeInfo::Struct {
+            name: struct_name,
+            type_parameters,
+            ..
+        } if struct_name.as_str() == "StorageMap" && type_parameters.len() == 2 => {
+            let key_type = resolve(type_parameters[0].type_id)?;
+            let value_type = resolve(type_parameters[1].type_id)?;
+            let value_ir_type =
+                convert_resolved_typeid(context, &type_parameters[1].type_id, span)?;
+            Ok(vec![StorageFieldLayout {
+                name,
+                type_field: format!(
+                    "StorageMap<{}, {}>",
+                    key_type.json_abi_str(),
+                    value_type.json_abi_str()
+                ),
+                slots: vec![format!("0x{:x}", storage_key)],
+                offset: 0,
+                size: ir_type_size_in_bytes(context, &value_ir_type),
+                key_derivation: Some(format!("sha256((key, 0x{:x}))", storage_key)),
+            }])
+        }
+        TypeInfo::Struct { fields, .. } => {
+            let mut layouts = vec![];
+            for (field_idx, field) in fields.iter().enumerate() {
+                let mut field_indices = indices.to_vec();
+                field_indices.push(field_idx);
+                layouts.extend(get_storage_field_layout(
+                    context,
+                    ix,
+                    &field_indices,
+                    format!("{}.{}", name, field.name),
+                    field.type_id,
+                    span,
+                )?);
+            }
+            Ok(layouts)
+        }
+        TypeInfo::Tuple(fields) if !fields.is_empty() => {
+            let mut layouts = vec![];
+            for (field_idx, field) in fields.iter().enumerate() {
+                let mut field_indices = indices.to_vec();
+                field_indices.push(field_idx);
+                layouts.extend(get_storage_field_layout(
+                    context,
+                    ix,
+                    &field_indices,
+                    format!("{}.{}", name, field_idx),
+                    field.type_id,
+                    span,
+                )?);
+            }
+            Ok(layouts)
+        }
+        type_info @ TypeInfo::Enum { .. } => {
+            // Enums are stored like a struct of their tag and, unless all the variants are unit,
+            // the union of their variants.
+            let ty = convert_resolved_typeid(context, &type_id, span)?;
+            let field_types = match ty {
+                Type::Struct(aggregate) => context.aggregates[aggregate.0].field_types().clone(),
+                _ => unreachable!("Enums are always converted to structs."),
+            };
+            let type_field = type_info.json_abi_str();
+            Ok(field_types
+                .iter()
+                .enumerate()
+                .map(|(field_idx, field_type)| {
+                    let mut field_indices = indices.to_vec();
+                    field_indices.push(field_idx);
+                    let (field_name, field_type_field) = match field_type {
+                        Type::Union(_) => (format!("{}.value", name), type_field.clone()),
+                        _ => (format!("{}.tag", name), "u64".to_owned()),
+                    };
+                    get_slots_layout(
+                        context,
+                        get_storage_key(ix, &field_indices),
+                        field_name,
+                        field_type_field,
+                        field_type,
+                    )
+                })
+                .collect())
+        }
+        type_info => {
+            let ty = convert_resolved_typeid(context, &type_id, span)?;
+            Ok(vec![get_slots_layout(
+                context,
+                storage_key,
+                name,
+                type_info.json_abi_str(),
+                &ty,
+            )])
+        }
+    }
+}
+
+/// Lay out a value of IR type `ty` which isn't stored field by field, starting at the beginning of
+/// the slot `storage_key`.
+fn get_slots_layout(
+    context: &Context,
+    storage_key: Bytes32,
+    name: String,
+    type_field: String,
+    ty: &Type,
+) -> StorageFieldLayout {
+    let size = ir_type_size_in_bytes(context, ty);
+    let slot_count = match ty {
+        Type::String(_) | Type::Union(_) | Type::BigUint(_) => (size + 31) / 32,
+        _ => 1,
+    };
+    StorageFieldLayout {
+        name,
+        type_field,
+        slots: (0..slot_count)
+            .map(|i| format!("0x{:x}", add_to_b256(storage_key, i)))
+            .collect(),
+        offset: 0,
+        size,
+        key_derivation: None,
+    }
+}
@@ -20,7 +20,7 @@ pub use asm_generation::from_ir::compile_ir_to_asm;
 use asm_generation::FinalizedAsm;
 pub use build_config::BuildConfig;
 use control_flow_analysis::ControlFlowGraph;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -387,6 +387,17 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     );
 
     let tree_type = program.kind.tree_type();
+
+    // Methods which make no contract calls, or which call `std::reentrancy::reentrancy_guard` on
+    // every path to their first contract call, can't be re-entered, so the reentrancy check below
+    // skips them.
+    let non_reentrant_methods = program
+        .generate_method_effects()
+        .into_iter()
+        .filter(|effects| !effects.can_reenter)
+        .map(|effects| effects.name)
+        .collect::<HashSet<_>>();
+
     let mut ir = match ir_generation::compile_program(program) {
         Ok(ir) => ir,
         Err(e) => {
@@ -432,14 +443,50 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         errors
     );
 
-    // The only other optimisation we have at the moment is constant combining.  In lieu of a
-    // forthcoming pass manager we can just call it here now.
+    // Check for storage writes after contract calls, now that each entry point contains
+    // everything it executes.
+    if tree_type == TreeType::Contract {
+        for entry_point in &entry_point_functions {
+            if !non_reentrant_methods.contains(entry_point.get_name(&ir)) {
+                warnings.extend(ir_generation::check_reentrancy(&ir, entry_point));
+            }
+        }
+    }
+
+    // The only other optimisations we have at the moment are constant combining, promoting locals
+    // to registers, removing redundant storage accesses and instructions and moving loop-invariant
+    // instructions out of loops.  In lieu of a forthcoming pass manager we can just call them here
+    // now.
     check!(
         combine_constants(&mut ir, &entry_point_functions),
         return err(warnings, errors),
         warnings,
         errors
     );
+    check!(
+        promote_locals(&mut ir, &entry_point_functions),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    check!(
+        optimize_storage_accesses(&mut ir, &entry_point_functions),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    check!(
+        eliminate_common_subexpressions(&mut ir, &entry_point_functions),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    check!(
+        hoist_loop_invariants(&mut ir, &entry_point_functions),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
 
     if build_config.print_ir {
         tracing::info!("{}", ir);
@@ -478,6 +525,73 @@ fn combine_constants(ir: &mut Context, functions: &[Function]) -> CompileResult<
     ok((), Vec::new(), Vec::new())
 }
 
+fn promote_locals(ir: &mut Context, functions: &[Function]) -> CompileResult<()> {
+    // The register allocator can't spill yet, so only a few locals are promoted per function to
+    // leave enough registers for everything else.
+    const MAX_PROMOTED_LOCALS: usize = 8;
+    for function in functions {
+        let res = sway_ir::optimize::sroa(ir, function)
+            .and_then(|_| sway_ir::optimize::mem2reg(ir, function, MAX_PROMOTED_LOCALS));
+        if let Err(ir_error) = res {
+            return err(
+                Vec::new(),
+                vec![CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::new("".into(), 0, 0, None).unwrap(),
+                )],
+            );
+        }
+    }
+    ok((), Vec::new(), Vec::new())
+}
+
+fn optimize_storage_accesses(ir: &mut Context, functions: &[Function]) -> CompileResult<()> {
+    for function in functions {
+        if let Err(ir_error) = sway_ir::optimize::optimize_storage_accesses(ir, function) {
+            return err(
+                Vec::new(),
+                vec![CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::new("".into(), 0, 0, None).unwrap(),
+                )],
+            );
+        }
+    }
+    ok((), Vec::new(), Vec::new())
+}
+
+fn eliminate_common_subexpressions(ir: &mut Context, functions: &[Function]) -> CompileResult<()> {
+    for function in functions {
+        if let Err(ir_error) = sway_ir::optimize::cse(ir, function) {
+            return err(
+                Vec::new(),
+                vec![CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::new("".into(), 0, 0, None).unwrap(),
+                )],
+            );
+        }
+    }
+    ok((), Vec::new(), Vec::new())
+}
+
+fn hoist_loop_invariants(ir: &mut Context, functions: &[Function]) -> CompileResult<()> {
+    // Like promoted locals, each hoisted value holds a register for the whole of its loop.
+    const MAX_HOISTED_VALUES: usize = 8;
+    for function in functions {
+        if let Err(ir_error) = sway_ir::optimize::licm(ir, function, MAX_HOISTED_VALUES) {
+            return err(
+                Vec::new(),
+                vec![CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::new("".into(), 0, 0, None).unwrap(),
+                )],
+            );
+        }
+    }
+    ok((), Vec::new(), Vec::new())
+}
+
 /// Given input Sway source code, compile to a [BytecodeCompilationResult] which contains the asm in
 /// bytecode form.
 pub fn compile_to_bytecode(
@@ -1,5 +1,6 @@
 mod abi;
 mod constant;
+mod derive;
 mod r#enum;
 pub mod function;
 mod impl_trait;
@@ -11,6 +12,7 @@ mod variable;
 
 pub(crate) use abi::*;
 pub use constant::*;
+pub use derive::*;
 pub use function::*;
 pub(crate) use impl_trait::*;
 pub use r#enum::*;
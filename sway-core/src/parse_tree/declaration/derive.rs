@@ -0,0 +1,27 @@
+use sway_types::span::Span;
+
+/// A trait whose implementation can be generated by the compiler for a struct or an enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerivableTrait {
+    Eq,
+    Hash,
+    Ord,
+}
+
+impl DerivableTrait {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Eq" => Some(DerivableTrait::Eq),
+            "Hash" => Some(DerivableTrait::Hash),
+            "Ord" => Some(DerivableTrait::Ord),
+            _otherwise => None,
+        }
+    }
+}
+
+/// A trait named in a `#[derive(...)]` attribute, e.g., the `Eq` in `#[derive(Eq)]`.
+#[derive(Debug, Clone)]
+pub struct Derive {
+    pub derived_trait: DerivableTrait,
+    pub(crate) span: Span,
+}
@@ -1,4 +1,7 @@
-use crate::{parse_tree::Visibility, type_engine::*};
+use crate::{
+    parse_tree::{Derive, Visibility},
+    type_engine::*,
+};
 
 use sway_types::{ident::Ident, span::Span};
 
@@ -9,6 +12,7 @@ pub struct EnumDeclaration {
     pub variants: Vec<EnumVariant>,
     pub(crate) span: Span,
     pub visibility: Visibility,
+    pub(crate) derives: Vec<Derive>,
 }
 
 #[derive(Debug, Clone)]
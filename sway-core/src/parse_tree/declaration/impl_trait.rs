@@ -1,7 +1,7 @@
 use super::FunctionDeclaration;
 use crate::{
     parse_tree::CallPath,
-    type_engine::{TypeInfo, TypeParameter},
+    type_engine::{TypeArgument, TypeInfo, TypeParameter},
 };
 
 use sway_types::span::Span;
@@ -9,6 +9,8 @@ use sway_types::span::Span;
 #[derive(Debug, Clone)]
 pub struct ImplTrait {
     pub trait_name: CallPath,
+    // the type arguments of a generic trait, like the `u64` in `impl From<u64> for MyType`
+    pub(crate) trait_type_arguments: Vec<TypeArgument>,
     pub(crate) type_implementing_for: TypeInfo,
     pub(crate) type_implementing_for_span: Span,
     pub(crate) type_parameters: Vec<TypeParameter>,
@@ -1,5 +1,5 @@
 use crate::{
-    parse_tree::Visibility,
+    parse_tree::{Derive, Visibility},
     type_engine::{TypeInfo, TypeParameter},
 };
 
@@ -11,11 +11,13 @@ pub struct StructDeclaration {
     pub fields: Vec<StructField>,
     pub(crate) type_parameters: Vec<TypeParameter>,
     pub visibility: Visibility,
+    pub(crate) derives: Vec<Derive>,
     pub(crate) span: Span,
 }
 
 #[derive(Debug, Clone)]
 pub struct StructField {
+    pub visibility: Visibility,
     pub name: Ident,
     pub(crate) type_info: TypeInfo,
     pub(crate) span: Span,
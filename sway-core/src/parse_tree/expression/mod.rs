@@ -1,5 +1,5 @@
 use crate::{
-    parse_tree::{CallPath, Literal},
+    parse_tree::{CallPath, FunctionDeclaration, Literal},
     type_engine::TypeArgument,
     CodeBlock, TypeInfo,
 };
@@ -73,6 +73,16 @@ pub enum Expression {
         branches: Vec<MatchBranch>,
         span: Span,
     },
+    /// A single-arm match produced by desugaring a `let` binding whose pattern
+    /// contains enum constructors, literals or constants. Unlike a regular
+    /// match expression, the `scrutinee` must be irrefutable for the type of
+    /// `value`.
+    IrrefutableMatchExp {
+        value: Box<Expression>,
+        scrutinee: Scrutinee,
+        result: Box<Expression>,
+        span: Span,
+    },
     // separated into other struct for parsing reasons
     AsmExpression {
         span: Span,
@@ -143,6 +153,12 @@ pub enum Expression {
         type_arguments: Vec<TypeArgument>,
         span: Span,
     },
+    /// A non-capturing closure, e.g. `|x: u64| x + 1`, desugared into an anonymous function
+    /// declaration whose unannotated parameter and return types are left to inference.
+    Closure {
+        function: Box<FunctionDeclaration>,
+        span: Span,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Hash)]
@@ -173,6 +189,7 @@ impl Spanned for Expression {
             CodeBlock { span, .. } => span,
             IfExp { span, .. } => span,
             MatchExp { span, .. } => span,
+            IrrefutableMatchExp { span, .. } => span,
             AsmExpression { span, .. } => span,
             MethodApplication { span, .. } => span,
             SubfieldExpression { span, .. } => span,
@@ -181,6 +198,7 @@ impl Spanned for Expression {
             ArrayIndex { span, .. } => span,
             StorageAccess { span, .. } => span,
             IntrinsicFunction { span, .. } => span,
+            Closure { span, .. } => span,
         })
         .clone()
     }
@@ -14,6 +14,8 @@ pub enum Literal {
     U16(u16),
     U32(u32),
     U64(u64),
+    U128(u128),
+    U256([u8; 32]),
     String(span::Span),
     Numeric(u64),
     Boolean(bool),
@@ -64,6 +66,14 @@ impl Hash for Literal {
                 state.write_u8(9);
                 x.hash(state);
             }
+            U128(x) => {
+                state.write_u8(10);
+                x.hash(state);
+            }
+            U256(x) => {
+                state.write_u8(11);
+                x.hash(state);
+            }
         }
     }
 }
@@ -78,6 +88,8 @@ impl PartialEq for Literal {
             (Self::U16(l0), Self::U16(r0)) => l0 == r0,
             (Self::U32(l0), Self::U32(r0)) => l0 == r0,
             (Self::U64(l0), Self::U64(r0)) => l0 == r0,
+            (Self::U128(l0), Self::U128(r0)) => l0 == r0,
+            (Self::U256(l0), Self::U256(r0)) => l0 == r0,
             (Self::String(l0), Self::String(r0)) => *l0.as_str() == *r0.as_str(),
             (Self::Numeric(l0), Self::Numeric(r0)) => l0 == r0,
             (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
@@ -95,6 +107,14 @@ impl fmt::Display for Literal {
             Literal::U16(content) => content.to_string(),
             Literal::U32(content) => content.to_string(),
             Literal::U64(content) => content.to_string(),
+            Literal::U128(content) => content.to_string(),
+            Literal::U256(content) => format!(
+                "0x{}",
+                content
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect::<String>()
+            ),
             Literal::Numeric(content) => content.to_string(),
             Literal::String(content) => content.as_str().to_string(),
             Literal::Boolean(content) => content.to_string(),
@@ -118,6 +138,8 @@ impl Literal {
             U16(_) => ResolvedType::UnsignedInteger(IntegerBits::Sixteen),
             U32(_) => ResolvedType::UnsignedInteger(IntegerBits::ThirtyTwo),
             U64(_) => ResolvedType::UnsignedInteger(IntegerBits::SixtyFour),
+            U128(_) => ResolvedType::UnsignedInteger(IntegerBits::OneTwentyEight),
+            U256(_) => ResolvedType::UnsignedInteger(IntegerBits::TwoFiftySix),
             Numeric(_) => ResolvedType::UnsignedInteger(IntegerBits::SixtyFour),
             String(inner) => ResolvedType::Str(inner.as_str().len() as u64),
             Boolean(_) => ResolvedType::Boolean,
@@ -140,6 +162,8 @@ impl Literal {
                 vec![0, 0, 0, 0, bytes[0], bytes[1], bytes[2], bytes[3]]
             }
             U64(val) => val.to_be_bytes().to_vec(),
+            U128(val) => val.to_be_bytes().to_vec(),
+            U256(b) => b.to_vec(),
             Numeric(val) => val.to_be_bytes().to_vec(),
             Boolean(b) => {
                 vec![
@@ -206,6 +230,8 @@ impl Literal {
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+            Literal::U128(_) => TypeInfo::UnsignedInteger(IntegerBits::OneTwentyEight),
+            Literal::U256(_) => TypeInfo::UnsignedInteger(IntegerBits::TwoFiftySix),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::Byte(_) => TypeInfo::Byte,
             Literal::B256(_) => TypeInfo::B256,
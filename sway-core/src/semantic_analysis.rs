@@ -1,8 +1,11 @@
 //! Type checking for Sway.
 pub mod ast_node;
+mod log_ids;
+mod method_effects;
 mod module;
 pub mod namespace;
 mod node_dependencies;
+mod predicate_checks;
 mod program;
 mod type_check_context;
 pub(crate) use ast_node::*;
@@ -1,4 +1,5 @@
 mod abi;
+mod derive;
 mod r#enum;
 mod function;
 mod impl_trait;
@@ -8,6 +9,7 @@ mod r#trait;
 mod variable;
 
 pub use abi::*;
+pub(crate) use derive::*;
 pub use function::*;
 pub use impl_trait::*;
 pub use r#enum::*;
@@ -386,14 +388,8 @@ impl TypedDeclaration {
             TypedDeclaration::VariableDeclaration(TypedVariableDeclaration { body, .. }) => {
                 body.return_type
             }
-            TypedDeclaration::FunctionDeclaration { .. } => {
-                return err(
-                    vec![],
-                    vec![CompileError::Unimplemented(
-                        "Function pointers have not yet been implemented.",
-                        self.span(),
-                    )],
-                )
+            TypedDeclaration::FunctionDeclaration(decl) if decl.type_parameters.is_empty() => {
+                decl.function_type()
             }
             TypedDeclaration::StructDeclaration(decl) => decl.create_type_id(),
             TypedDeclaration::EnumDeclaration(decl) => decl.create_type_id(),
@@ -469,6 +465,9 @@ pub struct TypedTraitFn {
 
 impl CopyTypes for TypedTraitFn {
     fn copy_types(&mut self, type_mapping: &TypeMapping) {
+        self.parameters
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
         self.return_type
             .update_type(type_mapping, &self.return_type_span);
     }
@@ -43,7 +43,7 @@ impl CreateTypeId for TypedAbiDeclaration {
 
 impl TypedAbiDeclaration {
     pub(crate) fn type_check(
-        ctx: TypeCheckContext,
+        mut ctx: TypeCheckContext,
         abi_decl: AbiDeclaration,
     ) -> CompileResult<Self> {
         let mut warnings = vec![];
@@ -62,7 +62,7 @@ impl TypedAbiDeclaration {
         // so we don't support the case of calling a contract's own interface
         // from itself. This is by design.
         let interface_surface = check!(
-            type_check_interface_surface(interface_surface, ctx.namespace),
+            type_check_interface_surface(interface_surface, ctx.by_ref()),
             return err(warnings, errors),
             warnings,
             errors
@@ -0,0 +1,587 @@
+use crate::{error::*, parse_tree::*, semantic_analysis::*, type_engine::*};
+use sway_types::{Ident, Span, Spanned};
+
+/// Type checks and registers the trait implementations requested by the `#[derive(...)]`
+/// attribute of a struct or an enum declaration.
+///
+/// The implementations are built as regular `impl` blocks in the parse tree and then go through
+/// the same type checking as hand written ones.
+pub(crate) fn type_check_derives(
+    mut ctx: TypeCheckContext,
+    decl: &TypedDeclaration,
+    derives: Vec<Derive>,
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let derived_type = match DerivedType::from_decl(decl) {
+        Some(derived_type) => derived_type,
+        None => {
+            errors.push(CompileError::Internal(
+                "Derives are only supported for structs and enums.",
+                decl.span(),
+            ));
+            return err(warnings, errors);
+        }
+    };
+
+    for Derive {
+        derived_trait,
+        span,
+    } in derives
+    {
+        let trait_name = trait_call_path(derived_trait, &span);
+        if derived_type.has_type_parameters {
+            errors.push(CompileError::DeriveOnGenericType {
+                trait_name: trait_name.suffix.to_string(),
+                type_name: derived_type.name.clone(),
+                span,
+            });
+            continue;
+        }
+
+        // Make sure that every member of the type implements the trait before generating any
+        // code, otherwise the user would be told about a method missing from the generated body.
+        let mut members_implement_trait = true;
+        for member in derived_type.members.iter() {
+            if look_up_type_id(member.type_id).is_unit()
+                || implements_trait(&ctx, &trait_name, member.type_id)
+            {
+                continue;
+            }
+            errors.push(CompileError::DeriveMemberMissingTrait {
+                trait_name: trait_name.suffix.to_string(),
+                type_name: derived_type.name.clone(),
+                member: member.name.clone(),
+                member_type: member.type_id.to_string(),
+                span: member.span.clone(),
+            });
+            members_implement_trait = false;
+        }
+        if !members_implement_trait {
+            continue;
+        }
+
+        let impl_trait = ImplTrait {
+            trait_name,
+            trait_type_arguments: vec![],
+            type_implementing_for: TypeInfo::Custom {
+                name: derived_type.name.clone(),
+                type_arguments: None,
+            },
+            type_implementing_for_span: span.clone(),
+            type_parameters: vec![],
+            functions: derived_type.trait_functions(derived_trait, &span),
+            block_span: span,
+        };
+        let (impl_trait, implementing_for_type_id) = check!(
+            TypedImplTrait::type_check_impl_trait(ctx.by_ref(), impl_trait),
+            continue,
+            warnings,
+            errors
+        );
+        ctx.namespace.insert_trait_implementation(
+            impl_trait.trait_name.clone(),
+            vec![],
+            implementing_for_type_id,
+            impl_trait.methods,
+        );
+    }
+
+    if errors.is_empty() {
+        ok((), warnings, errors)
+    } else {
+        err(warnings, errors)
+    }
+}
+
+/// The path of the trait that `derived_trait` implements, e.g., `core::ops::Eq` for `Eq`.
+fn trait_call_path(derived_trait: DerivableTrait, span: &Span) -> CallPath {
+    let (library, module, name) = match derived_trait {
+        DerivableTrait::Eq => ("core", "ops", "Eq"),
+        DerivableTrait::Hash => ("std", "hash", "Hash"),
+        DerivableTrait::Ord => ("core", "ops", "Ord"),
+    };
+    CallPath {
+        prefixes: vec![
+            Ident::new_with_override(library, span.clone()),
+            Ident::new_with_override(module, span.clone()),
+        ],
+        suffix: Ident::new_with_override(name, span.clone()),
+        is_absolute: true,
+    }
+}
+
+/// Whether an implementation of `trait_name` for `type_id` is visible either from the current
+/// module or from the module that declares the trait.
+fn implements_trait(ctx: &TypeCheckContext, trait_name: &CallPath, type_id: TypeId) -> bool {
+    if ctx
+        .namespace
+        .has_trait_implementation(&trait_name.suffix, type_id)
+    {
+        return true;
+    }
+    let trait_module_path = ctx.namespace.find_module_path(&trait_name.prefixes);
+    match ctx
+        .namespace
+        .root()
+        .check_submodule(&trait_module_path)
+        .value
+    {
+        Some(trait_module) => trait_module.has_trait_implementation(&trait_name.suffix, type_id),
+        // The trait itself cannot be found. Report that when type checking the impl instead.
+        None => true,
+    }
+}
+
+/// A struct field or an enum variant of the type a trait is derived for.
+struct DerivedMember {
+    name: Ident,
+    type_id: TypeId,
+    span: Span,
+}
+
+struct DerivedType {
+    name: Ident,
+    is_enum: bool,
+    has_type_parameters: bool,
+    members: Vec<DerivedMember>,
+}
+
+impl DerivedType {
+    fn from_decl(decl: &TypedDeclaration) -> Option<Self> {
+        match decl {
+            TypedDeclaration::StructDeclaration(decl) => Some(DerivedType {
+                name: decl.name.clone(),
+                is_enum: false,
+                has_type_parameters: !decl.type_parameters.is_empty(),
+                members: decl
+                    .fields
+                    .iter()
+                    .map(|field| DerivedMember {
+                        name: field.name.clone(),
+                        type_id: field.type_id,
+                        span: field.span.clone(),
+                    })
+                    .collect(),
+            }),
+            TypedDeclaration::EnumDeclaration(decl) => Some(DerivedType {
+                name: decl.name.clone(),
+                is_enum: true,
+                has_type_parameters: !decl.type_parameters.is_empty(),
+                members: decl
+                    .variants
+                    .iter()
+                    .map(|variant| DerivedMember {
+                        name: variant.name.clone(),
+                        type_id: variant.type_id,
+                        span: variant.span.clone(),
+                    })
+                    .collect(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn trait_functions(
+        &self,
+        derived_trait: DerivableTrait,
+        span: &Span,
+    ) -> Vec<FunctionDeclaration> {
+        match derived_trait {
+            DerivableTrait::Eq => {
+                vec![self.method("eq", true, TypeInfo::Boolean, self.eq_body(span), span)]
+            }
+            DerivableTrait::Hash => {
+                vec![self.method("hash", false, TypeInfo::B256, self.hash_body(span), span)]
+            }
+            DerivableTrait::Ord => vec![
+                self.method(
+                    "gt",
+                    true,
+                    TypeInfo::Boolean,
+                    self.ord_body("gt", span),
+                    span,
+                ),
+                self.method(
+                    "lt",
+                    true,
+                    TypeInfo::Boolean,
+                    self.ord_body("lt", span),
+                    span,
+                ),
+            ],
+        }
+    }
+
+    fn method(
+        &self,
+        name: &'static str,
+        takes_other: bool,
+        return_type: TypeInfo,
+        body: Expression,
+        span: &Span,
+    ) -> FunctionDeclaration {
+        let mut parameters = vec![FunctionParameter {
+            name: ident("self", span),
+            is_reference: false,
+            is_mutable: false,
+            type_id: insert_type(TypeInfo::SelfType),
+            type_span: span.clone(),
+        }];
+        if takes_other {
+            parameters.push(FunctionParameter {
+                name: ident("other", span),
+                is_reference: false,
+                is_mutable: false,
+                type_id: insert_type(TypeInfo::SelfType),
+                type_span: span.clone(),
+            });
+        }
+        FunctionDeclaration {
+            purity: Purity::Pure,
+            name: ident(name, span),
+            visibility: Visibility::Private,
+            body: CodeBlock {
+                contents: vec![AstNode {
+                    content: AstNodeContent::ImplicitReturnExpression(body),
+                    span: span.clone(),
+                }],
+                whole_block_span: span.clone(),
+            },
+            parameters,
+            span: span.clone(),
+            return_type,
+            type_parameters: vec![],
+            return_type_span: span.clone(),
+        }
+    }
+
+    /// The members whose values take part in the generated code. Unit typed struct fields
+    /// carry no data and are left out.
+    fn data_members(&self) -> impl Iterator<Item = &DerivedMember> {
+        self.members
+            .iter()
+            .filter(|member| !look_up_type_id(member.type_id).is_unit())
+    }
+
+    /// `self.a == other.a && self.b == other.b` for structs and
+    /// `match (self, other) { (E::A(l), E::A(r)) => l == r, .., _ => false }` for enums.
+    fn eq_body(&self, span: &Span) -> Expression {
+        if self.is_enum {
+            return self.match_same_variants(span, "eq", bool_literal(true, span), || {
+                bool_literal(false, span)
+            });
+        }
+        self.data_members()
+            .map(|member| {
+                op_call(
+                    "eq",
+                    vec![
+                        field(var("self", span), member),
+                        field(var("other", span), member),
+                    ],
+                    span,
+                )
+            })
+            .reduce(|lhs, rhs| lazy_op(LazyOp::And, lhs, rhs, span))
+            .unwrap_or_else(|| bool_literal(true, span))
+    }
+
+    /// Lexicographic comparison of the fields in declaration order for structs. Enums compare
+    /// by variant declaration order first and by payload second.
+    fn ord_body(&self, op: &'static str, span: &Span) -> Expression {
+        if self.is_enum {
+            return self.match_same_variants(span, op, bool_literal(false, span), || {
+                op_call(
+                    op,
+                    vec![
+                        self.variant_index(var("self", span), span),
+                        self.variant_index(var("other", span), span),
+                    ],
+                    span,
+                )
+            });
+        }
+        // `self.a < other.a || (!(other.a < self.a) && <rest>)`
+        let mut members = self.data_members().collect::<Vec<_>>().into_iter().rev();
+        let last = match members.next() {
+            Some(last) => last,
+            None => return bool_literal(false, span),
+        };
+        let compare = |lhs: &'static str, rhs: &'static str, member: &DerivedMember| {
+            op_call(
+                op,
+                vec![field(var(lhs, span), member), field(var(rhs, span), member)],
+                span,
+            )
+        };
+        members.fold(compare("self", "other", last), |rest, member| {
+            let not_reversed = Expression::FunctionApplication {
+                name: path(&["core", "ops"], "not", false, span),
+                arguments: vec![compare("other", "self", member)],
+                type_arguments: vec![],
+                span: span.clone(),
+            };
+            lazy_op(
+                LazyOp::Or,
+                compare("self", "other", member),
+                lazy_op(LazyOp::And, not_reversed, rest, span),
+                span,
+            )
+        })
+    }
+
+    /// Folds the hashes of the fields into a running `sha256` digest for structs. Enums hash the
+    /// variant index together with the hash of the payload.
+    fn hash_body(&self, span: &Span) -> Expression {
+        if self.is_enum {
+            let branches = self
+                .members
+                .iter()
+                .enumerate()
+                .map(|(index, member)| {
+                    let (payload, payload_hash) = if look_up_type_id(member.type_id).is_unit() {
+                        (Scrutinee::CatchAll { span: span.clone() }, zero_b256(span))
+                    } else {
+                        (
+                            Scrutinee::Variable {
+                                name: ident("__derive_payload", span),
+                                span: span.clone(),
+                            },
+                            hash_call(var("__derive_payload", span), span),
+                        )
+                    };
+                    MatchBranch {
+                        scrutinee: self.variant_scrutinee(member, payload, span),
+                        result: sha256_call(
+                            vec![
+                                Expression::Literal {
+                                    value: Literal::U64(index as u64),
+                                    span: span.clone(),
+                                },
+                                payload_hash,
+                            ],
+                            span,
+                        ),
+                        span: span.clone(),
+                    }
+                })
+                .collect();
+            return Expression::MatchExp {
+                value: Box::new(var("self", span)),
+                branches,
+                span: span.clone(),
+            };
+        }
+        self.data_members().fold(zero_b256(span), |acc, member| {
+            sha256_call(
+                vec![acc, hash_call(field(var("self", span), member), span)],
+                span,
+            )
+        })
+    }
+
+    /// `match (self, other) { (E::A(l), E::A(r)) => l <op> r, (E::B, E::B) => <unit_result>,
+    /// _ => <otherwise> }`. The catch-all branch is only added when there is more than one
+    /// variant, as it would be unreachable otherwise.
+    fn match_same_variants(
+        &self,
+        span: &Span,
+        op: &'static str,
+        unit_result: Expression,
+        otherwise: impl FnOnce() -> Expression,
+    ) -> Expression {
+        let mut branches = self
+            .members
+            .iter()
+            .map(|member| {
+                let (lhs, rhs, result) = if look_up_type_id(member.type_id).is_unit() {
+                    (
+                        Scrutinee::CatchAll { span: span.clone() },
+                        Scrutinee::CatchAll { span: span.clone() },
+                        unit_result.clone(),
+                    )
+                } else {
+                    (
+                        Scrutinee::Variable {
+                            name: ident("__derive_lhs", span),
+                            span: span.clone(),
+                        },
+                        Scrutinee::Variable {
+                            name: ident("__derive_rhs", span),
+                            span: span.clone(),
+                        },
+                        op_call(
+                            op,
+                            vec![var("__derive_lhs", span), var("__derive_rhs", span)],
+                            span,
+                        ),
+                    )
+                };
+                MatchBranch {
+                    scrutinee: Scrutinee::Tuple {
+                        elems: vec![
+                            self.variant_scrutinee(member, lhs, span),
+                            self.variant_scrutinee(member, rhs, span),
+                        ],
+                        span: span.clone(),
+                    },
+                    result,
+                    span: span.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        if branches.len() > 1 {
+            branches.push(MatchBranch {
+                scrutinee: Scrutinee::CatchAll { span: span.clone() },
+                result: otherwise(),
+                span: span.clone(),
+            });
+        }
+        Expression::MatchExp {
+            value: Box::new(Expression::Tuple {
+                fields: vec![var("self", span), var("other", span)],
+                span: span.clone(),
+            }),
+            branches,
+            span: span.clone(),
+        }
+    }
+
+    /// `match value { E::A(_) => 0, E::B => 1, .. }`
+    fn variant_index(&self, value: Expression, span: &Span) -> Expression {
+        let branches = self
+            .members
+            .iter()
+            .enumerate()
+            .map(|(index, member)| MatchBranch {
+                scrutinee: self.variant_scrutinee(
+                    member,
+                    Scrutinee::CatchAll { span: span.clone() },
+                    span,
+                ),
+                result: Expression::Literal {
+                    value: Literal::U64(index as u64),
+                    span: span.clone(),
+                },
+                span: span.clone(),
+            })
+            .collect();
+        Expression::MatchExp {
+            value: Box::new(value),
+            branches,
+            span: span.clone(),
+        }
+    }
+
+    fn variant_scrutinee(
+        &self,
+        variant: &DerivedMember,
+        value: Scrutinee,
+        span: &Span,
+    ) -> Scrutinee {
+        Scrutinee::EnumScrutinee {
+            call_path: CallPath {
+                prefixes: vec![self.name.clone()],
+                suffix: variant.name.clone(),
+                is_absolute: false,
+            },
+            value: Box::new(value),
+            span: span.clone(),
+        }
+    }
+}
+
+fn ident(name: &'static str, span: &Span) -> Ident {
+    Ident::new_with_override(name, span.clone())
+}
+
+fn path(
+    prefixes: &[&'static str],
+    suffix: &'static str,
+    is_absolute: bool,
+    span: &Span,
+) -> CallPath {
+    CallPath {
+        prefixes: prefixes.iter().map(|prefix| ident(prefix, span)).collect(),
+        suffix: ident(suffix, span),
+        is_absolute,
+    }
+}
+
+fn var(name: &'static str, span: &Span) -> Expression {
+    Expression::VariableExpression {
+        name: ident(name, span),
+        span: span.clone(),
+    }
+}
+
+fn field(prefix: Expression, member: &DerivedMember) -> Expression {
+    Expression::SubfieldExpression {
+        span: member.span.clone(),
+        prefix: Box::new(prefix),
+        field_to_access: member.name.clone(),
+    }
+}
+
+fn bool_literal(value: bool, span: &Span) -> Expression {
+    Expression::Literal {
+        value: Literal::Boolean(value),
+        span: span.clone(),
+    }
+}
+
+fn zero_b256(span: &Span) -> Expression {
+    Expression::Literal {
+        value: Literal::B256([0; 32]),
+        span: span.clone(),
+    }
+}
+
+fn lazy_op(op: LazyOp, lhs: Expression, rhs: Expression, span: &Span) -> Expression {
+    Expression::LazyOperator {
+        op,
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+        span: span.clone(),
+    }
+}
+
+/// A call to one of the `core::ops` operator methods, as the parser produces for `==`, `<`, etc.
+fn op_call(name: &'static str, arguments: Vec<Expression>, span: &Span) -> Expression {
+    Expression::MethodApplication {
+        method_name: MethodName::FromTrait {
+            call_path: path(&["core", "ops"], name, true, span),
+        },
+        contract_call_params: vec![],
+        arguments,
+        type_arguments: vec![],
+        span: span.clone(),
+    }
+}
+
+fn hash_call(value: Expression, span: &Span) -> Expression {
+    Expression::MethodApplication {
+        method_name: MethodName::FromTrait {
+            call_path: path(&["std", "hash"], "hash", true, span),
+        },
+        contract_call_params: vec![],
+        arguments: vec![value],
+        type_arguments: vec![],
+        span: span.clone(),
+    }
+}
+
+/// `std::hash::sha256((a, b, ..))`
+fn sha256_call(fields: Vec<Expression>, span: &Span) -> Expression {
+    Expression::FunctionApplication {
+        name: path(&["std", "hash"], "sha256", false, span),
+        arguments: vec![Expression::Tuple {
+            fields,
+            span: span.clone(),
+        }],
+        type_arguments: vec![],
+        span: span.clone(),
+    }
+}
@@ -81,6 +81,7 @@ impl TypedEnumDeclaration {
             variants,
             span,
             visibility,
+            derives: _,
         } = decl;
 
         // create a namespace for the decl, used to create a scope for generics
@@ -213,6 +213,13 @@ impl TypedFunctionDeclaration {
             errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
         }
 
+        // function values only exist at compile time, so they can't be returned
+        if let TypeInfo::Function { .. } = look_up_type_id(return_type) {
+            errors.push(CompileError::FunctionValueNotKnown {
+                span: return_type_span.clone(),
+            });
+        }
+
         let function_decl = TypedFunctionDeclaration {
             name,
             body,
@@ -230,6 +237,24 @@ impl TypedFunctionDeclaration {
         ok(function_decl, warnings, errors)
     }
 
+    /// The type of this function when it is used as a value.
+    pub(crate) fn function_type(&self) -> TypeId {
+        insert_type(TypeInfo::Function {
+            parameters: self
+                .parameters
+                .iter()
+                .map(|param| TypeArgument {
+                    type_id: param.type_id,
+                    span: param.type_span.clone(),
+                })
+                .collect(),
+            return_type: TypeArgument {
+                type_id: self.return_type,
+                span: self.return_type_span.clone(),
+            },
+        })
+    }
+
     /// If there are parameters, join their spans. Otherwise, use the fn name span.
     pub(crate) fn parameters_span(&self) -> Span {
         if !self.parameters.is_empty() {
@@ -331,12 +356,14 @@ fn test_function_selector_behavior() {
         parameters: vec![
             TypedFunctionParameter {
                 name: Ident::new_no_span("foo"),
+                is_reference: false,
                 is_mutable: false,
                 type_id: crate::type_engine::insert_type(TypeInfo::Str(5)),
                 type_span: Span::dummy(),
             },
             TypedFunctionParameter {
                 name: Ident::new_no_span("baz"),
+                is_reference: false,
                 is_mutable: false,
                 type_id: insert_type(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
                 type_span: Span::dummy(),
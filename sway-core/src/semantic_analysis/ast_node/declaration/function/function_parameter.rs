@@ -13,6 +13,7 @@ use sway_types::{span::Span, Spanned};
 #[derive(Debug, Clone, Eq)]
 pub struct TypedFunctionParameter {
     pub name: Ident,
+    pub is_reference: bool,
     pub is_mutable: bool,
     pub type_id: TypeId,
     pub(crate) type_span: Span,
@@ -25,6 +26,7 @@ impl PartialEq for TypedFunctionParameter {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
             && look_up_type_id(self.type_id) == look_up_type_id(other.type_id)
+            && self.is_reference == other.is_reference
             && self.is_mutable == other.is_mutable
     }
 }
@@ -77,6 +79,7 @@ impl TypedFunctionParameter {
         );
         let parameter = TypedFunctionParameter {
             name: parameter.name,
+            is_reference: parameter.is_reference,
             is_mutable: parameter.is_mutable,
             type_id,
             type_span: parameter.type_span,
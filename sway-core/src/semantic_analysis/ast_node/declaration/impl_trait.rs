@@ -6,18 +6,19 @@ use crate::{
     error::{err, ok},
     semantic_analysis::{Mode, TypeCheckContext},
     type_engine::{
-        insert_type, look_up_type_id, resolve_type, unify_with_self, CopyTypes, TypeId,
-        TypeMapping, TypeParameter,
+        insert_type, look_up_type_id, resolve_type, unify_with_self, CopyTypes,
+        EnforceTypeArguments, TypeArgument, TypeId, TypeMapping, TypeParameter,
     },
     CallPath, CompileError, CompileResult, FunctionDeclaration, ImplSelf, ImplTrait, Purity,
     TypeInfo, TypedDeclaration, TypedFunctionDeclaration,
 };
 
-use super::TypedTraitFn;
+use super::{TypedTraitDeclaration, TypedTraitFn};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TypedImplTrait {
     pub trait_name: CallPath,
+    pub(crate) trait_type_arguments: Vec<TypeId>,
     pub(crate) span: Span,
     pub methods: Vec<TypedFunctionDeclaration>,
     pub(crate) implementing_for_type_id: TypeId,
@@ -41,6 +42,7 @@ impl TypedImplTrait {
 
         let ImplTrait {
             trait_name,
+            trait_type_arguments,
             type_parameters,
             functions,
             type_implementing_for,
@@ -90,7 +92,7 @@ impl TypedImplTrait {
         );
 
         // Update the context with the new `self` type.
-        let ctx = ctx.with_self_type(implementing_for_type_id);
+        let mut ctx = ctx.with_self_type(implementing_for_type_id);
 
         let impl_trait = match ctx
             .namespace
@@ -98,7 +100,18 @@ impl TypedImplTrait {
             .ok(&mut warnings, &mut errors)
             .cloned()
         {
-            Some(TypedDeclaration::TraitDeclaration(tr)) => {
+            Some(TypedDeclaration::TraitDeclaration(mut tr)) => {
+                let trait_type_arguments = check!(
+                    apply_trait_type_arguments(
+                        ctx.by_ref(),
+                        &mut tr,
+                        trait_type_arguments,
+                        &trait_name
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
                 let functions_buf = check!(
                     type_check_trait_implementation(
                         ctx,
@@ -115,6 +128,7 @@ impl TypedImplTrait {
                 );
                 let impl_trait = TypedImplTrait {
                     trait_name,
+                    trait_type_arguments,
                     span: block_span,
                     methods: functions_buf,
                     implementing_for_type_id,
@@ -158,8 +172,15 @@ impl TypedImplTrait {
                     warnings,
                     errors
                 );
+                if !trait_type_arguments.is_empty() {
+                    errors.push(CompileError::DoesNotTakeTypeArguments {
+                        name: trait_name.suffix.clone(),
+                        span: trait_name.span(),
+                    });
+                }
                 let impl_trait = TypedImplTrait {
                     trait_name,
+                    trait_type_arguments: vec![],
                     span: block_span,
                     methods: functions_buf,
                     implementing_for_type_id,
@@ -260,6 +281,7 @@ impl TypedImplTrait {
 
         let impl_trait = TypedImplTrait {
             trait_name,
+            trait_type_arguments: vec![],
             span: block_span,
             methods,
             implementing_for_type_id,
@@ -268,6 +290,94 @@ impl TypedImplTrait {
     }
 }
 
+/// Substitutes the type arguments of an implementation of a generic trait for the type
+/// parameters of the trait, e.g., `u64` for `T` in `impl From<u64> for MyType`.
+///
+/// The names of the type parameters are also declared in the impl namespace so that the default
+/// methods of the trait, which are type checked as part of the impl, can refer to them.
+fn apply_trait_type_arguments(
+    mut ctx: TypeCheckContext,
+    trait_decl: &mut TypedTraitDeclaration,
+    trait_type_arguments: Vec<TypeArgument>,
+    trait_name: &CallPath,
+) -> CompileResult<Vec<TypeId>> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    match (trait_decl.type_parameters.len(), trait_type_arguments.len()) {
+        (0, 0) => return ok(vec![], warnings, errors),
+        (0, _) => {
+            errors.push(CompileError::DoesNotTakeTypeArguments {
+                name: trait_name.suffix.clone(),
+                span: trait_name.span(),
+            });
+            return err(warnings, errors);
+        }
+        (_, 0) => {
+            errors.push(CompileError::NeedsTypeArguments {
+                name: trait_name.suffix.clone(),
+                span: trait_name.span(),
+            });
+            return err(warnings, errors);
+        }
+        (expected, given) if expected != given => {
+            errors.push(CompileError::IncorrectNumberOfTypeArguments {
+                given,
+                expected,
+                span: trait_name.span(),
+            });
+            return err(warnings, errors);
+        }
+        _ => {}
+    }
+
+    let mut type_arguments = vec![];
+    for type_argument in trait_type_arguments.into_iter() {
+        type_arguments.push(check!(
+            ctx.resolve_type_with_self(
+                type_argument.type_id,
+                &type_argument.span,
+                EnforceTypeArguments::Yes,
+                None
+            ),
+            insert_type(TypeInfo::ErrorRecovery),
+            warnings,
+            errors
+        ));
+    }
+
+    for (type_parameter, type_argument) in trait_decl.type_parameters.iter().zip(&type_arguments) {
+        // The impl may declare a type parameter of the same name, as in
+        // `impl<T> From<T> for MyType<T>`, in which case that one is already in scope.
+        if ctx
+            .namespace
+            .check_symbol(&type_parameter.name_ident)
+            .is_ok()
+        {
+            continue;
+        }
+        ctx.namespace
+            .insert_symbol(
+                type_parameter.name_ident.clone(),
+                TypedDeclaration::GenericTypeForFunctionScope {
+                    name: type_parameter.name_ident.clone(),
+                    type_id: *type_argument,
+                },
+            )
+            .ok(&mut warnings, &mut errors);
+    }
+
+    let type_mapping = trait_decl
+        .type_parameters
+        .iter()
+        .map(|type_parameter| type_parameter.type_id)
+        .zip(type_arguments.iter().cloned())
+        .collect::<TypeMapping>();
+    trait_decl.copy_types(&type_mapping);
+
+    ok(type_arguments, warnings, errors)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn type_check_trait_implementation(
     mut ctx: TypeCheckContext,
@@ -346,6 +456,15 @@ fn type_check_trait_implementation(
         for (fn_signature_param, fn_decl_param) in
             fn_signature.parameters.iter().zip(&fn_decl.parameters)
         {
+            if fn_decl_param.is_reference != fn_signature_param.is_reference {
+                errors.push(CompileError::RefMutableParameterMismatchInTrait {
+                    param_name: fn_decl_param.name.clone(),
+                    fn_name: fn_decl.name.clone(),
+                    trait_name: trait_name.suffix.clone(),
+                    span: fn_decl_param.name.span(),
+                });
+            }
+
             // TODO use trait constraints as part of the type here to
             // implement trait constraint solver */
             let fn_decl_param_type = fn_decl_param.type_id;
@@ -439,6 +558,7 @@ fn type_check_trait_implementation(
             suffix: trait_name.suffix.clone(),
             is_absolute: false,
         },
+        vec![],
         self_type_id,
         functions_buf.clone(),
     );
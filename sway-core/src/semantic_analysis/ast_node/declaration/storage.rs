@@ -1,10 +1,12 @@
 use crate::{
     error::*,
     ir_generation::{
-        const_eval::compile_constant_expression_to_constant, storage::serialize_to_storage_slots,
+        const_eval::compile_constant_expression_to_constant,
+        storage::{get_storage_field_layout, serialize_to_storage_slots},
     },
+    parse_tree::Visibility,
     semantic_analysis::{
-        TypeCheckedStorageAccess, TypeCheckedStorageAccessDescriptor, TypedExpression,
+        namespace, TypeCheckedStorageAccess, TypeCheckedStorageAccessDescriptor, TypedExpression,
         TypedStructField,
     },
     type_engine::{look_up_type_id, TypeId, TypeInfo},
@@ -13,7 +15,7 @@ use crate::{
 use derivative::Derivative;
 use fuel_tx::StorageSlot;
 use sway_ir::{Context, Kind, Module};
-use sway_types::{state::StateIndex, Span, Spanned};
+use sway_types::{state::StateIndex, Span, Spanned, StorageLayout};
 
 #[derive(Clone, Debug, Derivative)]
 #[derivative(PartialEq, Eq)]
@@ -42,7 +44,7 @@ impl TypedStorageDeclaration {
         storage_fields: &[TypedStorageField],
     ) -> CompileResult<(TypeCheckedStorageAccess, TypeId)> {
         let mut errors = vec![];
-        let warnings = vec![];
+        let mut warnings = vec![];
 
         let mut type_checked_buf = vec![];
         let mut fields: Vec<_> = fields.into_iter().rev().collect();
@@ -92,6 +94,15 @@ impl TypedStorageDeclaration {
                 .find(|x| x.name.as_str() == field.as_str())
             {
                 Some(struct_field) => {
+                    check!(
+                        struct_field.expect_accessible_from(
+                            &type_checked_buf.last().unwrap().name,
+                            &field.span()
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
                     type_checked_buf.push(TypeCheckedStorageAccessDescriptor {
                         name: field.clone(),
                         type_id: struct_field.type_id,
@@ -141,19 +152,23 @@ impl TypedStorageDeclaration {
                  }| TypedStructField {
                     name: name.clone(),
                     type_id: *r#type,
+                    visibility: Visibility::Public,
                     span: span.clone(),
                 },
             )
             .collect()
     }
 
-    pub(crate) fn get_initialized_storage_slots(&self) -> CompileResult<Vec<StorageSlot>> {
+    pub(crate) fn get_initialized_storage_slots(
+        &self,
+        module_ns: &namespace::Module,
+    ) -> CompileResult<Vec<StorageSlot>> {
         let mut errors = vec![];
         let storage_slots = self
             .fields
             .iter()
             .enumerate()
-            .map(|(i, f)| f.get_initialized_storage_slots(&StateIndex::new(i)))
+            .map(|(i, f)| f.get_initialized_storage_slots(module_ns, &StateIndex::new(i)))
             .filter_map(|s| s.map_err(|e| errors.push(e)).ok())
             .flatten()
             .collect::<Vec<_>>();
@@ -163,6 +178,33 @@ impl TypedStorageDeclaration {
             false => err(vec![], errors),
         }
     }
+
+    pub(crate) fn get_storage_layout(&self) -> CompileResult<StorageLayout> {
+        let mut errors = vec![];
+        let mut context = Context::default();
+        let fields = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                get_storage_field_layout(
+                    &mut context,
+                    &StateIndex::new(i),
+                    &[],
+                    f.name.to_string(),
+                    f.type_id,
+                    &f.span,
+                )
+            })
+            .filter_map(|s| s.map_err(|e| errors.push(e)).ok())
+            .flatten()
+            .collect::<Vec<_>>();
+
+        match errors.is_empty() {
+            true => ok(StorageLayout { fields }, vec![], vec![]),
+            false => err(vec![], errors),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq)]
@@ -201,18 +243,20 @@ impl TypedStorageField {
 
     pub fn get_initialized_storage_slots(
         &self,
+        module_ns: &namespace::Module,
         ix: &StateIndex,
     ) -> Result<Vec<StorageSlot>, CompileError> {
         let mut context = Context::default();
         let module = Module::new(&mut context, Kind::Contract);
         match &self.initializer {
             None => Ok(vec![]),
-            Some(initializer) => {
-                compile_constant_expression_to_constant(&mut context, module, None, initializer)
-                    .map(|constant| {
-                        serialize_to_storage_slots(&constant, &context, ix, &constant.ty, &[])
-                    })
-            }
+            Some(initializer) => compile_constant_expression_to_constant(
+                &mut context,
+                module,
+                Some(module_ns),
+                initializer,
+            )
+            .map(|constant| serialize_to_storage_slots(&constant, &context, ix, &constant.ty, &[])),
         }
     }
 }
@@ -74,6 +74,7 @@ impl TypedStructDeclaration {
             type_parameters,
             visibility,
             span,
+            derives: _,
         } = decl;
 
         // create a namespace for the decl, used to create a scope for generics
@@ -145,6 +146,7 @@ impl TypedStructDeclaration {
 pub struct TypedStructField {
     pub name: Ident,
     pub type_id: TypeId,
+    pub(crate) visibility: Visibility,
     pub(crate) span: Span,
 }
 
@@ -163,7 +165,9 @@ impl Hash for TypedStructField {
 // https://doc.rust-lang.org/std/collections/struct.HashMap.html
 impl PartialEq for TypedStructField {
     fn eq(&self, other: &Self) -> bool {
-        self.name == other.name && look_up_type_id(self.type_id) == look_up_type_id(other.type_id)
+        self.name == other.name
+            && look_up_type_id(self.type_id) == look_up_type_id(other.type_id)
+            && self.visibility == other.visibility
     }
 }
 
@@ -213,8 +217,37 @@ impl TypedStructField {
         let field = TypedStructField {
             name: field.name,
             type_id: r#type,
+            visibility: field.visibility,
             span: field.span,
         };
         ok(field, warnings, errors)
     }
+
+    /// Private fields may only be used from within the module that declares their struct. Every
+    /// module lives in its own source file, so this compares the path of the field declaration
+    /// with the path of the expression that is using it.
+    pub(crate) fn is_accessible_from(&self, access_span: &Span) -> bool {
+        self.visibility == Visibility::Public || self.span.path() == access_span.path()
+    }
+
+    /// Returns an error if this field is private to a module other than the one containing
+    /// `access_span`.
+    pub(crate) fn expect_accessible_from(
+        &self,
+        struct_name: &Ident,
+        access_span: &Span,
+    ) -> CompileResult<()> {
+        if self.is_accessible_from(access_span) {
+            ok((), vec![], vec![])
+        } else {
+            err(
+                vec![],
+                vec![CompileError::StructFieldIsPrivate {
+                    field_name: self.name.clone(),
+                    struct_name: struct_name.clone(),
+                    span: access_span.clone(),
+                }],
+            )
+        }
+    }
 }
@@ -8,9 +8,9 @@ use crate::{
         Mode, TypeCheckContext, TypedCodeBlock,
     },
     style::is_upper_camel_case,
-    type_engine::{insert_type, CopyTypes, TypeMapping},
-    CallPath, CompileError, CompileResult, FunctionDeclaration, FunctionParameter, Namespace,
-    Supertrait, TraitDeclaration, TypeInfo, TypedDeclaration, TypedFunctionDeclaration, Visibility,
+    type_engine::{insert_type, CopyTypes, TypeMapping, TypeParameter},
+    CallPath, CompileError, CompileResult, FunctionDeclaration, FunctionParameter, Supertrait,
+    TraitDeclaration, TypeInfo, TypedDeclaration, TypedFunctionDeclaration, Visibility,
 };
 
 use super::{EnforceTypeArguments, TypedFunctionParameter, TypedTraitFn};
@@ -19,6 +19,7 @@ use super::{EnforceTypeArguments, TypedFunctionParameter, TypedTraitFn};
 #[derivative(PartialEq, Eq)]
 pub struct TypedTraitDeclaration {
     pub name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
     pub interface_surface: Vec<TypedTraitFn>,
     // NOTE: deriving partialeq and hash on this element may be important in the
     // future, but I am not sure. For now, adding this would 2x the amount of
@@ -32,6 +33,9 @@ pub struct TypedTraitDeclaration {
 
 impl CopyTypes for TypedTraitDeclaration {
     fn copy_types(&mut self, type_mapping: &TypeMapping) {
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
         self.interface_surface
             .iter_mut()
             .for_each(|x| x.copy_types(type_mapping));
@@ -49,21 +53,33 @@ impl TypedTraitDeclaration {
 
         is_upper_camel_case(&trait_decl.name).ok(&mut warnings, &mut errors);
 
+        // A temporary namespace for checking within the trait's scope.
+        let mut trait_namespace = ctx.namespace.clone();
+        let mut ctx = ctx.scoped(&mut trait_namespace);
+
+        // type check the type parameters
+        // insert them into the namespace
+        let mut new_type_parameters = vec![];
+        for type_parameter in trait_decl.type_parameters.iter().cloned() {
+            new_type_parameters.push(check!(
+                TypeParameter::type_check(ctx.by_ref(), type_parameter),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+        }
+
         // type check the interface surface
         let interface_surface = check!(
-            type_check_interface_surface(trait_decl.interface_surface.to_vec(), ctx.namespace),
+            type_check_interface_surface(trait_decl.interface_surface.to_vec(), ctx.by_ref()),
             return err(warnings, errors),
             warnings,
             errors
         );
 
-        // A temporary namespace for checking within the trait's scope.
-        let mut trait_namespace = ctx.namespace.clone();
-        let ctx = ctx.scoped(&mut trait_namespace);
-
         // Recursively handle supertraits: make their interfaces and methods available to this trait
         check!(
-            handle_supertraits(&trait_decl.supertraits, ctx.namespace),
+            handle_supertraits(&trait_decl.supertraits, ctx.by_ref()),
             return err(warnings, errors),
             warnings,
             errors
@@ -77,6 +93,7 @@ impl TypedTraitDeclaration {
                 suffix: trait_decl.name.clone(),
                 is_absolute: false,
             },
+            vec![],
             insert_type(TypeInfo::SelfType),
             interface_surface
                 .iter()
@@ -93,6 +110,7 @@ impl TypedTraitDeclaration {
         );
         let typed_trait_decl = TypedTraitDeclaration {
             name: trait_decl.name.clone(),
+            type_parameters: new_type_parameters,
             interface_surface,
             methods: trait_decl.methods.to_vec(),
             supertraits: trait_decl.supertraits.to_vec(),
@@ -104,15 +122,13 @@ impl TypedTraitDeclaration {
 
 /// Recursively handle supertraits by adding all their interfaces and methods to some namespace
 /// which is meant to be the namespace of the subtrait in question
-fn handle_supertraits(
-    supertraits: &[Supertrait],
-    trait_namespace: &mut Namespace,
-) -> CompileResult<()> {
+fn handle_supertraits(supertraits: &[Supertrait], mut ctx: TypeCheckContext) -> CompileResult<()> {
     let mut warnings = Vec::new();
     let mut errors = Vec::new();
 
     for supertrait in supertraits.iter() {
-        match trait_namespace
+        match ctx
+            .namespace
             .resolve_call_path(&supertrait.name)
             .ok(&mut warnings, &mut errors)
             .cloned()
@@ -124,8 +140,9 @@ fn handle_supertraits(
                 ..
             })) => {
                 // insert dummy versions of the interfaces for all of the supertraits
-                trait_namespace.insert_trait_implementation(
+                ctx.namespace.insert_trait_implementation(
                     supertrait.name.clone(),
+                    vec![],
                     insert_type(TypeInfo::SelfType),
                     interface_surface
                         .iter()
@@ -135,13 +152,14 @@ fn handle_supertraits(
 
                 // insert dummy versions of the methods of all of the supertraits
                 let dummy_funcs = check!(
-                    convert_trait_methods_to_dummy_funcs(methods, trait_namespace),
+                    convert_trait_methods_to_dummy_funcs(methods, ctx.by_ref()),
                     return err(warnings, errors),
                     warnings,
                     errors
                 );
-                trait_namespace.insert_trait_implementation(
+                ctx.namespace.insert_trait_implementation(
                     supertrait.name.clone(),
+                    vec![],
                     insert_type(TypeInfo::SelfType),
                     dummy_funcs,
                 );
@@ -149,7 +167,7 @@ fn handle_supertraits(
                 // Recurse to insert dummy versions of interfaces and methods of the *super*
                 // supertraits
                 check!(
-                    handle_supertraits(supertraits, trait_namespace),
+                    handle_supertraits(supertraits, ctx.by_ref()),
                     return err(warnings, errors),
                     warnings,
                     errors
@@ -173,7 +191,7 @@ fn handle_supertraits(
 /// the parameters and the return types are type checked.
 fn convert_trait_methods_to_dummy_funcs(
     methods: &[FunctionDeclaration],
-    trait_namespace: &mut Namespace,
+    mut ctx: TypeCheckContext,
 ) -> CompileResult<Vec<TypedFunctionDeclaration>> {
     let mut warnings = vec![];
     let mut errors = vec![];
@@ -195,20 +213,23 @@ fn convert_trait_methods_to_dummy_funcs(
                     .map(
                         |FunctionParameter {
                              name,
+                             is_reference,
                              is_mutable,
                              type_id,
                              type_span,
                          }| TypedFunctionParameter {
                             name: name.clone(),
+                            is_reference: *is_reference,
                             is_mutable: *is_mutable,
                             type_id: check!(
-                                trait_namespace.resolve_type_with_self(
-                                    *type_id,
-                                    insert_type(TypeInfo::SelfType),
-                                    type_span,
-                                    EnforceTypeArguments::Yes,
-                                    None
-                                ),
+                                ctx.by_ref()
+                                    .with_self_type(insert_type(TypeInfo::SelfType))
+                                    .resolve_type_with_self(
+                                        *type_id,
+                                        type_span,
+                                        EnforceTypeArguments::Yes,
+                                        None
+                                    ),
                                 insert_type(TypeInfo::ErrorRecovery),
                                 warnings,
                                 errors,
@@ -219,13 +240,14 @@ fn convert_trait_methods_to_dummy_funcs(
                     .collect(),
                 span: name.span(),
                 return_type: check!(
-                    trait_namespace.resolve_type_with_self(
-                        insert_type(return_type.clone()),
-                        insert_type(TypeInfo::SelfType),
-                        return_type_span,
-                        EnforceTypeArguments::Yes,
-                        None
-                    ),
+                    ctx.by_ref()
+                        .with_self_type(insert_type(TypeInfo::SelfType))
+                        .resolve_type_with_self(
+                            insert_type(return_type.clone()),
+                            return_type_span,
+                            EnforceTypeArguments::Yes,
+                            None
+                        ),
                     insert_type(TypeInfo::ErrorRecovery),
                     warnings,
                     errors,
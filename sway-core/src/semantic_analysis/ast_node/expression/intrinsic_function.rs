@@ -20,6 +20,8 @@ pub struct TypedIntrinsicFunctionKind {
     pub arguments: Vec<TypedExpression>,
     pub type_arguments: Vec<TypeArgument>,
     pub span: Span,
+    /// The log ID of a `__log` call, assigned once the whole program has been type checked.
+    pub log_id: Option<u64>,
 }
 
 impl CopyTypes for TypedIntrinsicFunctionKind {
@@ -100,6 +102,7 @@ impl TypedIntrinsicFunctionKind {
                     arguments: vec![exp],
                     type_arguments: vec![],
                     span,
+                    log_id: None,
                 };
                 let return_type = insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
                 (intrinsic_function, return_type)
@@ -141,6 +144,7 @@ impl TypedIntrinsicFunctionKind {
                         span: targ.span,
                     }],
                     span,
+                    log_id: None,
                 };
                 let return_type = insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
                 (intrinsic_function, return_type)
@@ -174,6 +178,7 @@ impl TypedIntrinsicFunctionKind {
                         span: targ.span,
                     }],
                     span,
+                    log_id: None,
                 };
                 (intrinsic_function, insert_type(TypeInfo::Boolean))
             }
@@ -183,10 +188,17 @@ impl TypedIntrinsicFunctionKind {
                     arguments: vec![],
                     type_arguments: vec![],
                     span,
+                    log_id: None,
                 },
                 insert_type(TypeInfo::B256),
             ),
-            Intrinsic::Eq => {
+            Intrinsic::Eq
+            | Intrinsic::Gt
+            | Intrinsic::Lt
+            | Intrinsic::Add
+            | Intrinsic::Sub
+            | Intrinsic::Mul
+            | Intrinsic::Div => {
                 if arguments.len() != 2 {
                     errors.push(CompileError::IntrinsicIncorrectNumArgs {
                         name: kind.to_string(),
@@ -210,7 +222,7 @@ impl TypedIntrinsicFunctionKind {
                 // Check for supported argument types
                 let arg_ty = resolve_type(lhs.return_type, &lhs.span).unwrap();
                 let is_valid_arg_ty = matches!(arg_ty, TypeInfo::UnsignedInteger(_))
-                    || matches!(arg_ty, TypeInfo::Boolean);
+                    || (kind == Intrinsic::Eq && matches!(arg_ty, TypeInfo::Boolean));
                 if !is_valid_arg_ty {
                     errors.push(CompileError::IntrinsicUnsupportedArgType {
                         name: kind.to_string(),
@@ -230,16 +242,48 @@ impl TypedIntrinsicFunctionKind {
                     warnings,
                     errors
                 );
+                let return_type = match kind {
+                    Intrinsic::Eq | Intrinsic::Gt | Intrinsic::Lt => insert_type(TypeInfo::Boolean),
+                    _arithmetic => lhs.return_type,
+                };
                 (
                     TypedIntrinsicFunctionKind {
                         kind,
                         arguments: vec![lhs, rhs],
                         type_arguments: vec![],
                         span,
+                        log_id: None,
                     },
-                    insert_type(TypeInfo::Boolean),
+                    return_type,
                 )
             }
+            Intrinsic::Log => {
+                if arguments.len() != 1 {
+                    errors.push(CompileError::IntrinsicIncorrectNumArgs {
+                        name: kind.to_string(),
+                        expected: 1,
+                        span,
+                    });
+                    return err(warnings, errors);
+                }
+                let ctx = ctx
+                    .with_help_text("")
+                    .with_type_annotation(insert_type(TypeInfo::Unknown));
+                let exp = check!(
+                    TypedExpression::type_check(ctx, arguments[0].clone()),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let intrinsic_function = TypedIntrinsicFunctionKind {
+                    kind,
+                    arguments: vec![exp],
+                    type_arguments: vec![],
+                    span,
+                    log_id: None,
+                };
+                (intrinsic_function, insert_type(TypeInfo::Tuple(vec![])))
+            }
         };
         ok((intrinsic_function, return_type), warnings, errors)
     }
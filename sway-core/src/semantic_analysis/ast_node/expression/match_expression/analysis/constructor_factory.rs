@@ -242,8 +242,8 @@ impl ConstructorFactory {
             // we will not present every string case
             Pattern::String(_) => Pattern::Wildcard,
             Pattern::Wildcard => Pattern::Wildcard,
-            // we will not present every b256 case
-            Pattern::B256(_) => Pattern::Wildcard,
+            // we will not present every b256, u128 or u256 case
+            Pattern::B256(_) | Pattern::U128(_) | Pattern::U256(_) => Pattern::Wildcard,
             Pattern::Boolean(b) => {
                 let mut true_found = false;
                 let mut false_found = false;
@@ -442,8 +442,8 @@ impl ConstructorFactory {
         match first {
             // its assumed that no one is ever going to list every string
             Pattern::String(_) => ok(false, warnings, errors),
-            // its assumed that no one is ever going to list every B256
-            Pattern::B256(_) => ok(false, warnings, errors),
+            // its assumed that no one is ever going to list every B256, U128 or U256
+            Pattern::B256(_) | Pattern::U128(_) | Pattern::U256(_) => ok(false, warnings, errors),
             Pattern::U8(range) => {
                 let mut ranges = vec![range];
                 for pat in rest.into_iter() {
@@ -104,6 +104,8 @@ pub(crate) enum Pattern {
     U16(Range<u16>),
     U32(Range<u32>),
     U64(Range<u64>),
+    U128(u128),
+    U256([u8; 32]),
     B256([u8; 32]),
     Boolean(bool),
     Byte(Range<u8>),
@@ -128,6 +130,8 @@ impl Pattern {
                 Literal::U16(x) => Pattern::U16(Range::from_single(x)),
                 Literal::U32(x) => Pattern::U32(Range::from_single(x)),
                 Literal::U64(x) => Pattern::U64(Range::from_single(x)),
+                Literal::U128(x) => Pattern::U128(x),
+                Literal::U256(x) => Pattern::U256(x),
                 Literal::B256(x) => Pattern::B256(x),
                 Literal::Boolean(b) => Pattern::Boolean(b),
                 Literal::Byte(x) => Pattern::Byte(Range::from_single(x)),
@@ -356,6 +360,26 @@ impl Pattern {
                 }
                 Pattern::U64(range.clone())
             }
+            Pattern::U128(n) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::U128(*n)
+            }
+            Pattern::U256(n) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::U256(*n)
+            }
             Pattern::B256(b) => {
                 if !args.is_empty() {
                     errors.push(CompileError::Internal(
@@ -520,6 +544,8 @@ impl Pattern {
             Pattern::U16(_) => 0,
             Pattern::U32(_) => 0,
             Pattern::U64(_) => 0,
+            Pattern::U128(_) => 0,
+            Pattern::U256(_) => 0,
             Pattern::B256(_) => 0,
             Pattern::Boolean(_) => 0,
             Pattern::Byte(_) => 0,
@@ -568,6 +594,8 @@ impl Pattern {
             (Pattern::U16(a), Pattern::U16(b)) => a == b,
             (Pattern::U32(a), Pattern::U32(b)) => a == b,
             (Pattern::U64(a), Pattern::U64(b)) => a == b,
+            (Pattern::U128(a), Pattern::U128(b)) => a == b,
+            (Pattern::U256(a), Pattern::U256(b)) => a == b,
             (Pattern::B256(a), Pattern::B256(b)) => a == b,
             (Pattern::Boolean(a), Pattern::Boolean(b)) => a == b,
             (Pattern::Byte(a), Pattern::Byte(b)) => a == b,
@@ -693,15 +721,17 @@ impl Pattern {
             Pattern::U16(_) => 2,
             Pattern::U32(_) => 3,
             Pattern::U64(_) => 4,
-            Pattern::B256(_) => 5,
-            Pattern::Boolean(_) => 6,
-            Pattern::Byte(_) => 7,
-            Pattern::Numeric(_) => 8,
-            Pattern::String(_) => 9,
-            Pattern::Struct(_) => 10,
-            Pattern::Enum(_) => 11,
-            Pattern::Tuple(_) => 12,
-            Pattern::Or(_) => 13,
+            Pattern::U128(_) => 5,
+            Pattern::U256(_) => 6,
+            Pattern::B256(_) => 7,
+            Pattern::Boolean(_) => 8,
+            Pattern::Byte(_) => 9,
+            Pattern::Numeric(_) => 10,
+            Pattern::String(_) => 11,
+            Pattern::Struct(_) => 12,
+            Pattern::Enum(_) => 13,
+            Pattern::Tuple(_) => 14,
+            Pattern::Or(_) => 15,
         }
     }
 }
@@ -715,6 +745,8 @@ impl fmt::Display for Pattern {
             Pattern::U32(range) => format!("{}", range),
             Pattern::U64(range) => format!("{}", range),
             Pattern::Numeric(range) => format!("{}", range),
+            Pattern::U128(n) => format!("{}", n),
+            Pattern::U256(n) => format!("{}", Literal::U256(*n)),
             Pattern::B256(n) => format!("{:#?}", n),
             Pattern::Boolean(b) => format!("{}", b),
             Pattern::Byte(range) => format!("{}", range),
@@ -744,6 +776,8 @@ impl std::cmp::Ord for Pattern {
             (Pattern::U16(x), Pattern::U16(y)) => x.cmp(y),
             (Pattern::U32(x), Pattern::U32(y)) => x.cmp(y),
             (Pattern::U64(x), Pattern::U64(y)) => x.cmp(y),
+            (Pattern::U128(x), Pattern::U128(y)) => x.cmp(y),
+            (Pattern::U256(x), Pattern::U256(y)) => x.cmp(y),
             (Pattern::B256(x), Pattern::B256(y)) => x.cmp(y),
             (Pattern::Boolean(x), Pattern::Boolean(y)) => x.cmp(y),
             (Pattern::Byte(x), Pattern::Byte(y)) => x.cmp(y),
@@ -99,13 +99,20 @@ impl TypedScrutinee {
                             scrutinee,
                             span,
                         } => {
-                            // ensure that the struct definition has this field
-                            let _ = check!(
+                            // ensure that the struct definition has this field and that it
+                            // is visible from here
+                            let struct_field = check!(
                                 struct_decl.expect_field(&field),
                                 return err(warnings, errors),
                                 warnings,
                                 errors
                             );
+                            check!(
+                                struct_field.expect_accessible_from(&struct_decl.name, &span),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
                             // type check the nested scrutinee
                             let typed_scrutinee = match scrutinee {
                                 None => None,
@@ -1,5 +1,6 @@
 mod enum_instantiation;
 mod function_application;
+mod function_value;
 mod if_expression;
 mod lazy_operator;
 mod method_application;
@@ -8,8 +9,9 @@ mod tuple_index_access;
 mod unsafe_downcast;
 
 pub(crate) use self::{
-    enum_instantiation::*, function_application::*, if_expression::*, lazy_operator::*,
-    method_application::*, struct_field_access::*, tuple_index_access::*, unsafe_downcast::*,
+    enum_instantiation::*, function_application::*, function_value::*, if_expression::*,
+    lazy_operator::*, method_application::*, struct_field_access::*, tuple_index_access::*,
+    unsafe_downcast::*,
 };
 
 use crate::{
@@ -201,10 +203,29 @@ impl UnresolvedTypeCheck for TypedExpression {
                 res.append(&mut exp.check_for_unresolved_types());
                 res.append(&mut variant.type_id.check_for_unresolved_types());
             }
+            FunctionValue { function } => {
+                res.append(
+                    &mut function
+                        .body
+                        .contents
+                        .iter()
+                        .flat_map(UnresolvedTypeCheck::check_for_unresolved_types)
+                        .collect(),
+                );
+            }
+            IndirectFunctionApplication { arguments, .. } => {
+                res.append(
+                    &mut arguments
+                        .iter()
+                        .flat_map(|x| x.check_for_unresolved_types())
+                        .collect(),
+                );
+            }
             // storage access can never be generic
             // variable expressions don't ever have return types themselves, they're stored in
             // `TypedExpression::return_type`. Variable expressions are just names of variables.
             VariableExpression { .. }
+            | VariableReference { .. }
             | StorageAccess { .. }
             | Literal(_)
             | AbiName(_)
@@ -242,6 +263,7 @@ impl DeterministicallyAborts for TypedExpression {
             | Literal(_)
             | StorageAccess { .. }
             | VariableExpression { .. }
+            | VariableReference { .. }
             | FunctionParameter
             | TupleElemAccess { .. } => false,
             IntrinsicFunction(kind) => kind.deterministically_aborts(),
@@ -281,6 +303,10 @@ impl DeterministicallyAborts for TypedExpression {
             AbiName(_) => false,
             EnumTag { exp } => exp.deterministically_aborts(),
             UnsafeDowncast { exp, .. } => exp.deterministically_aborts(),
+            FunctionValue { .. } => false,
+            IndirectFunctionApplication { arguments, .. } => {
+                arguments.iter().any(|x| x.deterministically_aborts())
+            }
         }
     }
 }
@@ -382,7 +408,10 @@ impl TypedExpression {
             | TypedExpressionVariant::StorageAccess { .. }
             | TypedExpressionVariant::FunctionApplication { .. }
             | TypedExpressionVariant::EnumTag { .. }
-            | TypedExpressionVariant::UnsafeDowncast { .. } => vec![],
+            | TypedExpressionVariant::UnsafeDowncast { .. }
+            | TypedExpressionVariant::FunctionValue { .. }
+            | TypedExpressionVariant::IndirectFunctionApplication { .. }
+            | TypedExpressionVariant::VariableReference { .. } => vec![],
         }
     }
 
@@ -391,7 +420,7 @@ impl TypedExpression {
         let res = match expr {
             Expression::Literal { value: lit, span } => Self::type_check_literal(lit, span),
             Expression::VariableExpression { name, span, .. } => {
-                Self::type_check_variable_expression(ctx.namespace, name, span)
+                Self::type_check_variable_expression(ctx.by_ref(), name, span)
             }
             Expression::FunctionApplication {
                 name,
@@ -439,6 +468,18 @@ impl TypedExpression {
                 branches,
                 span,
             ),
+            Expression::IrrefutableMatchExp {
+                value,
+                scrutinee,
+                result,
+                span,
+            } => Self::type_check_irrefutable_match_expression(
+                ctx.by_ref().with_help_text(""),
+                *value,
+                scrutinee,
+                *result,
+                span,
+            ),
             Expression::AsmExpression { asm, span, .. } => {
                 Self::type_check_asm_expression(ctx.by_ref(), asm, span)
             }
@@ -532,6 +573,9 @@ impl TypedExpression {
                 arguments,
                 span,
             ),
+            Expression::Closure { function, span } => {
+                instantiate_closure(ctx.by_ref(), *function, span)
+            }
         };
         let mut typed_expression = match res.value {
             Some(r) => r,
@@ -595,6 +639,8 @@ impl TypedExpression {
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+            Literal::U128(_) => TypeInfo::UnsignedInteger(IntegerBits::OneTwentyEight),
+            Literal::U256(_) => TypeInfo::UnsignedInteger(IntegerBits::TwoFiftySix),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::Byte(_) => TypeInfo::Byte,
             Literal::B256(_) => TypeInfo::B256,
@@ -610,12 +656,20 @@ impl TypedExpression {
     }
 
     pub(crate) fn type_check_variable_expression(
-        namespace: &Namespace,
+        ctx: TypeCheckContext,
         name: Ident,
         span: Span,
     ) -> CompileResult<TypedExpression> {
         let mut errors = vec![];
-        let exp = match namespace.resolve_symbol(&name).value {
+        // functions used as values are checked separately as they need the whole context
+        let function_decl = match ctx.namespace.resolve_symbol(&name).value {
+            Some(TypedDeclaration::FunctionDeclaration(decl)) => Some(decl.clone()),
+            _ => None,
+        };
+        if let Some(decl) = function_decl {
+            return instantiate_function_value(ctx, decl, name, span);
+        }
+        let exp = match ctx.namespace.resolve_symbol(&name).value {
             Some(TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
                 body, ..
             })) => TypedExpression {
@@ -665,10 +719,33 @@ impl TypedExpression {
         name: CallPath,
         arguments: Vec<Expression>,
         type_arguments: Vec<TypeArgument>,
-        _span: Span,
+        span: Span,
     ) -> CompileResult<TypedExpression> {
         let mut warnings = vec![];
         let mut errors = vec![];
+
+        // a variable or parameter of function type is called through its value
+        let function_type = match ctx.namespace.resolve_symbol(&name.suffix).value {
+            Some(TypedDeclaration::VariableDeclaration(decl))
+                if name.prefixes.is_empty() && !name.is_absolute =>
+            {
+                Some(decl.body.return_type)
+            }
+            _ => None,
+        };
+        if let Some(function_type) = function_type {
+            if let TypeInfo::Function { .. } = look_up_type_id(function_type) {
+                return instantiate_indirect_function_application(
+                    ctx,
+                    name.suffix,
+                    function_type,
+                    type_arguments,
+                    arguments,
+                    span,
+                );
+            }
+        }
+
         let unknown_decl = check!(
             ctx.namespace.resolve_call_path(&name).cloned(),
             return err(warnings, errors),
@@ -894,6 +971,81 @@ impl TypedExpression {
         ok(typed_if_exp, warnings, errors)
     }
 
+    fn type_check_irrefutable_match_expression(
+        mut ctx: TypeCheckContext,
+        value: Expression,
+        scrutinee: Scrutinee,
+        result: Expression,
+        span: Span,
+    ) -> CompileResult<TypedExpression> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        // type check the value
+        let typed_value = {
+            let ctx = ctx
+                .by_ref()
+                .with_help_text("")
+                .with_type_annotation(insert_type(TypeInfo::Unknown));
+            check!(
+                TypedExpression::type_check(ctx, value.clone()),
+                error_recovery_expr(value.span()),
+                warnings,
+                errors
+            )
+        };
+        let type_id = typed_value.return_type;
+
+        check!(
+            look_up_type_id(type_id).expect_is_supported_in_match_expressions(&typed_value.span),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        // type check the single arm of the match expression
+        let branch = MatchBranch {
+            scrutinee: scrutinee.clone(),
+            result,
+            span: span.clone(),
+        };
+        let typed_match_expression = {
+            let ctx = ctx.by_ref().with_help_text("");
+            check!(
+                TypedMatchExpression::type_check(ctx, typed_value, vec![branch], span.clone()),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )
+        };
+
+        // the pattern of a `let` binding has to match every possible value,
+        // otherwise there would be nothing to bind in the uncovered cases
+        let (witness_report, _) = check!(
+            check_match_expression_usefulness(type_id, vec![scrutinee.clone()], span),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        if witness_report.has_witnesses() {
+            errors.push(CompileError::RefutablePatternInLet {
+                missing_patterns: format!("{}", witness_report),
+                span: scrutinee.span(),
+            });
+            return err(warnings, errors);
+        }
+
+        // desugar the typed match expression to a typed if expression
+        let typed_if_exp = check!(
+            typed_match_expression.convert_to_typed_if_expression(ctx),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        ok(typed_if_exp, warnings, errors)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn type_check_asm_expression(
         mut ctx: TypeCheckContext,
@@ -1036,6 +1188,14 @@ impl TypedExpression {
                     }
                 };
 
+            // private fields can only be initialized from within the struct's own module
+            check!(
+                def_field.expect_accessible_from(struct_name, &expr_field.span),
+                (),
+                warnings,
+                errors
+            );
+
             let ctx = ctx
                 .by_ref()
                 .with_help_text(
@@ -1457,8 +1617,12 @@ impl TypedExpression {
         }
 
         functions_buf.append(&mut type_checked_fn_buf);
-        ctx.namespace
-            .insert_trait_implementation(abi_name.clone(), return_type, functions_buf);
+        ctx.namespace.insert_trait_implementation(
+            abi_name.clone(),
+            vec![],
+            return_type,
+            functions_buf,
+        );
         let exp = TypedExpression {
             expression: TypedExpressionVariant::AbiCast {
                 abi_name,
@@ -1695,6 +1859,13 @@ impl TypedExpression {
                         }),
                         new_type,
                     ),
+                    // Every `Numeric` literal fits in a `u64`, so widening it can't fail.
+                    IntegerBits::OneTwentyEight => (Ok(Literal::U128(num as u128)), new_type),
+                    IntegerBits::TwoFiftySix => {
+                        let mut bytes = [0u8; 32];
+                        bytes[24..].copy_from_slice(&num.to_be_bytes());
+                        (Ok(Literal::U256(bytes)), new_type)
+                    }
                 },
                 TypeInfo::Numeric => (
                     num.to_string().parse().map(Literal::U64).map_err(|e| {
@@ -1736,7 +1907,8 @@ mod tests {
 
     fn do_type_check(expr: Expression, type_annotation: TypeId) -> CompileResult<TypedExpression> {
         let mut namespace = Namespace::init_root(namespace::Module::default());
-        let ctx = TypeCheckContext::from_root(&mut namespace).with_type_annotation(type_annotation);
+        let ctx = TypeCheckContext::from_root(&mut namespace, TreeType::Script)
+            .with_type_annotation(type_annotation);
         TypedExpression::type_check(ctx, expr)
     }
 
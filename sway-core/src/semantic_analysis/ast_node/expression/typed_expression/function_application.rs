@@ -1,6 +1,6 @@
 use crate::{
     error::*,
-    semantic_analysis::{ast_node::*, TypeCheckContext},
+    semantic_analysis::{ast_node::*, Namespace, TypeCheckContext},
 };
 use std::collections::{hash_map::RandomState, HashMap, VecDeque};
 use sway_types::{state::StateIndex, Spanned};
@@ -60,15 +60,28 @@ pub(crate) fn instantiate_function_application(
                     declaration.",
                 )
                 .with_type_annotation(param.type_id);
-            let exp = check!(
+            check!(
                 TypedExpression::type_check(ctx, arg.clone()),
                 error_recovery_expr(arg.span()),
                 warnings,
                 errors
-            );
-            (param.name.clone(), exp)
+            )
         })
-        .collect();
+        .collect::<Vec<_>>();
+    let typed_call_arguments = check!(
+        check_reference_arguments(
+            ctx.namespace,
+            &function_decl.parameters,
+            typed_call_arguments
+        ),
+        return err(warnings, errors),
+        warnings,
+        errors
+    )
+    .into_iter()
+    .zip(function_decl.parameters.iter())
+    .map(|(exp, param)| (param.name.clone(), exp))
+    .collect();
 
     let span = function_decl.span.clone();
     let exp = instantiate_function_application_inner(
@@ -126,6 +139,82 @@ pub(crate) fn instantiate_function_application_simple(
     ok(exp, warnings, errors)
 }
 
+/// Check the arguments given to `ref mut` parameters, which must be mutable variables, and mark
+/// them to be passed by reference. Such a variable may not also be used by any other argument of
+/// the same call, as the callee would then see its memory through two different names.
+pub(crate) fn check_reference_arguments(
+    namespace: &Namespace,
+    parameters: &[TypedFunctionParameter],
+    mut arguments: Vec<TypedExpression>,
+) -> CompileResult<Vec<TypedExpression>> {
+    let warnings = vec![];
+    let mut errors = vec![];
+
+    let mut references = Vec::new();
+    for (index, (arg, param)) in arguments.iter_mut().zip(parameters).enumerate() {
+        if !param.is_reference {
+            continue;
+        }
+        let name = match &arg.expression {
+            TypedExpressionVariant::VariableExpression { name } => name.clone(),
+            _ => {
+                errors.push(CompileError::RefMutableArgumentNotVariable {
+                    param_name: param.name.clone(),
+                    span: arg.span.clone(),
+                });
+                continue;
+            }
+        };
+        let is_mutable = matches!(
+            namespace.resolve_symbol(&name).value,
+            Some(TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                is_mutable,
+                ..
+            })) if is_mutable.is_mutable()
+        );
+        if !is_mutable {
+            errors.push(CompileError::RefMutableArgumentNotMutable {
+                name,
+                param_name: param.name.clone(),
+                span: arg.span.clone(),
+            });
+            continue;
+        }
+        arg.expression = TypedExpressionVariant::VariableReference { name: name.clone() };
+        references.push((index, name));
+    }
+
+    for (index, name) in references.iter() {
+        let aliasing_arg = arguments.iter().enumerate().find(|(other_index, arg)| {
+            other_index != index && base_variable(arg).map_or(false, |base| base == name)
+        });
+        if let Some((_, arg)) = aliasing_arg {
+            errors.push(CompileError::RefMutableArgumentAliased {
+                name: name.clone(),
+                span: arg.span.clone(),
+            });
+        }
+    }
+
+    if errors.is_empty() {
+        ok(arguments, warnings, errors)
+    } else {
+        err(warnings, errors)
+    }
+}
+
+/// The variable whose memory an expression reads in place, if any.
+fn base_variable(exp: &TypedExpression) -> Option<&Ident> {
+    match &exp.expression {
+        TypedExpressionVariant::VariableExpression { name }
+        | TypedExpressionVariant::VariableReference { name } => Some(name),
+        TypedExpressionVariant::StructFieldAccess { prefix, .. }
+        | TypedExpressionVariant::TupleElemAccess { prefix, .. }
+        | TypedExpressionVariant::ArrayIndex { prefix, .. } => base_variable(prefix),
+        _ => None,
+    }
+}
+
 pub(crate) fn check_function_arguments_arity(
     arguments_len: usize,
     function_decl: &TypedFunctionDeclaration,
@@ -0,0 +1,211 @@
+use crate::{
+    error::*,
+    semantic_analysis::{ast_node::*, TypeCheckContext},
+};
+use sway_types::{Span, Spanned};
+
+/// Use a named function as a value. Generic functions have no single type and so cannot be used
+/// as values.
+pub(crate) fn instantiate_function_value(
+    ctx: TypeCheckContext,
+    function_decl: TypedFunctionDeclaration,
+    name: Ident,
+    span: Span,
+) -> CompileResult<TypedExpression> {
+    let warnings = vec![];
+    let mut errors = vec![];
+    if !function_decl.type_parameters.is_empty() {
+        errors.push(CompileError::GenericFunctionAsValue { name, span });
+        return err(warnings, errors);
+    }
+    if function_decl
+        .parameters
+        .iter()
+        .any(|param| param.is_reference)
+    {
+        errors.push(CompileError::RefMutableFunctionAsValue { name, span });
+        return err(warnings, errors);
+    }
+    if !ctx.purity().can_call(function_decl.purity) {
+        errors.push(CompileError::StorageAccessMismatch {
+            attrs: promote_purity(ctx.purity(), function_decl.purity).to_attribute_syntax(),
+            span: span.clone(),
+        });
+    }
+    let exp = TypedExpression {
+        return_type: function_decl.function_type(),
+        expression: TypedExpressionVariant::FunctionValue {
+            function: Box::new(function_decl),
+        },
+        is_constant: IsConstant::No,
+        span,
+    };
+    ok(exp, warnings, errors)
+}
+
+/// Type check a closure as an anonymous function. Unannotated parameters take their types from
+/// the expected function type, if there is one, and the return type is inferred from the body.
+///
+/// Closures cannot capture anything, so the variables in scope are hidden from the body and any
+/// reference to one of them is reported as a capture.
+pub(crate) fn instantiate_closure(
+    ctx: TypeCheckContext,
+    mut function: FunctionDeclaration,
+    span: Span,
+) -> CompileResult<TypedExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    if let TypeInfo::Function {
+        parameters,
+        return_type,
+    } = look_up_type_id(ctx.type_annotation())
+    {
+        if parameters.len() == function.parameters.len() {
+            for (param, expected) in function.parameters.iter_mut().zip(parameters) {
+                if matches!(look_up_type_id(param.type_id), TypeInfo::Unknown) {
+                    param.type_id = insert_type(TypeInfo::Ref(expected.type_id, expected.span));
+                }
+            }
+            function.return_type = TypeInfo::Ref(return_type.type_id, return_type.span);
+        }
+    }
+    function.purity = ctx.purity();
+
+    let mut closure_namespace = ctx.namespace.clone();
+    let hidden_variables = closure_namespace
+        .module()
+        .symbols()
+        .iter()
+        .filter(|(_, decl)| matches!(decl, TypedDeclaration::VariableDeclaration(_)))
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    for name in hidden_variables.iter() {
+        closure_namespace.module_mut().symbols.remove(name);
+    }
+
+    let ctx = ctx
+        .scoped(&mut closure_namespace)
+        .with_mode(Mode::NonAbi)
+        .with_type_annotation(insert_type(TypeInfo::Unknown));
+    let res = TypedFunctionDeclaration::type_check(ctx, function);
+    warnings.extend(res.warnings);
+    errors.extend(res.errors.into_iter().map(|error| match error {
+        CompileError::UnknownVariable { var_name } if hidden_variables.contains(&var_name) => {
+            CompileError::ClosureCapturesVariable {
+                span: var_name.span(),
+                name: var_name,
+            }
+        }
+        CompileError::SymbolNotFound { name } if hidden_variables.contains(&name) => {
+            CompileError::ClosureCapturesVariable {
+                span: name.span(),
+                name,
+            }
+        }
+        error => error,
+    }));
+    let function = match res.value {
+        Some(function) => function,
+        None => return err(warnings, errors),
+    };
+
+    let exp = TypedExpression {
+        return_type: function.function_type(),
+        expression: TypedExpressionVariant::FunctionValue {
+            function: Box::new(function),
+        },
+        is_constant: IsConstant::No,
+        span,
+    };
+    ok(exp, warnings, errors)
+}
+
+/// Call a variable or parameter of function type. The arguments are checked against the
+/// parameter types of the function type.
+pub(crate) fn instantiate_indirect_function_application(
+    mut ctx: TypeCheckContext,
+    callee: Ident,
+    function_type: TypeId,
+    type_arguments: Vec<TypeArgument>,
+    arguments: Vec<Expression>,
+    span: Span,
+) -> CompileResult<TypedExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let (parameters, return_type) = match look_up_type_id(function_type) {
+        TypeInfo::Function {
+            parameters,
+            return_type,
+        } => (parameters, return_type),
+        _ => {
+            errors.push(CompileError::Internal(
+                "Attempted to call a value that is not a function.",
+                span,
+            ));
+            return err(warnings, errors);
+        }
+    };
+
+    if let Some(type_argument) = type_arguments.first() {
+        errors.push(CompileError::DoesNotTakeTypeArguments {
+            name: callee,
+            span: type_argument.span.clone(),
+        });
+        return err(warnings, errors);
+    }
+
+    match arguments.len().cmp(&parameters.len()) {
+        std::cmp::Ordering::Equal => (),
+        std::cmp::Ordering::Less => {
+            errors.push(CompileError::TooFewArgumentsForFunction {
+                span,
+                method_name: callee,
+                expected: parameters.len(),
+                received: arguments.len(),
+            });
+            return err(warnings, errors);
+        }
+        std::cmp::Ordering::Greater => {
+            errors.push(CompileError::TooManyArgumentsForFunction {
+                span,
+                method_name: callee,
+                expected: parameters.len(),
+                received: arguments.len(),
+            });
+            return err(warnings, errors);
+        }
+    }
+
+    let typed_arguments = arguments
+        .into_iter()
+        .zip(parameters.iter())
+        .map(|(arg, param)| {
+            let ctx = ctx
+                .by_ref()
+                .with_help_text(
+                    "The argument that has been provided to this function value does not \
+                    match the declared type of the parameter in its function type.",
+                )
+                .with_type_annotation(param.type_id);
+            check!(
+                TypedExpression::type_check(ctx, arg.clone()),
+                error_recovery_expr(arg.span()),
+                warnings,
+                errors
+            )
+        })
+        .collect();
+
+    let exp = TypedExpression {
+        expression: TypedExpressionVariant::IndirectFunctionApplication {
+            callee,
+            arguments: typed_arguments,
+        },
+        return_type: return_type.type_id,
+        is_constant: IsConstant::No,
+        span,
+    };
+    ok(exp, warnings, errors)
+}
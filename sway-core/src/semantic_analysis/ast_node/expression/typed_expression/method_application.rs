@@ -284,6 +284,15 @@ pub(crate) fn type_check_method_application(
         }
     }
 
+    // arguments to `ref mut` parameters are passed by reference
+    let args_buf = check!(
+        check_reference_arguments(ctx.namespace, &method.parameters, args_buf.into()),
+        return err(warnings, errors),
+        warnings,
+        errors
+    )
+    .into();
+
     // build the function application
     let exp = check!(
         instantiate_function_application_simple(
@@ -355,7 +364,8 @@ pub(crate) fn resolve_method_name(
                     &type_info_prefix,
                     method_name,
                     ctx.self_type(),
-                    &arguments
+                    &arguments,
+                    ctx.type_annotation()
                 ),
                 return err(warnings, errors),
                 warnings,
@@ -379,7 +389,8 @@ pub(crate) fn resolve_method_name(
                     &module_path,
                     &call_path.suffix,
                     ctx.self_type(),
-                    &arguments
+                    &arguments,
+                    ctx.type_annotation()
                 ),
                 return err(warnings, errors),
                 warnings,
@@ -403,7 +414,8 @@ pub(crate) fn resolve_method_name(
                     &module_path,
                     method_name,
                     ctx.self_type(),
-                    &arguments
+                    &arguments,
+                    ctx.type_annotation()
                 ),
                 return err(warnings, errors),
                 warnings,
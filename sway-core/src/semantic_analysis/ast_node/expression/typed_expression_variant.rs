@@ -111,6 +111,22 @@ pub enum TypedExpressionVariant {
         exp: Box<TypedExpression>,
         variant: TypedEnumVariant,
     },
+    /// a function known at compile time that is used as a value, either a named function or a
+    /// closure
+    FunctionValue {
+        function: Box<TypedFunctionDeclaration>,
+    },
+    /// a call through a variable or parameter of function type, e.g. `f(x)` where
+    /// `f: fn(u64) -> u64`
+    IndirectFunctionApplication {
+        callee: Ident,
+        arguments: Vec<TypedExpression>,
+    },
+    /// a mutable variable passed to a `ref mut` parameter, which refers to the variable itself
+    /// rather than to a copy of its value
+    VariableReference {
+        name: Ident,
+    },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -307,6 +323,28 @@ impl PartialEq for TypedExpressionVariant {
                 },
             ) => *l_exp == *r_exp && l_variant == r_variant,
             (Self::EnumTag { exp: l_exp }, Self::EnumTag { exp: r_exp }) => *l_exp == *r_exp,
+            (
+                Self::FunctionValue {
+                    function: l_function,
+                },
+                Self::FunctionValue {
+                    function: r_function,
+                },
+            ) => l_function == r_function,
+            (
+                Self::IndirectFunctionApplication {
+                    callee: l_callee,
+                    arguments: l_arguments,
+                },
+                Self::IndirectFunctionApplication {
+                    callee: r_callee,
+                    arguments: r_arguments,
+                },
+            ) => l_callee == r_callee && l_arguments == r_arguments,
+            (
+                Self::VariableReference { name: l_name },
+                Self::VariableReference { name: r_name },
+            ) => l_name == r_name,
             _ => false,
         }
     }
@@ -408,6 +446,11 @@ impl CopyTypes for TypedExpressionVariant {
                 variant.copy_types(type_mapping);
             }
             AbiName(_) => (),
+            FunctionValue { function } => function.copy_types(type_mapping),
+            IndirectFunctionApplication { arguments, .. } => arguments
+                .iter_mut()
+                .for_each(|x| x.copy_types(type_mapping)),
+            VariableReference { .. } => (),
         }
     }
 }
@@ -494,6 +537,15 @@ impl fmt::Display for TypedExpressionVariant {
             TypedExpressionVariant::UnsafeDowncast { exp, variant } => {
                 format!("({} as {})", look_up_type_id(exp.return_type), variant.name)
             }
+            TypedExpressionVariant::FunctionValue { function } => {
+                format!("\"{}\" fn value", function.name.as_str())
+            }
+            TypedExpressionVariant::IndirectFunctionApplication { callee, .. } => {
+                format!("\"{}\" indirect fn entry", callee.as_str())
+            }
+            TypedExpressionVariant::VariableReference { name } => {
+                format!("\"{}\" variable ref", name.as_str())
+            }
         };
         write!(f, "{}", s)
     }
@@ -319,6 +319,7 @@ impl TypedAstNode {
                             typed_const_decl
                         }
                         Declaration::EnumDeclaration(decl) => {
+                            let derives = decl.derives.clone();
                             let enum_decl = check!(
                                 TypedEnumDeclaration::type_check(ctx.by_ref(), decl),
                                 return err(warnings, errors),
@@ -333,6 +334,12 @@ impl TypedAstNode {
                                 warnings,
                                 errors
                             );
+                            check!(
+                                type_check_derives(ctx.by_ref(), &decl, derives),
+                                (),
+                                warnings,
+                                errors
+                            );
                             decl
                         }
                         Declaration::FunctionDeclaration(fn_decl) => {
@@ -380,6 +387,7 @@ impl TypedAstNode {
                             );
                             ctx.namespace.insert_trait_implementation(
                                 impl_trait.trait_name.clone(),
+                                impl_trait.trait_type_arguments.clone(),
                                 implementing_for_type_id,
                                 impl_trait.methods.clone(),
                             );
@@ -394,12 +402,14 @@ impl TypedAstNode {
                             );
                             ctx.namespace.insert_trait_implementation(
                                 impl_trait.trait_name.clone(),
+                                vec![],
                                 impl_trait.implementing_for_type_id,
                                 impl_trait.methods.clone(),
                             );
                             TypedDeclaration::ImplTrait(impl_trait)
                         }
                         Declaration::StructDeclaration(decl) => {
+                            let derives = decl.derives.clone();
                             let decl = check!(
                                 TypedStructDeclaration::type_check(ctx.by_ref(), decl),
                                 return err(warnings, errors),
@@ -415,6 +425,12 @@ impl TypedAstNode {
                                 warnings,
                                 errors
                             );
+                            check!(
+                                type_check_derives(ctx.by_ref(), &decl, derives),
+                                (),
+                                warnings,
+                                errors
+                            );
                             decl
                         }
                         Declaration::AbiDeclaration(abi_decl) => {
@@ -691,7 +707,7 @@ fn reassignment(
 
 fn type_check_interface_surface(
     interface_surface: Vec<TraitFn>,
-    namespace: &mut Namespace,
+    mut ctx: TypeCheckContext,
 ) -> CompileResult<Vec<TypedTraitFn>> {
     let mut warnings = vec![];
     let mut errors = vec![];
@@ -713,20 +729,23 @@ fn type_check_interface_surface(
                     .map(
                         |FunctionParameter {
                              name,
+                             is_reference,
                              is_mutable,
                              type_id,
                              type_span,
                          }| TypedFunctionParameter {
                             name,
+                            is_reference,
                             is_mutable,
                             type_id: check!(
-                                namespace.resolve_type_with_self(
-                                    type_id,
-                                    insert_type(TypeInfo::SelfType),
-                                    &type_span,
-                                    EnforceTypeArguments::Yes,
-                                    None
-                                ),
+                                ctx.by_ref()
+                                    .with_self_type(insert_type(TypeInfo::SelfType))
+                                    .resolve_type_with_self(
+                                        type_id,
+                                        &type_span,
+                                        EnforceTypeArguments::Yes,
+                                        None
+                                    ),
                                 insert_type(TypeInfo::ErrorRecovery),
                                 warnings,
                                 errors,
@@ -736,13 +755,14 @@ fn type_check_interface_surface(
                     )
                     .collect(),
                 return_type: check!(
-                    namespace.resolve_type_with_self(
-                        insert_type(return_type),
-                        insert_type(TypeInfo::SelfType),
-                        &return_type_span,
-                        EnforceTypeArguments::Yes,
-                        None
-                    ),
+                    ctx.by_ref()
+                        .with_self_type(insert_type(TypeInfo::SelfType))
+                        .resolve_type_with_self(
+                            insert_type(return_type),
+                            &return_type_span,
+                            EnforceTypeArguments::Yes,
+                            None
+                        ),
                     insert_type(TypeInfo::ErrorRecovery),
                     warnings,
                     errors,
@@ -776,7 +796,10 @@ fn type_check_trait_methods(
         let mut sig_ctx = ctx.by_ref().with_self_type(insert_type(TypeInfo::SelfType));
         parameters.clone().into_iter().for_each(
             |FunctionParameter {
-                 name, ref type_id, ..
+                 name,
+                 ref type_id,
+                 is_mutable,
+                 ..
              }| {
                 let r#type = check!(
                     sig_ctx.resolve_type_with_self(
@@ -799,8 +822,7 @@ fn type_check_trait_methods(
                             is_constant: IsConstant::No,
                             span: name.span(),
                         },
-                        // TODO allow mutable function params?
-                        is_mutable: VariableMutability::Immutable,
+                        is_mutable: is_mutable.into(),
                         type_ascription: r#type,
                     }),
                 );
@@ -851,11 +873,13 @@ fn type_check_trait_methods(
                 |FunctionParameter {
                      name,
                      type_id,
+                     is_reference,
                      is_mutable,
                      type_span,
                  }| {
                     TypedFunctionParameter {
                         name,
+                        is_reference,
                         is_mutable,
                         type_id: check!(
                             sig_ctx.resolve_type_with_self(
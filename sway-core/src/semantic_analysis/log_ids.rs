@@ -0,0 +1,218 @@
+//! Assignment of log IDs to the `__log` calls of a type checked program.
+//!
+//! Calls are inlined into their call sites, so each function application carries its own copy of
+//! the callee's body. Walking those copies gives every call site of a logging function its own log
+//! ID, which is what an indexer needs to tell the receipts of different call sites apart.
+use super::{
+    TypedAstNode, TypedAstNodeContent, TypedCodeBlock, TypedDeclaration, TypedExpression,
+    TypedExpressionVariant, TypedFunctionDeclaration, TypedProgramKind, TypedWhileLoop,
+};
+use crate::type_engine::*;
+use sway_parse::intrinsics::Intrinsic;
+use sway_types::Span;
+
+/// Assign a log ID to every `__log` call reachable from the entry points of `kind`, returning
+/// the type logged under each ID in the order the IDs were assigned.
+pub(crate) fn assign_log_ids(kind: &mut TypedProgramKind) -> Vec<(u64, TypeId)> {
+    let mut logged_types = vec![];
+    match kind {
+        TypedProgramKind::Contract { abi_entries, .. } => {
+            for function in abi_entries {
+                assign_in_function(function, &mut logged_types);
+            }
+        }
+        TypedProgramKind::Script { main_function, .. }
+        | TypedProgramKind::Predicate { main_function, .. } => {
+            assign_in_function(main_function, &mut logged_types);
+        }
+        TypedProgramKind::Library { .. } => (),
+    }
+    logged_types
+}
+
+fn assign_in_function(
+    function: &mut TypedFunctionDeclaration,
+    logged_types: &mut Vec<(u64, TypeId)>,
+) {
+    assign_in_code_block(&mut function.body, logged_types);
+}
+
+fn assign_in_code_block(block: &mut TypedCodeBlock, logged_types: &mut Vec<(u64, TypeId)>) {
+    for node in block.contents.iter_mut() {
+        assign_in_node(node, logged_types);
+    }
+}
+
+fn assign_in_node(node: &mut TypedAstNode, logged_types: &mut Vec<(u64, TypeId)>) {
+    match &mut node.content {
+        TypedAstNodeContent::ReturnStatement(stmt) => {
+            assign_in_expression(&mut stmt.expr, logged_types)
+        }
+        TypedAstNodeContent::Declaration(decl) => assign_in_declaration(decl, logged_types),
+        TypedAstNodeContent::Expression(exp)
+        | TypedAstNodeContent::ImplicitReturnExpression(exp) => {
+            assign_in_expression(exp, logged_types)
+        }
+        TypedAstNodeContent::WhileLoop(TypedWhileLoop { condition, body }) => {
+            assign_in_expression(condition, logged_types);
+            assign_in_code_block(body, logged_types);
+        }
+        TypedAstNodeContent::SideEffect => (),
+    }
+}
+
+fn assign_in_declaration(decl: &mut TypedDeclaration, logged_types: &mut Vec<(u64, TypeId)>) {
+    match decl {
+        TypedDeclaration::VariableDeclaration(decl) => {
+            assign_in_expression(&mut decl.body, logged_types)
+        }
+        TypedDeclaration::ConstantDeclaration(decl) => {
+            assign_in_expression(&mut decl.value, logged_types)
+        }
+        TypedDeclaration::Reassignment(reassignment) => {
+            assign_in_expression(&mut reassignment.rhs, logged_types)
+        }
+        TypedDeclaration::StorageReassignment(reassignment) => {
+            assign_in_expression(&mut reassignment.rhs, logged_types)
+        }
+        // Functions declared here are compiled from the copies at their call sites, and the
+        // remaining declarations don't contain any code.
+        _ => (),
+    }
+}
+
+fn assign_in_expression(exp: &mut TypedExpression, logged_types: &mut Vec<(u64, TypeId)>) {
+    use TypedExpressionVariant::*;
+    match &mut exp.expression {
+        FunctionApplication {
+            contract_call_params,
+            arguments,
+            function_body,
+            selector,
+            ..
+        } => {
+            // Visit the contract call parameters in a fixed order to keep the IDs deterministic.
+            let mut params = contract_call_params.iter_mut().collect::<Vec<_>>();
+            params.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+            for (_, param) in params {
+                assign_in_expression(param, logged_types);
+            }
+            for (_, arg) in arguments.iter_mut() {
+                assign_in_expression(arg, logged_types);
+            }
+            if let Some(metadata) = selector {
+                assign_in_expression(&mut metadata.contract_address, logged_types);
+            }
+            assign_in_code_block(function_body, logged_types);
+        }
+        LazyOperator { lhs, rhs, .. } => {
+            assign_in_expression(lhs, logged_types);
+            assign_in_expression(rhs, logged_types);
+        }
+        Tuple { fields } => {
+            for field in fields.iter_mut() {
+                assign_in_expression(field, logged_types);
+            }
+        }
+        Array { contents } => {
+            for elem in contents.iter_mut() {
+                assign_in_expression(elem, logged_types);
+            }
+        }
+        ArrayIndex { prefix, index } => {
+            assign_in_expression(prefix, logged_types);
+            assign_in_expression(index, logged_types);
+        }
+        StructExpression { fields, .. } => {
+            for field in fields.iter_mut() {
+                assign_in_expression(&mut field.value, logged_types);
+            }
+        }
+        CodeBlock(block) => assign_in_code_block(block, logged_types),
+        IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            assign_in_expression(condition, logged_types);
+            assign_in_expression(then, logged_types);
+            if let Some(r#else) = r#else {
+                assign_in_expression(r#else, logged_types);
+            }
+        }
+        AsmExpression { registers, .. } => {
+            for initializer in registers
+                .iter_mut()
+                .filter_map(|register| register.initializer.as_mut())
+            {
+                assign_in_expression(initializer, logged_types);
+            }
+        }
+        StructFieldAccess { prefix, .. } | TupleElemAccess { prefix, .. } => {
+            assign_in_expression(prefix, logged_types)
+        }
+        EnumInstantiation { contents, .. } => {
+            if let Some(contents) = contents {
+                assign_in_expression(contents, logged_types);
+            }
+        }
+        AbiCast { address, .. } => assign_in_expression(address, logged_types),
+        IntrinsicFunction(intrinsic) => {
+            for arg in intrinsic.arguments.iter_mut() {
+                assign_in_expression(arg, logged_types);
+            }
+            if intrinsic.kind == Intrinsic::Log {
+                let log_id = logged_types.len() as u64;
+                intrinsic.log_id = Some(log_id);
+                logged_types.push((log_id, logged_type(&intrinsic.arguments[0])));
+            }
+        }
+        EnumTag { exp } | UnsafeDowncast { exp, .. } => assign_in_expression(exp, logged_types),
+        FunctionValue { function } => assign_in_function(function, logged_types),
+        IndirectFunctionApplication { arguments, .. } => {
+            for arg in arguments.iter_mut() {
+                assign_in_expression(arg, logged_types);
+            }
+        }
+        Literal(_)
+        | VariableExpression { .. }
+        | FunctionParameter
+        | StorageAccess(_)
+        | AbiName(_)
+        | VariableReference { .. } => (),
+    }
+}
+
+/// The type of a logged value. Numeric literals whose width was never pinned down are logged as
+/// `u64`s, just as they are compiled.
+fn logged_type(value: &TypedExpression) -> TypeId {
+    default_numeric_to_u64(value.return_type, &value.span);
+    value.return_type
+}
+
+fn default_numeric_to_u64(type_id: TypeId, span: &Span) {
+    match look_up_type_id(type_id) {
+        TypeInfo::Numeric => {
+            let u64_type = insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
+            // Unifying a numeric type with an integer type cannot fail.
+            let _ = unify(type_id, u64_type, span, "");
+        }
+        TypeInfo::Tuple(fields) => {
+            for field in fields {
+                default_numeric_to_u64(field.type_id, span);
+            }
+        }
+        TypeInfo::Struct { fields, .. } => {
+            for field in fields {
+                default_numeric_to_u64(field.type_id, span);
+            }
+        }
+        TypeInfo::Enum { variant_types, .. } => {
+            for variant in variant_types {
+                default_numeric_to_u64(variant.type_id, span);
+            }
+        }
+        TypeInfo::Array(elem_ty, _) => default_numeric_to_u64(elem_ty, span),
+        _ => (),
+    }
+}
@@ -0,0 +1,256 @@
+//! A summary of the effects of each ABI method of a contract: the storage fields it reads and
+//! writes, the other contracts' methods it calls and whether it can be re-entered.
+//!
+//! Calls are inlined into their call sites, so each function application carries its own copy of
+//! the callee's body, and walking the body of a method walks everything it may execute.
+use super::{
+    TypeCheckedStorageReassignment, TypedAstNode, TypedAstNodeContent, TypedCodeBlock,
+    TypedDeclaration, TypedExpression, TypedExpressionVariant, TypedFunctionDeclaration,
+    TypedWhileLoop,
+};
+use crate::parse_tree::Purity;
+use std::collections::BTreeSet;
+use sway_types::{Ident, MethodEffects, Span};
+
+/// The name recorded for storage accessed through a computed key, e.g. by `std::storage::get`,
+/// rather than through a storage field.
+const COMPUTED_KEY: &str = "*";
+
+/// Summarize the effects of the ABI method `function`. `storage_fields` are the names of the
+/// contract's storage fields, indexed by their state index, and `reentrancy_guard` is the name
+/// span of the declaration of `std::reentrancy::reentrancy_guard`, if `std` is available.
+pub(crate) fn method_effects(
+    function: &TypedFunctionDeclaration,
+    storage_fields: &[Ident],
+    reentrancy_guard: Option<&Span>,
+) -> MethodEffects {
+    let mut collector = EffectsCollector {
+        storage_fields,
+        reentrancy_guard,
+        reads: BTreeSet::new(),
+        writes: BTreeSet::new(),
+        contract_calls: BTreeSet::new(),
+        is_guarded: false,
+        conditional_depth: 0,
+    };
+    collector.visit_code_block(&function.body);
+    MethodEffects {
+        name: function.name.to_string(),
+        can_reenter: !collector.contract_calls.is_empty() && !collector.is_guarded,
+        reads: collector.reads.into_iter().collect(),
+        writes: collector.writes.into_iter().collect(),
+        contract_calls: collector.contract_calls.into_iter().collect(),
+    }
+}
+
+struct EffectsCollector<'a> {
+    storage_fields: &'a [Ident],
+    reentrancy_guard: Option<&'a Span>,
+    reads: BTreeSet<String>,
+    writes: BTreeSet<String>,
+    contract_calls: BTreeSet<String>,
+    /// Whether `std::reentrancy::reentrancy_guard` is called on every path to the first contract
+    /// call, i.e. unconditionally and before any contract call is made.
+    is_guarded: bool,
+    /// The number of enclosing branches, loop bodies and closures, which code visited may not be
+    /// executed on every path through the method.
+    conditional_depth: usize,
+}
+
+impl<'a> EffectsCollector<'a> {
+    fn visit_code_block(&mut self, block: &TypedCodeBlock) {
+        for node in &block.contents {
+            self.visit_node(node);
+        }
+    }
+
+    fn visit_node(&mut self, node: &TypedAstNode) {
+        match &node.content {
+            TypedAstNodeContent::ReturnStatement(stmt) => self.visit_expression(&stmt.expr),
+            TypedAstNodeContent::Declaration(decl) => self.visit_declaration(decl),
+            TypedAstNodeContent::Expression(exp)
+            | TypedAstNodeContent::ImplicitReturnExpression(exp) => self.visit_expression(exp),
+            TypedAstNodeContent::WhileLoop(TypedWhileLoop { condition, body }) => {
+                self.visit_expression(condition);
+                self.visit_conditionally(|collector| collector.visit_code_block(body));
+            }
+            TypedAstNodeContent::SideEffect => (),
+        }
+    }
+
+    fn visit_declaration(&mut self, decl: &TypedDeclaration) {
+        match decl {
+            TypedDeclaration::VariableDeclaration(decl) => self.visit_expression(&decl.body),
+            TypedDeclaration::ConstantDeclaration(decl) => self.visit_expression(&decl.value),
+            TypedDeclaration::Reassignment(reassignment) => {
+                self.visit_expression(&reassignment.rhs)
+            }
+            TypedDeclaration::StorageReassignment(TypeCheckedStorageReassignment {
+                fields,
+                rhs,
+                ..
+            }) => {
+                self.writes
+                    .insert(field_path(fields.iter().map(|field| &field.name)));
+                self.visit_expression(rhs);
+            }
+            // Functions declared here are compiled from the copies at their call sites, and the
+            // remaining declarations don't contain any code.
+            _ => (),
+        }
+    }
+
+    fn visit_expression(&mut self, exp: &TypedExpression) {
+        use TypedExpressionVariant::*;
+        match &exp.expression {
+            FunctionApplication {
+                call_path,
+                contract_call_params,
+                arguments,
+                function_body,
+                function_body_name_span,
+                function_body_purity,
+                self_state_idx,
+                selector,
+                ..
+            } => {
+                for param in contract_call_params.values() {
+                    self.visit_expression(param);
+                }
+                // The `self` of a method of a storage field is the field itself, which isn't
+                // read by passing it.
+                let skipped_args = if self_state_idx.is_some() { 1 } else { 0 };
+                for (_, arg) in arguments.iter().skip(skipped_args) {
+                    self.visit_expression(arg);
+                }
+                if let Some(metadata) = selector {
+                    // The body of a contract call runs in the other contract.
+                    self.visit_expression(&metadata.contract_address);
+                    self.contract_calls.insert(call_path.suffix.to_string());
+                } else if let Some(ix) = self_state_idx {
+                    // A method of a storage field, e.g. `StorageMap::insert`, accesses storage
+                    // through a key derived from the field's own.
+                    let field = self.storage_fields[ix.to_usize()].to_string();
+                    if matches!(function_body_purity, Purity::Reads | Purity::ReadsWrites) {
+                        self.reads.insert(field.clone());
+                    }
+                    if matches!(function_body_purity, Purity::Writes | Purity::ReadsWrites) {
+                        self.writes.insert(field);
+                    }
+                } else {
+                    if self.reentrancy_guard == Some(function_body_name_span)
+                        && self.conditional_depth == 0
+                        && self.contract_calls.is_empty()
+                    {
+                        self.is_guarded = true;
+                    }
+                    self.visit_code_block(function_body);
+                }
+            }
+            StorageAccess(access) => {
+                self.reads
+                    .insert(field_path(access.fields.iter().map(|field| &field.name)));
+            }
+            AsmExpression {
+                registers, body, ..
+            } => {
+                for initializer in registers
+                    .iter()
+                    .filter_map(|register| register.initializer.as_ref())
+                {
+                    self.visit_expression(initializer);
+                }
+                for op in body {
+                    let accesses = match op.op_name.as_str() {
+                        "srw" | "srwq" => &mut self.reads,
+                        "sww" | "swwq" => &mut self.writes,
+                        _ => continue,
+                    };
+                    accesses.insert(COMPUTED_KEY.to_string());
+                }
+            }
+            LazyOperator { lhs, rhs, .. } => {
+                self.visit_expression(lhs);
+                self.visit_conditionally(|collector| collector.visit_expression(rhs));
+            }
+            Tuple { fields } => {
+                for field in fields {
+                    self.visit_expression(field);
+                }
+            }
+            Array { contents } => {
+                for elem in contents {
+                    self.visit_expression(elem);
+                }
+            }
+            ArrayIndex { prefix, index } => {
+                self.visit_expression(prefix);
+                self.visit_expression(index);
+            }
+            StructExpression { fields, .. } => {
+                for field in fields {
+                    self.visit_expression(&field.value);
+                }
+            }
+            CodeBlock(block) => self.visit_code_block(block),
+            IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                self.visit_expression(condition);
+                self.visit_conditionally(|collector| {
+                    collector.visit_expression(then);
+                    if let Some(r#else) = r#else {
+                        collector.visit_expression(r#else);
+                    }
+                });
+            }
+            StructFieldAccess { prefix, .. } | TupleElemAccess { prefix, .. } => {
+                self.visit_expression(prefix)
+            }
+            EnumInstantiation { contents, .. } => {
+                if let Some(contents) = contents {
+                    self.visit_expression(contents);
+                }
+            }
+            AbiCast { address, .. } => self.visit_expression(address),
+            IntrinsicFunction(intrinsic) => {
+                for arg in &intrinsic.arguments {
+                    self.visit_expression(arg);
+                }
+            }
+            EnumTag { exp } | UnsafeDowncast { exp, .. } => self.visit_expression(exp),
+            // A closure's body runs wherever it is called, which we can't track, so its effects
+            // are attributed to the method creating it.
+            FunctionValue { function } => {
+                self.visit_conditionally(|collector| collector.visit_code_block(&function.body))
+            }
+            IndirectFunctionApplication { arguments, .. } => {
+                for arg in arguments {
+                    self.visit_expression(arg);
+                }
+            }
+            Literal(_)
+            | VariableExpression { .. }
+            | FunctionParameter
+            | AbiName(_)
+            | VariableReference { .. } => (),
+        }
+    }
+
+    /// Visit code which may not be executed on every path through the method.
+    fn visit_conditionally(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.conditional_depth += 1;
+        visit(self);
+        self.conditional_depth -= 1;
+    }
+}
+
+/// The path of a storage field, e.g. `config.owner`.
+fn field_path<'a>(names: impl Iterator<Item = &'a Ident>) -> String {
+    names
+        .map(|name| name.as_str())
+        .collect::<Vec<_>>()
+        .join(".")
+}
@@ -107,6 +107,7 @@ impl Items {
     pub(crate) fn insert_trait_implementation(
         &mut self,
         trait_name: CallPath,
+        trait_type_arguments: Vec<TypeId>,
         implementing_for_type_id: TypeId,
         functions_buf: Vec<TypedFunctionDeclaration>,
     ) {
@@ -123,8 +124,12 @@ impl Items {
             prefixes: new_prefixes,
             is_absolute: trait_name.is_absolute,
         };
-        self.implemented_traits
-            .insert(trait_name, implementing_for_type_id, functions_buf);
+        self.implemented_traits.insert(
+            trait_name,
+            trait_type_arguments,
+            implementing_for_type_id,
+            functions_buf,
+        );
     }
 
     pub(crate) fn get_methods_for_type(
@@ -135,6 +140,15 @@ impl Items {
             .get_methods_for_type(implementing_for_type_id)
     }
 
+    pub(crate) fn has_trait_implementation(
+        &self,
+        trait_name: &Ident,
+        implementing_for_type_id: TypeId,
+    ) -> bool {
+        self.implemented_traits
+            .has_trait_for_type(trait_name, implementing_for_type_id)
+    }
+
     pub(crate) fn get_canonical_path(&self, symbol: &Ident) -> &[Ident] {
         self.use_synonyms.get(symbol).map(|v| &v[..]).unwrap_or(&[])
     }
@@ -198,23 +212,19 @@ impl Items {
                     },
                     ProjectionKind::StructField { name: field_name },
                 ) => {
-                    let field_type_opt = {
-                        fields.iter().find_map(
-                            |TypedStructField {
-                                 type_id: r#type,
-                                 name,
-                                 ..
-                             }| {
-                                if name == field_name {
-                                    Some(r#type)
-                                } else {
-                                    None
-                                }
-                            },
-                        )
-                    };
-                    let field_type = match field_type_opt {
-                        Some(field_type) => field_type,
+                    let field_opt = fields
+                        .iter()
+                        .find(|TypedStructField { name, .. }| name == field_name);
+                    let field_type = match field_opt {
+                        Some(field) => {
+                            check!(
+                                field.expect_accessible_from(&struct_name, &field_name.span()),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
+                            field.type_id
+                        }
                         None => {
                             // gather available fields for the error message
                             let available_fields = fields
@@ -231,7 +241,7 @@ impl Items {
                         }
                     };
                     parent_rover = symbol;
-                    symbol = *field_type;
+                    symbol = field_type;
                     symbol_span = field_name.span().clone();
                     full_name_for_error.push_str(field_name.as_str());
                     full_span_for_error =
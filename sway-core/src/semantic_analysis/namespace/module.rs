@@ -213,13 +213,16 @@ impl Module {
         };
 
         let dst_ns = &mut self[dst];
-        impls_to_insert
-            .into_iter()
-            .for_each(|((call_path, type_info), methods)| {
-                dst_ns
-                    .implemented_traits
-                    .insert(call_path, type_info, methods);
-            });
+        impls_to_insert.into_iter().for_each(
+            |((call_path, trait_type_arguments, type_info), methods)| {
+                dst_ns.implemented_traits.insert(
+                    call_path,
+                    trait_type_arguments,
+                    type_info,
+                    methods,
+                );
+            },
+        );
 
         ok((), warnings, errors)
     }
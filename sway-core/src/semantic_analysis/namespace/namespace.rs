@@ -140,6 +140,7 @@ impl Namespace {
         method_name: &Ident,
         self_type: TypeId,
         args_buf: &VecDeque<TypedExpression>,
+        return_type_annotation: TypeId,
     ) -> CompileResult<TypedFunctionDeclaration> {
         self.root.find_method_for_type(
             &self.mod_path,
@@ -148,6 +149,7 @@ impl Namespace {
             method_name,
             self_type,
             args_buf,
+            return_type_annotation,
         )
     }
 
@@ -161,6 +161,13 @@ impl Root {
                 );
                 insert_type(TypeInfo::Array(new_type_id, n))
             }
+            TypeInfo::UnresolvedArray(..) => {
+                errors.push(CompileError::Internal(
+                    "Array lengths are evaluated by the type check context.",
+                    span.clone(),
+                ));
+                return err(warnings, errors);
+            }
             TypeInfo::Tuple(mut type_arguments) => {
                 for type_argument in type_arguments.iter_mut() {
                     type_argument.type_id = check!(
@@ -178,6 +185,29 @@ impl Root {
                 }
                 insert_type(TypeInfo::Tuple(type_arguments))
             }
+            TypeInfo::Function {
+                mut parameters,
+                mut return_type,
+            } => {
+                for type_argument in parameters.iter_mut().chain(Some(&mut return_type)) {
+                    type_argument.type_id = check!(
+                        self.resolve_type(
+                            type_argument.type_id,
+                            span,
+                            enforce_type_arguments,
+                            None,
+                            mod_path
+                        ),
+                        insert_type(TypeInfo::ErrorRecovery),
+                        warnings,
+                        errors
+                    );
+                }
+                insert_type(TypeInfo::Function {
+                    parameters,
+                    return_type,
+                })
+            }
             o => insert_type(o),
         };
         ok(type_id, warnings, errors)
@@ -199,6 +229,7 @@ impl Root {
         method_name: &Ident,
         self_type: TypeId,
         args_buf: &VecDeque<TypedExpression>,
+        return_type_annotation: TypeId,
     ) -> CompileResult<TypedFunctionDeclaration> {
         let mut warnings = vec![];
         let mut errors = vec![];
@@ -239,14 +270,10 @@ impl Root {
         );
 
         // grab the methods from where the type is declared
-        let mut type_methods = type_module.get_methods_for_type(type_id);
+        let type_methods = type_module.get_methods_for_type(type_id);
 
-        let mut methods = local_methods;
-        methods.append(&mut type_methods);
-
-        match methods
-            .into_iter()
-            .find(|TypedFunctionDeclaration { name, .. }| name == method_name)
+        match select_method(local_methods, method_name, args_buf, return_type_annotation)
+            .or_else(|| select_method(type_methods, method_name, args_buf, return_type_annotation))
         {
             Some(o) => ok(o, warnings, errors),
             None => {
@@ -264,6 +291,52 @@ impl Root {
     }
 }
 
+/// Picks the method named `method_name` out of `methods`.
+///
+/// Implementations of a generic trait for the same type, e.g., `From<u64>` and `From<b256>`,
+/// provide methods of the same name. The method whose parameters accept the arguments and whose
+/// return type fits the expected type is chosen among those, preferring the most recently
+/// implemented one.
+fn select_method(
+    methods: Vec<TypedFunctionDeclaration>,
+    method_name: &Ident,
+    args_buf: &VecDeque<TypedExpression>,
+    return_type_annotation: TypeId,
+) -> Option<TypedFunctionDeclaration> {
+    let mut candidates = methods
+        .into_iter()
+        .filter(|TypedFunctionDeclaration { name, .. }| name == method_name)
+        .collect::<Vec<_>>();
+    if candidates.len() <= 1 {
+        return candidates.pop();
+    }
+    let position = candidates.iter().rposition(|method| {
+        method.parameters.len() == args_buf.len()
+            && method
+                .parameters
+                .iter()
+                .zip(args_buf.iter())
+                .all(|(param, arg)| type_accepts(param.type_id, arg.return_type))
+            && type_accepts(return_type_annotation, method.return_type)
+    });
+    Some(candidates.swap_remove(position.unwrap_or(0)))
+}
+
+/// Whether a value of type `given` could be used where a value of type `expected` is required.
+/// Unlike unification, this does not affect either of the types.
+fn type_accepts(expected: TypeId, given: TypeId) -> bool {
+    let expected = look_up_type_id(expected);
+    let given = look_up_type_id(given);
+    match (&expected, &given) {
+        (TypeInfo::Unknown | TypeInfo::SelfType | TypeInfo::ErrorRecovery, _)
+        | (_, TypeInfo::Unknown | TypeInfo::UnknownGeneric { .. } | TypeInfo::ErrorRecovery) => {
+            true
+        }
+        (TypeInfo::UnsignedInteger(_), TypeInfo::Numeric) => true,
+        _ => given.is_subset_of(&expected),
+    }
+}
+
 impl std::ops::Deref for Root {
     type Target = Module;
     fn deref(&self) -> &Self::Target {
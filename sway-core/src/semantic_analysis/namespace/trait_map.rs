@@ -1,10 +1,15 @@
 use crate::{
     type_engine::{create_type_mapping, look_up_type_id, CopyTypes, TypeId},
-    CallPath, TypeInfo, TypedFunctionDeclaration,
+    CallPath, Ident, TypeInfo, TypedFunctionDeclaration,
 };
 
 type TraitName = CallPath;
 
+// The type arguments of an implementation of a generic trait, e.g., the `u64` of
+// `impl From<u64> for MyType`. Implementations of the same generic trait for the same type are
+// told apart by these.
+type TraitTypeArguments = Vec<TypeId>;
+
 // This cannot be a HashMap because of how TypeInfo's are handled.
 //
 // In Rust, in general, a custom type should uphold the invariant
@@ -27,7 +32,7 @@ type TraitName = CallPath;
 // However, we need this structure to be able to maintain the
 // difference between 3 and 4, as in practice, 1 and 2 might not yet
 // be resolved.
-type TraitMapInner = im::Vector<((TraitName, TypeId), TraitMethods)>;
+type TraitMapInner = im::Vector<((TraitName, TraitTypeArguments, TypeId), TraitMethods)>;
 type TraitMethods = im::HashMap<String, TypedFunctionDeclaration>;
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -39,6 +44,7 @@ impl TraitMap {
     pub(crate) fn insert(
         &mut self,
         trait_name: TraitName,
+        trait_type_arguments: TraitTypeArguments,
         incoming_type_id: TypeId,
         methods: Vec<TypedFunctionDeclaration>,
     ) {
@@ -46,14 +52,19 @@ impl TraitMap {
         for method in methods.into_iter() {
             methods_map.insert(method.name.as_str().to_string(), method);
         }
-        self.trait_map
-            .push_back(((trait_name, incoming_type_id), methods_map));
+        self.trait_map.push_back((
+            (trait_name, trait_type_arguments, incoming_type_id),
+            methods_map,
+        ));
     }
 
     pub(crate) fn extend(&mut self, other: TraitMap) {
-        for ((trait_name, type_implementing_for), methods) in other.trait_map.into_iter() {
+        for ((trait_name, trait_type_arguments, type_implementing_for), methods) in
+            other.trait_map.into_iter()
+        {
             self.insert(
                 trait_name,
+                trait_type_arguments,
                 type_implementing_for,
                 methods.values().cloned().collect(),
             );
@@ -63,12 +74,19 @@ impl TraitMap {
     pub(crate) fn get_call_path_and_type_info(
         &self,
         incoming_type_id: TypeId,
-    ) -> Vec<((TraitName, TypeId), Vec<TypedFunctionDeclaration>)> {
+    ) -> Vec<(
+        (TraitName, TraitTypeArguments, TypeId),
+        Vec<TypedFunctionDeclaration>,
+    )> {
         let mut ret = vec![];
-        for ((call_path, map_type_id), methods) in self.trait_map.iter() {
+        for ((call_path, trait_type_arguments, map_type_id), methods) in self.trait_map.iter() {
             if look_up_type_id(incoming_type_id).is_subset_of(&look_up_type_id(*map_type_id)) {
                 ret.push((
-                    (call_path.clone(), *map_type_id),
+                    (
+                        call_path.clone(),
+                        trait_type_arguments.clone(),
+                        *map_type_id,
+                    ),
                     methods.values().cloned().collect(),
                 ));
             }
@@ -85,7 +103,7 @@ impl TraitMap {
         if look_up_type_id(incoming_type_id) == TypeInfo::ErrorRecovery {
             return methods;
         }
-        for ((_, map_type_id), trait_methods) in self.trait_map.iter() {
+        for ((_, _, map_type_id), trait_methods) in self.trait_map.iter() {
             if look_up_type_id(incoming_type_id).is_subset_of(&look_up_type_id(*map_type_id)) {
                 let type_mapping = create_type_mapping(*map_type_id, incoming_type_id);
                 let mut trait_methods = trait_methods.values().cloned().collect::<Vec<_>>();
@@ -97,4 +115,14 @@ impl TraitMap {
         }
         methods
     }
+    /// Returns `true` if a trait named `trait_name` is implemented for `incoming_type_id`.
+    pub(crate) fn has_trait_for_type(&self, trait_name: &Ident, incoming_type_id: TypeId) -> bool {
+        self.trait_map
+            .iter()
+            .any(|((call_path, _, map_type_id), _)| {
+                call_path.suffix == *trait_name
+                    && look_up_type_id(incoming_type_id)
+                        .is_subset_of(&look_up_type_id(*map_type_id))
+            })
+    }
 }
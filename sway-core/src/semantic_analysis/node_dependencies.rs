@@ -316,11 +316,13 @@ impl Dependencies {
                 .gather_from_type_parameters(type_parameters),
             Declaration::Reassignment(decl) => self.gather_from_expr(&decl.rhs),
             Declaration::TraitDeclaration(TraitDeclaration {
+                type_parameters,
                 interface_surface,
                 methods,
                 supertraits,
                 ..
             }) => self
+                .gather_from_type_parameters(type_parameters)
                 .gather_from_iter(supertraits.iter(), |deps, sup| {
                     deps.gather_from_call_path(&sup.name, false, false)
                 })
@@ -335,12 +337,14 @@ impl Dependencies {
                 }),
             Declaration::ImplTrait(ImplTrait {
                 trait_name,
+                trait_type_arguments,
                 type_implementing_for,
                 type_parameters: type_arguments,
                 functions,
                 ..
             }) => self
                 .gather_from_call_path(trait_name, false, false)
+                .gather_from_type_arguments(trait_type_arguments)
                 .gather_from_typeinfo(type_implementing_for)
                 .gather_from_type_parameters(type_arguments)
                 .gather_from_iter(functions.iter(), |deps, fn_decl| {
@@ -399,8 +403,10 @@ impl Dependencies {
         match expr {
             Expression::VariableExpression { name, .. } => {
                 // in the case of ABI variables, we actually want to check if the ABI needs to be
-                // ordered
-                self.gather_from_call_path(&(name.clone()).into(), false, false)
+                // ordered, and the variable may also be a function used as a value
+                let mut deps = self.gather_from_call_path(&(name.clone()).into(), false, false);
+                deps.deps.insert(DependentSymbol::FnValue(name.clone()));
+                deps
             }
             Expression::FunctionApplication {
                 name, arguments, ..
@@ -429,6 +435,18 @@ impl Dependencies {
                 .gather_from_iter(branches.iter(), |deps, branch| {
                     deps.gather_from_match_branch(branch)
                 }),
+            Expression::IrrefutableMatchExp {
+                value,
+                scrutinee,
+                result,
+                ..
+            } => self
+                .gather_from_expr(value)
+                .gather_from_iter(
+                    scrutinee.gather_approximate_typeinfo_dependencies().iter(),
+                    |deps, type_info| deps.gather_from_typeinfo(type_info),
+                )
+                .gather_from_expr(result),
             Expression::CodeBlock { contents, .. } => self.gather_from_block(contents),
             Expression::Array { contents, .. } => {
                 self.gather_from_iter(contents.iter(), |deps, expr| deps.gather_from_expr(expr))
@@ -481,6 +499,7 @@ impl Dependencies {
             Expression::IntrinsicFunction { arguments, .. } => {
                 self.gather_from_iter(arguments.iter(), |deps, arg| deps.gather_from_expr(arg))
             }
+            Expression::Closure { function, .. } => self.gather_from_fn_decl(function),
         }
     }
 
@@ -533,12 +552,15 @@ impl Dependencies {
         is_fn_app: bool,
     ) -> Self {
         if call_path.prefixes.is_empty() {
-            // We can just use the suffix.
-            self.deps.insert(if is_fn_app {
-                DependentSymbol::Fn(call_path.suffix.clone(), None)
+            // We can just use the suffix.  A call replaces any use of the same function as a
+            // value so that it is still followed when looking for recursion.
+            if is_fn_app {
+                self.deps
+                    .replace(DependentSymbol::Fn(call_path.suffix.clone(), None));
             } else {
-                DependentSymbol::Symbol(call_path.suffix.clone())
-            });
+                self.deps
+                    .insert(DependentSymbol::Symbol(call_path.suffix.clone()));
+            }
         } else if use_prefix && call_path.prefixes.len() == 1 {
             // Here we can use the prefix (e.g., for 'Enum::Variant' -> 'Enum') as long is it's
             // only a single element.
@@ -583,6 +605,9 @@ impl Dependencies {
                 deps.gather_from_typeinfo(&look_up_type_id(elem.type_id))
             }),
             TypeInfo::Array(type_id, _) => self.gather_from_typeinfo(&look_up_type_id(*type_id)),
+            TypeInfo::UnresolvedArray(type_id, length) => self
+                .gather_from_expr(&length.0)
+                .gather_from_typeinfo(&look_up_type_id(*type_id)),
             TypeInfo::Struct { fields, .. } => self
                 .gather_from_iter(fields.iter(), |deps, field| {
                     deps.gather_from_typeinfo(&look_up_type_id(field.type_id))
@@ -591,6 +616,14 @@ impl Dependencies {
                 .gather_from_iter(variant_types.iter(), |deps, variant| {
                     deps.gather_from_typeinfo(&look_up_type_id(variant.type_id))
                 }),
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => self
+                .gather_from_iter(parameters.iter(), |deps, parameter| {
+                    deps.gather_from_typeinfo(&look_up_type_id(parameter.type_id))
+                })
+                .gather_from_typeinfo(&look_up_type_id(return_type.type_id)),
             _ => self,
         }
     }
@@ -613,6 +646,10 @@ impl Dependencies {
 enum DependentSymbol {
     Symbol(Ident),
     Fn(Ident, Option<Span>),
+    // A function used as a value.  It is equal to the `Fn` of the same name so that declarations
+    // are ordered by it, but it isn't followed when looking for recursive calls as it may just as
+    // well be a local variable.
+    FnValue(Ident),
     Impl(Ident, String, String), // Trait or self, type implementing for, and method names concatenated.
 }
 
@@ -622,7 +659,10 @@ impl PartialEq for DependentSymbol {
     fn eq(&self, rhs: &Self) -> bool {
         match (self, rhs) {
             (DependentSymbol::Symbol(l), DependentSymbol::Symbol(r)) => l.eq(r),
-            (DependentSymbol::Fn(l, _), DependentSymbol::Fn(r, _)) => l.eq(r),
+            (
+                DependentSymbol::Fn(l, _) | DependentSymbol::FnValue(l),
+                DependentSymbol::Fn(r, _) | DependentSymbol::FnValue(r),
+            ) => l.eq(r),
             (DependentSymbol::Impl(lt, ls, lm), DependentSymbol::Impl(rt, rs, rm)) => {
                 lt.eq(rt) && ls.eq(rs) && lm.eq(rm)
             }
@@ -637,7 +677,7 @@ impl Hash for DependentSymbol {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             DependentSymbol::Symbol(s) => s.hash(state),
-            DependentSymbol::Fn(s, _) => s.hash(state),
+            DependentSymbol::Fn(s, _) | DependentSymbol::FnValue(s) => s.hash(state),
             DependentSymbol::Impl(t, s, m) => {
                 t.hash(state);
                 s.hash(state);
@@ -723,6 +763,8 @@ fn type_info_name(type_info: &TypeInfo) -> String {
             IntegerBits::Sixteen => "uint16",
             IntegerBits::ThirtyTwo => "uint32",
             IntegerBits::SixtyFour => "uint64",
+            IntegerBits::OneTwentyEight => "uint128",
+            IntegerBits::TwoFiftySix => "uint256",
         },
         TypeInfo::Boolean => "bool",
         TypeInfo::Custom { name, .. } => name.as_str(),
@@ -742,8 +784,9 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         }
         TypeInfo::Struct { .. } => "struct",
         TypeInfo::Enum { .. } => "enum",
-        TypeInfo::Array(..) => "array",
+        TypeInfo::Array(..) | TypeInfo::UnresolvedArray(..) => "array",
         TypeInfo::Storage { .. } => "contract storage",
+        TypeInfo::Function { .. } => "function",
     }
     .to_string()
 }
@@ -754,13 +797,24 @@ fn recursively_depends_on(
     dependee: &DependentSymbol,
     decl_dependencies: &DependencyMap,
 ) -> bool {
-    set.contains(dependee)
-        || set.iter().any(|dep| {
-            decl_dependencies
-                .get(dep)
-                .map(|dep| recursively_depends_on(&dep.deps, dependee, decl_dependencies))
-                .unwrap_or(false)
-        })
+    // Uses of functions as values aren't checked for recursion, so the dependencies may contain
+    // cycles and the visited symbols must be tracked.
+    fn visit<'a>(
+        set: &'a HashSet<DependentSymbol>,
+        dependee: &DependentSymbol,
+        decl_dependencies: &'a DependencyMap,
+        visited: &mut HashSet<&'a DependentSymbol>,
+    ) -> bool {
+        set.contains(dependee)
+            || set.iter().any(|dep| {
+                visited.insert(dep)
+                    && decl_dependencies
+                        .get(dep)
+                        .map(|dep| visit(&dep.deps, dependee, decl_dependencies, visited))
+                        .unwrap_or(false)
+            })
+    }
+    visit(set, dependee, decl_dependencies, &mut HashSet::new())
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -0,0 +1,164 @@
+//! Checks for code which a predicate may not execute.  Predicates are validated before a
+//! transaction is accepted, so the VM rejects those which could make contract calls or run for an
+//! unbounded time, i.e., those which jump backwards.
+//!
+//! Calls are inlined into their call sites, so each function application carries its own copy of
+//! the callee's body, and walking the body of `main` walks everything the predicate may execute,
+//! including the std functions it calls.
+use super::{
+    TypeCheckedStorageReassignment, TypedAstNode, TypedAstNodeContent, TypedCodeBlock,
+    TypedDeclaration, TypedExpression, TypedExpressionVariant, TypedFunctionDeclaration,
+    TypedWhileLoop,
+};
+use crate::error::CompileError;
+
+/// Check the `main` function of a predicate, returning an error for each contract call or loop.
+pub(crate) fn check_predicate(main_function: &TypedFunctionDeclaration) -> Vec<CompileError> {
+    let mut checker = PredicateChecker { errors: Vec::new() };
+    checker.visit_code_block(&main_function.body);
+    checker.errors
+}
+
+struct PredicateChecker {
+    errors: Vec<CompileError>,
+}
+
+impl PredicateChecker {
+    fn visit_code_block(&mut self, block: &TypedCodeBlock) {
+        for node in &block.contents {
+            self.visit_node(node);
+        }
+    }
+
+    fn visit_node(&mut self, node: &TypedAstNode) {
+        match &node.content {
+            TypedAstNodeContent::ReturnStatement(stmt) => self.visit_expression(&stmt.expr),
+            TypedAstNodeContent::Declaration(decl) => self.visit_declaration(decl),
+            TypedAstNodeContent::Expression(exp)
+            | TypedAstNodeContent::ImplicitReturnExpression(exp) => self.visit_expression(exp),
+            TypedAstNodeContent::WhileLoop(TypedWhileLoop { condition, body }) => {
+                self.errors.push(CompileError::LoopInPredicate {
+                    span: node.span.clone(),
+                });
+                self.visit_expression(condition);
+                self.visit_code_block(body);
+            }
+            TypedAstNodeContent::SideEffect => (),
+        }
+    }
+
+    fn visit_declaration(&mut self, decl: &TypedDeclaration) {
+        match decl {
+            TypedDeclaration::VariableDeclaration(decl) => self.visit_expression(&decl.body),
+            TypedDeclaration::ConstantDeclaration(decl) => self.visit_expression(&decl.value),
+            TypedDeclaration::Reassignment(reassignment) => {
+                self.visit_expression(&reassignment.rhs)
+            }
+            TypedDeclaration::StorageReassignment(TypeCheckedStorageReassignment {
+                rhs, ..
+            }) => self.visit_expression(rhs),
+            // Functions declared here are checked via the copies at their call sites, and the
+            // remaining declarations don't contain any code.
+            _ => (),
+        }
+    }
+
+    fn visit_expression(&mut self, exp: &TypedExpression) {
+        use TypedExpressionVariant::*;
+        match &exp.expression {
+            FunctionApplication {
+                contract_call_params,
+                arguments,
+                function_body,
+                selector,
+                ..
+            } => {
+                for param in contract_call_params.values() {
+                    self.visit_expression(param);
+                }
+                for (_, arg) in arguments {
+                    self.visit_expression(arg);
+                }
+                if let Some(metadata) = selector {
+                    self.errors.push(CompileError::ContractCallInPredicate {
+                        span: exp.span.clone(),
+                    });
+                    self.visit_expression(&metadata.contract_address);
+                } else {
+                    self.visit_code_block(function_body);
+                }
+            }
+            AsmExpression { registers, .. } => {
+                // The ops themselves are checked once the predicate is compiled.
+                for initializer in registers
+                    .iter()
+                    .filter_map(|register| register.initializer.as_ref())
+                {
+                    self.visit_expression(initializer);
+                }
+            }
+            LazyOperator { lhs, rhs, .. } => {
+                self.visit_expression(lhs);
+                self.visit_expression(rhs);
+            }
+            Tuple { fields } => {
+                for field in fields {
+                    self.visit_expression(field);
+                }
+            }
+            Array { contents } => {
+                for elem in contents {
+                    self.visit_expression(elem);
+                }
+            }
+            ArrayIndex { prefix, index } => {
+                self.visit_expression(prefix);
+                self.visit_expression(index);
+            }
+            StructExpression { fields, .. } => {
+                for field in fields {
+                    self.visit_expression(&field.value);
+                }
+            }
+            CodeBlock(block) => self.visit_code_block(block),
+            IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                self.visit_expression(condition);
+                self.visit_expression(then);
+                if let Some(r#else) = r#else {
+                    self.visit_expression(r#else);
+                }
+            }
+            StructFieldAccess { prefix, .. } | TupleElemAccess { prefix, .. } => {
+                self.visit_expression(prefix)
+            }
+            EnumInstantiation { contents, .. } => {
+                if let Some(contents) = contents {
+                    self.visit_expression(contents);
+                }
+            }
+            AbiCast { address, .. } => self.visit_expression(address),
+            IntrinsicFunction(intrinsic) => {
+                for arg in &intrinsic.arguments {
+                    self.visit_expression(arg);
+                }
+            }
+            EnumTag { exp } | UnsafeDowncast { exp, .. } => self.visit_expression(exp),
+            FunctionValue { function } => self.visit_code_block(&function.body),
+            IndirectFunctionApplication { arguments, .. } => {
+                for arg in arguments {
+                    self.visit_expression(arg);
+                }
+            }
+            StorageAccess(_)
+            | Literal(_)
+            | VariableExpression { .. }
+            | FunctionParameter
+            | AbiName(_)
+            | VariableReference { .. } => (),
+        }
+    }
+}
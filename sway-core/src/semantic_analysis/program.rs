@@ -1,4 +1,5 @@
 use super::{
+    log_ids::assign_log_ids, method_effects::method_effects, predicate_checks::check_predicate,
     TypedAstNode, TypedAstNodeContent, TypedDeclaration, TypedFunctionDeclaration, TypedImplTrait,
     TypedStorageDeclaration,
 };
@@ -10,16 +11,23 @@ use crate::{
         TypeCheckContext, TypedModule,
     },
     type_engine::*,
-    types::ToJsonAbi,
+    types::{JsonAbiString, ToJsonAbi},
 };
 use fuel_tx::StorageSlot;
-use sway_types::{span::Span, Ident, JsonABI, Spanned};
+use sway_types::{
+    span::Span, Function, Ident, JsonABI, LoggedType, MethodEffects, Property, Spanned,
+    StorageLayout,
+};
 
 #[derive(Clone, Debug)]
 pub struct TypedProgram {
     pub kind: TypedProgramKind,
     pub root: TypedModule,
     pub storage_slots: Vec<StorageSlot>,
+    /// Where each storage field of a contract lives in storage.
+    pub storage_layout: StorageLayout,
+    /// The log ID of every `__log` call in the program and the type it logs.
+    pub logged_types: Vec<(u64, TypeId)>,
 }
 
 impl TypedProgram {
@@ -32,16 +40,21 @@ impl TypedProgram {
         initial_namespace: namespace::Module,
     ) -> CompileResult<Self> {
         let mut namespace = Namespace::init_root(initial_namespace);
-        let ctx = TypeCheckContext::from_root(&mut namespace);
         let ParseProgram { root, kind } = parsed;
+        let ctx = TypeCheckContext::from_root(&mut namespace, kind.clone());
         let mod_span = root.tree.span.clone();
         let mod_res = TypedModule::type_check(ctx, root);
         mod_res.flat_map(|root| {
             let kind_res = Self::validate_root(&root, kind.clone(), mod_span);
-            kind_res.map(|kind| Self {
-                kind,
-                root,
-                storage_slots: vec![],
+            kind_res.map(|mut kind| {
+                let logged_types = assign_log_ids(&mut kind);
+                Self {
+                    kind,
+                    root,
+                    storage_slots: vec![],
+                    storage_layout: StorageLayout::default(),
+                    logged_types,
+                }
             })
         })
     }
@@ -136,6 +149,24 @@ impl TypedProgram {
             }
         }
 
+        // Entry points take their arguments from outside the VM, so there is nothing for a `ref mut`
+        // parameter to refer to.
+        let entry_points: Vec<_> = match &kind {
+            TreeType::Contract => abi_entries.iter().collect(),
+            TreeType::Script | TreeType::Predicate => mains.iter().collect(),
+            TreeType::Library { .. } => Vec::new(),
+        };
+        for param in entry_points
+            .into_iter()
+            .flat_map(|func| func.parameters.iter())
+            .filter(|param| param.is_reference)
+        {
+            errors.push(CompileError::RefMutableParameterInEntryPoint {
+                param_name: param.name.clone(),
+                span: param.name.span(),
+            });
+        }
+
         // Perform other validation based on the tree type.
         let typed_program_kind = match kind {
             TreeType::Contract => TypedProgramKind::Contract {
@@ -161,6 +192,7 @@ impl TypedProgram {
                         main_func.span.clone(),
                     )),
                 }
+                errors.extend(check_predicate(&main_func));
                 TypedProgramKind::Predicate {
                     main_function: main_func,
                     declarations,
@@ -240,7 +272,7 @@ impl TypedProgram {
                 match storage_decl {
                     Some(TypedDeclaration::StorageDeclaration(decl)) => {
                         let mut storage_slots = check!(
-                            decl.get_initialized_storage_slots(),
+                            decl.get_initialized_storage_slots(&self.root.namespace),
                             return err(warnings, errors),
                             warnings,
                             errors,
@@ -248,11 +280,19 @@ impl TypedProgram {
                         // Sort the slots to standardize the output. Not strictly required by the
                         // spec.
                         storage_slots.sort();
+                        let storage_layout = check!(
+                            decl.get_storage_layout(),
+                            return err(warnings, errors),
+                            warnings,
+                            errors,
+                        );
                         ok(
                             Self {
                                 kind: self.kind.clone(),
                                 root: self.root.clone(),
                                 storage_slots,
+                                storage_layout,
+                                logged_types: self.logged_types.clone(),
                             },
                             warnings,
                             errors,
@@ -263,6 +303,8 @@ impl TypedProgram {
                             kind: self.kind.clone(),
                             root: self.root.clone(),
                             storage_slots: vec![],
+                            storage_layout: StorageLayout::default(),
+                            logged_types: self.logged_types.clone(),
                         },
                         warnings,
                         errors,
@@ -274,12 +316,57 @@ impl TypedProgram {
                     kind: self.kind.clone(),
                     root: self.root.clone(),
                     storage_slots: vec![],
+                    storage_layout: StorageLayout::default(),
+                    logged_types: self.logged_types.clone(),
                 },
                 warnings,
                 errors,
             ),
         }
     }
+
+    /// Summarize the effects of each ABI method of a contract.
+    pub fn generate_method_effects(&self) -> Vec<MethodEffects> {
+        match &self.kind {
+            TypedProgramKind::Contract {
+                abi_entries,
+                declarations,
+            } => {
+                let storage_fields = declarations
+                    .iter()
+                    .find_map(|decl| match decl {
+                        TypedDeclaration::StorageDeclaration(decl) => {
+                            Some(decl.fields.iter().map(|field| field.name.clone()).collect())
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+                let reentrancy_guard = self
+                    .root
+                    .namespace
+                    .submodules()
+                    .get("std")
+                    .and_then(|std| std.submodules().get("reentrancy"))
+                    .and_then(|reentrancy| {
+                        reentrancy.symbols().values().find_map(|decl| match decl {
+                            TypedDeclaration::FunctionDeclaration(decl)
+                                if decl.name.as_str() == "reentrancy_guard" =>
+                            {
+                                Some(decl.name.span())
+                            }
+                            _ => None,
+                        })
+                    });
+                abi_entries
+                    .iter()
+                    .map(|function| {
+                        method_effects(function, &storage_fields, reentrancy_guard.as_ref())
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -301,9 +388,34 @@ pub enum TypedProgramKind {
     },
 }
 
-impl ToJsonAbi for TypedProgramKind {
+impl ToJsonAbi for TypedProgram {
     type Output = JsonABI;
 
+    fn generate_json_abi(&self) -> Self::Output {
+        JsonABI {
+            functions: self.kind.generate_json_abi(),
+            logged_types: self
+                .logged_types
+                .iter()
+                .map(|(log_id, type_id)| LoggedType {
+                    log_id: *log_id,
+                    logged_type: Property {
+                        name: "".to_string(),
+                        type_field: type_id.json_abi_str(),
+                        components: type_id.generate_json_abi(),
+                        type_arguments: type_id
+                            .get_type_parameters()
+                            .map(|v| v.iter().map(|param| param.generate_json_abi()).collect()),
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ToJsonAbi for TypedProgramKind {
+    type Output = Vec<Function>;
+
     // TODO: Update this to match behaviour described in the `compile` doc comment above.
     fn generate_json_abi(&self) -> Self::Output {
         match self {
@@ -1,14 +1,17 @@
 use crate::{
+    error::*,
+    ir_generation::const_eval::compile_constant_expression_to_constant,
     namespace::Path,
-    parse_tree::declaration::Purity,
-    semantic_analysis::{ast_node::Mode, Namespace},
+    parse_tree::{declaration::Purity, TreeType},
+    semantic_analysis::{ast_node::Mode, Namespace, TypedExpression},
     type_engine::{
-        insert_type, monomorphize, unify_with_self, CopyTypes, EnforceTypeArguments,
-        MonomorphizeHelper, TypeArgument, TypeId, TypeInfo,
+        insert_type, look_up_type_id, monomorphize, unify_with_self, ArrayLength, CopyTypes,
+        EnforceTypeArguments, IntegerBits, MonomorphizeHelper, TypeArgument, TypeId, TypeInfo,
     },
     CompileResult, CompileWarning, TypeError,
 };
-use sway_types::{span::Span, Ident};
+use sway_ir::{Constant, ConstantValue, Context, Kind};
+use sway_types::{span::Span, Ident, Spanned};
 
 /// Contextual state tracked and accumulated throughout type-checking.
 pub struct TypeCheckContext<'ns> {
@@ -46,6 +49,8 @@ pub struct TypeCheckContext<'ns> {
     /// Tracks the purity of the context, e.g. whether or not we should be allowed to write to
     /// storage.
     purity: Purity,
+    /// The kind of program being type-checked, which submodules share.
+    kind: TreeType,
 }
 
 impl<'ns> TypeCheckContext<'ns> {
@@ -57,11 +62,11 @@ impl<'ns> TypeCheckContext<'ns> {
     /// - mode: NoneAbi
     /// - help_text: ""
     /// - purity: Pure
-    pub fn from_root(root_namespace: &'ns mut Namespace) -> Self {
-        Self::from_module_namespace(root_namespace)
+    pub fn from_root(root_namespace: &'ns mut Namespace, kind: TreeType) -> Self {
+        Self::from_module_namespace(root_namespace, kind)
     }
 
-    fn from_module_namespace(namespace: &'ns mut Namespace) -> Self {
+    fn from_module_namespace(namespace: &'ns mut Namespace, kind: TreeType) -> Self {
         Self {
             namespace,
             type_annotation: insert_type(TypeInfo::Unknown),
@@ -70,6 +75,7 @@ impl<'ns> TypeCheckContext<'ns> {
             self_type: insert_type(TypeInfo::Contract),
             mode: Mode::NonAbi,
             purity: Purity::default(),
+            kind,
         }
     }
 
@@ -89,6 +95,7 @@ impl<'ns> TypeCheckContext<'ns> {
             mode: self.mode,
             help_text: self.help_text,
             purity: self.purity,
+            kind: self.kind.clone(),
         }
     }
 
@@ -101,6 +108,7 @@ impl<'ns> TypeCheckContext<'ns> {
             mode: self.mode,
             help_text: self.help_text,
             purity: self.purity,
+            kind: self.kind,
         }
     }
 
@@ -113,11 +121,13 @@ impl<'ns> TypeCheckContext<'ns> {
         F: FnOnce(TypeCheckContext) -> T,
     {
         // We're checking a submodule, so no need to pass through anything other than the
-        // namespace. However, we will likely want to pass through the type engine and declaration
+        // namespace and the program kind. However, we will likely want to pass through the type engine and declaration
         // engine here once they're added.
-        let Self { namespace, .. } = self;
+        let Self {
+            namespace, kind, ..
+        } = self;
         let mut submod_ns = namespace.enter_submodule(dep_name);
-        let submod_ctx = TypeCheckContext::from_module_namespace(&mut submod_ns);
+        let submod_ctx = TypeCheckContext::from_module_namespace(&mut submod_ns, kind);
         with_submod_ctx(submod_ctx)
     }
 
@@ -178,21 +188,37 @@ impl<'ns> TypeCheckContext<'ns> {
     pub(crate) fn monomorphize<T>(
         &mut self,
         value: &mut T,
-        type_arguments: Vec<TypeArgument>,
+        mut type_arguments: Vec<TypeArgument>,
         enforce_type_arguments: EnforceTypeArguments,
         call_site_span: &Span,
     ) -> CompileResult<()>
     where
         T: MonomorphizeHelper + CopyTypes,
     {
-        monomorphize(
-            value,
-            type_arguments,
-            enforce_type_arguments,
-            call_site_span,
-            &mut self.namespace.root,
-            &self.namespace.mod_path,
-        )
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        for type_argument in type_arguments.iter_mut() {
+            type_argument.type_id = check!(
+                self.resolve_array_lengths(type_argument.type_id),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+        }
+        check!(
+            monomorphize(
+                value,
+                type_arguments,
+                enforce_type_arguments,
+                call_site_span,
+                &mut self.namespace.root,
+                &self.namespace.mod_path,
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok((), warnings, errors)
     }
 
     /// Short-hand for calling [Namespace::resolve_type_with_self] with the `self_type` provided by
@@ -204,13 +230,27 @@ impl<'ns> TypeCheckContext<'ns> {
         enforce_type_args: EnforceTypeArguments,
         type_info_prefix: Option<&Path>,
     ) -> CompileResult<TypeId> {
-        self.namespace.resolve_type_with_self(
-            type_id,
-            self.self_type,
-            span,
-            enforce_type_args,
-            type_info_prefix,
-        )
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let type_id = check!(
+            self.resolve_array_lengths(type_id),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let type_id = check!(
+            self.namespace.resolve_type_with_self(
+                type_id,
+                self.self_type,
+                span,
+                enforce_type_args,
+                type_info_prefix,
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok(type_id, warnings, errors)
     }
 
     /// Short-hand for calling [Namespace::resolve_type_without_self]
@@ -220,8 +260,136 @@ impl<'ns> TypeCheckContext<'ns> {
         span: &Span,
         type_info_prefix: Option<&Path>,
     ) -> CompileResult<TypeId> {
-        self.namespace
-            .resolve_type_without_self(type_id, span, type_info_prefix)
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let type_id = check!(
+            self.resolve_array_lengths(type_id),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let type_id = check!(
+            self.namespace
+                .resolve_type_without_self(type_id, span, type_info_prefix),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok(type_id, warnings, errors)
+    }
+
+    /// Replace the arrays within `type_id` whose lengths are given by constant expressions with
+    /// arrays of the lengths they evaluate to.  Evaluating them needs the whole context, so this
+    /// is done before the rest of the type is resolved within the namespace.
+    fn resolve_array_lengths(&mut self, type_id: TypeId) -> CompileResult<TypeId> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let type_info = match look_up_type_id(type_id) {
+            TypeInfo::UnresolvedArray(elem_type_id, length) => {
+                let elem_type_id = check!(
+                    self.resolve_array_lengths(elem_type_id),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let n = check!(
+                    self.resolve_array_length(&length),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                TypeInfo::Array(elem_type_id, n)
+            }
+            TypeInfo::Array(elem_type_id, n) => TypeInfo::Array(
+                check!(
+                    self.resolve_array_lengths(elem_type_id),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ),
+                n,
+            ),
+            TypeInfo::Tuple(mut type_arguments) => {
+                for type_argument in type_arguments.iter_mut() {
+                    type_argument.type_id = check!(
+                        self.resolve_array_lengths(type_argument.type_id),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                }
+                TypeInfo::Tuple(type_arguments)
+            }
+            TypeInfo::Custom {
+                name,
+                type_arguments: Some(mut type_arguments),
+            } => {
+                for type_argument in type_arguments.iter_mut() {
+                    type_argument.type_id = check!(
+                        self.resolve_array_lengths(type_argument.type_id),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                }
+                TypeInfo::Custom {
+                    name,
+                    type_arguments: Some(type_arguments),
+                }
+            }
+            _ => return ok(type_id, warnings, errors),
+        };
+        ok(insert_type(type_info), warnings, errors)
+    }
+
+    /// Evaluate the constant expression `length`, which must be a `u64`, for use as an array
+    /// length.
+    fn resolve_array_length(&mut self, length: &ArrayLength) -> CompileResult<usize> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let ctx = self
+            .by_ref()
+            .with_type_annotation(insert_type(TypeInfo::UnsignedInteger(
+                IntegerBits::SixtyFour,
+            )))
+            .with_help_text("Array lengths must be `u64` constants.");
+        let value = check!(
+            TypedExpression::type_check(ctx, length.0.clone()),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        let mut context = Context::default();
+        let kind = match self.kind {
+            TreeType::Contract => Kind::Contract,
+            TreeType::Library { .. } => Kind::Library,
+            TreeType::Predicate => Kind::Predicate,
+            TreeType::Script => Kind::Script,
+        };
+        let module = sway_ir::Module::new(&mut context, kind);
+        match compile_constant_expression_to_constant(
+            &mut context,
+            module,
+            Some(self.namespace.module()),
+            &value,
+        ) {
+            Ok(Constant {
+                value: ConstantValue::Uint(n),
+                ..
+            }) => ok(n as usize, warnings, errors),
+            Ok(_) => {
+                errors.push(CompileError::ArrayLengthNotConstU64 {
+                    length: length.to_string(),
+                    span: length.0.span(),
+                });
+                err(warnings, errors)
+            }
+            Err(e) => {
+                errors.push(e);
+                err(warnings, errors)
+            }
+        }
     }
 
     /// Short-hand around `type_engine::unify_with_self`, where the `TypeCheckContext` provides the
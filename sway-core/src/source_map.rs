@@ -13,74 +13,110 @@ pub struct PathIndex(usize);
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SourceMap {
-    /// Paths of dependencies in the `~/.forc` directory, with the prefix stripped.
-    /// This makes inverse source mapping work on any machine with deps downloaded.
+    /// Paths of dependencies.  Those in the `~/.forc` directory have the prefix stripped, which
+    /// makes inverse source mapping work on any machine with deps downloaded.  Path dependencies
+    /// are kept as they are.
     dependency_paths: Vec<PathBuf>,
-    /// Paths to source code files, defined separately to avoid repetition.
+    /// Paths to source code files, defined separately to avoid repetition.  Those in the `~/.forc`
+    /// directory have the prefix stripped too.
     paths: Vec<PathBuf>,
     /// Mapping from opcode index to source location
     map: HashMap<usize, SourceMapSpan>,
+    /// Mapping from opcode index to the locations of the calls its source location was inlined
+    /// at, innermost first.  Opcodes which weren't inlined have no entry.
+    #[serde(default)]
+    inlined_at: HashMap<usize, Vec<SourceMapSpan>>,
 }
 impl SourceMap {
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Inserts dependency path. Unsupported locations are ignored for now.
+    /// Inserts dependency path.
     pub fn insert_dependency<P: AsRef<Path>>(&mut self, path: P) {
-        if let Some(home) = home_dir() {
-            let forc = home.join(".forc/");
-            if let Ok(unprefixed) = path.as_ref().strip_prefix(forc) {
-                self.dependency_paths.push(unprefixed.to_owned());
-            }
+        let path = strip_forc_dir(path.as_ref());
+        if !self.dependency_paths.contains(&path) {
+            self.dependency_paths.push(path);
         }
-        // TODO: Only dependencies in ~/.forc are supported for now
     }
 
     pub fn insert(&mut self, pc: usize, span: &Span) {
-        if let Some(path) = span.path() {
+        if let Some(sms) = self.intern_span(span) {
+            self.map.insert(pc, sms);
+        }
+    }
+
+    /// Inserts the locations of the calls the source location of the opcode at `pc` was inlined
+    /// at, innermost first.
+    pub fn insert_inlined_at(&mut self, pc: usize, inlined_at: &[Span]) {
+        let spans = inlined_at
+            .iter()
+            .filter_map(|span| self.intern_span(span))
+            .collect::<Vec<_>>();
+        if !spans.is_empty() {
+            self.inlined_at.insert(pc, spans);
+        }
+    }
+
+    fn intern_span(&mut self, span: &Span) -> Option<SourceMapSpan> {
+        span.path().map(|path| {
+            let path = strip_forc_dir(path);
             let path_index = self
                 .paths
                 .iter()
-                .position(|p| *p == **path)
+                .position(|p| *p == path)
                 .unwrap_or_else(|| {
-                    self.paths.push((**path).to_owned());
+                    self.paths.push(path);
                     self.paths.len() - 1
                 });
-            self.map.insert(
-                pc,
-                SourceMapSpan {
-                    path: PathIndex(path_index),
-                    range: LocationRange {
-                        start: span.start(),
-                        end: span.end(),
-                    },
+            SourceMapSpan {
+                path: PathIndex(path_index),
+                range: LocationRange {
+                    start: span.start(),
+                    end: span.end(),
                 },
-            );
-        }
+            }
+        })
     }
 
     /// Inverse source mapping
     pub fn addr_to_span(&self, pc: usize) -> Option<(PathBuf, LocationRange)> {
-        self.map.get(&pc).map(|sms| {
-            let p = &self.paths[sms.path.0];
-            for dep in &self.dependency_paths {
-                if p.starts_with(dep.file_name().unwrap()) {
-                    let mut path = home_dir().expect("Could not get homedir").join(".forc");
+        self.map.get(&pc).map(|sms| self.resolve(sms))
+    }
 
-                    if let Some(dp) = dep.parent() {
-                        path = path.join(dp);
-                    }
+    /// Inverse source mapping, including the calls the location was inlined at.  The location
+    /// itself is first, followed by the calls, innermost first.
+    pub fn addr_to_spans(&self, pc: usize) -> Vec<(PathBuf, LocationRange)> {
+        self.map
+            .get(&pc)
+            .into_iter()
+            .chain(self.inlined_at.get(&pc).into_iter().flatten())
+            .map(|sms| self.resolve(sms))
+            .collect()
+    }
 
+    fn resolve(&self, sms: &SourceMapSpan) -> (PathBuf, LocationRange) {
+        let p = &self.paths[sms.path.0];
+        if p.is_relative() {
+            for dep in &self.dependency_paths {
+                if dep.is_relative() && p.starts_with(dep) {
+                    let path = home_dir().expect("Could not get homedir").join(".forc");
                     return (path.join(p), sms.range);
                 }
             }
-
-            (p.to_owned(), sms.range)
-        })
+        }
+        (p.to_owned(), sms.range)
     }
 }
 
+/// Strip the `~/.forc` prefix from `path` if it has one.
+fn strip_forc_dir(path: &Path) -> PathBuf {
+    home_dir()
+        .and_then(|home| path.strip_prefix(home.join(".forc")).ok())
+        .unwrap_or(path)
+        .to_owned()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SourceMapSpan {
     pub path: PathIndex,
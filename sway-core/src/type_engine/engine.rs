@@ -200,7 +200,45 @@ impl Engine {
                 }
                 (warnings, errors)
             }
+            (
+                Function {
+                    parameters: parameters_a,
+                    return_type: return_type_a,
+                },
+                Function {
+                    parameters: parameters_b,
+                    return_type: return_type_b,
+                },
+            ) if parameters_a.len() == parameters_b.len() => {
+                let mut warnings = vec![];
+                let mut errors = vec![];
+                for (type_a, type_b) in parameters_a
+                    .iter()
+                    .chain(Some(&return_type_a))
+                    .zip(parameters_b.iter().chain(Some(&return_type_b)))
+                {
+                    let (new_warnings, new_errors) =
+                        self.unify(type_a.type_id, type_b.type_id, span, help_text.clone());
+                    warnings.extend(new_warnings);
+                    errors.extend(new_errors);
+                }
+                (warnings, errors)
+            }
 
+            // Wide integers have a different runtime representation to word sized integers, so
+            // they can't be implicitly cast to or from any other width.
+            (UnsignedInteger(received_width), UnsignedInteger(expected_width))
+                if received_width != expected_width
+                    && !(received_width.fits_in_word() && expected_width.fits_in_word()) =>
+            {
+                let errors = vec![TypeError::MismatchedType {
+                    expected,
+                    received,
+                    help_text,
+                    span: span.clone(),
+                }];
+                (vec![], errors)
+            }
             (UnsignedInteger(received_width), UnsignedInteger(expected_width)) => {
                 // E.g., in a variable declaration `let a: u32 = 10u64` the 'expected' type will be
                 // the annotation `u32`, and the 'received' type is 'self' of the initialiser, or
@@ -6,6 +6,28 @@ pub enum IntegerBits {
     Sixteen,
     ThirtyTwo,
     SixtyFour,
+    OneTwentyEight,
+    TwoFiftySix,
+}
+
+impl IntegerBits {
+    /// Returns the width of the integer type in bits.
+    pub fn num_bits(&self) -> u16 {
+        use IntegerBits::*;
+        match self {
+            Eight => 8,
+            Sixteen => 16,
+            ThirtyTwo => 32,
+            SixtyFour => 64,
+            OneTwentyEight => 128,
+            TwoFiftySix => 256,
+        }
+    }
+
+    /// Returns whether values of this integer type fit in a single VM word.
+    pub fn fits_in_word(&self) -> bool {
+        self.num_bits() <= 64
+    }
 }
 
 impl fmt::Display for IntegerBits {
@@ -16,6 +38,8 @@ impl fmt::Display for IntegerBits {
             Sixteen => "sixteen",
             ThirtyTwo => "thirty two",
             SixtyFour => "sixty four",
+            OneTwentyEight => "one hundred twenty eight",
+            TwoFiftySix => "two hundred fifty six",
         };
         write!(f, "{}", s)
     }
@@ -55,11 +55,8 @@ impl ResolvedType {
     pub(crate) fn is_copy_type(&self) -> bool {
         matches!(
             self,
-            ResolvedType::Boolean
-                | ResolvedType::Byte
-                | ResolvedType::Unit
-                | ResolvedType::UnsignedInteger(_)
-        )
+            ResolvedType::Boolean | ResolvedType::Byte | ResolvedType::Unit
+        ) || matches!(self, ResolvedType::UnsignedInteger(bits) if bits.fits_in_word())
     }
 
     #[allow(dead_code)]
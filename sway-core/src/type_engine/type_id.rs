@@ -129,7 +129,7 @@ impl ReplaceSelfType for TypeId {
                     }
                 }
             }
-            TypeInfo::Array(mut type_id, _) => {
+            TypeInfo::Array(mut type_id, _) | TypeInfo::UnresolvedArray(mut type_id, _) => {
                 type_id.replace_self_type(self_type);
             }
             TypeInfo::Storage { mut fields } => {
@@ -137,6 +137,15 @@ impl ReplaceSelfType for TypeId {
                     field.replace_self_type(self_type);
                 }
             }
+            TypeInfo::Function {
+                mut parameters,
+                mut return_type,
+            } => {
+                for parameter in parameters.iter_mut() {
+                    parameter.replace_self_type(self_type);
+                }
+                return_type.replace_self_type(self_type);
+            }
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::Str(_)
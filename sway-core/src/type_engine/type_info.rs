@@ -1,6 +1,6 @@
 use super::*;
 
-use crate::{semantic_analysis::*, types::*, CallPath, Ident};
+use crate::{semantic_analysis::*, types::*, CallPath, Expression, Ident};
 
 use sway_types::{span::Span, Spanned};
 
@@ -28,6 +28,32 @@ impl fmt::Display for AbiName {
     }
 }
 
+/// The length of an array type given by an expression, e.g. `N + 1`.  Lengths are compared by
+/// their value once resolved, when the array becomes a `TypeInfo::Array`.  Until then a length is
+/// only equal to itself, as the same source text may refer to different constants in different
+/// scopes, so it is compared by where it's written rather than by what's written.  The source
+/// text is only used for display.
+#[derive(Debug, Clone)]
+pub struct ArrayLength(pub Expression);
+
+impl Hash for ArrayLength {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.span().hash(state);
+    }
+}
+
+impl PartialEq for ArrayLength {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.span() == other.0.span()
+    }
+}
+
+impl fmt::Display for ArrayLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.span().as_str())
+    }
+}
+
 /// Type information without an associated value, used for type inferencing and definition.
 // TODO use idents instead of Strings when we have arena spans
 #[derive(Derivative)]
@@ -80,12 +106,21 @@ pub enum TypeInfo {
     ErrorRecovery,
     // Static, constant size arrays.
     Array(TypeId, usize),
+    /// An array whose length is given by a constant expression, which can't be evaluated until
+    /// the constants it refers to are in scope, so this is replaced by an `Array` during semantic
+    /// analysis.
+    UnresolvedArray(TypeId, ArrayLength),
     /// Represents the entire storage declaration struct
     /// Stored without initializers here, as typed struct fields,
     /// so type checking is able to treat it as a struct with fields.
     Storage {
         fields: Vec<TypedStructField>,
     },
+    /// The type of a function pointer or a non-capturing closure, e.g. `fn(u64) -> bool`.
+    Function {
+        parameters: Vec<TypeArgument>,
+        return_type: TypeArgument,
+    },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -184,6 +219,19 @@ impl Hash for TypeInfo {
                 state.write_u8(19);
                 fields.hash(state);
             }
+            TypeInfo::UnresolvedArray(elem_ty, length) => {
+                state.write_u8(20);
+                look_up_type_id(*elem_ty).hash(state);
+                length.hash(state);
+            }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => {
+                state.write_u8(21);
+                parameters.hash(state);
+                return_type.hash(state);
+            }
         }
     }
 }
@@ -262,9 +310,30 @@ impl PartialEq for TypeInfo {
             (Self::Array(l0, l1), Self::Array(r0, r1)) => {
                 look_up_type_id(*l0) == look_up_type_id(*r0) && l1 == r1
             }
+            (Self::UnresolvedArray(l0, l1), Self::UnresolvedArray(r0, r1)) => {
+                look_up_type_id(*l0) == look_up_type_id(*r0) && l1 == r1
+            }
             (TypeInfo::Storage { fields: l_fields }, TypeInfo::Storage { fields: r_fields }) => {
                 l_fields == r_fields
             }
+            (
+                Self::Function {
+                    parameters: l_parameters,
+                    return_type: l_return_type,
+                },
+                Self::Function {
+                    parameters: r_parameters,
+                    return_type: r_return_type,
+                },
+            ) => {
+                l_parameters.len() == r_parameters.len()
+                    && l_parameters
+                        .iter()
+                        .zip(r_parameters.iter())
+                        .all(|(l, r)| look_up_type_id(l.type_id) == look_up_type_id(r.type_id))
+                    && look_up_type_id(l_return_type.type_id)
+                        == look_up_type_id(r_return_type.type_id)
+            }
             _ => false,
         }
     }
@@ -290,6 +359,8 @@ impl fmt::Display for TypeInfo {
                 IntegerBits::Sixteen => "u16",
                 IntegerBits::ThirtyTwo => "u32",
                 IntegerBits::SixtyFour => "u64",
+                IntegerBits::OneTwentyEight => "u128",
+                IntegerBits::TwoFiftySix => "u256",
             }
             .into(),
             Boolean => "bool".into(),
@@ -328,7 +399,18 @@ impl fmt::Display for TypeInfo {
                 format!("contract caller {}", abi_name)
             }
             Array(elem_ty, count) => format!("[{}; {}]", elem_ty, count),
+            UnresolvedArray(elem_ty, length) => format!("[{}; {}]", elem_ty, length),
             Storage { .. } => "contract storage".into(),
+            Function {
+                parameters,
+                return_type,
+            } => {
+                let parameter_strs = parameters
+                    .iter()
+                    .map(|parameter| parameter.to_string())
+                    .collect::<Vec<String>>();
+                format!("fn({}) -> {}", parameter_strs.join(", "), return_type)
+            }
         };
         write!(f, "{}", s)
     }
@@ -346,6 +428,8 @@ impl JsonAbiString for TypeInfo {
                 IntegerBits::Sixteen => "u16",
                 IntegerBits::ThirtyTwo => "u32",
                 IntegerBits::SixtyFour => "u64",
+                IntegerBits::OneTwentyEight => "u128",
+                IntegerBits::TwoFiftySix => "u256",
             }
             .into(),
             Boolean => "bool".into(),
@@ -374,7 +458,24 @@ impl JsonAbiString for TypeInfo {
                 format!("contract caller {}", abi_name)
             }
             Array(elem_ty, count) => format!("[{}; {}]", elem_ty.json_abi_str(), count),
+            UnresolvedArray(elem_ty, length) => {
+                format!("[{}; {}]", elem_ty.json_abi_str(), length)
+            }
             Storage { .. } => "contract storage".into(),
+            Function {
+                parameters,
+                return_type,
+            } => {
+                let parameter_strs = parameters
+                    .iter()
+                    .map(|parameter| parameter.json_abi_str())
+                    .collect::<Vec<String>>();
+                format!(
+                    "fn({}) -> {}",
+                    parameter_strs.join(", "),
+                    return_type.json_abi_str()
+                )
+            }
         }
     }
 }
@@ -392,6 +493,8 @@ impl TypeInfo {
                     Sixteen => "u16",
                     ThirtyTwo => "u32",
                     SixtyFour => "u64",
+                    OneTwentyEight => "u128",
+                    TwoFiftySix => "u256",
                 }
                 .into()
             }
@@ -669,7 +772,9 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery
             | TypeInfo::Array(_, _)
-            | TypeInfo::Storage { .. } => {
+            | TypeInfo::UnresolvedArray(_, _)
+            | TypeInfo::Storage { .. }
+            | TypeInfo::Function { .. } => {
                 errors.push(CompileError::TypeArgumentsNotAllowed { span: span.clone() });
                 err(warnings, errors)
             }
@@ -756,6 +861,11 @@ impl TypeInfo {
             TypeInfo::Array(ary_ty_id, count) => look_up_type_id(*ary_ty_id)
                 .matches_type_parameter(mapping)
                 .map(|matching_id| insert_type(TypeInfo::Array(matching_id, *count))),
+            TypeInfo::UnresolvedArray(ary_ty_id, length) => look_up_type_id(*ary_ty_id)
+                .matches_type_parameter(mapping)
+                .map(|matching_id| {
+                    insert_type(TypeInfo::UnresolvedArray(matching_id, length.clone()))
+                }),
             TypeInfo::Tuple(fields) => {
                 let mut new_fields = Vec::new();
                 let mut index = 0;
@@ -798,6 +908,36 @@ impl TypeInfo {
                     Some(insert_type(TypeInfo::Tuple(new_fields)))
                 }
             }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => {
+                let mut changed = false;
+                let mut replace = |type_argument: &TypeArgument| {
+                    let type_info = look_up_type_id(type_argument.type_id);
+                    match type_info.matches_type_parameter(mapping) {
+                        Some(matching_id) => {
+                            changed = true;
+                            TypeArgument {
+                                type_id: insert_type(TypeInfo::Ref(
+                                    matching_id,
+                                    type_argument.span.clone(),
+                                )),
+                                span: type_argument.span.clone(),
+                            }
+                        }
+                        None => type_argument.clone(),
+                    }
+                };
+                let new_parameters = parameters.iter().map(&mut replace).collect::<Vec<_>>();
+                let new_return_type = replace(return_type);
+                changed.then(|| {
+                    insert_type(TypeInfo::Function {
+                        parameters: new_parameters,
+                        return_type: new_return_type,
+                    })
+                })
+            }
             Unknown
             | Str(..)
             | UnsignedInteger(..)
@@ -843,7 +983,9 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery
             | TypeInfo::Array(_, _)
-            | TypeInfo::Storage { .. } => {
+            | TypeInfo::UnresolvedArray(_, _)
+            | TypeInfo::Storage { .. }
+            | TypeInfo::Function { .. } => {
                 errors.push(CompileError::Unimplemented(
                     "matching on this type is unsupported right now",
                     span.clone(),
@@ -948,6 +1090,20 @@ impl TypeInfo {
                     all_nested_types.append(&mut nested_types);
                 }
             }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+            } => {
+                for type_argument in parameters.iter().chain(Some(&return_type)) {
+                    let mut nested_types = check!(
+                        look_up_type_id(type_argument.type_id).extract_nested_types(span),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    all_nested_types.append(&mut nested_types);
+                }
+            }
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::Str(_)
@@ -959,7 +1115,7 @@ impl TypeInfo {
             | TypeInfo::Numeric
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery => {}
-            TypeInfo::Custom { .. } | TypeInfo::SelfType => {
+            TypeInfo::Custom { .. } | TypeInfo::SelfType | TypeInfo::UnresolvedArray(_, _) => {
                 errors.push(CompileError::Internal(
                     "did not expect to find this type here",
                     span.clone(),
@@ -1150,6 +1306,28 @@ impl TypeInfo {
                     .collect::<Vec<_>>();
                 types_are_subset_of(&l_types, &r_types)
             }
+            (
+                Self::Function {
+                    parameters: l_parameters,
+                    return_type: l_return_type,
+                },
+                Self::Function {
+                    parameters: r_parameters,
+                    return_type: r_return_type,
+                },
+            ) => {
+                let l_types = l_parameters
+                    .iter()
+                    .chain(Some(l_return_type))
+                    .map(|x| look_up_type_id(x.type_id))
+                    .collect::<Vec<_>>();
+                let r_types = r_parameters
+                    .iter()
+                    .chain(Some(r_return_type))
+                    .map(|x| look_up_type_id(x.type_id))
+                    .collect::<Vec<_>>();
+                l_parameters.len() == r_parameters.len() && types_are_subset_of(&l_types, &r_types)
+            }
             (a, b) => a == b,
         }
     }
@@ -1192,6 +1370,12 @@ impl TypeInfo {
                         return err(warnings, errors);
                     }
                 };
+                check!(
+                    field.expect_accessible_from(name, &first.span()),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
                 let field = if rest.is_empty() {
                     field
                 } else {
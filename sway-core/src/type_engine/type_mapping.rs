@@ -89,6 +89,27 @@ pub(crate) fn create_type_mapping(superset_type: TypeId, subset_type: TypeId) ->
         (TypeInfo::Array(superset_type, _), TypeInfo::Array(subset_type, _)) => {
             vec![(superset_type, subset_type)]
         }
+        (
+            TypeInfo::Function {
+                parameters: type_parameters,
+                return_type: return_type_parameter,
+            },
+            TypeInfo::Function {
+                parameters: type_arguments,
+                return_type: return_type_argument,
+            },
+        ) => insert_type_parameters_with_type_arguments(
+            type_parameters
+                .iter()
+                .chain(Some(&return_type_parameter))
+                .map(|x| x.type_id)
+                .collect::<Vec<_>>(),
+            type_arguments
+                .iter()
+                .chain(Some(&return_type_argument))
+                .map(|x| x.type_id)
+                .collect::<Vec<_>>(),
+        ),
         (
             TypeInfo::Storage {
                 fields: type_parameters,
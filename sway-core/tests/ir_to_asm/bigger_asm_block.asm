@@ -12,12 +12,10 @@ lw   $r1 data_0               ; literal instantiation
 addi $r0 $r2 i0               ; get store offset
 mcpi $r0 $r1 i32              ; store value
 addi $r0 $r2 i0               ; get offset reg for get_ptr
-addi $r2 $r2 i0               ; load address
 lw   $r1 data_1               ; literal instantiation
 addi $r0 $zero i32            ; asm block
 meq  $r0 $r2 $r1 $r0          ; asm block
 ret  $r0
-noop                          ; word-alignment of data section
 .data:
 data_0 .b256 0x0202020202020202020202020202020202020202020202020202020202020202
 data_1 .b256 0x0303030303030303030303030303030303030303030303030303030303030303
@@ -6,7 +6,7 @@ DATA_SECTION_OFFSET[32..64]
 lw   $ds $is 1
 add  $$ds $$ds $is
 lw   $r1 $fp i73              ; load input function selector
-lw   $r0 data_7               ; load fn selector for comparison
+lw   $r0 data_6               ; load fn selector for comparison
 eq   $r0 $r1 $r0              ; function selector comparison
 jnzi $r0 i11                  ; jump to selected function
 rvrt $zero                    ; revert if no selectors matched
@@ -74,7 +74,7 @@ addi $r0 $r1 i0               ; get struct field(s) 0 offset
 mcpi $r0 $r2 i48              ; store struct field value
 addi $r0 $r1 i48              ; get struct field(s) 1 offset
 mcpi $r0 $r4 i48              ; store struct field value
-lw   $r0 data_6               ; loading size for RETD
+movi $r0 i96                  ; loading size for RETD
 retd  $r1 $r0
 .data:
 data_0 .b256 0xd625ff6d8e88efd7bb3476e748e5d5935618d78bfc7eedf584fe909ce0809fc3
@@ -83,5 +83,4 @@ data_2 .b256 0xc4f29cca5a7266ecbc35c82c55dd2b0059a3db4c83a3410653ec33aded8e9841
 data_3 .b256 0x2817e0819d6fcad797114fbcf350fa281aca33a39b0abf977797bddd69b8e7af
 data_4 .b256 0x12ea9b9b05214a0d64996d259c59202b80a21415bb68b83121353e2a5925ec47
 data_5 .b256 0x12ea9b9b05214a0d64996d259c59202b80a21415bb68b83121353e2a5925ec48
-data_6 .u64 0x60
-data_7 .u32 0x1665bf4
+data_6 .u32 0x1665bf4
@@ -5,14 +5,12 @@ DATA_SECTION_OFFSET[0..32]
 DATA_SECTION_OFFSET[32..64]
 lw   $ds $is 1
 add  $$ds $$ds $is
-lw   $r0 data_0               ; literal instantiation
+movi $r0 i0                   ; literal instantiation
 jnzi $r0 i9
 ji   i11
-lw   $r0 data_1               ; literal instantiation
+lw   $r0 data_0               ; literal instantiation
 ji   i12
-lw   $r0 data_2               ; literal instantiation
+movi $r0 i42                  ; literal instantiation
 ret  $r0
 .data:
-data_0 .bool 0x00
-data_1 .u64 0xf4240
-data_2 .u64 0x2a
+data_0 .u64 0xf4240
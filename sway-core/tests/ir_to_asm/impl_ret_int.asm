@@ -5,8 +5,7 @@ DATA_SECTION_OFFSET[0..32]
 DATA_SECTION_OFFSET[32..64]
 lw   $ds $is 1
 add  $$ds $$ds $is
-lw   $r0 data_0               ; literal instantiation
+movi $r0 i42                  ; literal instantiation
 ret  $r0
 noop                          ; word-alignment of data section
 .data:
-data_0 .u64 0x2a
@@ -7,25 +7,19 @@ lw   $ds $is 1
 add  $$ds $$ds $is
 move $r2 $sp                  ; save locals base register
 cfei i8                       ; allocate 8 bytes for all locals
-addi $r0 $r2 i0               ; get offset reg for get_ptr
-lw   $r0 data_0               ; literal instantiation
+movi $r0 i1                   ; literal instantiation
 sw   $r2 $r0 i0               ; store value
-addi $r0 $r2 i0               ; get offset reg for get_ptr
 lw   $r0 $r2 i0               ; load value
-jnzi $r0 i15
-ji   i23
-addi $r0 $r2 i0               ; get offset reg for get_ptr
-lw   $r0 $r2 i0               ; load value
-jnzi $r0 i19
+jnzi $r0 i13
 ji   i20
-lw   $r0 data_1               ; literal instantiation
+lw   $r0 $r2 i0               ; load value
+jnzi $r0 i16
+ji   i17
+movi $r0 i0                   ; literal instantiation
 addi $r1 $r2 i0               ; get offset reg for get_ptr
 sw   $r2 $r0 i0               ; store value
-ji   i11
-addi $r0 $r2 i0               ; get offset reg for get_ptr
+ji   i10
 lw   $r0 $r2 i0               ; load value
 ret  $r0
 noop                          ; word-alignment of data section
 .data:
-data_0 .bool 0x01
-data_1 .bool 0x00
@@ -9,20 +9,17 @@ move $r2 $sp                  ; save locals base register
 cfei i16                      ; allocate 16 bytes for all locals
 move $r1 $sp                  ; save register for temporary stack value
 cfei i16                      ; allocate 16 bytes for temporary struct
-lw   $r0 data_0               ; literal instantiation
+movi $r0 i40                  ; literal instantiation
 sw   $r1 $r0 i0               ; insert_value @ 0
-lw   $r0 data_1               ; literal instantiation
+movi $r0 i2                   ; literal instantiation
 sw   $r1 $r0 i1               ; insert_value @ 1
-addi $r0 $r2 i0               ; get offset reg for get_ptr
 addi $r0 $r2 i0               ; get store offset
 mcpi $r0 $r1 i16              ; store value
 addi $r1 $r2 i0               ; get offset reg for get_ptr
-lw   $r0 data_2               ; literal instantiation
+movi $r0 i50                  ; literal instantiation
 sw   $r1 $r0 i0               ; insert_value @ 0
 addi $r0 $r2 i0               ; get offset reg for get_ptr
 lw   $r0 $r0 i1               ; extract_value @ 1
 ret  $r0
+noop                          ; word-alignment of data section
 .data:
-data_0 .u64 0x28
-data_1 .u64 0x02
-data_2 .u64 0x32
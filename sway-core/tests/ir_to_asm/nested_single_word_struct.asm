@@ -11,8 +11,8 @@ eq   $r0 $r1 $r0              ; function selector comparison
 jnzi $r0 i11                  ; jump to selected function
 rvrt $zero                    ; revert if no selectors matched
 lw   $r0 $fp i74              ; Base register for method parameter
-addi $r0 $r0 i0               ; extract address
 lw   $r0 $r0 i0               ; extract_value @ 0
 ret  $r0
+noop                          ; word-alignment of data section
 .data:
 data_0 .u32 0x495d4a23
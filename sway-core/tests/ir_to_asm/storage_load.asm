@@ -6,10 +6,10 @@ DATA_SECTION_OFFSET[32..64]
 lw   $ds $is 1
 add  $$ds $$ds $is
 lw   $r1 $fp i73              ; load input function selector
-lw   $r0 data_3               ; load fn selector for comparison
+lw   $r0 data_2               ; load fn selector for comparison
 eq   $r0 $r1 $r0              ; function selector comparison
 jnzi $r0 i14                  ; jump to selected function
-lw   $r0 data_4               ; load fn selector for comparison
+lw   $r0 data_3               ; load fn selector for comparison
 eq   $r0 $r1 $r0              ; function selector comparison
 jnzi $r0 i28                  ; jump to selected function
 rvrt $zero                    ; revert if no selectors matched
@@ -38,11 +38,10 @@ addi $r1 $r0 i0               ; get offset
 srwq $r2 $r1                  ; quad word state access
 addi $r1 $r0 i32              ; get offset reg for get_ptr
 addi $r1 $r0 i32              ; load address
-lw   $r0 data_2               ; loading size for RETD
+movi $r0 i32                  ; loading size for RETD
 retd  $r1 $r0
 .data:
 data_0 .b256 0x7fbd1192666bfac3767b890bd4d048c940879d316071e20c7c8c81bce2ca41c5
 data_1 .b256 0xa15d6d36b54df993ed1fbe4544a45d4c4f70d81b4229861dfde0e20eb652202c
-data_2 .u64 0x20
-data_3 .u32 0x8e277065
-data_4 .u32 0x449e8e93
+data_2 .u32 0x8e277065
+data_3 .u32 0x449e8e93
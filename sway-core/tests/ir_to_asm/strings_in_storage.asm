@@ -6,10 +6,10 @@ DATA_SECTION_OFFSET[32..64]
 lw   $ds $is 1
 add  $$ds $$ds $is
 lw   $r1 $fp i73              ; load input function selector
-lw   $r0 data_3               ; load fn selector for comparison
+lw   $r0 data_2               ; load fn selector for comparison
 eq   $r0 $r1 $r0              ; function selector comparison
 jnzi $r0 i14                  ; jump to selected function
-lw   $r0 data_4               ; load fn selector for comparison
+lw   $r0 data_3               ; load fn selector for comparison
 eq   $r0 $r1 $r0              ; function selector comparison
 jnzi $r0 i39                  ; jump to selected function
 rvrt $zero                    ; revert if no selectors matched
@@ -55,12 +55,11 @@ addi $r0 $r3 i64              ; get offset reg for get_ptr
 addi $r1 $r3 i64              ; get offset
 addi $r0 $r3 i0               ; get offset
 srwq $r1 $r0                  ; quad word state access
-lw   $r0 data_2               ; loading size for RETD
+movi $r0 i40                  ; loading size for RETD
 retd  $r2 $r0
 noop                          ; word-alignment of data section
 .data:
 data_0 .b256 0xf383b0ce51358be57daa3b725fe44acdb2d880604e367199080b4379c41bb6ed
 data_1 .b256 0xf383b0ce51358be57daa3b725fe44acdb2d880604e367199080b4379c41bb6ee
-data_2 .u64 0x28
-data_3 .u32 0xe63a9733
-data_4 .u32 0xb8c27db9
+data_2 .u32 0xe63a9733
+data_3 .u32 0xb8c27db9
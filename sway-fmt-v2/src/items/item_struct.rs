@@ -91,7 +91,13 @@ fn format_struct(
                 // In first iteration we are going to be collecting the lengths of the struct fields.
                 let field_length: Vec<usize> = value_pairs
                     .iter()
-                    .map(|field| field.0.name.as_str().len())
+                    .map(|field| {
+                        let visibility_length = match &field.0.visibility {
+                            Some(visibility) => visibility.span().as_str().len() + 1,
+                            None => 0,
+                        };
+                        visibility_length + field.0.name.as_str().len()
+                    })
                     .collect();
 
                 // Find the maximum length in the `field_length` vector that is still smaller than `struct_field_align_threshold`.
@@ -112,6 +118,10 @@ fn format_struct(
                     )?;
 
                     let type_field = &field.0;
+                    // Add visibility
+                    if let Some(visibility) = &type_field.visibility {
+                        write!(formatted_code, "{} ", visibility.span().as_str())?;
+                    }
                     // Add name
                     write!(formatted_code, "{}", type_field.name.as_str())?;
 
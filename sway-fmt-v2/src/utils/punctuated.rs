@@ -52,6 +52,9 @@ impl Format for TypeField {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        if let Some(visibility) = &self.visibility {
+            write!(formatted_code, "{} ", visibility.span().as_str())?;
+        }
         write!(
             formatted_code,
             "{}{} ",
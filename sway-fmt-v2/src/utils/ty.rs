@@ -38,6 +38,28 @@ impl Format for Ty {
                 write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
                 Ok(())
             }
+            Self::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            } => {
+                write!(
+                    formatted_code,
+                    "{}{}",
+                    fn_token.span().as_str(),
+                    Delimiter::Parenthesis.as_open_char()
+                )?;
+                arguments
+                    .clone()
+                    .into_inner()
+                    .format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
+                if let Some((right_arrow_token, return_type)) = return_type_opt {
+                    write!(formatted_code, " {} ", right_arrow_token.span().as_str())?;
+                    return_type.format(formatted_code, formatter)?;
+                }
+                Ok(())
+            }
         }
     }
 }
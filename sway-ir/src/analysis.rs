@@ -0,0 +1,123 @@
+//! A collection of analyses of functions.
+//!
+//! Each analysis implements [`Analysis`] and is retrieved with [`Function::get_analysis`], which
+//! computes it on demand and caches it in the [`Context`].  A cached analysis is recomputed when
+//! the function it's for has been modified since, so passes don't need to invalidate them by hand.
+//! A function is considered modified if its blocks, their instructions, the operands of those
+//! instructions or the blocks they branch to have changed.
+//!
+//! An analysis is returned in an [`Rc`], so it may be held on to while modifying the IR, though it
+//! will then of course be out of date.
+
+pub mod def_use;
+pub use def_use::*;
+pub mod dominators;
+pub use dominators::*;
+pub mod liveness;
+pub use liveness::*;
+pub mod loops;
+pub use loops::*;
+
+use std::{
+    any::{Any, TypeId},
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::{
+    block::Block,
+    context::Context,
+    function::Function,
+    instruction::Instruction,
+    value::{Value, ValueDatum},
+};
+
+/// An analysis of a function.
+pub trait Analysis: Sized + 'static {
+    /// Analyse `function`.  Any other analyses this one depends on may be retrieved with
+    /// [`Function::get_analysis`].
+    fn compute(context: &Context, function: &Function) -> Self;
+}
+
+/// The analyses cached for each function, along with a fingerprint of the function at the time
+/// each was computed.
+#[derive(Default)]
+pub struct AnalysisCache {
+    results: HashMap<(Function, TypeId), (u64, Rc<dyn Any>)>,
+}
+
+pub(crate) fn get_analysis<A: Analysis>(context: &Context, function: &Function) -> Rc<A> {
+    let key = (*function, TypeId::of::<A>());
+    let fingerprint = function_fingerprint(context, function);
+
+    // The cache mustn't be borrowed while computing the analysis, as it may depend on others.
+    let cached = context
+        .analyses
+        .borrow()
+        .results
+        .get(&key)
+        .filter(|(cached_fingerprint, _)| *cached_fingerprint == fingerprint)
+        .map(|(_, result)| result.clone());
+    if let Some(result) = cached {
+        return result.downcast().expect("Analysis is cached by its type.");
+    }
+
+    let result = Rc::new(A::compute(context, function));
+    context
+        .analyses
+        .borrow_mut()
+        .results
+        .insert(key, (fingerprint, result.clone()));
+    result
+}
+
+fn function_fingerprint(context: &Context, function: &Function) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for block in function.block_iter(context) {
+        block.hash(&mut hasher);
+        for ins_val in &context.blocks[block.0].instructions {
+            ins_val.hash(&mut hasher);
+            if let ValueDatum::Instruction(ins) = &context.values[ins_val.0].value {
+                ins.get_operands().hash(&mut hasher);
+                if let Instruction::Phi(pairs) = ins {
+                    pairs
+                        .iter()
+                        .for_each(|(from_block, _)| from_block.hash(&mut hasher));
+                }
+            }
+        }
+        block.successors(context).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Return the blocks which branch to each block in `function`.
+pub(crate) fn predecessors(context: &Context, function: &Function) -> HashMap<Block, Vec<Block>> {
+    let mut preds: HashMap<Block, Vec<Block>> = function
+        .block_iter(context)
+        .map(|block| (block, Vec::new()))
+        .collect();
+    for block in function.block_iter(context) {
+        for succ in block.successors(context) {
+            preds.entry(succ).or_default().push(block);
+        }
+    }
+    preds
+}
+
+/// Return the position of each argument and instruction of `function`, in the order they're
+/// defined.  This is used to order sets of values consistently.
+pub(crate) fn value_positions(context: &Context, function: &Function) -> HashMap<Value, usize> {
+    function
+        .args_iter(context)
+        .map(|(_, arg_val)| *arg_val)
+        .chain(
+            function
+                .instruction_iter(context)
+                .map(|(_, ins_val)| ins_val),
+        )
+        .enumerate()
+        .map(|(idx, value)| (value, idx))
+        .collect()
+}
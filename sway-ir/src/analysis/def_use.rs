@@ -0,0 +1,54 @@
+//! Def-use chains, linking each value to the instructions which use it.
+
+use std::collections::HashMap;
+
+use crate::{
+    block::Block,
+    context::Context,
+    function::Function,
+    value::{Value, ValueDatum},
+};
+
+use super::Analysis;
+
+/// The users of each argument and instruction in a function, and the block each instruction is in.
+pub struct DefUse {
+    users: HashMap<Value, Vec<Value>>,
+    def_blocks: HashMap<Value, Block>,
+}
+
+impl Analysis for DefUse {
+    fn compute(context: &Context, function: &Function) -> Self {
+        let mut users: HashMap<Value, Vec<Value>> = HashMap::new();
+        let mut def_blocks = HashMap::new();
+        for (block, ins_val) in function.instruction_iter(context) {
+            def_blocks.insert(ins_val, block);
+            if let ValueDatum::Instruction(ins) = &context.values[ins_val.0].value {
+                for value in ins.get_operands() {
+                    let value_users = users.entry(value).or_default();
+                    if value_users.last() != Some(&ins_val) {
+                        value_users.push(ins_val);
+                    }
+                }
+            }
+        }
+        DefUse { users, def_blocks }
+    }
+}
+
+impl DefUse {
+    /// Return the instructions which use `value`, in function order.
+    pub fn users(&self, value: &Value) -> &[Value] {
+        self.users.get(value).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Return whether anything uses `value`.
+    pub fn is_used(&self, value: &Value) -> bool {
+        self.users.contains_key(value)
+    }
+
+    /// Return the block `value` is defined in, or `None` if it isn't an instruction in the function.
+    pub fn def_block(&self, value: &Value) -> Option<Block> {
+        self.def_blocks.get(value).copied()
+    }
+}
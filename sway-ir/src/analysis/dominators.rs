@@ -0,0 +1,235 @@
+//! Dominator and post-dominator trees.
+//!
+//! A block dominates another if every path from the entry block to the other goes through it,
+//! and post-dominates another if every path from the other to a return goes through it.  Both
+//! trees are computed using the algorithm from "A Simple, Fast Dominance Algorithm" by Cooper,
+//! Harvey and Kennedy.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{block::Block, context::Context, function::Function};
+
+use super::{predecessors, Analysis};
+
+/// The dominator tree of a function.  Blocks which are unreachable from the entry block aren't in
+/// the tree.
+pub struct DominatorTree(Tree);
+
+impl Analysis for DominatorTree {
+    fn compute(context: &Context, function: &Function) -> Self {
+        DominatorTree(Tree::new(&[function.get_entry_block(context)], |block| {
+            block.successors(context)
+        }))
+    }
+}
+
+impl DominatorTree {
+    /// Return the blocks in the tree in reverse post-order, which means a block comes after its
+    /// dominators.  The first is the entry block.
+    pub fn blocks(&self) -> &[Block] {
+        &self.0.order
+    }
+
+    /// Return whether `block` is reachable from the entry block.
+    pub fn is_reachable(&self, block: &Block) -> bool {
+        self.0.intervals.contains_key(block)
+    }
+
+    /// Return the immediate dominator of `block`, or `None` for the entry block or an unreachable
+    /// block.
+    pub fn immediate_dominator(&self, block: &Block) -> Option<Block> {
+        self.0.parents.get(block).copied()
+    }
+
+    /// Return the blocks which `block` immediately dominates.
+    pub fn children(&self, block: &Block) -> &[Block] {
+        self.0.children(block)
+    }
+
+    /// Return whether `dominator` dominates `block`.  Every block dominates itself.
+    pub fn dominates(&self, dominator: &Block, block: &Block) -> bool {
+        self.0.is_ancestor(dominator, block)
+    }
+}
+
+/// The post-dominator tree of a function.  It has a root for each block which returns, and blocks
+/// from which no return is reachable aren't in the tree.
+pub struct PostDominatorTree(Tree);
+
+impl Analysis for PostDominatorTree {
+    fn compute(context: &Context, function: &Function) -> Self {
+        let exits = function
+            .block_iter(context)
+            .filter(|block| block.successors(context).is_empty())
+            .collect::<Vec<_>>();
+        let preds = predecessors(context, function);
+        PostDominatorTree(Tree::new(&exits, |block| preds[block].clone()))
+    }
+}
+
+impl PostDominatorTree {
+    /// Return the blocks in the tree in reverse post-order of the reversed control flow graph,
+    /// which means a block comes after its post-dominators.
+    pub fn blocks(&self) -> &[Block] {
+        &self.0.order
+    }
+
+    /// Return the immediate post-dominator of `block`, or `None` for a block which returns or one
+    /// from which no return is reachable.
+    pub fn immediate_post_dominator(&self, block: &Block) -> Option<Block> {
+        self.0.parents.get(block).copied()
+    }
+
+    /// Return the blocks which `block` immediately post-dominates.
+    pub fn children(&self, block: &Block) -> &[Block] {
+        self.0.children(block)
+    }
+
+    /// Return whether `post_dominator` post-dominates `block`.  Every block post-dominates itself.
+    pub fn post_dominates(&self, post_dominator: &Block, block: &Block) -> bool {
+        self.0.is_ancestor(post_dominator, block)
+    }
+}
+
+struct Tree {
+    /// The blocks reachable from the roots, in reverse post-order.
+    order: Vec<Block>,
+    parents: HashMap<Block, Block>,
+    children: HashMap<Block, Vec<Block>>,
+    /// The times each block is entered and left in a depth first walk of the tree, so that a
+    /// block's descendants are those whose interval is within its own.
+    intervals: HashMap<Block, (usize, usize)>,
+}
+
+impl Tree {
+    /// Build the tree for the graph with the given `roots` and `edges`.  With more than one root
+    /// the tree is really a forest, which is built as a tree with an implicit extra root.
+    fn new<F: Fn(&Block) -> Vec<Block>>(roots: &[Block], edges: F) -> Tree {
+        let mut post_order = Vec::new();
+        let mut visited = HashSet::new();
+        for root in roots.iter().rev() {
+            if !visited.insert(*root) {
+                continue;
+            }
+            let mut stack = vec![(*root, edges(root))];
+            while let Some((block, succs)) = stack.last_mut() {
+                match succs.pop() {
+                    Some(succ) => {
+                        if visited.insert(succ) {
+                            let succ_succs = edges(&succ);
+                            stack.push((succ, succ_succs));
+                        }
+                    }
+                    None => {
+                        post_order.push(*block);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        let order = post_order.into_iter().rev().collect::<Vec<_>>();
+
+        // Index 0 is the implicit root, and the blocks follow in reverse post-order.
+        let indices = order
+            .iter()
+            .enumerate()
+            .map(|(idx, block)| (*block, idx + 1))
+            .collect::<HashMap<_, _>>();
+        let mut preds = vec![Vec::new(); order.len() + 1];
+        for root in roots {
+            preds[indices[root]].push(0);
+        }
+        for block in &order {
+            for succ in edges(block) {
+                if let Some(succ_idx) = indices.get(&succ) {
+                    preds[*succ_idx].push(indices[block]);
+                }
+            }
+        }
+
+        let mut idoms: Vec<Option<usize>> = vec![None; order.len() + 1];
+        idoms[0] = Some(0);
+        let intersect = |idoms: &[Option<usize>], mut a: usize, mut b: usize| {
+            while a != b {
+                while a > b {
+                    a = idoms[a].unwrap();
+                }
+                while b > a {
+                    b = idoms[b].unwrap();
+                }
+            }
+            a
+        };
+        let mut modified = true;
+        while modified {
+            modified = false;
+            for idx in 1..idoms.len() {
+                let new_idom = preds[idx]
+                    .iter()
+                    .filter(|pred_idx| idoms[**pred_idx].is_some())
+                    .fold(None, |new_idom, pred_idx| match new_idom {
+                        None => Some(*pred_idx),
+                        Some(new_idom) => Some(intersect(&idoms, *pred_idx, new_idom)),
+                    });
+                if idoms[idx] != new_idom {
+                    idoms[idx] = new_idom;
+                    modified = true;
+                }
+            }
+        }
+
+        let mut parents = HashMap::new();
+        let mut children: HashMap<Block, Vec<Block>> = HashMap::new();
+        let mut root_children = Vec::new();
+        for (block, idom) in order.iter().zip(idoms.iter().skip(1)) {
+            match idom.unwrap() {
+                0 => root_children.push(*block),
+                idom => {
+                    let parent = order[idom - 1];
+                    parents.insert(*block, parent);
+                    children.entry(parent).or_default().push(*block);
+                }
+            }
+        }
+
+        let mut intervals = HashMap::new();
+        let mut time = 0;
+        let mut stack = root_children
+            .into_iter()
+            .rev()
+            .map(|block| (block, false))
+            .collect::<Vec<_>>();
+        while let Some((block, is_exit)) = stack.pop() {
+            time += 1;
+            if is_exit {
+                intervals.entry(block).and_modify(|(_, exit)| *exit = time);
+            } else {
+                intervals.insert(block, (time, time));
+                stack.push((block, true));
+                for child in children.get(&block).into_iter().flatten().rev() {
+                    stack.push((*child, false));
+                }
+            }
+        }
+
+        Tree {
+            order,
+            parents,
+            children,
+            intervals,
+        }
+    }
+
+    fn children(&self, block: &Block) -> &[Block] {
+        self.children.get(block).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    fn is_ancestor(&self, ancestor: &Block, block: &Block) -> bool {
+        match (self.intervals.get(ancestor), self.intervals.get(block)) {
+            (Some((anc_entry, anc_exit)), Some((entry, exit))) => {
+                anc_entry <= entry && exit <= anc_exit
+            }
+            _otherwise => ancestor == block,
+        }
+    }
+}
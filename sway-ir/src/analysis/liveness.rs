@@ -0,0 +1,105 @@
+//! Which values are live at the start and end of each block.
+//!
+//! A value is live at a point if it may be used after it.  Only arguments and instructions are
+//! tracked, as constants are always available.  A value passed to a phi is used at the end of
+//! the block it comes from, rather than at the start of the block with the phi.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    block::Block,
+    context::Context,
+    function::Function,
+    instruction::Instruction,
+    value::{Value, ValueDatum},
+};
+
+use super::Analysis;
+
+/// The live values at the boundaries of each block in a function.
+pub struct Liveness {
+    live_in: HashMap<Block, HashSet<Value>>,
+    live_out: HashMap<Block, HashSet<Value>>,
+}
+
+impl Analysis for Liveness {
+    fn compute(context: &Context, function: &Function) -> Self {
+        let blocks = function.block_iter(context).collect::<Vec<_>>();
+        let is_variable = |value: &Value| !value.is_constant(context);
+
+        // The values each block uses before defining them, and those it defines.  Values passed
+        // to phis are gathered separately, for each block they come from.
+        let mut uses: HashMap<Block, HashSet<Value>> = HashMap::new();
+        let mut defs: HashMap<Block, HashSet<Value>> = HashMap::new();
+        let mut phi_uses: HashMap<Block, HashSet<Value>> = HashMap::new();
+        for block in &blocks {
+            let block_uses = uses.entry(*block).or_default();
+            let block_defs = defs.entry(*block).or_default();
+            for ins_val in &context.blocks[block.0].instructions {
+                match &context.values[ins_val.0].value {
+                    ValueDatum::Instruction(Instruction::Phi(pairs)) => {
+                        for (from_block, value) in pairs.iter().filter(|(_, v)| is_variable(v)) {
+                            phi_uses.entry(*from_block).or_default().insert(*value);
+                        }
+                    }
+                    ValueDatum::Instruction(ins) => {
+                        for value in ins.get_operands().into_iter().filter(is_variable) {
+                            if !block_defs.contains(&value) {
+                                block_uses.insert(value);
+                            }
+                        }
+                    }
+                    _otherwise => (),
+                }
+                block_defs.insert(*ins_val);
+            }
+        }
+
+        let mut live_in: HashMap<Block, HashSet<Value>> = blocks
+            .iter()
+            .map(|block| (*block, HashSet::new()))
+            .collect();
+        let mut live_out = live_in.clone();
+        let mut modified = true;
+        while modified {
+            modified = false;
+            for block in blocks.iter().rev() {
+                let mut new_live_out = phi_uses.get(block).cloned().unwrap_or_default();
+                for succ in block.successors(context) {
+                    new_live_out.extend(live_in[&succ].iter().copied());
+                }
+                let mut new_live_in = uses[block].clone();
+                new_live_in.extend(
+                    new_live_out
+                        .iter()
+                        .filter(|value| !defs[block].contains(value)),
+                );
+                if new_live_in != live_in[block] || new_live_out != live_out[block] {
+                    live_in.insert(*block, new_live_in);
+                    live_out.insert(*block, new_live_out);
+                    modified = true;
+                }
+            }
+        }
+
+        Liveness { live_in, live_out }
+    }
+}
+
+impl Liveness {
+    /// Return the values which are live at the start of `block`.
+    pub fn live_in(&self, block: &Block) -> &HashSet<Value> {
+        &self.live_in[block]
+    }
+
+    /// Return the values which are live at the end of `block`.
+    pub fn live_out(&self, block: &Block) -> &HashSet<Value> {
+        &self.live_out[block]
+    }
+
+    /// Return whether `value` is live at the end of `block`, i.e., whether it may be used after
+    /// `block`.
+    pub fn is_live_out(&self, block: &Block, value: &Value) -> bool {
+        self.live_out[block].contains(value)
+    }
+}
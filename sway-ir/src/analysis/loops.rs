@@ -0,0 +1,204 @@
+//! Natural loops and how they're nested.
+//!
+//! A back edge is a branch to a block which dominates the branching block.  The natural loop of a
+//! back edge is made of its target, the loop header, and every block which can reach the back edge
+//! without going through the header.  Loops with the same header are merged, so any two loops are
+//! either disjoint or one is nested in the other.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{block::Block, context::Context, function::Function};
+
+use super::{predecessors, Analysis, DominatorTree};
+
+/// The natural loops of a function.
+pub struct LoopNest {
+    /// Every loop, with each after the loops it's nested in.
+    loops: Vec<Loop>,
+    innermost_loops: HashMap<Block, LoopId>,
+}
+
+/// A handle to a [`Loop`] in a [`LoopNest`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct LoopId(usize);
+
+pub struct Loop {
+    header: Block,
+    blocks: Vec<Block>,
+    block_set: HashSet<Block>,
+    latches: Vec<Block>,
+    exits: Vec<Block>,
+    preheader: Option<Block>,
+    parent: Option<LoopId>,
+    depth: usize,
+}
+
+impl Analysis for LoopNest {
+    fn compute(context: &Context, function: &Function) -> Self {
+        let dom_tree = function.get_analysis::<DominatorTree>(context);
+        let preds = predecessors(context, function);
+        let block_indices = function
+            .block_iter(context)
+            .enumerate()
+            .map(|(idx, block)| (block, idx))
+            .collect::<HashMap<_, _>>();
+
+        // Find the back edges, grouped by their header.
+        let mut latches: HashMap<Block, Vec<Block>> = HashMap::new();
+        let mut headers = Vec::new();
+        for block in function.block_iter(context) {
+            for succ in block.successors(context) {
+                if dom_tree.is_reachable(&block) && dom_tree.dominates(&succ, &block) {
+                    let header_latches = latches.entry(succ).or_default();
+                    if header_latches.is_empty() {
+                        headers.push(succ);
+                    }
+                    header_latches.push(block);
+                }
+            }
+        }
+
+        // Find the blocks in each loop, by walking back from the latches to the header.
+        let mut loops = headers
+            .into_iter()
+            .map(|header| {
+                let latches = latches.remove(&header).unwrap();
+                let mut block_set = HashSet::from([header]);
+                let mut worklist = latches.clone();
+                while let Some(block) = worklist.pop() {
+                    if block_set.insert(block) {
+                        worklist.extend(
+                            preds[&block]
+                                .iter()
+                                .filter(|pred| dom_tree.is_reachable(pred)),
+                        );
+                    }
+                }
+                let mut blocks = block_set.iter().copied().collect::<Vec<_>>();
+                blocks.sort_by_key(|block| block_indices[block]);
+
+                let mut exits = Vec::new();
+                for block in &blocks {
+                    for succ in block.successors(context) {
+                        if !block_set.contains(&succ) && !exits.contains(&succ) {
+                            exits.push(succ);
+                        }
+                    }
+                }
+
+                // The preheader is the only block outside the loop which branches to the header,
+                // if it branches only to the header.
+                let outside_preds = preds[&header]
+                    .iter()
+                    .filter(|pred| !block_set.contains(pred))
+                    .collect::<Vec<_>>();
+                let preheader = match outside_preds.as_slice() {
+                    [pred] if pred.successors(context) == [header] => Some(**pred),
+                    _otherwise => None,
+                };
+
+                Loop {
+                    header,
+                    blocks,
+                    block_set,
+                    latches,
+                    exits,
+                    preheader,
+                    parent: None,
+                    depth: 1,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // A loop is nested in every bigger loop which contains its header, and its parent is the
+        // smallest of those.
+        loops.sort_by_key(|a_loop| std::cmp::Reverse(a_loop.blocks.len()));
+        let mut innermost_loops = HashMap::new();
+        for idx in 0..loops.len() {
+            let parent = (0..idx)
+                .rev()
+                .find(|outer_idx| loops[*outer_idx].contains(&loops[idx].header));
+            if let Some(parent) = parent {
+                loops[idx].parent = Some(LoopId(parent));
+                loops[idx].depth = loops[parent].depth + 1;
+            }
+            for block in &loops[idx].blocks {
+                innermost_loops.insert(*block, LoopId(idx));
+            }
+        }
+
+        LoopNest {
+            loops,
+            innermost_loops,
+        }
+    }
+}
+
+impl LoopNest {
+    /// Return every loop in the function, with each loop before the loops nested in it.
+    pub fn loops(&self) -> impl Iterator<Item = (LoopId, &Loop)> {
+        self.loops
+            .iter()
+            .enumerate()
+            .map(|(idx, a_loop)| (LoopId(idx), a_loop))
+    }
+
+    /// Return the loop with the id `loop_id`.
+    pub fn get(&self, loop_id: LoopId) -> &Loop {
+        &self.loops[loop_id.0]
+    }
+
+    /// Return the innermost loop containing `block`, if it's in one.
+    pub fn innermost_loop(&self, block: &Block) -> Option<LoopId> {
+        self.innermost_loops.get(block).copied()
+    }
+
+    /// Return the number of loops `block` is in.
+    pub fn loop_depth(&self, block: &Block) -> usize {
+        self.innermost_loop(block)
+            .map_or(0, |loop_id| self.get(loop_id).depth)
+    }
+}
+
+impl Loop {
+    /// Return the header of this loop, which dominates every block in it.
+    pub fn header(&self) -> Block {
+        self.header
+    }
+
+    /// Return the blocks in this loop, including those in nested loops, in function order.
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// Return whether `block` is in this loop or a loop nested in it.
+    pub fn contains(&self, block: &Block) -> bool {
+        self.block_set.contains(block)
+    }
+
+    /// Return the blocks in this loop which branch back to the header.
+    pub fn latches(&self) -> &[Block] {
+        &self.latches
+    }
+
+    /// Return the blocks outside this loop which are branched to from inside it.
+    pub fn exits(&self) -> &[Block] {
+        &self.exits
+    }
+
+    /// Return the block which is the only way into this loop from outside it and which branches
+    /// only to the header, if there is one.
+    pub fn preheader(&self) -> Option<Block> {
+        self.preheader
+    }
+
+    /// Return the loop which this loop is directly nested in, if any.
+    pub fn parent(&self) -> Option<LoopId> {
+        self.parent
+    }
+
+    /// Return the number of loops this loop is in, including itself.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
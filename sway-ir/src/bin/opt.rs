@@ -3,7 +3,13 @@ use std::{
     io::{BufReader, BufWriter, Error, ErrorKind, Read, Write},
 };
 
-use sway_ir::{error::IrError, function::Function, optimize, Context};
+use sway_ir::{
+    error::IrError,
+    function::Function,
+    interpreter::{DefaultHooks, InterpValue, Interpreter},
+    irtype::Type,
+    optimize, Context,
+};
 
 // -------------------------------------------------------------------------------------------------
 
@@ -26,10 +32,20 @@ fn main() -> std::io::Result<()> {
         match pass.name.as_ref() {
             "inline" => perform_inline(&mut ir).map_err(&to_err)?,
             "constcombine" => perform_combine_constants(&mut ir).map_err(&to_err)?,
+            "sroa" => perform_sroa(&mut ir).map_err(&to_err)?,
+            "mem2reg" => perform_mem2reg(&mut ir).map_err(&to_err)?,
+            "cse" => perform_cse(&mut ir).map_err(&to_err)?,
+            "storage" => perform_storage_opts(&mut ir).map_err(&to_err)?,
+            "licm" => perform_licm(&mut ir).map_err(&to_err)?,
             _otherwise => unreachable!("Unknown pass name: {}", pass.name),
         };
     }
 
+    // Run a function after the passes if asked to.
+    if let Some(run) = &config.run {
+        run_function(&ir, run).map_err(&to_err)?;
+    }
+
     // Write the output file or standard out.
     write_to_output(ir, &config.output_path)
 }
@@ -88,6 +104,166 @@ fn perform_combine_constants(ir: &mut Context) -> Result<bool, IrError> {
     Ok(modified)
 }
 
+// -------------------------------------------------------------------------------------------------
+
+fn perform_sroa(ir: &mut Context) -> Result<bool, IrError> {
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::sroa(ir, &Function(idx))? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn perform_mem2reg(ir: &mut Context) -> Result<bool, IrError> {
+    // Unlike the compiler, which has to leave enough registers for everything else, promote every
+    // local which can be.
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::mem2reg(ir, &Function(idx), usize::MAX)? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn perform_cse(ir: &mut Context) -> Result<bool, IrError> {
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::cse(ir, &Function(idx))? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn perform_storage_opts(ir: &mut Context) -> Result<bool, IrError> {
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::optimize_storage_accesses(ir, &Function(idx))? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn perform_licm(ir: &mut Context) -> Result<bool, IrError> {
+    // As with mem2reg, there's no need to leave registers for anything else here.
+    let funcs = ir.functions.iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+    let mut modified = false;
+    for idx in funcs {
+        if optimize::licm(ir, &Function(idx), usize::MAX)? {
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn run_function(ir: &Context, run: &Run) -> Result<(), String> {
+    let function = ir
+        .functions
+        .iter()
+        .find_map(|(idx, fc)| {
+            if fc.name == run.fn_name {
+                Some(Function(idx))
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| format!("Unable to find function '{}'.", run.fn_name))?;
+    let arg_types = function
+        .args_iter(ir)
+        .map(|(_, arg_val)| arg_val.get_type(ir).unwrap())
+        .collect::<Vec<_>>();
+    if arg_types.len() != run.args.len() {
+        return Err(format!(
+            "Function '{}' expects {} arguments, got {}.",
+            run.fn_name,
+            arg_types.len(),
+            run.args.len()
+        ));
+    }
+    let args = arg_types
+        .iter()
+        .zip(&run.args)
+        .map(|(ty, arg)| parse_arg(ir, ty, arg))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Print the logs and the storage written along with the result, so they can be checked too.
+    let mut hooks = DefaultHooks::default();
+    let result = Interpreter::new(ir, &mut hooks)
+        .run(&function, &args)
+        .map_err(|err| err.to_string())?;
+    for (log_id, value) in &hooks.logs {
+        println!("log {log_id}: {value}");
+    }
+    let mut slots = hooks.storage.iter().collect::<Vec<_>>();
+    slots.sort();
+    for (key, slot) in slots {
+        println!(
+            "storage {}: {}",
+            InterpValue::B256(*key),
+            InterpValue::B256(*slot)
+        );
+    }
+    println!("{result}");
+    Ok(())
+}
+
+fn parse_arg(ir: &Context, ty: &Type, arg: &str) -> Result<InterpValue, String> {
+    let bad_arg = || format!("Invalid argument '{}' of type {}.", arg, ty.as_string(ir));
+    let parse_hex = |size: usize| {
+        let digits = arg.strip_prefix("0x")?;
+        if digits.len() > size * 2 {
+            return None;
+        }
+        let digits = format!("{:0>width$}", digits, width = size * 2);
+        (0..size)
+            .map(|idx| u8::from_str_radix(&digits[idx * 2..idx * 2 + 2], 16).ok())
+            .collect::<Option<Vec<_>>>()
+    };
+    let parse_u64 = || match arg.strip_prefix("0x") {
+        Some(digits) => u64::from_str_radix(digits, 16).ok(),
+        None => arg.parse().ok(),
+    };
+    match ty {
+        Type::Unit if arg == "()" => Ok(InterpValue::Unit),
+        Type::Bool => arg.parse().map(InterpValue::Bool).map_err(|_| bad_arg()),
+        Type::Uint(_) => parse_u64().map(InterpValue::Uint).ok_or_else(bad_arg),
+        Type::BigUint(nbits) => {
+            let size = *nbits as usize / 8;
+            parse_hex(size)
+                .or_else(|| {
+                    let n = arg.parse::<u64>().ok()?;
+                    let mut bytes = vec![0; size];
+                    bytes[size - 8..].copy_from_slice(&n.to_be_bytes());
+                    Some(bytes)
+                })
+                .map(InterpValue::BigUint)
+                .ok_or_else(bad_arg)
+        }
+        Type::B256 => parse_hex(32)
+            .map(|bytes| InterpValue::B256(bytes.try_into().unwrap()))
+            .ok_or_else(bad_arg),
+        _otherwise => Err(bad_arg()),
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // Using a bespoke CLI parser since the order in which passes are specified is important.
 
@@ -101,6 +277,13 @@ struct Config {
     _stats: bool,
 
     passes: Vec<Pass>,
+    run: Option<Run>,
+}
+
+// A function to run with the interpreter once the passes are done.
+struct Run {
+    fn_name: String,
+    args: Vec<String>,
 }
 
 #[derive(Default)]
@@ -147,9 +330,15 @@ impl<I: Iterator<Item = String>> ConfigBuilder<I> {
                 match opt.as_str() {
                     "-i" => self.build_input(),
                     "-o" => self.build_output(),
+                    "--run" => self.build_run(),
 
                     "inline" => self.build_inline_pass(),
                     "constcombine" => self.build_const_combine_pass(),
+                    "sroa" => self.build_sroa_pass(),
+                    "mem2reg" => self.build_mem2reg_pass(),
+                    "cse" => self.build_cse_pass(),
+                    "storage" => self.build_storage_pass(),
+                    "licm" => self.build_licm_pass(),
 
                     _otherwise => Err(format!("Unrecognised option '{}'.", opt)),
                 }
@@ -179,6 +368,30 @@ impl<I: Iterator<Item = String>> ConfigBuilder<I> {
         }
     }
 
+    fn build_run(mut self) -> Result<Config, String> {
+        // The function name is followed by its arguments, if any.  These are all literals, so are
+        // told apart from the options and passes which may follow by how they start.
+        let fn_name = match self.next {
+            None => return Err("--run option requires a function name.".to_owned()),
+            Some(fn_name) => fn_name,
+        };
+        let mut args = Vec::new();
+        self.next = self.rest.next();
+        while let Some(arg) = self.next.take() {
+            if arg.starts_with(|c: char| c.is_ascii_digit())
+                || ["()", "true", "false"].contains(&arg.as_str())
+            {
+                args.push(arg);
+                self.next = self.rest.next();
+            } else {
+                self.next = Some(arg);
+                break;
+            }
+        }
+        self.cfg.run = Some(Run { fn_name, args });
+        self.build_root()
+    }
+
     fn build_inline_pass(mut self) -> Result<Config, String> {
         // No args yet.  Eventually we should allow specifying which functions are to be inlined
         // or which functions are to have all embedded calls inlined.
@@ -191,7 +404,31 @@ impl<I: Iterator<Item = String>> ConfigBuilder<I> {
         // No args yet.  Eventually we should allow specifying which functions should have consts
         // combined.
         self.cfg.passes.push("constcombine".into());
-        self.next = self.rest.next();
+        self.build_root()
+    }
+
+    fn build_sroa_pass(mut self) -> Result<Config, String> {
+        self.cfg.passes.push("sroa".into());
+        self.build_root()
+    }
+
+    fn build_mem2reg_pass(mut self) -> Result<Config, String> {
+        self.cfg.passes.push("mem2reg".into());
+        self.build_root()
+    }
+
+    fn build_cse_pass(mut self) -> Result<Config, String> {
+        self.cfg.passes.push("cse".into());
+        self.build_root()
+    }
+
+    fn build_storage_pass(mut self) -> Result<Config, String> {
+        self.cfg.passes.push("storage".into());
+        self.build_root()
+    }
+
+    fn build_licm_pass(mut self) -> Result<Config, String> {
+        self.cfg.passes.push("licm".into());
         self.build_root()
     }
 }
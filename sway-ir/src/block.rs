@@ -149,6 +149,24 @@ impl Block {
         })
     }
 
+    /// Return the blocks this block branches to, without duplicates.
+    pub fn successors(&self, context: &Context) -> Vec<Block> {
+        match self.get_term_inst(context) {
+            Some(Instruction::Branch(to_block)) => vec![*to_block],
+            Some(Instruction::ConditionalBranch {
+                true_block,
+                false_block,
+                ..
+            }) if true_block == false_block => vec![*true_block],
+            Some(Instruction::ConditionalBranch {
+                true_block,
+                false_block,
+                ..
+            }) => vec![*true_block, *false_block],
+            _otherwise => Vec::new(),
+        }
+    }
+
     /// Return whether this block is already terminated.  Checks if the final instruction, if it
     /// exists, is a terminator.
     pub fn is_terminated(&self, context: &Context) -> bool {
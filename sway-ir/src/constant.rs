@@ -21,6 +21,8 @@ pub enum ConstantValue {
     Unit,
     Bool(bool),
     Uint(u64),
+    /// Big-endian bytes of a [`Type::BigUint`], one byte per 8 bits of the type.
+    BigUint(Vec<u8>),
     B256([u8; 32]),
     String(Vec<u8>),
     Array(Vec<Constant>),
@@ -71,6 +73,15 @@ impl Constant {
         }
     }
 
+    /// `bytes` are big-endian and must be exactly `nbits / 8` long.
+    pub fn new_big_uint(nbits: u16, bytes: Vec<u8>) -> Self {
+        assert_eq!(bytes.len(), nbits as usize / 8);
+        Constant {
+            ty: Type::BigUint(nbits),
+            value: ConstantValue::BigUint(bytes),
+        }
+    }
+
     pub fn new_b256(bytes: [u8; 32]) -> Self {
         Constant {
             ty: Type::B256,
@@ -124,6 +135,15 @@ impl Constant {
         Value::new_constant(context, Constant::new_uint(nbits, value), span_md_idx)
     }
 
+    pub fn get_big_uint(
+        context: &mut Context,
+        nbits: u16,
+        bytes: Vec<u8>,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Value {
+        Value::new_constant(context, Constant::new_big_uint(nbits, bytes), span_md_idx)
+    }
+
     pub fn get_b256(
         context: &mut Context,
         value: [u8; 32],
@@ -6,11 +6,12 @@
 //!
 //! It is passed around as a mutable reference to many of the Sway-IR APIs.
 
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap};
 
 use generational_arena::Arena;
 
 use crate::{
+    analysis::AnalysisCache,
     asm::AsmBlockContent,
     block::BlockContent,
     function::FunctionContent,
@@ -42,6 +43,9 @@ pub struct Context {
     pub metadata_reverse_map: HashMap<*const std::path::PathBuf, MetadataIndex>,
     pub(crate) metadata_storage_indices: HashMap<StorageOperation, MetadataIndex>,
 
+    // The analyses are cached on demand, via a shared reference to the context.
+    pub(crate) analyses: RefCell<AnalysisCache>,
+
     next_unique_sym_tag: u64,
 }
 
@@ -20,6 +20,8 @@ pub enum IrError {
     VerifyAccessValueInvalidIndices,
     VerifyAccessValueOnNonStruct,
     VerifyArgumentValueIsNotArgument(String),
+    VerifyBinaryOpIncorrectArgTypes(String, String),
+    VerifyBinaryOpUnknownArgTypes,
     VerifyBitcastUnknownSourceType,
     VerifyBitcastFromNonCopyType(String),
     VerifyBitcastToNonCopyType(String),
@@ -37,6 +39,8 @@ pub enum IrError {
     VerifyInsertValueOfIncorrectType,
     VerifyLoadFromNonPointer,
     VerifyLoadNonExistentPointer,
+    VerifyLogId,
+    VerifyMismatchedLoggedTypes,
     VerifyMismatchedReturnTypes(String),
     VerifyPhiFromMissingBlock(String),
     VerifyPhiInconsistentTypes,
@@ -146,6 +150,18 @@ impl fmt::Display for IrError {
                     "Verification failed: Call to invalid function '{callee}'."
                 )
             }
+            IrError::VerifyBinaryOpIncorrectArgTypes(arg1_ty, arg2_ty) => {
+                write!(
+                    f,
+                    "Verification failed: Cannot apply binary operation to {arg1_ty} and {arg2_ty}, integers of the same width are required."
+                )
+            }
+            IrError::VerifyBinaryOpUnknownArgTypes => {
+                write!(
+                    f,
+                    "Verification failed: Unable to determine type(s) of binary operation argument(s)."
+                )
+            }
             IrError::VerifyCmpBadTypes(lhs_ty, rhs_ty) => {
                 write!(
                     f,
@@ -201,6 +217,15 @@ impl fmt::Display for IrError {
                 f,
                 "Verification failed: Attempt to load from a pointer not found in function locals."
             ),
+            IrError::VerifyLogId => {
+                write!(f, "Verification failed: log ID must be an integer.")
+            }
+            IrError::VerifyMismatchedLoggedTypes => {
+                write!(
+                    f,
+                    "Verification failed: log type must match the type of the value being logged."
+                )
+            }
             IrError::VerifyMismatchedReturnTypes(fn_str) => write!(
                 f,
                 "Verification failed: Function {fn_str} return type must match its RET instructions."
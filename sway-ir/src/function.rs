@@ -6,9 +6,13 @@
 //! It also maintains a collection of local values which can be typically regarded as variables
 //! existing in the function scope.
 
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
 
 use crate::{
+    analysis::{self, Analysis},
     block::{Block, BlockIterator, Label},
     constant::Constant,
     context::Context,
@@ -197,6 +201,13 @@ impl Function {
         context.functions[self.0].blocks[0]
     }
 
+    /// Return the analysis `A` of this function.
+    ///
+    /// It's computed if it isn't cached, or if this function has been modified since it was.
+    pub fn get_analysis<A: Analysis>(&self, context: &Context) -> Rc<A> {
+        analysis::get_analysis(context, self)
+    }
+
     /// Whether this function has a valid selector.
     pub fn has_selector(&self, context: &Context) -> bool {
         context.functions[self.0].selector.is_some()
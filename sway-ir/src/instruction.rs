@@ -1,13 +1,17 @@
 //! Instructions for data manipulation, but mostly control flow.
 //!
 //! Since Sway abstracts most low level operations behind traits they are translated into function
-//! calls which contain ASM blocks.  Therefore _at this stage_ Sway-IR doesn't need low level
-//! operations such as binary arithmetic and logic operators.
+//! calls which contain ASM blocks.  The exceptions are the compiler intrinsics for arithmetic and
+//! comparison, which are needed for integer types too wide to be handled by a single VM
+//! instruction, and so are represented here by [`Instruction::BinaryOp`] and
+//! [`Instruction::Cmp`].
 //!
 //! Unfortuntely, using opaque ASM blocks limits the effectiveness of certain optimizations and
 //! this should be addressed in the future, perhaps by using compiler intrinsic calls instead of
 //! the ASM blocks where possible.
 
+use std::collections::HashMap;
+
 use sway_types::ident::Ident;
 
 use crate::{
@@ -25,6 +29,13 @@ use crate::{
 pub enum Instruction {
     /// An opaque list of ASM instructions passed directly to codegen.
     AsmBlock(AsmBlock, Vec<AsmArg>),
+    /// Unsigned integer arithmetic between two values of the same integer type, returning a value
+    /// of that type.  Overflow and division by zero panic.
+    BinaryOp {
+        op: BinaryOpKind,
+        arg1: Value,
+        arg2: Value,
+    },
     /// Cast the type of a value without changing its actual content.
     BitCast(Value, Type),
     /// An unconditional jump.
@@ -84,6 +95,13 @@ pub enum Instruction {
     },
     /// Read a value from a memory pointer.
     Load(Value),
+    /// Log a value of type `log_ty`, tagged with the ID in `log_id`.  Copy types are logged
+    /// directly and reference types are logged as a blob of data.
+    Log {
+        log_val: Value,
+        log_ty: Type,
+        log_id: Value,
+    },
     /// No-op, handy as a placeholder instruction.
     Nop,
     /// Choose a value from a list depending on the preceding block.
@@ -106,11 +124,23 @@ pub enum Instruction {
     Store { dst_val: Value, stored_val: Value },
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Predicate {
     /// Equivalence.
     Equal,
-    // More soon.  NotEqual, LessThan, LessThanOrEqual, GreaterThan, GreaterThanOrEqual.
+    /// Unsigned less than.
+    LessThan,
+    /// Unsigned greater than.
+    GreaterThan,
+    // More soon.  NotEqual, LessThanOrEqual, GreaterThanOrEqual.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOpKind {
+    Add,
+    Sub,
+    Mul,
+    Div,
 }
 
 /// Special registers in the Fuel Virtual Machine.
@@ -154,6 +184,7 @@ impl Instruction {
     pub fn get_type(&self, context: &Context) -> Option<Type> {
         match self {
             Instruction::AsmBlock(asm_block, _) => asm_block.get_type(context),
+            Instruction::BinaryOp { arg1, .. } => arg1.get_type(context),
             Instruction::BitCast(_, ty) => Some(*ty),
             Instruction::Call(function, _) => Some(context.functions[function.0].return_type),
             Instruction::Cmp(..) => Some(Type::Bool),
@@ -187,6 +218,7 @@ impl Instruction {
             Instruction::Ret(..) => None,
 
             // These write values but don't return one.  If we're explicit we could return Unit.
+            Instruction::Log { .. } => None,
             Instruction::StateLoadQuadWord { .. } => None,
             Instruction::StateStoreQuadWord { .. } => None,
             Instruction::StateStoreWord { .. } => None,
@@ -231,20 +263,89 @@ impl Instruction {
         }
     }
 
+    /// Return the values used by this instruction, including the pointers accessed by `load` and
+    /// `store`.
+    pub fn get_operands(&self) -> Vec<Value> {
+        match self {
+            Instruction::AsmBlock(_, args) => args
+                .iter()
+                .filter_map(|asm_arg| asm_arg.initializer)
+                .collect(),
+            Instruction::BinaryOp { arg1, arg2, .. } => vec![*arg1, *arg2],
+            Instruction::BitCast(value, _) => vec![*value],
+            Instruction::Branch(_) => vec![],
+            Instruction::Call(_, args) => args.clone(),
+            Instruction::Cmp(_, lhs_val, rhs_val) => vec![*lhs_val, *rhs_val],
+            Instruction::ConditionalBranch { cond_value, .. } => vec![*cond_value],
+            Instruction::ContractCall {
+                params,
+                coins,
+                asset_id,
+                gas,
+                ..
+            } => vec![*params, *coins, *asset_id, *gas],
+            Instruction::GetPointer { .. } => vec![],
+            Instruction::InsertElement {
+                array,
+                value,
+                index_val,
+                ..
+            } => vec![*array, *value, *index_val],
+            Instruction::InsertValue {
+                aggregate, value, ..
+            } => vec![*aggregate, *value],
+            Instruction::ExtractElement {
+                array, index_val, ..
+            } => vec![*array, *index_val],
+            Instruction::ExtractValue { aggregate, .. } => vec![*aggregate],
+            Instruction::GetStorageKey => vec![],
+            Instruction::Load(src_val) => vec![*src_val],
+            Instruction::Log {
+                log_val, log_id, ..
+            } => vec![*log_val, *log_id],
+            Instruction::Nop => vec![],
+            Instruction::Phi(pairs) => pairs.iter().map(|(_, val)| *val).collect(),
+            Instruction::ReadRegister(_) => vec![],
+            Instruction::Ret(ret_val, _) => vec![*ret_val],
+            Instruction::StateLoadQuadWord { load_val, key } => vec![*load_val, *key],
+            Instruction::StateLoadWord(key) => vec![*key],
+            Instruction::StateStoreQuadWord { stored_val, key }
+            | Instruction::StateStoreWord { stored_val, key } => vec![*stored_val, *key],
+            Instruction::Store {
+                dst_val,
+                stored_val,
+            } => vec![*dst_val, *stored_val],
+        }
+    }
+
     /// Replace `old_val` with `new_val` if it is referenced by this instruction's arguments.
     pub fn replace_value(&mut self, old_val: Value, new_val: Value) {
-        let replace = |val: &mut Value| {
+        self.replace_with(|val: &mut Value| {
             if val == &old_val {
                 *val = new_val
             }
-        };
+        })
+    }
+
+    /// Replace each of this instruction's arguments which is a key in `replace_map` with its
+    /// value.
+    pub fn replace_values(&mut self, replace_map: &HashMap<Value, Value>) {
+        self.replace_with(|val: &mut Value| {
+            if let Some(new_val) = replace_map.get(val) {
+                *val = *new_val
+            }
+        })
+    }
+
+    fn replace_with<F: FnMut(&mut Value)>(&mut self, mut replace: F) {
         match self {
-            Instruction::AsmBlock(_, args) => args.iter_mut().for_each(|asm_arg| {
-                asm_arg
-                    .initializer
-                    .iter_mut()
-                    .for_each(|init_val| replace(init_val))
-            }),
+            Instruction::AsmBlock(_, args) => args
+                .iter_mut()
+                .for_each(|asm_arg| asm_arg.initializer.iter_mut().for_each(&mut replace)),
+            Instruction::BinaryOp { arg1, arg2, .. } => {
+                replace(arg1);
+                replace(arg2);
+            }
             Instruction::BitCast(value, _) => replace(value),
             Instruction::Branch(_) => (),
             Instruction::Call(_, args) => args.iter_mut().for_each(replace),
@@ -290,7 +391,13 @@ impl Instruction {
             }
             Instruction::ExtractValue { aggregate, .. } => replace(aggregate),
             Instruction::GetStorageKey => (),
-            Instruction::Load(_) => (),
+            Instruction::Load(src_val) => replace(src_val),
+            Instruction::Log {
+                log_val, log_id, ..
+            } => {
+                replace(log_val);
+                replace(log_id);
+            }
             Instruction::Nop => (),
             Instruction::Phi(pairs) => pairs.iter_mut().for_each(|(_, val)| replace(val)),
             Instruction::ReadRegister { .. } => (),
@@ -310,7 +417,11 @@ impl Instruction {
                 replace(key);
                 replace(stored_val);
             }
-            Instruction::Store { stored_val, .. } => {
+            Instruction::Store {
+                dst_val,
+                stored_val,
+            } => {
+                replace(dst_val);
                 replace(stored_val);
             }
         }
@@ -405,6 +516,25 @@ impl<'a> InstructionInserter<'a> {
         asm_val
     }
 
+    pub fn binary_op(
+        self,
+        op: BinaryOpKind,
+        arg1: Value,
+        arg2: Value,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Value {
+        let binary_op_val = Value::new_instruction(
+            self.context,
+            Instruction::BinaryOp { op, arg1, arg2 },
+            span_md_idx,
+            None,
+        );
+        self.context.blocks[self.block.0]
+            .instructions
+            .push(binary_op_val);
+        binary_op_val
+    }
+
     pub fn bitcast(self, value: Value, ty: Type, span_md_idx: Option<MetadataIndex>) -> Value {
         let bitcast_val = Value::new_instruction(
             self.context,
@@ -674,6 +804,29 @@ impl<'a> InstructionInserter<'a> {
         load_val
     }
 
+    pub fn log(
+        self,
+        log_val: Value,
+        log_ty: Type,
+        log_id: Value,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Value {
+        let log_instr_val = Value::new_instruction(
+            self.context,
+            Instruction::Log {
+                log_val,
+                log_ty,
+                log_id,
+            },
+            span_md_idx,
+            None,
+        );
+        self.context.blocks[self.block.0]
+            .instructions
+            .push(log_instr_val);
+        log_instr_val
+    }
+
     pub fn nop(self) -> Value {
         let nop_val = Value::new_instruction(self.context, Instruction::Nop, None, None);
         self.context.blocks[self.block.0].instructions.push(nop_val);
@@ -0,0 +1,1033 @@
+//! A reference interpreter for the IR.
+//!
+//! [`Interpreter`] runs a function with a given set of arguments and returns its result, without
+//! going via the backend and the VM.  It's intended for testing, in particular for comparing the
+//! behaviour of a function before and after an optimization pass.
+//!
+//! Memory is modelled the way the backend lays it out.  Every value is a single word: copy types
+//! are held directly and any other type is the address of its bytes in memory.  So `load` of an
+//! aggregate yields the address of the local, `insert_value` and `insert_element` write to the
+//! aggregate in place, and unions are left padded.  Memory is never freed, as a run is expected to
+//! be short.
+//!
+//! Integer overflow, underflow, division by zero and out of bounds array indices all panic, and
+//! the run stops with [`InterpError::Panic`].
+//!
+//! Contract storage, contract calls, logging and reading VM registers go via [`Hooks`], with
+//! [`DefaultHooks`] providing an in-memory storage map and a log.  ASM blocks are opaque to the
+//! interpreter and can't be run.
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    constant::{Constant, ConstantValue},
+    context::Context,
+    function::Function,
+    instruction::{BinaryOpKind, Instruction, Predicate, Register},
+    irtype::{Aggregate, AggregateContent, Type},
+    pointer::Pointer,
+    value::{Value, ValueDatum},
+};
+
+/// The default maximum number of instructions to execute before giving up.
+pub const DEFAULT_STEP_LIMIT: u64 = 1_000_000;
+
+/// The maximum depth of nested calls.
+const MAX_CALL_DEPTH: usize = 256;
+
+/// A runtime value, as passed to and returned from an interpreted function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpValue {
+    Unit,
+    Bool(bool),
+    Uint(u64),
+    /// Big-endian bytes, one byte per 8 bits of the type.
+    BigUint(Vec<u8>),
+    B256([u8; 32]),
+    String(Vec<u8>),
+    Array(Vec<InterpValue>),
+    Struct(Vec<InterpValue>),
+    /// The raw bytes of a union, as there's no way of telling which variant it holds.
+    Union(Vec<u8>),
+}
+
+impl InterpValue {
+    /// Return the value of `constant`.  Undefined constants are zero.
+    pub fn from_constant(context: &Context, constant: &Constant) -> Self {
+        match &constant.value {
+            ConstantValue::Undef => InterpValue::zero(context, &constant.ty),
+            ConstantValue::Unit => InterpValue::Unit,
+            ConstantValue::Bool(b) => InterpValue::Bool(*b),
+            ConstantValue::Uint(n) => InterpValue::Uint(*n),
+            ConstantValue::BigUint(bytes) => InterpValue::BigUint(bytes.clone()),
+            ConstantValue::B256(bytes) => InterpValue::B256(*bytes),
+            ConstantValue::String(bytes) => InterpValue::String(bytes.clone()),
+            ConstantValue::Array(elems) => InterpValue::Array(
+                elems
+                    .iter()
+                    .map(|elem| InterpValue::from_constant(context, elem))
+                    .collect(),
+            ),
+            ConstantValue::Struct(fields) => InterpValue::Struct(
+                fields
+                    .iter()
+                    .map(|field| InterpValue::from_constant(context, field))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Return the all zero value of `ty`.
+    pub fn zero(context: &Context, ty: &Type) -> Self {
+        match ty {
+            Type::Unit => InterpValue::Unit,
+            Type::Bool => InterpValue::Bool(false),
+            Type::Uint(_) => InterpValue::Uint(0),
+            Type::BigUint(nbits) => InterpValue::BigUint(vec![0; *nbits as usize / 8]),
+            Type::B256 => InterpValue::B256([0; 32]),
+            Type::String(n) => InterpValue::String(vec![0; *n as usize]),
+            Type::Array(aggregate) => {
+                let (elem_ty, count) = context.aggregates[aggregate.0].array_type();
+                InterpValue::Array(vec![InterpValue::zero(context, elem_ty); *count as usize])
+            }
+            Type::Struct(aggregate) => InterpValue::Struct(
+                context.aggregates[aggregate.0]
+                    .field_types()
+                    .iter()
+                    .map(|field_ty| InterpValue::zero(context, field_ty))
+                    .collect(),
+            ),
+            Type::Union(_) => InterpValue::Union(vec![0; type_size(context, ty) as usize]),
+        }
+    }
+}
+
+impl fmt::Display for InterpValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let write_hex = |f: &mut fmt::Formatter, bytes: &[u8]| {
+            write!(f, "0x")?;
+            bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+        };
+        let write_list = |f: &mut fmt::Formatter, values: &[InterpValue]| {
+            values.iter().enumerate().try_for_each(|(idx, value)| {
+                if idx > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{value}")
+            })
+        };
+        match self {
+            InterpValue::Unit => write!(f, "()"),
+            InterpValue::Bool(b) => write!(f, "{b}"),
+            InterpValue::Uint(n) => write!(f, "{n}"),
+            InterpValue::BigUint(bytes) => write_hex(f, bytes),
+            InterpValue::B256(bytes) => write_hex(f, bytes),
+            InterpValue::String(bytes) => write!(f, "{:?}", String::from_utf8_lossy(bytes)),
+            InterpValue::Array(elems) => {
+                write!(f, "[")?;
+                write_list(f, elems)?;
+                write!(f, "]")
+            }
+            InterpValue::Struct(fields) => {
+                write!(f, "{{ ")?;
+                write_list(f, fields)?;
+                write!(f, " }}")
+            }
+            InterpValue::Union(bytes) => {
+                write!(f, "union ")?;
+                write_hex(f, bytes)
+            }
+        }
+    }
+}
+
+/// Why a run stopped without returning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpError {
+    /// The program panicked, e.g., on overflow or division by zero.
+    Panic(String),
+    /// The step limit was reached before the function returned.
+    StepLimitReached,
+    /// Calls were nested too deeply.
+    CallDepthExceeded,
+    /// The program did something the interpreter can't do, e.g., run an ASM block.
+    Unsupported(String),
+    /// The IR is malformed or was passed arguments of the wrong type.
+    Invalid(String),
+}
+
+impl fmt::Display for InterpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InterpError::Panic(reason) => write!(f, "Panic: {reason}"),
+            InterpError::StepLimitReached => write!(f, "Step limit reached."),
+            InterpError::CallDepthExceeded => write!(f, "Call depth exceeded."),
+            InterpError::Unsupported(what) => write!(f, "Unsupported: {what}"),
+            InterpError::Invalid(reason) => write!(f, "Invalid IR: {reason}"),
+        }
+    }
+}
+
+/// The interpreter's interface to everything outside of the function being run.
+pub trait Hooks {
+    /// Return the storage key for state index `state_idx`, as used by `get_storage_key`.  The
+    /// backend hashes the index, whereas by default it's just written big-endian in the last word.
+    fn storage_key(&mut self, state_idx: usize) -> [u8; 32] {
+        let mut key = [0; 32];
+        key[24..].copy_from_slice(&(state_idx as u64).to_be_bytes());
+        key
+    }
+
+    /// Read a storage slot.  An unset slot is all zeros.
+    fn load_slot(&mut self, key: &[u8; 32]) -> [u8; 32];
+
+    /// Write a storage slot.
+    fn store_slot(&mut self, key: &[u8; 32], value: [u8; 32]);
+
+    /// Make a contract call, returning a value of `return_type`.
+    fn contract_call(
+        &mut self,
+        _context: &Context,
+        _return_type: &Type,
+        call: ContractCallArgs,
+    ) -> Result<InterpValue, InterpError> {
+        Err(InterpError::Unsupported(format!(
+            "contract call to '{}'",
+            call.name
+        )))
+    }
+
+    /// Log `value` with the ID `log_id`.
+    fn log(&mut self, _log_id: u64, _value: InterpValue) {}
+
+    /// Read a VM register.
+    fn read_register(&mut self, reg: Register) -> Result<u64, InterpError> {
+        Err(InterpError::Unsupported(format!(
+            "reading register {reg:?}"
+        )))
+    }
+}
+
+/// The arguments to a contract call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractCallArgs {
+    /// The name of the method called.
+    pub name: String,
+    /// The call parameters: the contract ID, the method selector and the user argument.
+    pub params: InterpValue,
+    pub coins: u64,
+    pub asset_id: [u8; 32],
+    pub gas: u64,
+}
+
+/// Hooks with the contract storage held in a map, and which keep the values logged.  Contract
+/// calls and reading registers aren't supported.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DefaultHooks {
+    pub storage: HashMap<[u8; 32], [u8; 32]>,
+    pub logs: Vec<(u64, InterpValue)>,
+}
+
+impl Hooks for DefaultHooks {
+    fn load_slot(&mut self, key: &[u8; 32]) -> [u8; 32] {
+        self.storage.get(key).copied().unwrap_or([0; 32])
+    }
+
+    fn store_slot(&mut self, key: &[u8; 32], value: [u8; 32]) {
+        self.storage.insert(*key, value);
+    }
+
+    fn log(&mut self, log_id: u64, value: InterpValue) {
+        self.logs.push((log_id, value));
+    }
+}
+
+/// Runs functions in a [`Context`].  Each run starts with fresh memory but the same hooks, so
+/// storage written by one run is seen by the next.
+pub struct Interpreter<'a> {
+    context: &'a Context,
+    hooks: &'a mut dyn Hooks,
+    memory: Vec<u8>,
+    step_limit: u64,
+    steps: u64,
+    call_depth: usize,
+}
+
+/// The values and local addresses of a function being run.
+#[derive(Default)]
+struct Frame {
+    values: HashMap<Value, u64>,
+    locals: HashMap<Pointer, u64>,
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(context: &'a Context, hooks: &'a mut dyn Hooks) -> Self {
+        Interpreter {
+            context,
+            hooks,
+            memory: Vec::new(),
+            step_limit: DEFAULT_STEP_LIMIT,
+            steps: 0,
+            call_depth: 0,
+        }
+    }
+
+    /// Stop each run after `step_limit` instructions.
+    pub fn with_step_limit(mut self, step_limit: u64) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    /// Run `function` with `args`, returning its result.
+    pub fn run(
+        &mut self,
+        function: &Function,
+        args: &[InterpValue],
+    ) -> Result<InterpValue, InterpError> {
+        // Keep address zero unused, so that it's never a valid address.
+        self.memory = vec![0; 8];
+        self.steps = 0;
+        self.call_depth = 0;
+
+        let context = self.context;
+        let arg_types = function
+            .args_iter(context)
+            .map(|(_, arg_val)| arg_val.get_type(context).unwrap())
+            .collect::<Vec<_>>();
+        if arg_types.len() != args.len() {
+            return Err(InterpError::Invalid(format!(
+                "function '{}' expects {} arguments, got {}",
+                function.get_name(context),
+                arg_types.len(),
+                args.len()
+            )));
+        }
+        let arg_words = arg_types
+            .iter()
+            .zip(args)
+            .map(|(ty, arg)| self.value_to_word(ty, arg))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let ret_word = self.call(function, &arg_words)?;
+        let ret_type = context.functions[function.0].return_type;
+        self.word_to_value(&ret_type, ret_word)
+    }
+
+    fn call(&mut self, function: &Function, arg_words: &[u64]) -> Result<u64, InterpError> {
+        if self.call_depth == MAX_CALL_DEPTH {
+            return Err(InterpError::CallDepthExceeded);
+        }
+        self.call_depth += 1;
+
+        let context = self.context;
+        let mut frame = Frame::default();
+        for ((_, arg_val), word) in function.args_iter(context).zip(arg_words) {
+            frame.values.insert(*arg_val, *word);
+        }
+        for (_, ptr) in function.locals_iter(context) {
+            let ptr_content = &context.pointers[ptr.0];
+            let addr = self.alloc(type_size(context, &ptr_content.ty));
+            if let Some(initializer) = &ptr_content.initializer {
+                let value = InterpValue::from_constant(context, initializer);
+                self.write_value(addr, &ptr_content.ty, &value)?;
+            }
+            frame.locals.insert(*ptr, addr);
+        }
+
+        let mut block = function.get_entry_block(context);
+        let mut prev_block = None;
+        'blocks: loop {
+            for ins_val in &context.blocks[block.0].instructions {
+                self.steps += 1;
+                if self.steps > self.step_limit {
+                    return Err(InterpError::StepLimitReached);
+                }
+                let ins = match &context.values[ins_val.0].value {
+                    ValueDatum::Instruction(ins) => ins,
+                    _otherwise => return Err(invalid("block contains a non-instruction")),
+                };
+                let word = match ins {
+                    Instruction::Phi(pairs) if pairs.is_empty() => continue,
+                    Instruction::Phi(pairs) => {
+                        let value = pairs
+                            .iter()
+                            .find(|(from_block, _)| Some(*from_block) == prev_block)
+                            .map(|(_, value)| *value)
+                            .ok_or_else(|| invalid("phi has no value for the previous block"))?;
+                        self.eval(&frame, &value)?
+                    }
+                    Instruction::Branch(to_block) => {
+                        prev_block = Some(block);
+                        block = *to_block;
+                        continue 'blocks;
+                    }
+                    Instruction::ConditionalBranch {
+                        cond_value,
+                        true_block,
+                        false_block,
+                    } => {
+                        prev_block = Some(block);
+                        block = if self.eval(&frame, cond_value)? != 0 {
+                            *true_block
+                        } else {
+                            *false_block
+                        };
+                        continue 'blocks;
+                    }
+                    Instruction::Ret(ret_val, _) => {
+                        let word = self.eval(&frame, ret_val)?;
+                        self.call_depth -= 1;
+                        return Ok(word);
+                    }
+                    _otherwise => match self.execute(&frame, ins_val, ins)? {
+                        Some(word) => word,
+                        None => continue,
+                    },
+                };
+                frame.values.insert(*ins_val, word);
+            }
+            return Err(invalid(&format!(
+                "block '{}' has no terminator",
+                block.get_label(context)
+            )));
+        }
+    }
+
+    /// Execute an instruction which isn't a phi or a terminator, returning its value if it has
+    /// one.
+    fn execute(
+        &mut self,
+        frame: &Frame,
+        ins_val: &Value,
+        ins: &Instruction,
+    ) -> Result<Option<u64>, InterpError> {
+        let context = self.context;
+        let word = match ins {
+            Instruction::AsmBlock(..) => {
+                return Err(InterpError::Unsupported("ASM blocks".to_owned()))
+            }
+            Instruction::BinaryOp { op, arg1, arg2 } => {
+                let ty = value_type(context, arg1)?;
+                let word1 = self.eval(frame, arg1)?;
+                let word2 = self.eval(frame, arg2)?;
+                match ty {
+                    Type::Uint(nbits) => uint_binary_op(*op, word1, word2, nbits)?,
+                    Type::BigUint(nbits) => {
+                        let size = nbits as u64 / 8;
+                        let lhs = self.read_bytes(word1, size)?.to_vec();
+                        let rhs = self.read_bytes(word2, size)?.to_vec();
+                        let result = big_uint_binary_op(*op, &lhs, &rhs)?;
+                        let addr = self.alloc(size);
+                        self.write_bytes(addr, &result)?;
+                        addr
+                    }
+                    _otherwise => return Err(invalid("binary op on a non-integer")),
+                }
+            }
+            Instruction::BitCast(value, ty) => {
+                let word = self.eval(frame, value)?;
+                match ty {
+                    Type::Bool => (word != 0) as u64,
+                    _otherwise => word,
+                }
+            }
+            Instruction::Call(function, args) => {
+                let arg_words = args
+                    .iter()
+                    .map(|arg| self.eval(frame, arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.call(function, &arg_words)?
+            }
+            Instruction::Cmp(pred, lhs_val, rhs_val) => {
+                let ty = value_type(context, lhs_val)?;
+                let lhs_word = self.eval(frame, lhs_val)?;
+                let rhs_word = self.eval(frame, rhs_val)?;
+                let ordering = if ty.is_copy_type() {
+                    lhs_word.cmp(&rhs_word)
+                } else {
+                    // Big-endian bytes of the same length compare the same as the numbers do.
+                    let size = type_size(context, &ty);
+                    let lhs = self.read_bytes(lhs_word, size)?;
+                    let rhs = self.read_bytes(rhs_word, size)?;
+                    lhs.cmp(rhs)
+                };
+                let result = match pred {
+                    Predicate::Equal => ordering.is_eq(),
+                    Predicate::LessThan => ordering.is_lt(),
+                    Predicate::GreaterThan => ordering.is_gt(),
+                };
+                result as u64
+            }
+            Instruction::ContractCall {
+                return_type,
+                name,
+                params,
+                coins,
+                asset_id,
+                gas,
+            } => {
+                let params_ty = value_type(context, params)?;
+                let params_word = self.eval(frame, params)?;
+                let params = self.word_to_value(&params_ty, params_word)?;
+                let coins = self.eval(frame, coins)?;
+                let asset_id_addr = self.eval(frame, asset_id)?;
+                let asset_id = self.read_b256(asset_id_addr)?;
+                let gas = self.eval(frame, gas)?;
+                let call = ContractCallArgs {
+                    name: name.clone(),
+                    params,
+                    coins,
+                    asset_id,
+                    gas,
+                };
+                let result = self.hooks.contract_call(context, return_type, call)?;
+                self.value_to_word(return_type, &result)?
+            }
+            Instruction::ExtractElement {
+                array,
+                ty,
+                index_val,
+            } => {
+                let elem_addr = self.element_addr(frame, array, ty, index_val)?;
+                let elem_ty = ty.get_elem_type(context).unwrap();
+                self.read_field(elem_addr, &elem_ty)?
+            }
+            Instruction::ExtractValue {
+                aggregate, indices, ..
+            } => {
+                let aggregate_ty = value_type(context, aggregate)?;
+                let aggregate_addr = self.eval(frame, aggregate)?;
+                let (field_offset, field_ty) = field_layout(context, &aggregate_ty, indices)?;
+                self.read_field(aggregate_addr + field_offset, &field_ty)?
+            }
+            Instruction::GetStorageKey => {
+                let state_idx = ins_val
+                    .get_storage_key(context)
+                    .ok_or_else(|| invalid("get_storage_key has no state index"))?;
+                let key = self.hooks.storage_key(state_idx);
+                let addr = self.alloc(32);
+                self.write_bytes(addr, &key)?;
+                addr
+            }
+            Instruction::GetPointer {
+                base_ptr,
+                ptr_ty,
+                offset,
+            } => {
+                let base_addr = frame
+                    .locals
+                    .get(base_ptr)
+                    .ok_or_else(|| invalid("get_ptr to a pointer which isn't a local"))?;
+                base_addr + offset * type_size(context, ptr_ty)
+            }
+            Instruction::InsertElement {
+                array,
+                ty,
+                value,
+                index_val,
+            } => {
+                let elem_addr = self.element_addr(frame, array, ty, index_val)?;
+                let elem_ty = ty.get_elem_type(context).unwrap();
+                let word = self.eval(frame, value)?;
+                self.write_field(elem_addr, &elem_ty, word)?;
+                self.eval(frame, array)?
+            }
+            Instruction::InsertValue {
+                aggregate,
+                value,
+                indices,
+                ..
+            } => {
+                let aggregate_ty = value_type(context, aggregate)?;
+                let aggregate_addr = self.eval(frame, aggregate)?;
+                let (mut field_offset, field_ty) = field_layout(context, &aggregate_ty, indices)?;
+
+                // A variant smaller than the union is padded on the left, as in the backend.
+                let value_ty = value_type(context, value)?;
+                if matches!(field_ty, Type::Union(_)) {
+                    field_offset +=
+                        type_size(context, &field_ty).saturating_sub(type_size(context, &value_ty));
+                }
+                let word = self.eval(frame, value)?;
+                self.write_field(aggregate_addr + field_offset, &value_ty, word)?;
+                aggregate_addr
+            }
+            Instruction::Load(src_val) => {
+                let addr = self.eval(frame, src_val)?;
+                let ty = ins
+                    .get_type(context)
+                    .ok_or_else(|| invalid("load has no type"))?;
+                self.read_field(addr, &ty)?
+            }
+            Instruction::Log {
+                log_val,
+                log_ty,
+                log_id,
+            } => {
+                let log_word = self.eval(frame, log_val)?;
+                let value = self.word_to_value(log_ty, log_word)?;
+                let log_id = self.eval(frame, log_id)?;
+                self.hooks.log(log_id, value);
+                return Ok(None);
+            }
+            Instruction::Nop => return Ok(None),
+            Instruction::ReadRegister(reg) => self.hooks.read_register(*reg)?,
+            Instruction::StateLoadQuadWord { load_val, key } => {
+                let key = self.eval_b256(frame, key)?;
+                let slot = self.hooks.load_slot(&key);
+                let addr = self.eval(frame, load_val)?;
+                self.write_bytes(addr, &slot)?;
+                return Ok(None);
+            }
+            Instruction::StateLoadWord(key) => {
+                let key = self.eval_b256(frame, key)?;
+                let slot = self.hooks.load_slot(&key);
+                u64::from_be_bytes(slot[..8].try_into().unwrap())
+            }
+            Instruction::StateStoreQuadWord { stored_val, key } => {
+                let key = self.eval_b256(frame, key)?;
+                let slot = self.eval_b256(frame, stored_val)?;
+                self.hooks.store_slot(&key, slot);
+                return Ok(None);
+            }
+            Instruction::StateStoreWord { stored_val, key } => {
+                // Like the VM, the word is written to the start of the slot and the rest cleared.
+                let key = self.eval_b256(frame, key)?;
+                let mut slot = [0; 32];
+                slot[..8].copy_from_slice(&self.eval(frame, stored_val)?.to_be_bytes());
+                self.hooks.store_slot(&key, slot);
+                return Ok(None);
+            }
+            Instruction::Store {
+                dst_val,
+                stored_val,
+            } => {
+                let ty = value_type(context, stored_val)?;
+                let addr = self.eval(frame, dst_val)?;
+                let word = self.eval(frame, stored_val)?;
+                self.write_field(addr, &ty, word)?;
+                return Ok(None);
+            }
+            Instruction::Branch(_)
+            | Instruction::ConditionalBranch { .. }
+            | Instruction::Phi(_)
+            | Instruction::Ret(..) => unreachable!("Handled by the caller."),
+        };
+        Ok(Some(word))
+    }
+
+    /// Return the word for `value`.  A constant of a non-copy type is written to fresh memory
+    /// each time, as the backend does.
+    fn eval(&mut self, frame: &Frame, value: &Value) -> Result<u64, InterpError> {
+        match &self.context.values[value.0].value {
+            ValueDatum::Constant(constant) => {
+                let value = InterpValue::from_constant(self.context, constant);
+                self.value_to_word(&constant.ty, &value)
+            }
+            ValueDatum::Argument(_) | ValueDatum::Instruction(_) => frame
+                .values
+                .get(value)
+                .copied()
+                .ok_or_else(|| invalid("value used before it's defined")),
+        }
+    }
+
+    fn eval_b256(&mut self, frame: &Frame, value: &Value) -> Result<[u8; 32], InterpError> {
+        let addr = self.eval(frame, value)?;
+        self.read_b256(addr)
+    }
+
+    /// Return the address of an array element, panicking if the index is out of bounds.
+    fn element_addr(
+        &mut self,
+        frame: &Frame,
+        array: &Value,
+        ty: &Aggregate,
+        index_val: &Value,
+    ) -> Result<u64, InterpError> {
+        let (elem_ty, count) = match &self.context.aggregates[ty.0] {
+            AggregateContent::ArrayType(elem_ty, count) => (*elem_ty, *count),
+            _otherwise => return Err(invalid("element access on a non-array")),
+        };
+        let index = self.eval(frame, index_val)?;
+        if index >= count {
+            return Err(InterpError::Panic(format!(
+                "index {index} out of bounds for array of {count}"
+            )));
+        }
+        let array_addr = self.eval(frame, array)?;
+        Ok(array_addr + index * type_size(self.context, &elem_ty))
+    }
+
+    /// Read the field of type `ty` at `addr`, returning its word.
+    fn read_field(&self, addr: u64, ty: &Type) -> Result<u64, InterpError> {
+        if ty.is_copy_type() {
+            self.read_word(addr)
+        } else {
+            Ok(addr)
+        }
+    }
+
+    /// Write `word`, of type `ty`, to the field at `addr`.
+    fn write_field(&mut self, addr: u64, ty: &Type, word: u64) -> Result<(), InterpError> {
+        if ty.is_copy_type() {
+            self.write_word(addr, word)
+        } else {
+            let size = type_size(self.context, ty);
+            let bytes = self.read_bytes(word, size)?.to_vec();
+            self.write_bytes(addr, &bytes)
+        }
+    }
+
+    /// Convert `value` to a word, writing it to fresh memory if `ty` isn't a copy type.
+    fn value_to_word(&mut self, ty: &Type, value: &InterpValue) -> Result<u64, InterpError> {
+        match (ty, value) {
+            (Type::Unit, InterpValue::Unit) => Ok(0),
+            (Type::Bool, InterpValue::Bool(b)) => Ok(*b as u64),
+            (Type::Uint(nbits), InterpValue::Uint(n)) if *nbits >= 64 || *n >> nbits == 0 => Ok(*n),
+            _otherwise if ty.is_copy_type() => Err(type_mismatch(self.context, ty, value)),
+            _otherwise => {
+                let addr = self.alloc(type_size(self.context, ty));
+                self.write_value(addr, ty, value)?;
+                Ok(addr)
+            }
+        }
+    }
+
+    /// Convert `word`, of type `ty`, to a value, reading it from memory if `ty` isn't a copy type.
+    fn word_to_value(&self, ty: &Type, word: u64) -> Result<InterpValue, InterpError> {
+        let context = self.context;
+        Ok(match ty {
+            Type::Unit => InterpValue::Unit,
+            Type::Bool => InterpValue::Bool(word != 0),
+            Type::Uint(_) => InterpValue::Uint(word),
+            Type::BigUint(nbits) => {
+                InterpValue::BigUint(self.read_bytes(word, *nbits as u64 / 8)?.to_vec())
+            }
+            Type::B256 => InterpValue::B256(self.read_b256(word)?),
+            Type::String(n) => InterpValue::String(self.read_bytes(word, *n)?.to_vec()),
+            Type::Array(aggregate) => {
+                let (elem_ty, count) = context.aggregates[aggregate.0].array_type();
+                let elem_size = type_size(context, elem_ty);
+                InterpValue::Array(
+                    (0..*count)
+                        .map(|idx| {
+                            let elem_word = self.read_field(word + idx * elem_size, elem_ty)?;
+                            self.word_to_value(elem_ty, elem_word)
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Type::Struct(aggregate) => {
+                let mut field_addr = word;
+                let mut fields = Vec::new();
+                for field_ty in context.aggregates[aggregate.0].field_types() {
+                    let field_word = self.read_field(field_addr, field_ty)?;
+                    fields.push(self.word_to_value(field_ty, field_word)?);
+                    field_addr += type_size(context, field_ty);
+                }
+                InterpValue::Struct(fields)
+            }
+            Type::Union(_) => {
+                InterpValue::Union(self.read_bytes(word, type_size(context, ty))?.to_vec())
+            }
+        })
+    }
+
+    /// Write `value`, of type `ty`, to memory at `addr`.
+    fn write_value(
+        &mut self,
+        addr: u64,
+        ty: &Type,
+        value: &InterpValue,
+    ) -> Result<(), InterpError> {
+        let context = self.context;
+        let size = type_size(context, ty);
+        match (ty, value) {
+            (Type::Unit | Type::Bool | Type::Uint(_), _) => {
+                let word = self.value_to_word(ty, value)?;
+                self.write_word(addr, word)
+            }
+            (Type::BigUint(_), InterpValue::BigUint(bytes))
+            | (Type::String(_), InterpValue::String(bytes))
+            | (Type::Union(_), InterpValue::Union(bytes))
+                if bytes.len() as u64 <= size =>
+            {
+                // Strings are padded to a whole number of words.
+                let mut padded = bytes.clone();
+                padded.resize(size as usize, 0);
+                self.write_bytes(addr, &padded)
+            }
+            (Type::B256, InterpValue::B256(bytes)) => self.write_bytes(addr, bytes),
+            (Type::Array(aggregate), InterpValue::Array(elems)) => {
+                let (elem_ty, count) = context.aggregates[aggregate.0].array_type();
+                if elems.len() as u64 != *count {
+                    return Err(type_mismatch(context, ty, value));
+                }
+                let elem_size = type_size(context, elem_ty);
+                for (idx, elem) in elems.iter().enumerate() {
+                    self.write_value(addr + idx as u64 * elem_size, elem_ty, elem)?;
+                }
+                Ok(())
+            }
+            (Type::Struct(aggregate), InterpValue::Struct(fields)) => {
+                let field_types = context.aggregates[aggregate.0].field_types();
+                if fields.len() != field_types.len() {
+                    return Err(type_mismatch(context, ty, value));
+                }
+                let mut field_addr = addr;
+                for (field_ty, field) in field_types.iter().zip(fields) {
+                    self.write_value(field_addr, field_ty, field)?;
+                    field_addr += type_size(context, field_ty);
+                }
+                Ok(())
+            }
+            _otherwise => Err(type_mismatch(context, ty, value)),
+        }
+    }
+
+    fn alloc(&mut self, size: u64) -> u64 {
+        // Keep everything word aligned, and give zero sized values a distinct address too.
+        let addr = self.memory.len() as u64;
+        let size = (size + (8 - size % 8) % 8).max(8);
+        self.memory.resize(self.memory.len() + size as usize, 0);
+        addr
+    }
+
+    fn read_bytes(&self, addr: u64, size: u64) -> Result<&[u8], InterpError> {
+        let start = addr as usize;
+        let end = start.saturating_add(size as usize);
+        if addr == 0 || end > self.memory.len() {
+            return Err(invalid(&format!(
+                "read of {size} bytes at bad address {addr}"
+            )));
+        }
+        Ok(&self.memory[start..end])
+    }
+
+    fn write_bytes(&mut self, addr: u64, bytes: &[u8]) -> Result<(), InterpError> {
+        let start = addr as usize;
+        let end = start.saturating_add(bytes.len());
+        if addr == 0 || end > self.memory.len() {
+            return Err(invalid(&format!(
+                "write of {} bytes at bad address {addr}",
+                bytes.len()
+            )));
+        }
+        self.memory[start..end].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    fn read_word(&self, addr: u64) -> Result<u64, InterpError> {
+        let bytes = self.read_bytes(addr, 8)?;
+        Ok(u64::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn write_word(&mut self, addr: u64, word: u64) -> Result<(), InterpError> {
+        self.write_bytes(addr, &word.to_be_bytes())
+    }
+
+    fn read_b256(&self, addr: u64) -> Result<[u8; 32], InterpError> {
+        Ok(self.read_bytes(addr, 32)?.try_into().unwrap())
+    }
+}
+
+fn invalid(reason: &str) -> InterpError {
+    InterpError::Invalid(reason.to_owned())
+}
+
+fn type_mismatch(context: &Context, ty: &Type, value: &InterpValue) -> InterpError {
+    InterpError::Invalid(format!(
+        "value {value} is not of type {}",
+        ty.as_string(context)
+    ))
+}
+
+fn value_type(context: &Context, value: &Value) -> Result<Type, InterpError> {
+    value
+        .get_type(context)
+        .ok_or_else(|| invalid("value has no type"))
+}
+
+/// The size of `ty` in memory, matching the backend.
+fn type_size(context: &Context, ty: &Type) -> u64 {
+    match ty {
+        Type::Unit | Type::Bool | Type::Uint(_) => 8,
+        Type::BigUint(nbits) => *nbits as u64 / 8,
+        Type::B256 => 32,
+        Type::String(n) => n + (8 - n % 8) % 8,
+        Type::Array(aggregate) => {
+            let (elem_ty, count) = context.aggregates[aggregate.0].array_type();
+            count * type_size(context, elem_ty)
+        }
+        Type::Struct(aggregate) => context.aggregates[aggregate.0]
+            .field_types()
+            .iter()
+            .map(|field_ty| type_size(context, field_ty))
+            .sum(),
+        Type::Union(aggregate) => context.aggregates[aggregate.0]
+            .field_types()
+            .iter()
+            .map(|field_ty| type_size(context, field_ty))
+            .max()
+            .unwrap_or(0),
+    }
+}
+
+/// The offset in bytes and the type of a (nested) field of `ty`.  Union variants are left padded
+/// to the size of the union.
+fn field_layout(context: &Context, ty: &Type, indices: &[u64]) -> Result<(u64, Type), InterpError> {
+    indices.iter().try_fold((0, *ty), |(offset, ty), idx| {
+        let field_types = match ty {
+            Type::Struct(aggregate) | Type::Union(aggregate) => {
+                context.aggregates[aggregate.0].field_types()
+            }
+            _otherwise => return Err(invalid("field access on a non-aggregate")),
+        };
+        let field_ty = *field_types
+            .get(*idx as usize)
+            .ok_or_else(|| invalid("field index out of range"))?;
+        let field_offset = match ty {
+            Type::Union(_) => type_size(context, &ty) - type_size(context, &field_ty),
+            _otherwise => field_types[..*idx as usize]
+                .iter()
+                .map(|field_ty| type_size(context, field_ty))
+                .sum(),
+        };
+        Ok((offset + field_offset, field_ty))
+    })
+}
+
+fn uint_binary_op(op: BinaryOpKind, lhs: u64, rhs: u64, nbits: u8) -> Result<u64, InterpError> {
+    let result = match op {
+        BinaryOpKind::Add => lhs.checked_add(rhs),
+        BinaryOpKind::Sub => lhs.checked_sub(rhs),
+        BinaryOpKind::Mul => lhs.checked_mul(rhs),
+        BinaryOpKind::Div => {
+            if rhs == 0 {
+                return Err(InterpError::Panic("division by zero".to_owned()));
+            }
+            Some(lhs / rhs)
+        }
+    };
+    match result {
+        Some(n) if nbits >= 64 || n >> nbits == 0 => Ok(n),
+        _otherwise => Err(InterpError::Panic(format!("u{nbits} {op:?} overflow"))),
+    }
+}
+
+/// Big-endian bytes as little-endian words.
+fn to_limbs(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .rchunks(8)
+        .map(|chunk| {
+            let mut word = [0; 8];
+            word[8 - chunk.len()..].copy_from_slice(chunk);
+            u64::from_be_bytes(word)
+        })
+        .collect()
+}
+
+fn from_limbs(limbs: &[u64], size: usize) -> Vec<u8> {
+    let bytes = limbs
+        .iter()
+        .rev()
+        .flat_map(|limb| limb.to_be_bytes())
+        .collect::<Vec<_>>();
+    bytes[bytes.len() - size..].to_vec()
+}
+
+fn big_uint_binary_op(op: BinaryOpKind, lhs: &[u8], rhs: &[u8]) -> Result<Vec<u8>, InterpError> {
+    let size = lhs.len();
+    let lhs = to_limbs(lhs);
+    let rhs = to_limbs(rhs);
+    let overflow = || InterpError::Panic(format!("u{} {op:?} overflow", size * 8));
+    let result = match op {
+        BinaryOpKind::Add => {
+            let (sum, carry) = limbs_add(&lhs, &rhs);
+            if carry {
+                return Err(overflow());
+            }
+            sum
+        }
+        BinaryOpKind::Sub => {
+            let (diff, borrow) = limbs_sub(&lhs, &rhs);
+            if borrow {
+                return Err(overflow());
+            }
+            diff
+        }
+        BinaryOpKind::Mul => {
+            let mut product = vec![0u64; lhs.len() * 2];
+            for (i, l) in lhs.iter().enumerate() {
+                let mut carry = 0u128;
+                for (j, r) in rhs.iter().enumerate() {
+                    let acc = product[i + j] as u128 + *l as u128 * *r as u128 + carry;
+                    product[i + j] = acc as u64;
+                    carry = acc >> 64;
+                }
+                product[i + rhs.len()] = carry as u64;
+            }
+            if product[lhs.len()..].iter().any(|limb| *limb != 0) {
+                return Err(overflow());
+            }
+            product.truncate(lhs.len());
+            product
+        }
+        BinaryOpKind::Div => {
+            if rhs.iter().all(|limb| *limb == 0) {
+                return Err(InterpError::Panic("division by zero".to_owned()));
+            }
+            // Shift and subtract, one bit at a time.
+            // The remainder has an extra limb so that shifting it never overflows.
+            let rhs = rhs.iter().copied().chain([0]).collect::<Vec<_>>();
+            let mut quotient = vec![0u64; lhs.len()];
+            let mut remainder = vec![0u64; lhs.len() + 1];
+            for bit in (0..lhs.len() * 64).rev() {
+                let mut carry = (lhs[bit / 64] >> (bit % 64)) & 1;
+                for limb in remainder.iter_mut() {
+                    let next_carry = *limb >> 63;
+                    *limb = (*limb << 1) | carry;
+                    carry = next_carry;
+                }
+                let (diff, borrow) = limbs_sub(&remainder, &rhs);
+                if !borrow {
+                    remainder = diff;
+                    quotient[bit / 64] |= 1 << (bit % 64);
+                }
+            }
+            quotient
+        }
+    };
+    Ok(from_limbs(&result, size))
+}
+
+fn limbs_add(lhs: &[u64], rhs: &[u64]) -> (Vec<u64>, bool) {
+    let mut carry = false;
+    let sum = lhs
+        .iter()
+        .zip(rhs)
+        .map(|(l, r)| {
+            let (sum, carry1) = l.overflowing_add(*r);
+            let (sum, carry2) = sum.overflowing_add(carry as u64);
+            carry = carry1 || carry2;
+            sum
+        })
+        .collect();
+    (sum, carry)
+}
+
+fn limbs_sub(lhs: &[u64], rhs: &[u64]) -> (Vec<u64>, bool) {
+    let mut borrow = false;
+    let diff = lhs
+        .iter()
+        .zip(rhs)
+        .map(|(l, r)| {
+            let (diff, borrow1) = l.overflowing_sub(*r);
+            let (diff, borrow2) = diff.overflowing_sub(borrow as u64);
+            borrow = borrow1 || borrow2;
+            diff
+        })
+        .collect();
+    (diff, borrow)
+}
@@ -3,6 +3,8 @@
 //! These generally mimic the Sway types with a couple of exceptions:
 //! - [`Type::Unit`] is still a discrete type rather than an empty tuple.  This may change in the
 //!   future.
+//! - [`Type::BigUint`] is an unsigned integer wider than a register, i.e., `u128` or `u256`.  It
+//!   is not a 'copy' type and is stored big-endian in memory, like a [`Type::B256`].
 //! - [`Type::Union`] is a sum type which resembles a C union.  Each member of the union uses the
 //!   same storage and the size of the union is the size of the largest member.
 //!
@@ -16,6 +18,7 @@ pub enum Type {
     Unit,
     Bool,
     Uint(u8),
+    BigUint(u16),
     B256,
     String(u64),
     Array(Aggregate),
@@ -44,6 +47,7 @@ impl Type {
             Type::Unit => "()".into(),
             Type::Bool => "bool".into(),
             Type::Uint(nbits) => format!("u{}", nbits),
+            Type::BigUint(nbits) => format!("u{}", nbits),
             Type::B256 => "b256".into(),
             Type::String(n) => format!("string<{}>", n),
             Type::Array(agg) => {
@@ -68,6 +72,7 @@ impl Type {
             (Type::Unit, Type::Unit) => true,
             (Type::Bool, Type::Bool) => true,
             (Type::Uint(l), Type::Uint(r)) => l == r,
+            (Type::BigUint(l), Type::BigUint(r)) => l == r,
             (Type::B256, Type::B256) => true,
             (Type::String(l), Type::String(r)) => l == r,
 
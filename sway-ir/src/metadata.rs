@@ -24,6 +24,13 @@ pub enum Metadatum {
         end: usize,
     },
 
+    /// A span within a function which has been inlined, and the span of the call it was inlined
+    /// at.  The call may itself be within an inlined function.
+    InlinedSpan {
+        span_idx: MetadataIndex,
+        inlined_at: MetadataIndex,
+    },
+
     /// A unique token for storage operations.
     StateIndex(usize),
 
@@ -31,7 +38,7 @@ pub enum Metadatum {
     StorageAttribute(StorageOperation),
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct MetadataIndex(pub generational_arena::Index);
 
 impl MetadataIndex {
@@ -62,8 +69,10 @@ impl MetadataIndex {
         })
     }
 
+    /// Return the span, which for an inlined span is the innermost one, in the inlined function.
     pub fn to_span(&self, context: &Context) -> Result<Span, IrError> {
         match &context.metadata[self.0] {
+            Metadatum::InlinedSpan { span_idx, .. } => span_idx.to_span(context),
             Metadatum::Span {
                 loc_idx,
                 start,
@@ -79,6 +88,98 @@ impl MetadataIndex {
         }
     }
 
+    /// Return the span followed by the spans of the calls it was inlined at, innermost first.
+    pub fn to_inlined_spans(&self, context: &Context) -> Result<Vec<Span>, IrError> {
+        let mut spans = Vec::new();
+        let mut md_idx = *self;
+        while let Metadatum::InlinedSpan {
+            span_idx,
+            inlined_at,
+        } = &context.metadata[md_idx.0]
+        {
+            spans.push(span_idx.to_span(context)?);
+            md_idx = *inlined_at;
+        }
+        spans.push(md_idx.to_span(context)?);
+        Ok(spans)
+    }
+
+    /// Return this span, from a function being inlined, as inlined at the call with the span
+    /// `call_md_idx`.  If this span has already been inlined from a further function then the
+    /// outermost call it was inlined at is the one which is now inlined at `call_md_idx`.
+    pub fn inline_at(&self, context: &mut Context, call_md_idx: MetadataIndex) -> MetadataIndex {
+        let (span_idx, inlined_at) = match context.metadata[self.0] {
+            Metadatum::InlinedSpan {
+                span_idx,
+                inlined_at,
+            } => (span_idx, inlined_at.inline_at(context, call_md_idx)),
+            _ => (*self, call_md_idx),
+        };
+        MetadataIndex(context.metadata.insert(Metadatum::InlinedSpan {
+            span_idx,
+            inlined_at,
+        }))
+    }
+
+    /// Merge the spans of two values which are being combined into one.  Spans in the same file,
+    /// and inlined at the same call if at all, are merged into one which covers both.  Otherwise
+    /// the first span is kept, or the second if there's no first.
+    pub fn merge_spans(
+        context: &mut Context,
+        md_idx: Option<MetadataIndex>,
+        other_md_idx: Option<MetadataIndex>,
+    ) -> Option<MetadataIndex> {
+        let (md_idx, other_md_idx) = match (md_idx, other_md_idx) {
+            (Some(md_idx), Some(other_md_idx)) if md_idx != other_md_idx => (md_idx, other_md_idx),
+            (None, other_md_idx) => return other_md_idx,
+            (md_idx, _) => return md_idx,
+        };
+
+        // Peel off the call each span is inlined at, if any.
+        let split_inlined = |md_idx: MetadataIndex| match &context.metadata[md_idx.0] {
+            Metadatum::InlinedSpan {
+                span_idx,
+                inlined_at,
+            } => (*span_idx, Some(*inlined_at)),
+            _otherwise => (md_idx, None),
+        };
+        let (span_idx, inlined_at) = split_inlined(md_idx);
+        let (other_span_idx, other_inlined_at) = split_inlined(other_md_idx);
+        if inlined_at != other_inlined_at {
+            return Some(md_idx);
+        }
+        let merged_span = match (
+            &context.metadata[span_idx.0],
+            &context.metadata[other_span_idx.0],
+        ) {
+            (
+                Metadatum::Span {
+                    loc_idx,
+                    start,
+                    end,
+                },
+                Metadatum::Span {
+                    loc_idx: other_loc_idx,
+                    start: other_start,
+                    end: other_end,
+                },
+            ) if loc_idx == other_loc_idx => Metadatum::Span {
+                loc_idx: *loc_idx,
+                start: *start.min(other_start),
+                end: *end.max(other_end),
+            },
+            _otherwise => return Some(md_idx),
+        };
+        let merged_idx = MetadataIndex(context.metadata.insert(merged_span));
+        Some(match inlined_at {
+            Some(inlined_at) => MetadataIndex(context.metadata.insert(Metadatum::InlinedSpan {
+                span_idx: merged_idx,
+                inlined_at,
+            })),
+            None => merged_idx,
+        })
+    }
+
     pub fn from_state_idx(context: &mut Context, state_idx: usize) -> Option<MetadataIndex> {
         Some(MetadataIndex(
             context.metadata.insert(Metadatum::StateIndex(state_idx)),
@@ -17,3 +17,13 @@ pub mod inline;
 pub use inline::*;
 pub mod constants;
 pub use constants::*;
+pub mod sroa;
+pub use sroa::*;
+pub mod mem2reg;
+pub use mem2reg::*;
+pub mod cse;
+pub use cse::*;
+pub mod storage;
+pub use storage::*;
+pub mod licm;
+pub use licm::*;
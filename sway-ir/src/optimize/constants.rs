@@ -10,6 +10,7 @@ use crate::{
     error::IrError,
     function::Function,
     instruction::Instruction,
+    metadata::MetadataIndex,
     value::{Value, ValueContent, ValueDatum},
 };
 
@@ -64,8 +65,15 @@ fn combine_const_insert_values(context: &mut Context, function: &Function) -> bo
     if let Some((block, ins_val, aggregate, const_val, indices)) = candidate {
         // OK, here we have an `insert_value` of a constant directly into a constant
         // aggregate.  We want to replace the constant aggregate with an updated one.
-        let new_aggregate =
-            combine_const_aggregate_field(context, function, aggregate, const_val, &indices);
+        let ins_span_md_idx = context.values[ins_val.0].span_md_idx;
+        let new_aggregate = combine_const_aggregate_field(
+            context,
+            function,
+            aggregate,
+            const_val,
+            &indices,
+            ins_span_md_idx,
+        );
 
         // Replace uses of the `insert_value` instruction with the new aggregate.
         function.replace_value(context, ins_val, new_aggregate, None);
@@ -87,6 +95,7 @@ fn combine_const_aggregate_field(
     aggregate: Value,
     const_value: Value,
     indices: &[u64],
+    ins_span_md_idx: Option<MetadataIndex>,
 ) -> Value {
     // Create a copy of the aggregate constant and inserted value.
     let (mut new_aggregate, span_md_idx) = match &context.values[aggregate.0] {
@@ -109,7 +118,9 @@ fn combine_const_aggregate_field(
     // Update the new aggregate with the constant field, based in the indices.
     inject_constant_into_aggregate(&mut new_aggregate, const_value, indices);
 
-    // Replace the old aggregate with the new aggregate.
+    // Replace the old aggregate with the new aggregate, which is now the result of both the
+    // declaration and the `insert_value`, so it covers both their spans.
+    let span_md_idx = MetadataIndex::merge_spans(context, span_md_idx, ins_span_md_idx);
     let new_aggregate_value = Value::new_constant(context, new_aggregate, span_md_idx);
     function.replace_value(context, aggregate, new_aggregate_value, None);
 
@@ -0,0 +1,296 @@
+//! Common subexpression elimination and load forwarding.
+//!
+//! An instruction which computes the same thing as another which dominates it is replaced by that
+//! other instruction.  This is done for the pure instructions -- arithmetic, comparisons, casts and
+//! `get_ptr` -- throughout the function.
+//!
+//! Instructions which read memory, i.e., `extract_value` and `extract_element`, are only replaced
+//! by an earlier one in the same block with no writes to memory between them.  Similarly a `load`
+//! from a local is replaced by the value most recently stored to or loaded from the same local in
+//! the block.  Calls, contract calls, ASM blocks and storage instructions are all barriers, after
+//! which nothing is known about memory.
+
+use std::collections::HashMap;
+
+use crate::{
+    analysis::DominatorTree,
+    block::Block,
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::{BinaryOpKind, Instruction, Predicate},
+    pointer::Pointer,
+    value::{Value, ValueDatum},
+};
+
+/// Remove the redundant instructions from `function`.
+pub fn cse(context: &mut Context, function: &Function) -> Result<bool, IrError> {
+    let blocks = function.block_iter(context).collect::<Vec<_>>();
+    let block_indices = blocks
+        .iter()
+        .enumerate()
+        .map(|(idx, block)| (*block, idx))
+        .collect::<HashMap<_, _>>();
+    let dom_tree = function.get_analysis::<DominatorTree>(context);
+
+    // Walk the dominator tree depth first, so that the expressions available in a block are those
+    // computed by the blocks which dominate it.
+    enum Visit {
+        Enter(Block),
+        Exit(Vec<ExprKey>),
+    }
+    let mut state = CseState {
+        block_indices,
+        available: HashMap::new(),
+        replacements: HashMap::new(),
+    };
+    let mut worklist = vec![Visit::Enter(blocks[0])];
+    while let Some(visit) = worklist.pop() {
+        match visit {
+            Visit::Enter(block) => {
+                let new_keys = state.visit_block(context, block);
+                worklist.push(Visit::Exit(new_keys));
+                for child in dom_tree.children(&block).iter().rev() {
+                    worklist.push(Visit::Enter(*child));
+                }
+            }
+            Visit::Exit(keys) => {
+                for key in keys {
+                    if let Some(values) = state.available.get_mut(&key) {
+                        values.pop();
+                    }
+                }
+            }
+        }
+    }
+
+    let replacements = state.replacements;
+    if replacements.is_empty() {
+        return Ok(false);
+    }
+    for block in &blocks {
+        // The kept instructions now stand for the removed ones too, so they take on their spans.
+        for ins_val in context.blocks[block.0].instructions.clone() {
+            if let Some(kept_val) = replacements.get(&ins_val) {
+                kept_val.merge_span_from(context, &ins_val);
+            }
+        }
+        context.blocks[block.0]
+            .instructions
+            .retain(|ins_val| !replacements.contains_key(ins_val));
+        for ins_val in context.blocks[block.0].instructions.clone() {
+            if let ValueDatum::Instruction(ins) = &mut context.values[ins_val.0].value {
+                ins.replace_values(&replacements);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// What an instruction computes, given its operands.  Types aren't included, so they're compared
+/// separately when looking for a match.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum ExprKey {
+    BinaryOp(BinaryOpKind, Value, Value),
+    BitCast(Value),
+    Cmp(Predicate, Value, Value),
+    ExtractElement(Value, Value),
+    ExtractValue(Value, Vec<u64>),
+    GetPointer(Pointer, u64),
+}
+
+struct CseState {
+    block_indices: HashMap<Block, usize>,
+    /// For each expression, the instructions which compute it in the blocks on the current path
+    /// through the dominator tree, along with the index of their block.
+    available: HashMap<ExprKey, Vec<(Value, usize)>>,
+    /// The redundant instructions and what they're to be replaced with.
+    replacements: HashMap<Value, Value>,
+}
+
+impl CseState {
+    /// Find the redundant instructions in `block`, returning the keys of the expressions it makes
+    /// available to the blocks it dominates.
+    fn visit_block(&mut self, context: &Context, block: Block) -> Vec<ExprKey> {
+        let block_idx = self.block_indices[&block];
+        let mut new_keys = Vec::new();
+
+        // The memory reads in this block, and the known contents of locals, since the last write.
+        let mut memory_reads: HashMap<ExprKey, Value> = HashMap::new();
+        let mut local_values: HashMap<Pointer, Value> = HashMap::new();
+
+        for ins_val in &context.blocks[block.0].instructions {
+            let ins_val = *ins_val;
+            let ins = match &context.values[ins_val.0].value {
+                ValueDatum::Instruction(ins) => ins,
+                _otherwise => continue,
+            };
+            let resolve = |value: &Value| *self.replacements.get(value).unwrap_or(value);
+            match ins {
+                Instruction::BinaryOp { op, arg1, arg2 } => {
+                    let key = ExprKey::BinaryOp(*op, resolve(arg1), resolve(arg2));
+                    self.find_available(context, key, ins_val, block_idx, &mut new_keys);
+                }
+                Instruction::BitCast(value, _) => {
+                    let key = ExprKey::BitCast(resolve(value));
+                    self.find_available(context, key, ins_val, block_idx, &mut new_keys);
+                }
+                Instruction::Cmp(pred, lhs_val, rhs_val) => {
+                    let key = ExprKey::Cmp(*pred, resolve(lhs_val), resolve(rhs_val));
+                    self.find_available(context, key, ins_val, block_idx, &mut new_keys);
+                }
+                Instruction::GetPointer {
+                    base_ptr, offset, ..
+                } => {
+                    let key = ExprKey::GetPointer(*base_ptr, *offset);
+                    self.find_available(context, key, ins_val, block_idx, &mut new_keys);
+                }
+
+                Instruction::ExtractElement {
+                    array, index_val, ..
+                } => {
+                    let key = ExprKey::ExtractElement(resolve(array), resolve(index_val));
+                    self.find_memory_read(context, key, ins_val, &mut memory_reads);
+                }
+                Instruction::ExtractValue {
+                    aggregate, indices, ..
+                } => {
+                    let key = ExprKey::ExtractValue(resolve(aggregate), indices.clone());
+                    self.find_memory_read(context, key, ins_val, &mut memory_reads);
+                }
+
+                Instruction::Load(src_val) => {
+                    if let Some(ptr) = copy_type_local(context, &resolve(src_val)) {
+                        match local_values.get(&ptr) {
+                            Some(value) => {
+                                self.replacements.insert(ins_val, *value);
+                            }
+                            None => {
+                                local_values.insert(ptr, ins_val);
+                            }
+                        }
+                    }
+                }
+                Instruction::Store {
+                    dst_val,
+                    stored_val,
+                } => {
+                    match copy_type_local(context, &resolve(dst_val)) {
+                        Some(ptr) => {
+                            local_values.insert(ptr, resolve(stored_val));
+                        }
+                        None if is_get_ptr(context, dst_val) => (),
+                        None => local_values.clear(),
+                    }
+                    memory_reads.clear();
+                }
+                Instruction::InsertElement { .. } | Instruction::InsertValue { .. } => {
+                    // These write to aggregates in memory, but never to a local of a copy type.
+                    memory_reads.clear();
+                }
+
+                Instruction::AsmBlock(..)
+                | Instruction::Call(..)
+                | Instruction::ContractCall { .. }
+                | Instruction::StateLoadQuadWord { .. }
+                | Instruction::StateLoadWord(_)
+                | Instruction::StateStoreQuadWord { .. }
+                | Instruction::StateStoreWord { .. } => {
+                    memory_reads.clear();
+                    local_values.clear();
+                }
+
+                Instruction::Branch(_)
+                | Instruction::ConditionalBranch { .. }
+                | Instruction::GetStorageKey
+                | Instruction::Log { .. }
+                | Instruction::Nop
+                | Instruction::Phi(_)
+                | Instruction::ReadRegister(_)
+                | Instruction::Ret(..) => (),
+            }
+        }
+        new_keys
+    }
+
+    /// Replace `ins_val` with an equivalent instruction from a dominating block if there is one,
+    /// or otherwise make it available to the blocks dominated by this one.
+    fn find_available(
+        &mut self,
+        context: &Context,
+        key: ExprKey,
+        ins_val: Value,
+        block_idx: usize,
+        new_keys: &mut Vec<ExprKey>,
+    ) {
+        // The backend compiles blocks in order, so the replacement must come from an earlier one.
+        let available_val = self.available.get(&key).and_then(|values| {
+            values
+                .iter()
+                .rev()
+                .find(|(value, value_block_idx)| {
+                    *value_block_idx <= block_idx && same_type(context, value, &ins_val)
+                })
+                .map(|(value, _)| *value)
+        });
+        match available_val {
+            Some(value) => {
+                self.replacements.insert(ins_val, value);
+            }
+            None => {
+                self.available
+                    .entry(key.clone())
+                    .or_default()
+                    .push((ins_val, block_idx));
+                new_keys.push(key);
+            }
+        }
+    }
+
+    /// Replace `ins_val` with an equivalent memory read from earlier in the block if there is one.
+    fn find_memory_read(
+        &mut self,
+        context: &Context,
+        key: ExprKey,
+        ins_val: Value,
+        memory_reads: &mut HashMap<ExprKey, Value>,
+    ) {
+        match memory_reads.get(&key) {
+            Some(value) if same_type(context, value, &ins_val) => {
+                self.replacements.insert(ins_val, *value);
+            }
+            _otherwise => {
+                memory_reads.insert(key, ins_val);
+            }
+        }
+    }
+}
+
+fn same_type(context: &Context, a: &Value, b: &Value) -> bool {
+    match (a.get_type(context), b.get_type(context)) {
+        (Some(a_ty), Some(b_ty)) => a_ty.eq(context, &b_ty),
+        (None, None) => true,
+        _otherwise => false,
+    }
+}
+
+fn is_get_ptr(context: &Context, value: &Value) -> bool {
+    matches!(
+        context.values[value.0].value,
+        ValueDatum::Instruction(Instruction::GetPointer { .. })
+    )
+}
+
+/// The local that `value` points to, if it's a `get_ptr` to the whole of a local of a copy type.
+/// Those locals can only be written by a `store` via such a `get_ptr`, or by an instruction which
+/// is passed its address.
+fn copy_type_local(context: &Context, value: &Value) -> Option<Pointer> {
+    match &context.values[value.0].value {
+        ValueDatum::Instruction(Instruction::GetPointer {
+            base_ptr,
+            offset: 0,
+            ..
+        }) if base_ptr.get_type(context).is_copy_type() => Some(*base_ptr),
+        _otherwise => None,
+    }
+}
@@ -99,6 +99,10 @@ pub fn inline_function_call(
     // the __get_storage_key intrinsic
     let state_idx_md_idx = context.values[call_site.0].state_idx_md_idx;
 
+    // Get the span of the call too.  Each inlined instruction has its span marked as inlined at
+    // this call, so the source map can show the call stack.
+    let call_span_md_idx = context.values[call_site.0].span_md_idx;
+
     // Now remove the call altogether.
     context.values.remove(call_site.0);
 
@@ -135,6 +139,7 @@ pub fn inline_function_call(
     // as we add new instructions which replace the old ones to it too.
     //
     // Note: inline_instruction() doesn't translate `phi` instructions here.
+    let mut inlined_span_map = HashMap::new();
     let inlined_blocks = context.functions[inlined_function.0].blocks.clone();
     for block in &inlined_blocks {
         for ins in context.blocks[block.0].instructions.clone() {
@@ -147,6 +152,8 @@ pub fn inline_function_call(
                 &mut value_map,
                 &ptr_map,
                 state_idx_md_idx,
+                call_span_md_idx,
+                &mut inlined_span_map,
             );
         }
     }
@@ -183,6 +190,8 @@ fn inline_instruction(
     value_map: &mut HashMap<Value, Value>,
     ptr_map: &HashMap<Pointer, Pointer>,
     state_idx_md_idx: Option<MetadataIndex>,
+    call_span_md_idx: Option<MetadataIndex>,
+    inlined_span_map: &mut HashMap<MetadataIndex, MetadataIndex>,
 ) {
     // Util to translate old blocks to new.  If an old block isn't in the map then we panic, since
     // it should be guaranteed to be there...that's a bug otherwise.
@@ -207,6 +216,17 @@ fn inline_instruction(
         ..
     } = context.values[instruction.0].clone()
     {
+        // Mark the span as inlined at the call, if both are known.  Many instructions will share
+        // the same span so the new spans are cached.
+        let span_md_idx = match (span_md_idx, call_span_md_idx) {
+            (Some(span_md_idx), Some(call_span_md_idx)) => Some(
+                *inlined_span_map
+                    .entry(span_md_idx)
+                    .or_insert_with(|| span_md_idx.inline_at(context, call_span_md_idx)),
+            ),
+            (span_md_idx, _) => span_md_idx,
+        };
+
         let new_ins = match old_ins {
             Instruction::AsmBlock(asm, args) => {
                 let new_args = args
@@ -243,6 +263,11 @@ fn inline_instruction(
                 span_md_idx,
                 current_state_idx_md_idx,
             ),
+            Instruction::BinaryOp { op, arg1, arg2 } => {
+                new_block
+                    .ins(context)
+                    .binary_op(op, map_value(arg1), map_value(arg2), span_md_idx)
+            }
             Instruction::Cmp(pred, lhs_value, rhs_value) => new_block.ins(context).cmp(
                 pred,
                 map_value(lhs_value),
@@ -332,6 +357,16 @@ fn inline_instruction(
             Instruction::Load(src_val) => {
                 new_block.ins(context).load(map_value(src_val), span_md_idx)
             }
+            Instruction::Log {
+                log_val,
+                log_ty,
+                log_id,
+            } => new_block.ins(context).log(
+                map_value(log_val),
+                log_ty,
+                map_value(log_id),
+                span_md_idx,
+            ),
             Instruction::Nop => new_block.ins(context).nop(),
             Instruction::ReadRegister(reg) => {
                 new_block.ins(context).read_register(reg, span_md_idx)
@@ -0,0 +1,338 @@
+//! Loop-invariant code motion.
+//!
+//! An instruction in a loop whose operands are all defined outside the loop, or are themselves
+//! loop-invariant, computes the same thing on every iteration.  It's moved to the loop's
+//! preheader so that it's computed only once, and a preheader is created for a loop without one.
+//!
+//! The pure instructions -- comparisons, casts and `get_ptr` -- are always moved.  Arithmetic may
+//! revert on overflow or division by zero, so it's only moved from blocks which are executed
+//! whenever the loop is, i.e., those which dominate every block with a branch out of the loop.
+//!
+//! A `store` of a constant to a local is also moved if every write to the local is a store of the
+//! same constant.  These are typically the keys stored to a local before each storage access.
+//!
+//! Memory reads and instructions with side effects are left where they are.  Loops are visited
+//! innermost first, so an instruction may be moved out of several loops.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    analysis::{predecessors, DefUse, DominatorTree, Loop, LoopNest},
+    block::Block,
+    constant::{Constant, ConstantValue},
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::Instruction,
+    pointer::Pointer,
+    value::{Value, ValueDatum},
+};
+
+use super::storage::whole_local;
+
+/// Move the loop-invariant instructions in `function` out of their loops.  At most `max_hoisted`
+/// instructions which produce a value are moved out of each loop, as each will then hold a
+/// register for the whole of the loop.
+pub fn licm(
+    context: &mut Context,
+    function: &Function,
+    max_hoisted: usize,
+) -> Result<bool, IrError> {
+    let constant_locals = constant_locals(context, function);
+    let mut modified = false;
+
+    // Give each loop with something to move a preheader first, starting over after each as the
+    // loop nest changes.  A loop headed by the entry block can't have one.
+    let entry_block = function.get_entry_block(context);
+    'restart: loop {
+        let loop_nest = function.get_analysis::<LoopNest>(context);
+        for (_loop_id, a_loop) in loop_nest.loops() {
+            if a_loop.preheader().is_none()
+                && a_loop.header() != entry_block
+                && !find_invariants(context, function, a_loop, &constant_locals, max_hoisted)
+                    .is_empty()
+            {
+                create_preheader(context, function, a_loop)?;
+                modified = true;
+                continue 'restart;
+            }
+        }
+        break;
+    }
+
+    let loop_nest = function.get_analysis::<LoopNest>(context);
+    let loops = loop_nest.loops().collect::<Vec<_>>();
+    for (_loop_id, a_loop) in loops.into_iter().rev() {
+        let preheader = match a_loop.preheader() {
+            Some(preheader) if is_before(context, function, &preheader, &a_loop.header()) => {
+                preheader
+            }
+            _otherwise => continue,
+        };
+        let invariants = find_invariants(context, function, a_loop, &constant_locals, max_hoisted);
+        if invariants.is_empty() {
+            continue;
+        }
+        for block in a_loop.blocks() {
+            context.blocks[block.0]
+                .instructions
+                .retain(|ins_val| !invariants.contains(ins_val));
+        }
+        let preheader_instructions = &mut context.blocks[preheader.0].instructions;
+        let term_val = preheader_instructions.pop().unwrap();
+        preheader_instructions.extend(invariants);
+        preheader_instructions.push(term_val);
+        modified = true;
+    }
+    Ok(modified)
+}
+
+/// The instructions which can be moved out of `a_loop`, in the order they're to be moved in.
+fn find_invariants(
+    context: &Context,
+    function: &Function,
+    a_loop: &Loop,
+    constant_locals: &HashMap<Pointer, [u8; 32]>,
+    max_hoisted: usize,
+) -> Vec<Value> {
+    // The backend compiles blocks in order, so the header must be the first block in the loop and
+    // anything moved before it may only use values from earlier blocks.
+    let header = a_loop.header();
+    if a_loop.blocks()[0] != header {
+        return Vec::new();
+    }
+    let block_indices = function
+        .block_iter(context)
+        .enumerate()
+        .map(|(idx, block)| (block, idx))
+        .collect::<HashMap<_, _>>();
+    let def_use = function.get_analysis::<DefUse>(context);
+    let dom_tree = function.get_analysis::<DominatorTree>(context);
+    let is_available_before_loop = |value: &Value| match def_use.def_block(value) {
+        Some(def_block) => {
+            !a_loop.contains(&def_block) && block_indices[&def_block] < block_indices[&header]
+        }
+        None => true,
+    };
+
+    let exiting_blocks = a_loop
+        .blocks()
+        .iter()
+        .filter(|block| {
+            block
+                .successors(context)
+                .iter()
+                .any(|succ| !a_loop.contains(succ))
+        })
+        .collect::<Vec<_>>();
+    let is_always_executed = |block: &Block| {
+        exiting_blocks
+            .iter()
+            .all(|exiting_block| dom_tree.dominates(block, exiting_block))
+    };
+
+    // Visit the blocks in dominator order so that operands are seen before their users.
+    let mut invariants = Vec::new();
+    let mut invariant_set = HashSet::new();
+    let mut num_values = 0;
+    for block in dom_tree
+        .blocks()
+        .iter()
+        .filter(|block| a_loop.contains(block))
+    {
+        for ins_val in &context.blocks[block.0].instructions {
+            let ins = match &context.values[ins_val.0].value {
+                ValueDatum::Instruction(ins) => ins,
+                _otherwise => continue,
+            };
+            let is_invariant = ins
+                .get_operands()
+                .iter()
+                .all(|value| invariant_set.contains(value) || is_available_before_loop(value));
+            if !is_invariant {
+                continue;
+            }
+            let is_movable = match ins {
+                Instruction::BitCast(..)
+                | Instruction::Cmp(..)
+                | Instruction::GetPointer { .. } => num_values < max_hoisted,
+                Instruction::BinaryOp { .. } => {
+                    num_values < max_hoisted && is_always_executed(block)
+                }
+                Instruction::Store {
+                    dst_val,
+                    stored_val,
+                } => is_constant_local_store(context, constant_locals, dst_val, stored_val),
+                _otherwise => false,
+            };
+            if is_movable {
+                if !matches!(ins, Instruction::Store { .. }) {
+                    num_values += 1;
+                }
+                invariants.push(*ins_val);
+                invariant_set.insert(*ins_val);
+            }
+        }
+    }
+    invariants
+}
+
+/// Create a preheader for `a_loop`, which the blocks outside the loop branch to instead of the
+/// header.  The values they pass to the header's phi are merged in the preheader's phi.
+fn create_preheader(
+    context: &mut Context,
+    function: &Function,
+    a_loop: &Loop,
+) -> Result<Block, IrError> {
+    let header = a_loop.header();
+    let outside_preds = predecessors(context, function)[&header]
+        .iter()
+        .filter(|pred| !a_loop.contains(pred))
+        .copied()
+        .collect::<Vec<_>>();
+    let label = format!("{}_preheader", header.get_label(context));
+    let preheader = function.create_block_before(context, &header, Some(label))?;
+
+    for pred in &outside_preds {
+        let term_val = *context.blocks[pred.0].instructions.last().unwrap();
+        match &mut context.values[term_val.0].value {
+            ValueDatum::Instruction(Instruction::Branch(to_block)) => *to_block = preheader,
+            ValueDatum::Instruction(Instruction::ConditionalBranch {
+                true_block,
+                false_block,
+                ..
+            }) => {
+                for to_block in [true_block, false_block] {
+                    if *to_block == header {
+                        *to_block = preheader;
+                    }
+                }
+            }
+            _otherwise => unreachable!("Predecessor must be terminated by a branch."),
+        }
+    }
+
+    // The header's phi is typed by its first value, so the value from the preheader goes first
+    // as the values from inside the loop may depend on the phi itself.
+    let phi_val = header.get_phi(context);
+    let (outside_pairs, inside_pairs) = match &context.values[phi_val.0].value {
+        ValueDatum::Instruction(Instruction::Phi(pairs)) => pairs
+            .iter()
+            .partition::<Vec<_>, _>(|(from_block, _)| outside_preds.contains(from_block)),
+        _otherwise => unreachable!("Phi value must be a PHI instruction."),
+    };
+    let incoming_val = match outside_pairs.first() {
+        Some((_, first_val)) if outside_pairs.iter().all(|(_, value)| value == first_val) => {
+            Some(*first_val)
+        }
+        Some(_) => {
+            for (from_block, value) in outside_pairs {
+                preheader.add_phi(context, from_block, value);
+            }
+            Some(preheader.get_phi(context))
+        }
+        None => None,
+    };
+    if let ValueDatum::Instruction(Instruction::Phi(pairs)) = &mut context.values[phi_val.0].value {
+        *pairs = incoming_val
+            .map(|value| (preheader, value))
+            .into_iter()
+            .chain(inside_pairs)
+            .collect();
+    }
+    preheader.ins(context).branch(header, None, None);
+    Ok(preheader)
+}
+
+fn is_before(context: &Context, function: &Function, a: &Block, b: &Block) -> bool {
+    function
+        .block_iter(context)
+        .find(|block| block == a || block == b)
+        .map_or(false, |block| block == *a)
+}
+
+fn is_constant_local_store(
+    context: &Context,
+    constant_locals: &HashMap<Pointer, [u8; 32]>,
+    dst_val: &Value,
+    stored_val: &Value,
+) -> bool {
+    match (
+        whole_local(context, dst_val),
+        &context.values[stored_val.0].value,
+    ) {
+        (
+            Some(ptr),
+            ValueDatum::Constant(Constant {
+                value: ConstantValue::B256(bytes),
+                ..
+            }),
+        ) => constant_locals.get(&ptr) == Some(bytes),
+        _otherwise => false,
+    }
+}
+
+/// The `b256` locals in `function` which are only ever written by storing the same constant to
+/// them, and whose address doesn't escape, along with that constant.
+fn constant_locals(context: &Context, function: &Function) -> HashMap<Pointer, [u8; 32]> {
+    let mut constants: HashMap<Pointer, [u8; 32]> = HashMap::new();
+    let mut unknown = HashSet::new();
+    for (_ptr_name, ptr) in function.locals_iter(context) {
+        if context.pointers[ptr.0].initializer.is_some() {
+            unknown.insert(*ptr);
+        }
+    }
+
+    for (_block, ins_val) in function.instruction_iter(context) {
+        let ins = match &context.values[ins_val.0].value {
+            ValueDatum::Instruction(ins) => ins,
+            _otherwise => continue,
+        };
+        let escaping_vals = match ins {
+            Instruction::Store {
+                dst_val,
+                stored_val,
+            } => {
+                match (
+                    whole_local(context, dst_val),
+                    &context.values[stored_val.0].value,
+                ) {
+                    (
+                        Some(ptr),
+                        ValueDatum::Constant(Constant {
+                            value: ConstantValue::B256(bytes),
+                            ..
+                        }),
+                    ) => {
+                        if *constants.entry(ptr).or_insert(*bytes) != *bytes {
+                            unknown.insert(ptr);
+                        }
+                        vec![*stored_val]
+                    }
+                    _otherwise => vec![*dst_val, *stored_val],
+                }
+            }
+            Instruction::StateLoadQuadWord { load_val, .. } => vec![*load_val],
+            Instruction::ExtractElement { .. }
+            | Instruction::ExtractValue { .. }
+            | Instruction::GetPointer { .. }
+            | Instruction::Load(_)
+            | Instruction::StateLoadWord(_)
+            | Instruction::StateStoreQuadWord { .. }
+            | Instruction::StateStoreWord { .. } => Vec::new(),
+            _otherwise => ins.get_operands(),
+        };
+        for value in escaping_vals {
+            if let ValueDatum::Instruction(Instruction::GetPointer { base_ptr, .. }) =
+                &context.values[value.0].value
+            {
+                unknown.insert(*base_ptr);
+            }
+        }
+    }
+
+    constants
+        .into_iter()
+        .filter(|(ptr, _)| !unknown.contains(ptr))
+        .collect()
+}
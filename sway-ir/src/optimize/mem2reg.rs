@@ -0,0 +1,475 @@
+//! Promotion of local variables to registers.
+//!
+//! A local of a copy type which is only ever read by `load` and written by `store` doesn't need
+//! to live in memory.  Each `load` is replaced by the value most recently stored to the local, and
+//! where different values reach a block from its predecessors they're merged with the block's phi.
+//!
+//! e.g., here `x` is a candidate:
+//!
+//! ```text
+//! local mut ptr u64 x
+//!
+//! entry:
+//! v0 = get_ptr mut ptr u64 x, ptr u64, 0
+//! store v1, ptr v0
+//! br block0
+//!
+//! block0:
+//! v2 = get_ptr mut ptr u64 x, ptr u64, 0
+//! v3 = load ptr v2
+//! ret u64 v3
+//! ```
+//!
+//! and so becomes:
+//!
+//! ```text
+//! entry:
+//! br block0
+//!
+//! block0:
+//! ret u64 v1
+//! ```
+//!
+//! Blocks have only a single phi, so a merge is only possible at a block whose phi is otherwise
+//! unused, and only where each predecessor reaches it with an unconditional branch.  Any local
+//! which would need more than that is left alone.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    block::Block,
+    constant::{Constant, ConstantValue},
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::Instruction,
+    irtype::Type,
+    pointer::Pointer,
+    value::{Value, ValueDatum},
+};
+
+/// Promote up to `max_locals` of the eligible locals in `function` to registers, preferring the
+/// most frequently accessed.
+pub fn mem2reg(
+    context: &mut Context,
+    function: &Function,
+    max_locals: usize,
+) -> Result<bool, IrError> {
+    let cfg = FunctionCfg::new(context, function);
+    if !cfg.preds[&cfg.blocks[0]].is_empty() {
+        // There'd be no way to give locals their initial value on entry.
+        return Ok(false);
+    }
+
+    let mut candidates = function
+        .locals_iter(context)
+        .filter_map(|(name, ptr)| {
+            promotable_accesses(context, ptr, &cfg)
+                .map(|(get_ptrs, num_accesses)| (name.clone(), *ptr, get_ptrs, num_accesses))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_by(|(name_a, _, _, count_a), (name_b, _, _, count_b)| {
+        count_b.cmp(count_a).then_with(|| name_a.cmp(name_b))
+    });
+
+    let mut claimed_phis = HashSet::new();
+    let mut num_promoted = 0;
+    for (name, ptr, get_ptrs, _) in candidates {
+        if num_promoted == max_locals {
+            break;
+        }
+        if promote_local(
+            context,
+            function,
+            &cfg,
+            &mut claimed_phis,
+            name,
+            ptr,
+            get_ptrs,
+        ) {
+            num_promoted += 1;
+        }
+    }
+    Ok(num_promoted > 0)
+}
+
+/// The control flow graph of a function along with where each of its instructions are.
+struct FunctionCfg {
+    /// Each block, in the order they're in the function, which is also the order they're compiled.
+    blocks: Vec<Block>,
+    block_indices: HashMap<Block, usize>,
+    preds: HashMap<Block, Vec<Block>>,
+    users: HashMap<Value, Vec<Value>>,
+    get_ptrs: HashMap<Pointer, Vec<Value>>,
+    instruction_blocks: HashMap<Value, Block>,
+}
+
+impl FunctionCfg {
+    fn new(context: &Context, function: &Function) -> FunctionCfg {
+        let blocks = function.block_iter(context).collect::<Vec<_>>();
+        let block_indices = blocks
+            .iter()
+            .enumerate()
+            .map(|(idx, block)| (*block, idx))
+            .collect();
+        let mut preds: HashMap<Block, Vec<Block>> =
+            blocks.iter().map(|block| (*block, Vec::new())).collect();
+        for block in &blocks {
+            for succ in block.successors(context) {
+                preds.entry(succ).or_default().push(*block);
+            }
+        }
+
+        let mut users: HashMap<Value, Vec<Value>> = HashMap::new();
+        let mut get_ptrs: HashMap<Pointer, Vec<Value>> = HashMap::new();
+        let mut instruction_blocks = HashMap::new();
+        for (block, ins_val) in function.instruction_iter(context) {
+            if let ValueDatum::Instruction(ins) = &context.values[ins_val.0].value {
+                for operand in ins.get_operands() {
+                    users.entry(operand).or_default().push(ins_val);
+                }
+                if let Instruction::GetPointer { base_ptr, .. } = ins {
+                    get_ptrs.entry(*base_ptr).or_default().push(ins_val);
+                }
+            }
+            instruction_blocks.insert(ins_val, block);
+        }
+
+        FunctionCfg {
+            blocks,
+            block_indices,
+            preds,
+            users,
+            get_ptrs,
+            instruction_blocks,
+        }
+    }
+
+    /// The index of the block which defines `value`, if it's an instruction.
+    fn def_block_index(&self, value: &Value) -> Option<usize> {
+        self.instruction_blocks
+            .get(value)
+            .map(|block| self.block_indices[block])
+    }
+}
+
+/// Return the `get_ptr` instructions for `ptr` and the number of times it's accessed, if it's a
+/// copy type and only ever loaded from or stored to.
+fn promotable_accesses(
+    context: &Context,
+    ptr: &Pointer,
+    cfg: &FunctionCfg,
+) -> Option<(HashSet<Value>, usize)> {
+    let ty = ptr.get_type(context);
+    if !ty.is_copy_type() {
+        return None;
+    }
+
+    let mut get_ptrs = HashSet::new();
+    let mut num_accesses = 0;
+    for get_ptr_val in cfg.get_ptrs.get(ptr).into_iter().flatten() {
+        match &context.values[get_ptr_val.0].value {
+            ValueDatum::Instruction(Instruction::GetPointer {
+                ptr_ty, offset: 0, ..
+            }) if ptr_ty.eq(context, ty) => (),
+            _otherwise => return None,
+        }
+        for user in cfg.users.get(get_ptr_val).into_iter().flatten() {
+            let is_access = match &context.values[user.0].value {
+                ValueDatum::Instruction(Instruction::Load(src_val)) => src_val == get_ptr_val,
+                ValueDatum::Instruction(Instruction::Store {
+                    dst_val,
+                    stored_val,
+                }) => dst_val == get_ptr_val && stored_val != get_ptr_val,
+                _otherwise => false,
+            };
+            if !is_access {
+                return None;
+            }
+            num_accesses += 1;
+        }
+        get_ptrs.insert(*get_ptr_val);
+    }
+    Some((get_ptrs, num_accesses))
+}
+
+/// The definition of a local which reaches some point in the function.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Def {
+    /// The value stored by a `store`.
+    Value(Value),
+    /// The merge of the definitions reaching a block from its predecessors.
+    Phi(Block),
+    /// The local's initialiser, or zero if it has none.
+    Initial,
+}
+
+/// Replace every access to the local `ptr` with the values it holds.  Returns false, leaving the
+/// function untouched, if the local would need a phi which isn't available.
+fn promote_local(
+    context: &mut Context,
+    function: &Function,
+    cfg: &FunctionCfg,
+    claimed_phis: &mut HashSet<Block>,
+    name: String,
+    ptr: Pointer,
+    get_ptrs: HashSet<Value>,
+) -> bool {
+    // Gather the loads and stores in each block, in order.
+    let mut block_accesses: HashMap<Block, Vec<(Value, Option<Value>)>> = HashMap::new();
+    for block in &cfg.blocks {
+        for ins_val in &context.blocks[block.0].instructions {
+            let access = match &context.values[ins_val.0].value {
+                ValueDatum::Instruction(Instruction::Load(src_val))
+                    if get_ptrs.contains(src_val) =>
+                {
+                    (*ins_val, None)
+                }
+                ValueDatum::Instruction(Instruction::Store {
+                    dst_val,
+                    stored_val,
+                }) if get_ptrs.contains(dst_val) => (*ins_val, Some(*stored_val)),
+                _otherwise => continue,
+            };
+            block_accesses.entry(*block).or_default().push(access);
+        }
+    }
+    let out_stores = block_accesses
+        .iter()
+        .filter_map(|(block, accesses)| {
+            accesses
+                .iter()
+                .rev()
+                .find_map(|(_, stored_val)| *stored_val)
+                .map(|stored_val| (*block, stored_val))
+        })
+        .collect::<HashMap<_, _>>();
+
+    // The definition reaching the start of a block.  A block with a single predecessor inherits
+    // whatever leaves it, one with several needs a phi.
+    let in_def = |block: Block| {
+        let mut visited = HashSet::new();
+        let mut block = block;
+        loop {
+            let preds = &cfg.preds[&block];
+            if block == cfg.blocks[0] || preds.is_empty() || !visited.insert(block) {
+                return Def::Initial;
+            }
+            if preds.len() > 1 {
+                return Def::Phi(block);
+            }
+            block = preds[0];
+            if let Some(stored_val) = out_stores.get(&block) {
+                return Def::Value(*stored_val);
+            }
+        }
+    };
+    let out_def = |block: Block| match out_stores.get(&block) {
+        Some(stored_val) => Def::Value(*stored_val),
+        None => in_def(block),
+    };
+
+    // Find the definition reaching each load, and the operands of each phi needed along the way.
+    let mut load_defs = HashMap::new();
+    let mut phi_operands: HashMap<Block, Vec<(Block, Def)>> = HashMap::new();
+    let mut phi_worklist = Vec::new();
+    for (block, accesses) in &block_accesses {
+        let mut def = in_def(*block);
+        for (ins_val, stored_val) in accesses {
+            match stored_val {
+                Some(stored_val) => def = Def::Value(*stored_val),
+                None => {
+                    load_defs.insert(*ins_val, def);
+                    if let Def::Phi(phi_block) = def {
+                        phi_worklist.push(phi_block);
+                    }
+                }
+            }
+        }
+    }
+    while let Some(phi_block) = phi_worklist.pop() {
+        if phi_operands.contains_key(&phi_block) {
+            continue;
+        }
+        let operands = cfg.preds[&phi_block]
+            .iter()
+            .map(|pred| (*pred, out_def(*pred)))
+            .collect::<Vec<_>>();
+        for (_, def) in &operands {
+            if let Def::Phi(operand_block) = def {
+                phi_worklist.push(*operand_block);
+            }
+        }
+        phi_operands.insert(phi_block, operands);
+    }
+
+    // A phi which merges only a single definition, other than itself, is just that definition.
+    let mut phi_subst = HashMap::new();
+    let resolve = |phi_subst: &HashMap<Block, Def>, mut def: Def| {
+        while let Def::Phi(phi_block) = def {
+            match phi_subst.get(&phi_block) {
+                Some(subst_def) => def = *subst_def,
+                None => break,
+            }
+        }
+        def
+    };
+    loop {
+        let mut modified = false;
+        for (phi_block, operands) in &phi_operands {
+            if phi_subst.contains_key(phi_block) {
+                continue;
+            }
+            let distinct_defs = operands
+                .iter()
+                .map(|(_, def)| resolve(&phi_subst, *def))
+                .filter(|def| *def != Def::Phi(*phi_block))
+                .collect::<HashSet<_>>();
+            if distinct_defs.len() <= 1 {
+                let def = distinct_defs.into_iter().next().unwrap_or(Def::Initial);
+                phi_subst.insert(*phi_block, def);
+                modified = true;
+            }
+        }
+        if !modified {
+            break;
+        }
+    }
+    let load_defs = load_defs
+        .into_iter()
+        .map(|(load_val, def)| (load_val, resolve(&phi_subst, def)))
+        .collect::<HashMap<_, _>>();
+
+    // Only the phis which are still reachable from a load are needed.
+    let mut live_phis = HashSet::new();
+    let mut phi_worklist = load_defs
+        .values()
+        .filter_map(|def| match def {
+            Def::Phi(phi_block) => Some(*phi_block),
+            _otherwise => None,
+        })
+        .collect::<Vec<_>>();
+    while let Some(phi_block) = phi_worklist.pop() {
+        if live_phis.insert(phi_block) {
+            for (_, def) in &phi_operands[&phi_block] {
+                if let Def::Phi(operand_block) = resolve(&phi_subst, *def) {
+                    phi_worklist.push(operand_block);
+                }
+            }
+        }
+    }
+
+    // Each of those phis must be otherwise unused and reachable only via unconditional branches,
+    // as the backend would copy the value to the phi for every target of a conditional branch.
+    for phi_block in &live_phis {
+        let phi_val = phi_block.get_phi(context);
+        let phi_is_free = match &context.values[phi_val.0].value {
+            ValueDatum::Instruction(Instruction::Phi(pairs)) => {
+                pairs.is_empty() || !cfg.users.contains_key(&phi_val)
+            }
+            _otherwise => false,
+        };
+        if !phi_is_free
+            || claimed_phis.contains(phi_block)
+            || cfg.preds[phi_block]
+                .iter()
+                .any(|pred| !matches!(pred.get_term_inst(context), Some(Instruction::Branch(_))))
+        {
+            return false;
+        }
+    }
+
+    // Turn the definitions into values.  A load may find the result of another load which is
+    // itself being replaced, so chase those too.
+    let initializer = context.pointers[ptr.0]
+        .initializer
+        .clone()
+        .filter(|init| !matches!(init.value, ConstantValue::Undef));
+    let initial_val = match initializer {
+        Some(init) => Value::new_constant(context, init, None),
+        None => match *ptr.get_type(context) {
+            Type::Unit => Constant::get_unit(context, None),
+            Type::Bool => Constant::get_bool(context, false, None),
+            Type::Uint(nbits) => Constant::get_uint(context, nbits, 0, None),
+            _otherwise => unreachable!("Only copy types are promoted."),
+        },
+    };
+    let def_value = |context: &Context, def: Def| match def {
+        Def::Value(value) => value,
+        Def::Phi(phi_block) => phi_block.get_phi(context),
+        Def::Initial => initial_val,
+    };
+    let direct_values = load_defs
+        .iter()
+        .map(|(load_val, def)| (*load_val, def_value(context, *def)))
+        .collect::<HashMap<_, _>>();
+    let chase = |mut value: Value| {
+        for _ in 0..=direct_values.len() {
+            match direct_values.get(&value) {
+                Some(next_value) => value = *next_value,
+                None => break,
+            }
+        }
+        value
+    };
+    let replace_map = direct_values
+        .keys()
+        .map(|load_val| (*load_val, chase(*load_val)))
+        .collect::<HashMap<_, _>>();
+    let live_phi_pairs = live_phis
+        .iter()
+        .map(|phi_block| {
+            let pairs = phi_operands[phi_block]
+                .iter()
+                .map(|(pred, def)| (*pred, chase(def_value(context, resolve(&phi_subst, *def)))))
+                .collect::<Vec<_>>();
+            (*phi_block, pairs)
+        })
+        .collect::<Vec<_>>();
+
+    // The backend compiles blocks in order and needs each value defined before it's used.
+    let block_index = |block: &Block| cfg.block_indices[block];
+    let defined_before = |value: &Value, use_idx: usize| {
+        cfg.def_block_index(value)
+            .map_or(true, |def_idx| def_idx <= use_idx)
+    };
+    if replace_map
+        .iter()
+        .any(|(load_val, value)| !defined_before(value, cfg.def_block_index(load_val).unwrap()))
+        || live_phi_pairs.iter().any(|(_, pairs)| {
+            pairs
+                .iter()
+                .any(|(pred, value)| !defined_before(value, block_index(pred)))
+        })
+    {
+        return false;
+    }
+
+    // Everything checks out, so now make the changes.
+    for (phi_block, pairs) in live_phi_pairs {
+        let phi_val = phi_block.get_phi(context);
+        context.values[phi_val.0].value = ValueDatum::Instruction(Instruction::Phi(pairs));
+        claimed_phis.insert(phi_block);
+    }
+    for block in &cfg.blocks {
+        for ins_val in context.blocks[block.0].instructions.clone() {
+            if let ValueDatum::Instruction(ins) = &mut context.values[ins_val.0].value {
+                ins.replace_values(&replace_map);
+            }
+        }
+    }
+    let accesses = block_accesses
+        .values()
+        .flatten()
+        .map(|(ins_val, _)| *ins_val)
+        .chain(get_ptrs)
+        .collect::<HashSet<_>>();
+    for block in &cfg.blocks {
+        context.blocks[block.0]
+            .instructions
+            .retain(|ins_val| !accesses.contains(ins_val));
+    }
+    context.functions[function.0].local_storage.remove(&name);
+
+    true
+}
@@ -0,0 +1,336 @@
+//! Scalar replacement of aggregates.
+//!
+//! A local struct (or tuple) whose fields are all copy types, and which is only ever accessed a
+//! field at a time or initialised as a whole, is split into a separate local per field.  The new
+//! locals can then be promoted to registers by [`mem2reg`](super::mem2reg).
+//!
+//! e.g., here `pair` is only accessed via its fields:
+//!
+//! ```text
+//! local mut ptr { u64, bool } pair
+//!
+//! v0 = get_ptr mut ptr { u64, bool } pair, ptr { u64, bool }, 0
+//! store v1, ptr v0
+//! v2 = extract_value v0, { u64, bool }, 0
+//! ```
+//!
+//! and so becomes:
+//!
+//! ```text
+//! local mut ptr u64 pair_0
+//! local mut ptr bool pair_1
+//!
+//! v3 = extract_value v1, { u64, bool }, 0
+//! v4 = get_ptr mut ptr u64 pair_0, ptr u64, 0
+//! store v3, ptr v4
+//! v5 = extract_value v1, { u64, bool }, 1
+//! v6 = get_ptr mut ptr bool pair_1, ptr bool, 0
+//! store v5, ptr v6
+//! v7 = get_ptr mut ptr u64 pair_0, ptr u64, 0
+//! v2 = load ptr v7
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    block::Block,
+    constant::{Constant, ConstantValue},
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::Instruction,
+    irtype::{Aggregate, Type},
+    metadata::MetadataIndex,
+    pointer::Pointer,
+    value::{Value, ValueDatum},
+};
+
+/// The largest number of fields in a struct which will be split.
+const MAX_SROA_FIELDS: usize = 8;
+
+/// Split the eligible local structs in `function` into a local per field.
+pub fn sroa(context: &mut Context, function: &Function) -> Result<bool, IrError> {
+    let uses = FunctionUses::new(context, function);
+    let candidates = function
+        .locals_iter(context)
+        .filter_map(|(name, ptr)| {
+            split_fields(context, ptr, &uses).map(|accesses| (name.clone(), *ptr, accesses))
+        })
+        .collect::<Vec<_>>();
+
+    let modified = !candidates.is_empty();
+    for (name, ptr, accesses) in candidates {
+        split_local(context, function, &uses, name, ptr, accesses);
+    }
+    Ok(modified)
+}
+
+/// Every access to a candidate local, by way of the `get_ptr` instructions for it.
+struct LocalAccesses {
+    aggregate: Aggregate,
+    get_ptrs: Vec<Value>,
+    users: Vec<Value>,
+}
+
+/// The instructions in a function which use each value, and the `get_ptr` instructions for each
+/// local.
+struct FunctionUses {
+    users: HashMap<Value, Vec<Value>>,
+    get_ptrs: HashMap<Pointer, Vec<Value>>,
+    blocks: HashMap<Value, Block>,
+}
+
+impl FunctionUses {
+    fn new(context: &Context, function: &Function) -> FunctionUses {
+        let mut users: HashMap<Value, Vec<Value>> = HashMap::new();
+        let mut get_ptrs: HashMap<Pointer, Vec<Value>> = HashMap::new();
+        let mut blocks = HashMap::new();
+        for (block, ins_val) in function.instruction_iter(context) {
+            if let ValueDatum::Instruction(ins) = &context.values[ins_val.0].value {
+                for operand in ins.get_operands() {
+                    users.entry(operand).or_default().push(ins_val);
+                }
+                if let Instruction::GetPointer { base_ptr, .. } = ins {
+                    get_ptrs.entry(*base_ptr).or_default().push(ins_val);
+                }
+            }
+            blocks.insert(ins_val, block);
+        }
+        FunctionUses {
+            users,
+            get_ptrs,
+            blocks,
+        }
+    }
+}
+
+/// Return the accesses to `ptr` if it's a struct which may be split, i.e., it's small, each of its
+/// fields is a copy type and it's only accessed via `extract_value` and `insert_value` of single
+/// fields, or as the destination of a `store`.
+fn split_fields(context: &Context, ptr: &Pointer, uses: &FunctionUses) -> Option<LocalAccesses> {
+    let aggregate = match ptr.get_type(context) {
+        Type::Struct(aggregate) => *aggregate,
+        _otherwise => return None,
+    };
+    let field_types = context.aggregates[aggregate.0].field_types();
+    if field_types.is_empty()
+        || field_types.len() > MAX_SROA_FIELDS
+        || !field_types.iter().all(Type::is_copy_type)
+    {
+        return None;
+    }
+
+    let mut get_ptrs = Vec::new();
+    let mut ptr_users = Vec::new();
+    for get_ptr_val in uses.get_ptrs.get(ptr).into_iter().flatten() {
+        let get_ptr_val = *get_ptr_val;
+        match &context.values[get_ptr_val.0].value {
+            ValueDatum::Instruction(Instruction::GetPointer {
+                ptr_ty: Type::Struct(ptr_agg),
+                offset: 0,
+                ..
+            }) if ptr_agg.is_equivalent(context, &aggregate) => (),
+            _otherwise => return None,
+        }
+        for user in uses.users.get(&get_ptr_val).into_iter().flatten() {
+            let is_field_access = match &context.values[user.0].value {
+                ValueDatum::Instruction(Instruction::ExtractValue {
+                    aggregate, indices, ..
+                }) => *aggregate == get_ptr_val && indices.len() == 1,
+                ValueDatum::Instruction(Instruction::InsertValue {
+                    aggregate,
+                    value,
+                    indices,
+                    ..
+                }) => {
+                    *aggregate == get_ptr_val
+                        && *value != get_ptr_val
+                        && indices.len() == 1
+                        && !uses.users.contains_key(user)
+                }
+                ValueDatum::Instruction(Instruction::Store {
+                    dst_val,
+                    stored_val,
+                }) => *dst_val == get_ptr_val && *stored_val != get_ptr_val,
+                _otherwise => false,
+            };
+            if !is_field_access {
+                return None;
+            }
+            ptr_users.push(*user);
+        }
+        get_ptrs.push(get_ptr_val);
+    }
+
+    Some(LocalAccesses {
+        aggregate,
+        get_ptrs,
+        users: ptr_users,
+    })
+}
+
+fn split_local(
+    context: &mut Context,
+    function: &Function,
+    uses: &FunctionUses,
+    name: String,
+    ptr: Pointer,
+    accesses: LocalAccesses,
+) {
+    let LocalAccesses {
+        aggregate,
+        get_ptrs,
+        users,
+    } = accesses;
+
+    // Create the new locals, splitting the initialiser too if there is one.
+    let is_mutable = context.pointers[ptr.0].is_mutable;
+    let initializers = match &context.pointers[ptr.0].initializer {
+        Some(Constant {
+            value: ConstantValue::Struct(fields),
+            ..
+        }) => fields.iter().cloned().map(Some).collect(),
+        _otherwise => Vec::new(),
+    };
+    let field_types = context.aggregates[aggregate.0].field_types().clone();
+    let field_ptrs = field_types
+        .iter()
+        .enumerate()
+        .map(|(idx, field_ty)| {
+            let initializer = initializers
+                .get(idx)
+                .cloned()
+                .flatten()
+                .filter(|init| !matches!(init.value, ConstantValue::Undef));
+            function.new_unique_local_ptr(
+                context,
+                format!("{name}_{idx}"),
+                *field_ty,
+                is_mutable,
+                initializer,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let field_get_ptr = |context: &mut Context, idx: u64, span_md_idx: Option<MetadataIndex>| {
+        let field_ptr = field_ptrs[idx as usize];
+        Value::new_instruction(
+            context,
+            Instruction::GetPointer {
+                base_ptr: field_ptr,
+                ptr_ty: *field_ptr.get_type(context),
+                offset: 0,
+            },
+            span_md_idx,
+            None,
+        )
+    };
+
+    for user in users {
+        let span_md_idx = context.values[user.0].span_md_idx;
+        let ins = match &context.values[user.0].value {
+            ValueDatum::Instruction(ins) => ins.clone(),
+            _otherwise => unreachable!("Users are always instructions."),
+        };
+        let (mut new_instructions, replacement) = match ins {
+            // A field read becomes a load from the field's local.
+            Instruction::ExtractValue { indices, .. } => {
+                let get_ptr_val = field_get_ptr(context, indices[0], span_md_idx);
+                (vec![get_ptr_val], Some(Instruction::Load(get_ptr_val)))
+            }
+
+            // A field write becomes a store to the field's local.
+            Instruction::InsertValue { value, indices, .. } => {
+                let get_ptr_val = field_get_ptr(context, indices[0], span_md_idx);
+                (
+                    vec![get_ptr_val],
+                    Some(Instruction::Store {
+                        dst_val: get_ptr_val,
+                        stored_val: value,
+                    }),
+                )
+            }
+
+            // A whole struct store becomes a store per field.  Fields from a constant are stored
+            // directly, and there's no need to store undefined fields.
+            Instruction::Store { stored_val, .. } => {
+                let const_fields = match &context.values[stored_val.0].value {
+                    ValueDatum::Constant(Constant {
+                        value: ConstantValue::Struct(fields),
+                        ..
+                    }) => Some(fields.clone()),
+                    _otherwise => None,
+                };
+                let stored_agg = match stored_val.get_type(context) {
+                    Some(Type::Struct(stored_agg)) => stored_agg,
+                    _otherwise => aggregate,
+                };
+                let mut new_instructions = Vec::new();
+                for idx in 0..field_types.len() {
+                    let field_val = match &const_fields {
+                        Some(fields) => match &fields[idx].value {
+                            ConstantValue::Undef => continue,
+                            _otherwise => {
+                                Value::new_constant(context, fields[idx].clone(), span_md_idx)
+                            }
+                        },
+                        None => {
+                            let extract_val = Value::new_instruction(
+                                context,
+                                Instruction::ExtractValue {
+                                    aggregate: stored_val,
+                                    ty: stored_agg,
+                                    indices: vec![idx as u64],
+                                },
+                                span_md_idx,
+                                None,
+                            );
+                            new_instructions.push(extract_val);
+                            extract_val
+                        }
+                    };
+                    let get_ptr_val = field_get_ptr(context, idx as u64, span_md_idx);
+                    let store_val = Value::new_instruction(
+                        context,
+                        Instruction::Store {
+                            dst_val: get_ptr_val,
+                            stored_val: field_val,
+                        },
+                        span_md_idx,
+                        None,
+                    );
+                    new_instructions.push(get_ptr_val);
+                    new_instructions.push(store_val);
+                }
+                (new_instructions, None)
+            }
+            _otherwise => unreachable!("Only field accesses and stores are split."),
+        };
+
+        // Put the new instructions before the user, and then either change the user in place,
+        // keeping its uses intact, or remove it.
+        let block = uses.blocks[&user];
+        let instructions = &mut context.blocks[block.0].instructions;
+        let pos = instructions.iter().position(|ins| *ins == user).unwrap();
+        let mut tail = instructions.split_off(pos);
+        instructions.append(&mut new_instructions);
+        match replacement {
+            Some(replacement) => {
+                context.values[user.0].value = ValueDatum::Instruction(replacement)
+            }
+            None => {
+                tail.remove(0);
+            }
+        }
+        context.blocks[block.0].instructions.append(&mut tail);
+    }
+
+    // The original struct local is no longer used.
+    let get_ptrs = get_ptrs.into_iter().collect::<HashSet<_>>();
+    for block in function.block_iter(context) {
+        context.blocks[block.0]
+            .instructions
+            .retain(|ins| !get_ptrs.contains(ins));
+    }
+    context.functions[function.0].local_storage.remove(&name);
+}
@@ -0,0 +1,362 @@
+//! Redundant storage access elimination.
+//!
+//! Each access to a storage field is lowered to its own `state_load_word`, `state_load_quad_word`
+//! or store equivalent, with its key stored to a local just before it.  Within a block, and with
+//! no calls, contract calls or ASM blocks between them, this pass:
+//!
+//! - replaces a `state_load_word` by the result of an earlier load from, or the value of an earlier
+//!   store to, the same key,
+//! - removes a `state_load_quad_word` which reads the same key as an earlier one, and redirects
+//!   the local it read into to the local read into by the earlier one, as long as neither local
+//!   is written anywhere else,
+//! - merges a `state_load_word` into an earlier `state_load_quad_word` from the same key, by
+//!   loading the first word of the local read into instead, under the same condition,
+//! - removes a store to a key which is overwritten by a later store to the same key before being
+//!   read.
+//!
+//! Word and quad word accesses to the same key overlap, as a word is stored in the first 8 bytes
+//! of its slot and the rest of the slot is cleared.  So any store overwrites any earlier store to
+//! the same key.  A word access is never merged with a word access to another key, even if the
+//! keys are consecutive, as each word has a slot of its own.
+//!
+//! Keys are known only when they're a constant stored to the whole of a `b256` local earlier in
+//! the block.  A store to an unknown key may overwrite any slot and a load from one may read any
+//! slot.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    block::Block,
+    constant::{Constant, ConstantValue},
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::Instruction,
+    irtype::Type,
+    pointer::Pointer,
+    value::{Value, ValueDatum},
+};
+
+/// Remove the redundant storage accesses from `function`.
+pub fn optimize_storage_accesses(
+    context: &mut Context,
+    function: &Function,
+) -> Result<bool, IrError> {
+    let read_only_locals = read_only_locals(context, function);
+
+    let mut replacements = HashMap::new();
+    let mut dead_accesses = HashSet::new();
+    let mut redirected_locals = HashMap::new();
+    let mut merged_word_loads = HashMap::new();
+    for block in function.block_iter(context) {
+        find_redundant_accesses(
+            context,
+            &block,
+            &read_only_locals,
+            &mut replacements,
+            &mut dead_accesses,
+            &mut redirected_locals,
+            &mut merged_word_loads,
+        );
+    }
+    if replacements.is_empty() && dead_accesses.is_empty() && merged_word_loads.is_empty() {
+        return Ok(false);
+    }
+
+    for block in function.block_iter(context) {
+        for ins_val in context.blocks[block.0].instructions.clone() {
+            if let Some(kept_val) = replacements.get(&ins_val) {
+                kept_val.merge_span_from(context, &ins_val);
+            }
+        }
+        context.blocks[block.0].instructions.retain(|ins_val| {
+            !replacements.contains_key(ins_val) && !dead_accesses.contains(ins_val)
+        });
+        for (idx, ins_val) in context.blocks[block.0]
+            .instructions
+            .clone()
+            .into_iter()
+            .enumerate()
+            .rev()
+        {
+            if let Some(quad_word_ptr) = merged_word_loads.get(&ins_val) {
+                let span_md_idx = context.values[ins_val.0].span_md_idx;
+                let word_ptr_val = Value::new_instruction(
+                    context,
+                    Instruction::GetPointer {
+                        base_ptr: *quad_word_ptr,
+                        ptr_ty: Type::Uint(64),
+                        offset: 0,
+                    },
+                    span_md_idx,
+                    None,
+                );
+                context.blocks[block.0]
+                    .instructions
+                    .insert(idx, word_ptr_val);
+                context.values[ins_val.0].value =
+                    ValueDatum::Instruction(Instruction::Load(word_ptr_val));
+            }
+        }
+        for ins_val in context.blocks[block.0].instructions.clone() {
+            match &mut context.values[ins_val.0].value {
+                ValueDatum::Instruction(Instruction::GetPointer { base_ptr, .. }) => {
+                    if let Some(new_ptr) = redirected_locals.get(base_ptr) {
+                        *base_ptr = *new_ptr;
+                    }
+                }
+                ValueDatum::Instruction(ins) => ins.replace_values(&replacements),
+                _otherwise => (),
+            }
+        }
+    }
+    Ok(true)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct StorageKey([u8; 32]);
+
+/// The redundant accesses in `block`.  Removed loads are added to `replacements` if they have a
+/// value or to `dead_accesses` otherwise, along with the removed stores, and the locals read into
+/// by removed quad word loads are mapped to their replacements in `redirected_locals`.  Word loads
+/// which can read the first word of a quad word already loaded are mapped to the local holding it
+/// in `merged_word_loads`.
+fn find_redundant_accesses(
+    context: &Context,
+    block: &Block,
+    read_only_locals: &HashSet<Pointer>,
+    replacements: &mut HashMap<Value, Value>,
+    dead_accesses: &mut HashSet<Value>,
+    redirected_locals: &mut HashMap<Pointer, Pointer>,
+    merged_word_loads: &mut HashMap<Value, Pointer>,
+) {
+    // The constant most recently stored to each key local.
+    let mut key_locals: HashMap<Pointer, StorageKey> = HashMap::new();
+
+    // The known word in each slot, the local holding the known contents of each slot, and the
+    // stores which haven't been read yet.
+    let mut words: HashMap<StorageKey, Value> = HashMap::new();
+    let mut quad_words: HashMap<StorageKey, Pointer> = HashMap::new();
+    let mut unread_stores: HashMap<StorageKey, Value> = HashMap::new();
+
+    for ins_val in &context.blocks[block.0].instructions {
+        let ins_val = *ins_val;
+        let ins = match &context.values[ins_val.0].value {
+            ValueDatum::Instruction(ins) => ins,
+            _otherwise => continue,
+        };
+        let resolve = |value: &Value| *replacements.get(value).unwrap_or(value);
+        let get_key =
+            |key: &Value| whole_local(context, key).and_then(|ptr| key_locals.get(&ptr).copied());
+        match ins {
+            // A load which is removed doesn't read the slot, so the store before it may still be
+            // overwritten.
+            Instruction::StateLoadWord(key) => match get_key(key) {
+                Some(key) => match words.get(&key) {
+                    Some(word) => {
+                        replacements.insert(ins_val, *word);
+                    }
+                    None => {
+                        words.insert(key, ins_val);
+                        match quad_words.get(&key) {
+                            Some(quad_word_ptr) => {
+                                merged_word_loads.insert(ins_val, *quad_word_ptr);
+                            }
+                            None => {
+                                unread_stores.remove(&key);
+                            }
+                        }
+                    }
+                },
+                None => unread_stores.clear(),
+            },
+            Instruction::StateLoadQuadWord { load_val, key } => {
+                let key = get_key(key);
+                if let Some(ptr) = get_ptr_base(context, load_val) {
+                    key_locals.remove(&ptr);
+                }
+                let dst_ptr =
+                    whole_local(context, load_val).filter(|ptr| read_only_locals.contains(ptr));
+                match (key, dst_ptr) {
+                    (Some(key), Some(dst_ptr)) => match quad_words.get(&key) {
+                        Some(src_ptr) => {
+                            dead_accesses.insert(ins_val);
+                            redirected_locals.insert(dst_ptr, *src_ptr);
+                        }
+                        None => {
+                            quad_words.insert(key, dst_ptr);
+                            unread_stores.remove(&key);
+                        }
+                    },
+                    (Some(key), None) => {
+                        unread_stores.remove(&key);
+                    }
+                    (None, _) => unread_stores.clear(),
+                }
+            }
+            Instruction::StateStoreWord { stored_val, key } => {
+                let key = get_key(key);
+                forget_slot(&mut words, key);
+                forget_slot(&mut quad_words, key);
+                if let Some(key) = key {
+                    words.insert(key, resolve(stored_val));
+                    if let Some(prev_store) = unread_stores.insert(key, ins_val) {
+                        dead_accesses.insert(prev_store);
+                    }
+                }
+            }
+            Instruction::StateStoreQuadWord { key, .. } => {
+                let key = get_key(key);
+                forget_slot(&mut words, key);
+                forget_slot(&mut quad_words, key);
+                if let Some(key) = key {
+                    if let Some(prev_store) = unread_stores.insert(key, ins_val) {
+                        dead_accesses.insert(prev_store);
+                    }
+                }
+            }
+
+            Instruction::Store {
+                dst_val,
+                stored_val,
+            } => {
+                if let Some(ptr) = whole_local(context, dst_val) {
+                    match &context.values[stored_val.0].value {
+                        ValueDatum::Constant(Constant {
+                            value: ConstantValue::B256(bytes),
+                            ..
+                        }) => {
+                            key_locals.insert(ptr, StorageKey(*bytes));
+                        }
+                        _otherwise => {
+                            key_locals.remove(&ptr);
+                        }
+                    }
+                } else if let Some(ptr) = get_ptr_base(context, dst_val) {
+                    key_locals.remove(&ptr);
+                } else {
+                    key_locals.clear();
+                }
+            }
+            Instruction::InsertElement { array: dst_val, .. }
+            | Instruction::InsertValue {
+                aggregate: dst_val, ..
+            } => match get_ptr_base(context, dst_val) {
+                Some(ptr) => {
+                    key_locals.remove(&ptr);
+                }
+                None => key_locals.clear(),
+            },
+
+            Instruction::AsmBlock(..)
+            | Instruction::Call(..)
+            | Instruction::ContractCall { .. } => {
+                key_locals.clear();
+                words.clear();
+                quad_words.clear();
+                unread_stores.clear();
+            }
+
+            Instruction::BinaryOp { .. }
+            | Instruction::BitCast(..)
+            | Instruction::Branch(_)
+            | Instruction::Cmp(..)
+            | Instruction::ConditionalBranch { .. }
+            | Instruction::ExtractElement { .. }
+            | Instruction::ExtractValue { .. }
+            | Instruction::GetPointer { .. }
+            | Instruction::GetStorageKey
+            | Instruction::Load(_)
+            | Instruction::Log { .. }
+            | Instruction::Nop
+            | Instruction::Phi(_)
+            | Instruction::ReadRegister(_)
+            | Instruction::Ret(..) => (),
+        }
+    }
+}
+
+/// Forget what's known about the slot for `key`, or about every slot if the key is unknown.
+fn forget_slot<T>(slots: &mut HashMap<StorageKey, T>, key: Option<StorageKey>) {
+    match key {
+        Some(key) => {
+            slots.remove(&key);
+        }
+        None => slots.clear(),
+    }
+}
+
+fn get_ptr_base(context: &Context, value: &Value) -> Option<Pointer> {
+    match &context.values[value.0].value {
+        ValueDatum::Instruction(Instruction::GetPointer { base_ptr, .. }) => Some(*base_ptr),
+        _otherwise => None,
+    }
+}
+
+/// The local that `value` points to, if it's a `get_ptr` to the whole of a `b256` local.
+pub(crate) fn whole_local(context: &Context, value: &Value) -> Option<Pointer> {
+    match &context.values[value.0].value {
+        ValueDatum::Instruction(Instruction::GetPointer {
+            base_ptr,
+            ptr_ty,
+            offset: 0,
+        }) if matches!(base_ptr.get_type(context), Type::B256) && matches!(ptr_ty, Type::B256) => {
+            Some(*base_ptr)
+        }
+        _otherwise => None,
+    }
+}
+
+/// The `b256` locals in `function` which are written only by a single `state_load_quad_word` and
+/// whose address doesn't escape, so they can share their contents with another such local.
+fn read_only_locals(context: &Context, function: &Function) -> HashSet<Pointer> {
+    let mut writes: HashMap<Pointer, usize> = HashMap::new();
+    let mut unknown = HashSet::new();
+    for (_ptr_name, ptr) in function.locals_iter(context) {
+        if context.pointers[ptr.0].initializer.is_some() {
+            unknown.insert(*ptr);
+        }
+    }
+
+    for (_block, ins_val) in function.instruction_iter(context) {
+        let ins = match &context.values[ins_val.0].value {
+            ValueDatum::Instruction(ins) => ins,
+            _otherwise => continue,
+        };
+        let (written_val, escaping_vals) = match ins {
+            Instruction::StateLoadQuadWord { load_val, .. } => (Some(load_val), Vec::new()),
+            Instruction::Store { dst_val, .. } => (Some(dst_val), Vec::new()),
+            Instruction::ExtractElement { .. }
+            | Instruction::ExtractValue { .. }
+            | Instruction::GetPointer { .. }
+            | Instruction::Load(_)
+            | Instruction::Ret(..)
+            | Instruction::StateLoadWord(_)
+            | Instruction::StateStoreQuadWord { .. }
+            | Instruction::StateStoreWord { .. } => (None, Vec::new()),
+            _otherwise => (None, ins.get_operands()),
+        };
+        if let Some(written_val) = written_val {
+            let is_quad_word_load = matches!(ins, Instruction::StateLoadQuadWord { .. });
+            match whole_local(context, written_val) {
+                Some(ptr) if is_quad_word_load => *writes.entry(ptr).or_default() += 1,
+                _otherwise => {
+                    if let Some(ptr) = get_ptr_base(context, written_val) {
+                        unknown.insert(ptr);
+                    }
+                }
+            }
+        }
+        for value in escaping_vals {
+            if let Some(ptr) = get_ptr_base(context, &value) {
+                unknown.insert(ptr);
+            }
+        }
+    }
+
+    writes
+        .into_iter()
+        .filter(|(ptr, count)| *count == 1 && !unknown.contains(ptr))
+        .map(|(ptr, _)| ptr)
+        .collect()
+}
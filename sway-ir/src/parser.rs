@@ -34,6 +34,9 @@ mod ir_builder {
                 / _ c:contract() eoi() {
                     c
                 }
+                / _ p:predicate() eoi() {
+                    p
+                }
 
             rule script() -> IrAstModule
                 = "script" _ "{" _ fn_decls:fn_decl()* "}" _ metadata:metadata_decl()* {
@@ -53,6 +56,15 @@ mod ir_builder {
                     }
                 }
 
+            rule predicate() -> IrAstModule
+                = "predicate" _ "{" _ fn_decls:fn_decl()* "}" _ metadata:metadata_decl()* {
+                    IrAstModule {
+                        kind: crate::module::Kind::Predicate,
+                        fn_decls,
+                        metadata
+                    }
+                }
+
             rule fn_decl() -> IrAstFnDecl
                 = "fn" _ name:id() _ selector:selector_id()? _ "(" _
                       args:(fn_arg() ** comma()) ")" _ "->" _ ret_type:ast_ty()
@@ -130,6 +142,7 @@ mod ir_builder {
 
             rule operation() -> IrAstOperation
                 = op_asm()
+                / op_binary_op()
                 / op_branch()
                 / op_bitcast()
                 / op_call()
@@ -144,6 +157,7 @@ mod ir_builder {
                 / op_insert_element()
                 / op_insert_value()
                 / op_load()
+                / op_log()
                 / op_nop()
                 / op_phi()
                 / op_read_register()
@@ -187,6 +201,11 @@ mod ir_builder {
                     IrAstOperation::Cbr(cond, tblock, fblock)
                 }
 
+            rule op_binary_op() -> IrAstOperation
+                = op:binary_op_kind() l:id() r:id() {
+                    IrAstOperation::BinaryOp(op, l, r)
+                }
+
             rule op_cmp() -> IrAstOperation
                 = "cmp" _ p:cmp_pred() l:id() r:id() {
                     IrAstOperation::Cmp(p, l, r)
@@ -240,6 +259,11 @@ mod ir_builder {
                     IrAstOperation::Load(src)
                 }
 
+            rule op_log() -> IrAstOperation
+                = "log" _ log_ty:ast_ty() log_val:id() comma() log_id:id() {
+                    IrAstOperation::Log(log_ty, log_val, log_id)
+                }
+
             rule op_nop() -> IrAstOperation
                 = "nop" _ {
                     IrAstOperation::Nop
@@ -286,10 +310,16 @@ mod ir_builder {
                 }
 
             rule cmp_pred() -> String
-                = p:$("eq") _ {
+                = p:$("eq" / "lt" / "gt") _ {
                     p.to_string()
                 }
 
+            rule binary_op_kind() -> BinaryOpKind
+                = "add" _ { BinaryOpKind::Add }
+                / "sub" _ { BinaryOpKind::Sub }
+                / "mul" _ { BinaryOpKind::Mul }
+                / "div" _ { BinaryOpKind::Div }
+
             rule reg_name() -> String
                 = r:$("of" / "pc" / "ssp" / "sp" / "fp" / "hp" / "err" / "ggas" / "cgas" / "bal" / "is" / "ret" / "retl" / "flag") _ {
                     r.to_string()
@@ -348,6 +378,9 @@ mod ir_builder {
                 / "0x" s:$(hex_digit()*<64>) _ {
                     IrAstConstValue::B256(string_to_hex::<32>(s))
                 }
+                / "0x" s:$(hex_digit()*<32>) _ {
+                    IrAstConstValue::Hex128(string_to_hex::<16>(s))
+                }
                 / n:decimal() { IrAstConstValue::Number(n) }
                 / string_const()
                 / array_const()
@@ -404,7 +437,12 @@ mod ir_builder {
             rule ast_ty() -> IrAstTy
                 = ("unit" / "()") _ { IrAstTy::Unit }
                 / "bool" _ { IrAstTy::Bool }
+                / "u8" _ { IrAstTy::U8 }
+                / "u16" _ { IrAstTy::U16 }
+                / "u32" _ { IrAstTy::U32 }
                 / "u64" _ { IrAstTy::U64 }
+                / "u128" _ { IrAstTy::U128 }
+                / "u256" _ { IrAstTy::U256 }
                 / "b256" _ { IrAstTy::B256 }
                 / "string" _ "<" _ sz:decimal() ">" _ { IrAstTy::String(sz) }
                 / array_ty()
@@ -448,6 +486,9 @@ mod ir_builder {
                 / "span" _ "!" li:decimal() s:decimal() e:decimal() {
                     IrMetadatum::Span { loc_idx: li, start: s as usize, end: e as usize }
                 }
+                / "inlined" _ "!" s:decimal() "!" a:decimal() {
+                    IrMetadatum::InlinedSpan { span_idx: s, inlined_at: a }
+                }
                 / "state_index" _ idx:decimal() {
                     IrMetadatum::StateIndex { idx: idx as usize}
                 }
@@ -516,12 +557,12 @@ mod ir_builder {
         context::Context,
         error::IrError,
         function::Function,
-        instruction::{Instruction, Predicate, Register},
+        instruction::{BinaryOpKind, Instruction, Predicate, Register},
         irtype::{Aggregate, Type},
         metadata::{MetadataIndex, Metadatum, StorageOperation},
         module::{Kind, Module},
         pointer::Pointer,
-        value::Value,
+        value::{Value, ValueContent, ValueDatum},
     };
 
     #[derive(Debug)]
@@ -566,6 +607,7 @@ mod ir_builder {
             Vec<IrAstAsmOp>,
             Option<MdIdxRef>,
         ),
+        BinaryOp(BinaryOpKind, String, String),
         BitCast(String, IrAstTy),
         Br(String),
         Call(String, Vec<String>),
@@ -580,6 +622,7 @@ mod ir_builder {
         InsertElement(String, IrAstTy, String, String),
         InsertValue(String, IrAstTy, String, Vec<u64>),
         Load(String),
+        Log(IrAstTy, String, String),
         Nop,
         Phi(Vec<(String, String)>),
         ReadRegister(String),
@@ -603,6 +646,7 @@ mod ir_builder {
         Unit,
         Bool(bool),
         B256([u8; 32]),
+        Hex128([u8; 16]),
         Number(u64),
         String(Vec<u8>),
         Array(IrAstTy, Vec<IrAstConst>),
@@ -630,6 +674,7 @@ mod ir_builder {
                 IrAstConstValue::Unit => ConstantValue::Unit,
                 IrAstConstValue::Bool(b) => ConstantValue::Bool(*b),
                 IrAstConstValue::B256(bs) => ConstantValue::B256(*bs),
+                IrAstConstValue::Hex128(bs) => ConstantValue::BigUint(bs.to_vec()),
                 IrAstConstValue::Number(n) => ConstantValue::Uint(*n),
                 IrAstConstValue::String(bs) => ConstantValue::String(bs.clone()),
                 IrAstConstValue::Array(el_ty, els) => {
@@ -650,9 +695,14 @@ mod ir_builder {
         }
 
         fn as_constant(&self, context: &mut Context, val_ty: IrAstTy) -> Constant {
+            // A 64 digit hex constant is a `b256` unless it's been annotated as a `u256`.
+            let value = match (self, &val_ty) {
+                (IrAstConstValue::B256(bs), IrAstTy::U256) => ConstantValue::BigUint(bs.to_vec()),
+                _otherwise => self.as_constant_value(context),
+            };
             Constant {
                 ty: val_ty.to_ir_type(context),
-                value: self.as_constant_value(context),
+                value,
             }
         }
 
@@ -666,7 +716,13 @@ mod ir_builder {
                 IrAstConstValue::Undef(_) => unreachable!("Can't convert 'undef' to a value."),
                 IrAstConstValue::Unit => Constant::get_unit(context, span_md_idx),
                 IrAstConstValue::Bool(b) => Constant::get_bool(context, *b, span_md_idx),
-                IrAstConstValue::B256(bs) => Constant::get_b256(context, *bs, span_md_idx),
+                IrAstConstValue::B256(bs) => match val_ty {
+                    IrAstTy::U256 => Constant::get_big_uint(context, 256, bs.to_vec(), span_md_idx),
+                    _otherwise => Constant::get_b256(context, *bs, span_md_idx),
+                },
+                IrAstConstValue::Hex128(bs) => {
+                    Constant::get_big_uint(context, 128, bs.to_vec(), span_md_idx)
+                }
                 IrAstConstValue::Number(n) => Constant::get_uint(context, 64, *n, span_md_idx),
                 IrAstConstValue::String(s) => Constant::get_string(context, s.clone(), span_md_idx),
                 IrAstConstValue::Array(..) => {
@@ -685,7 +741,12 @@ mod ir_builder {
     enum IrAstTy {
         Unit,
         Bool,
+        U8,
+        U16,
+        U32,
         U64,
+        U128,
+        U256,
         B256,
         String(u64),
         Array(Box<IrAstTy>, u64),
@@ -698,7 +759,12 @@ mod ir_builder {
             match self {
                 IrAstTy::Unit => Type::Unit,
                 IrAstTy::Bool => Type::Bool,
+                IrAstTy::U8 => Type::Uint(8),
+                IrAstTy::U16 => Type::Uint(16),
+                IrAstTy::U32 => Type::Uint(32),
                 IrAstTy::U64 => Type::Uint(64),
+                IrAstTy::U128 => Type::BigUint(128),
+                IrAstTy::U256 => Type::BigUint(256),
                 IrAstTy::B256 => Type::B256,
                 IrAstTy::String(n) => Type::String(*n),
                 IrAstTy::Array(..) => Type::Array(self.to_ir_aggregate_type(context)),
@@ -732,6 +798,10 @@ mod ir_builder {
             start: usize,
             end: usize,
         },
+        InlinedSpan {
+            span_idx: MdIdxRef,
+            inlined_at: MdIdxRef,
+        },
         StateIndex {
             idx: usize,
         },
@@ -831,6 +901,9 @@ mod ir_builder {
             )
         }));
 
+        // Phi values may refer to values from later blocks, e.g., around a loop, so they're only
+        // resolved once all the blocks are built.
+        let mut unresolved_phis = Vec::new();
         for block in fn_decl.blocks {
             build_add_block_instructions(
                 context,
@@ -840,12 +913,22 @@ mod ir_builder {
                 &mut arg_map,
                 md_map,
                 unresolved_calls,
+                &mut unresolved_phis,
             );
         }
+        for (block, pairs) in unresolved_phis {
+            for (block_name, val_name) in pairs {
+                block.add_phi(
+                    context,
+                    *named_blocks.get(&block_name).unwrap(),
+                    *arg_map.get(&val_name).unwrap(),
+                );
+            }
+        }
         Ok(())
     }
 
-    #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     fn build_add_block_instructions(
         context: &mut Context,
         ir_block: IrAstBlock,
@@ -861,6 +944,7 @@ mod ir_builder {
             Option<MetadataIndex>,
             Option<MetadataIndex>,
         )>,
+        unresolved_phis: &mut Vec<(Block, Vec<(String, String)>)>,
     ) {
         let block = named_blocks.get(&ir_block.label).unwrap();
         for ins in ir_block.instructions {
@@ -953,9 +1037,17 @@ mod ir_builder {
                         opt_ins_span_md_idx,
                     )
                 }
+                IrAstOperation::BinaryOp(op, lhs, rhs) => block.ins(context).binary_op(
+                    op,
+                    *val_map.get(&lhs).unwrap(),
+                    *val_map.get(&rhs).unwrap(),
+                    opt_ins_span_md_idx,
+                ),
                 IrAstOperation::Cmp(pred_str, lhs, rhs) => block.ins(context).cmp(
                     match pred_str.as_str() {
                         "eq" => Predicate::Equal,
+                        "lt" => Predicate::LessThan,
+                        "gt" => Predicate::GreaterThan,
                         _ => unreachable!("Bug in `cmp` predicate rule."),
                     },
                     *val_map.get(&lhs).unwrap(),
@@ -1030,15 +1122,18 @@ mod ir_builder {
                 IrAstOperation::Load(src_name) => block
                     .ins(context)
                     .load(*val_map.get(&src_name).unwrap(), opt_ins_span_md_idx),
+                IrAstOperation::Log(log_ty, log_val, log_id) => {
+                    let log_ty = log_ty.to_ir_type(context);
+                    block.ins(context).log(
+                        *val_map.get(&log_val).unwrap(),
+                        log_ty,
+                        *val_map.get(&log_id).unwrap(),
+                        opt_ins_span_md_idx,
+                    )
+                }
                 IrAstOperation::Nop => block.ins(context).nop(),
                 IrAstOperation::Phi(pairs) => {
-                    for (block_name, val_name) in pairs {
-                        block.add_phi(
-                            context,
-                            *named_blocks.get(&block_name).unwrap(),
-                            *val_map.get(&val_name).unwrap(),
-                        );
-                    }
+                    unresolved_phis.push((*block, pairs));
                     block.get_phi(context)
                 }
                 IrAstOperation::ReadRegister(reg_name) => block.ins(context).read_register(
@@ -1156,7 +1251,39 @@ mod ir_builder {
                     );
                 }
 
-                IrMetadatum::FilePath { .. } => (),
+                IrMetadatum::FilePath { .. } | IrMetadatum::InlinedSpan { .. } => (),
+            }
+        }
+
+        // Inlined spans may refer to other inlined spans, so resolve them until none are left.
+        let mut unresolved = ir_metadata
+            .iter()
+            .filter_map(|(idx_ref, md)| match md {
+                IrMetadatum::InlinedSpan {
+                    span_idx,
+                    inlined_at,
+                } => Some((*idx_ref, *span_idx, *inlined_at)),
+                _otherwise => None,
+            })
+            .collect::<Vec<_>>();
+        while !unresolved.is_empty() {
+            let unresolved_count = unresolved.len();
+            unresolved.retain(|(idx_ref, span_idx, inlined_at)| {
+                match (md_map.get(span_idx), md_map.get(inlined_at)) {
+                    (Some(span_idx), Some(inlined_at)) => {
+                        let md_idx = context.metadata.insert(Metadatum::InlinedSpan {
+                            span_idx: *span_idx,
+                            inlined_at: *inlined_at,
+                        });
+                        md_map.insert(*idx_ref, MetadataIndex(md_idx));
+                        false
+                    }
+                    _otherwise => true,
+                }
+            });
+            if unresolved.len() == unresolved_count {
+                // Dangling or cyclic references; leave them unresolved.
+                break;
             }
         }
         md_map
@@ -1178,7 +1305,7 @@ mod ir_builder {
         // calls.  We couldn't do it above until we'd gone and created all the functions first.
         //
         // Now we can loop and find the callee function for each call and replace the NOPs.
-        for (block, nop, callee, args, opt_ins_span_md_idx, opt_ins_state_idx_md_idx) in
+        for (_block, nop, callee, args, opt_ins_span_md_idx, opt_ins_state_idx_md_idx) in
             unresolved_calls
         {
             let function = context
@@ -1192,13 +1319,13 @@ mod ir_builder {
                     }
                 })
                 .unwrap();
-            let call_val = Value::new_instruction(
-                context,
-                Instruction::Call(function, args),
-                opt_ins_span_md_idx,
-                opt_ins_state_idx_md_idx,
-            );
-            block.replace_instruction(context, nop, call_val)?;
+            // Replace the NOP in place, rather than with a new value, as later calls may already
+            // have it as an argument.
+            context.values[nop.0] = ValueContent {
+                value: ValueDatum::Instruction(Instruction::Call(function, args)),
+                span_md_idx: opt_ins_span_md_idx,
+                state_idx_md_idx: opt_ins_state_idx_md_idx,
+            };
         }
         Ok(())
     }
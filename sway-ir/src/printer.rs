@@ -4,15 +4,19 @@
 //! crates were assessed but didn't seem to work as well as this simple version, which is quite
 //! effective.
 
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    rc::Rc,
+};
 
 use crate::{
+    analysis::{value_positions, DefUse, DominatorTree, Liveness, LoopNest, PostDominatorTree},
     asm::*,
     block::Block,
     constant::{Constant, ConstantValue},
     context::Context,
     function::{Function, FunctionContent},
-    instruction::{Instruction, Predicate, Register},
+    instruction::{BinaryOpKind, Instruction, Predicate, Register},
     irtype::Type,
     metadata::{MetadataIndex, Metadatum},
     module::{Kind, ModuleContent},
@@ -79,12 +83,26 @@ impl Doc {
 ///
 /// The ouput from this function must always be suitable for [`crate::parser::parse`].
 pub fn to_string(context: &Context) -> String {
+    context_to_string(context, false)
+}
+
+/// Pretty-print a whole [`Context`] to a string, annotated with the results of the analyses in
+/// [`crate::analysis`].
+///
+/// Each block is preceded by its immediate dominator and post-dominator, the loop it's in and the
+/// values live at its start and end, and each instruction is followed by its users.  These are
+/// all comments, so the output is still suitable for [`crate::parser::parse`].
+pub fn to_string_with_analyses(context: &Context) -> String {
+    context_to_string(context, true)
+}
+
+fn context_to_string(context: &Context, annotate: bool) -> String {
     let mut md_namer = MetadataNamer::new();
     context
         .modules
         .iter()
         .fold(Doc::Empty, |doc, (_, module)| {
-            doc.append(module_to_doc(context, &mut md_namer, module))
+            doc.append(module_to_doc(context, &mut md_namer, module, annotate))
         })
         .append(metadata_to_doc(context, &md_namer))
         .build()
@@ -94,13 +112,14 @@ fn module_to_doc<'a>(
     context: &'a Context,
     md_namer: &mut MetadataNamer,
     module: &'a ModuleContent,
+    annotate: bool,
 ) -> Doc {
     Doc::line(Doc::Text(format!(
         "{} {{",
         match module.kind {
             Kind::Contract => "contract",
             Kind::Library => "library",
-            Kind::Predicate => "predicate ",
+            Kind::Predicate => "predicate",
             Kind::Script => "script",
         }
     )))
@@ -111,11 +130,27 @@ fn module_to_doc<'a>(
                 .functions
                 .iter()
                 .map(|function| {
+                    let function_content = &context.functions[function.0];
+                    let mut namer = Namer::new(*function);
+                    let analyses = annotate.then(|| {
+                        // Name the values as they are without annotations first, so that naming
+                        // them in the annotations doesn't change their numbering.
+                        function_to_doc(
+                            context,
+                            &mut MetadataNamer::new(),
+                            &mut namer,
+                            None,
+                            function_content,
+                        );
+                        namer.reset_known();
+                        Analyses::new(context, function)
+                    });
                     function_to_doc(
                         context,
                         md_namer,
-                        &mut Namer::new(*function),
-                        &context.functions[function.0],
+                        &mut namer,
+                        analyses.as_ref(),
+                        function_content,
                     )
                 })
                 .collect(),
@@ -129,6 +164,7 @@ fn function_to_doc<'a>(
     context: &'a Context,
     md_namer: &mut MetadataNamer,
     namer: &mut Namer,
+    analyses: Option<&Analyses>,
     function: &'a FunctionContent,
 ) -> Doc {
     Doc::line(
@@ -200,7 +236,7 @@ fn function_to_doc<'a>(
                     function
                         .blocks
                         .iter()
-                        .map(|block| block_to_doc(context, md_namer, namer, block))
+                        .map(|block| block_to_doc(context, md_namer, namer, analyses, block))
                         .collect(),
                     Doc::line(Doc::Empty),
                 ),
@@ -215,16 +251,142 @@ fn block_to_doc<'a>(
     context: &'a Context,
     md_namer: &mut MetadataNamer,
     namer: &mut Namer,
+    analyses: Option<&Analyses>,
     block: &Block,
 ) -> Doc {
     let block_content = &context.blocks[block.0];
-    Doc::text_line(format!("{}:", block_content.label)).append(Doc::List(
-        block_content
-            .instructions
-            .iter()
-            .map(|ins| instruction_to_doc(context, md_namer, namer, block, ins))
-            .collect(),
-    ))
+    Doc::text_line(format!("{}:", block_content.label))
+        .append(analyses.map_or(Doc::Empty, |analyses| {
+            analyses.block_to_doc(context, namer, block)
+        }))
+        .append(Doc::List(
+            block_content
+                .instructions
+                .iter()
+                .map(|ins| {
+                    instruction_to_doc(context, md_namer, namer, block, ins).append(
+                        analyses.map_or(Doc::Empty, |analyses| {
+                            analyses.users_to_doc(context, namer, ins)
+                        }),
+                    )
+                })
+                .collect(),
+        ))
+}
+
+/// The analyses of a function, for annotating it.
+struct Analyses {
+    dom_tree: Rc<DominatorTree>,
+    post_dom_tree: Rc<PostDominatorTree>,
+    loop_nest: Rc<LoopNest>,
+    liveness: Rc<Liveness>,
+    def_use: Rc<DefUse>,
+    value_positions: HashMap<Value, usize>,
+}
+
+impl Analyses {
+    fn new(context: &Context, function: &Function) -> Self {
+        Analyses {
+            dom_tree: function.get_analysis(context),
+            post_dom_tree: function.get_analysis(context),
+            loop_nest: function.get_analysis(context),
+            liveness: function.get_analysis(context),
+            def_use: function.get_analysis(context),
+            value_positions: value_positions(context, function),
+        }
+    }
+
+    fn block_to_doc(&self, context: &Context, namer: &mut Namer, block: &Block) -> Doc {
+        let label = |block: Option<Block>| {
+            block.map_or("none".to_owned(), |block| block.get_label(context))
+        };
+        let loop_str = match self.loop_nest.innermost_loop(block) {
+            Some(loop_id) => {
+                let a_loop = self.loop_nest.get(loop_id);
+                format!(
+                    "{}, depth {}",
+                    a_loop.header().get_label(context),
+                    a_loop.depth()
+                )
+            }
+            None => "none".to_owned(),
+        };
+        Doc::text_line(format!(
+            "// idom: {}, ipdom: {}, loop: {}",
+            label(self.dom_tree.immediate_dominator(block)),
+            label(self.post_dom_tree.immediate_post_dominator(block)),
+            loop_str,
+        ))
+        .append(Doc::text_line(format!(
+            "// live in: {}",
+            self.values_to_string(context, namer, self.liveness.live_in(block))
+        )))
+        .append(Doc::text_line(format!(
+            "// live out: {}",
+            self.values_to_string(context, namer, self.liveness.live_out(block))
+        )))
+    }
+
+    fn users_to_doc(&self, context: &Context, namer: &mut Namer, value: &Value) -> Doc {
+        let users = self.def_use.users(value);
+        if users.is_empty() {
+            return Doc::Empty;
+        }
+
+        // Instructions which aren't printed with a name are referred to by their opcode and block.
+        let user_to_string = |namer: &mut Namer, user: &Value| {
+            if namer.is_named(user) {
+                return namer.name(context, user);
+            }
+            let opcode = match &context.values[user.0].value {
+                ValueDatum::Instruction(Instruction::Branch(_)) => "br",
+                ValueDatum::Instruction(Instruction::ConditionalBranch { .. }) => "cbr",
+                ValueDatum::Instruction(Instruction::Log { .. }) => "log",
+                ValueDatum::Instruction(Instruction::Ret(..)) => "ret",
+                ValueDatum::Instruction(Instruction::StateLoadQuadWord { .. }) => {
+                    "state_load_quad_word"
+                }
+                ValueDatum::Instruction(Instruction::StateStoreQuadWord { .. }) => {
+                    "state_store_quad_word"
+                }
+                ValueDatum::Instruction(Instruction::StateStoreWord { .. }) => "state_store_word",
+                ValueDatum::Instruction(Instruction::Store { .. }) => "store",
+                _otherwise => return namer.name(context, user),
+            };
+            format!(
+                "{opcode} in {}",
+                self.def_use
+                    .def_block(user)
+                    .map_or("none".to_owned(), |block| block.get_label(context))
+            )
+        };
+        Doc::text_line(format!(
+            "// users: {}",
+            users
+                .iter()
+                .map(|user| user_to_string(namer, user))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    }
+
+    fn values_to_string(
+        &self,
+        context: &Context,
+        namer: &mut Namer,
+        values: &HashSet<Value>,
+    ) -> String {
+        if values.is_empty() {
+            return "none".to_owned();
+        }
+        let mut values = values.iter().collect::<Vec<_>>();
+        values.sort_by_key(|value| self.value_positions.get(value));
+        values
+            .into_iter()
+            .map(|value| namer.name(context, value))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 fn constant_to_doc(
@@ -300,6 +462,23 @@ fn instruction_to_doc<'a>(
             Instruction::AsmBlock(asm, args) => {
                 asm_block_to_doc(context, md_namer, namer, ins_value, asm, args, span_md_idx)
             }
+            Instruction::BinaryOp { op, arg1, arg2 } => {
+                let op_str = match op {
+                    BinaryOpKind::Add => "add",
+                    BinaryOpKind::Sub => "sub",
+                    BinaryOpKind::Mul => "mul",
+                    BinaryOpKind::Div => "div",
+                };
+                maybe_constant_to_doc(context, md_namer, namer, arg1)
+                    .append(maybe_constant_to_doc(context, md_namer, namer, arg2))
+                    .append(Doc::text_line(format!(
+                        "{} = {op_str} {} {}{}",
+                        namer.name(context, ins_value),
+                        namer.name(context, arg1),
+                        namer.name(context, arg2),
+                        md_namer.meta_as_string(context, span_md_idx, true)
+                    )))
+            }
             Instruction::BitCast(value, ty) => maybe_constant_to_doc(
                 context, md_namer, namer, value,
             )
@@ -348,6 +527,8 @@ fn instruction_to_doc<'a>(
             Instruction::Cmp(pred, lhs_value, rhs_value) => {
                 let pred_str = match pred {
                     Predicate::Equal => "eq",
+                    Predicate::LessThan => "lt",
+                    Predicate::GreaterThan => "gt",
                 };
                 maybe_constant_to_doc(context, md_namer, namer, lhs_value)
                     .append(maybe_constant_to_doc(context, md_namer, namer, rhs_value))
@@ -506,6 +687,19 @@ fn instruction_to_doc<'a>(
                 namer.name(context, src_value),
                 md_namer.meta_as_string(context, span_md_idx, true),
             )),
+            Instruction::Log {
+                log_val,
+                log_ty,
+                log_id,
+            } => maybe_constant_to_doc(context, md_namer, namer, log_val)
+                .append(maybe_constant_to_doc(context, md_namer, namer, log_id))
+                .append(Doc::text_line(format!(
+                    "log {} {}, {}{}",
+                    log_ty.as_string(context),
+                    namer.name(context, log_val),
+                    namer.name(context, log_id),
+                    md_namer.meta_as_string(context, span_md_idx, true),
+                ))),
             Instruction::Nop => Doc::text_line(format!("{} = nop", namer.name(context, ins_value))),
             Instruction::Phi(pairs) => {
                 if pairs.is_empty() {
@@ -697,6 +891,14 @@ fn metadata_to_doc(context: &Context, md_namer: &MetadataNamer) -> Doc {
                 } => md_namer
                     .get(loc_idx)
                     .map(|loc_ref_idx| format!("!{ref_idx} = span !{loc_ref_idx} {start} {end}")),
+                Metadatum::InlinedSpan {
+                    span_idx,
+                    inlined_at,
+                } => md_namer.get(span_idx).zip(md_namer.get(inlined_at)).map(
+                    |(span_ref_idx, inlined_at_ref_idx)| {
+                        format!("!{ref_idx} = inlined !{span_ref_idx} !{inlined_at_ref_idx}")
+                    },
+                ),
                 Metadatum::StateIndex(idx) => Some(format!("!{ref_idx} = state_index {idx:?}")),
                 Metadatum::StorageAttribute(storage_op) => {
                     Some(format!("!{ref_idx} = storage {storage_op}"))
@@ -721,6 +923,14 @@ impl Constant {
             ConstantValue::Unit => "unit ()".into(),
             ConstantValue::Bool(b) => format!("bool {}", if *b { "true" } else { "false" }),
             ConstantValue::Uint(v) => format!("{} {}", self.ty.as_string(context), v),
+            ConstantValue::BigUint(bs) => format!(
+                "{} 0x{}",
+                self.ty.as_string(context),
+                bs.iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<Vec<String>>()
+                    .concat()
+            ),
             ConstantValue::B256(bs) => format!(
                 "b256 0x{}",
                 bs.iter()
@@ -777,6 +987,9 @@ struct Namer {
 
     names: HashMap<Value, String>,
     next_value_idx: u64,
+
+    // The values which have been named since the last reset.
+    known: HashSet<Value>,
 }
 
 impl Namer {
@@ -785,6 +998,7 @@ impl Namer {
             function,
             names: HashMap::new(),
             next_value_idx: 0,
+            known: HashSet::new(),
         }
     }
 
@@ -801,6 +1015,7 @@ impl Namer {
     }
 
     fn default_name(&mut self, value: &Value) -> String {
+        self.known.insert(*value);
         self.names.get(value).cloned().unwrap_or_else(|| {
             let new_name = format!("v{}", self.next_value_idx);
             self.next_value_idx += 1;
@@ -810,8 +1025,17 @@ impl Namer {
     }
 
     fn is_known(&self, value: &Value) -> bool {
+        self.known.contains(value)
+    }
+
+    fn is_named(&self, value: &Value) -> bool {
         self.names.contains_key(value)
     }
+
+    /// Forget which values have been named so far, but not their names.
+    fn reset_known(&mut self) {
+        self.known.clear();
+    }
 }
 
 struct MetadataNamer {
@@ -868,8 +1092,18 @@ impl MetadataNamer {
     }
 
     fn get_or_add(&mut self, context: &Context, md_idx: &MetadataIndex) -> u64 {
-        if let Metadatum::Span { loc_idx, .. } = &context.metadata[md_idx.0] {
-            self.get_or_add(context, loc_idx);
+        match &context.metadata[md_idx.0] {
+            Metadatum::Span { loc_idx, .. } => {
+                self.get_or_add(context, loc_idx);
+            }
+            Metadatum::InlinedSpan {
+                span_idx,
+                inlined_at,
+            } => {
+                self.get_or_add(context, span_idx);
+                self.get_or_add(context, inlined_at);
+            }
+            _otherwise => (),
         }
         self.md_map.get(md_idx).copied().unwrap_or_else(|| {
             let new_idx = self.next_md_idx;
@@ -89,6 +89,20 @@ impl Value {
             .expect("A valid span.")
     }
 
+    /// If this value is an instruction which is replacing `other_val` then merge the span of
+    /// `other_val` into its own, so it's still attributed to both.  Constants and arguments may be
+    /// shared between many uses so are left alone.
+    pub fn merge_span_from(&self, context: &mut Context, other_val: &Value) {
+        if let ValueDatum::Instruction(_) = &context.values[self.0].value {
+            let span_md_idx = MetadataIndex::merge_spans(
+                context,
+                context.values[self.0].span_md_idx,
+                context.values[other_val.0].span_md_idx,
+            );
+            context.values[self.0].span_md_idx = span_md_idx;
+        }
+    }
+
     /// Return the content of the state index metadata
     pub fn get_storage_key(&self, context: &Context) -> Option<usize> {
         context.values[self.0]
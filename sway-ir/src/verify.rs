@@ -95,6 +95,9 @@ impl<'a> InstructionVerifier<'a> {
             if let ValueDatum::Instruction(instruction) = instruction {
                 match instruction {
                     Instruction::AsmBlock(..) => (),
+                    Instruction::BinaryOp { arg1, arg2, .. } => {
+                        self.verify_binary_op(arg1, arg2)?
+                    }
                     Instruction::BitCast(value, ty) => self.verify_bitcast(value, ty)?,
                     Instruction::Branch(block) => self.verify_br(block)?,
                     Instruction::Call(func, args) => self.verify_call(func, args)?,
@@ -142,6 +145,11 @@ impl<'a> InstructionVerifier<'a> {
                         indices,
                     } => self.verify_insert_value(aggregate, ty, value, indices)?,
                     Instruction::Load(ptr) => self.verify_load(ptr)?,
+                    Instruction::Log {
+                        log_val,
+                        log_ty,
+                        log_id,
+                    } => self.verify_log(log_val, log_ty, log_id)?,
                     Instruction::Nop => (),
                     Instruction::Phi(pairs) => self.verify_phi(&pairs[..])?,
                     Instruction::ReadRegister(_) => (),
@@ -171,8 +179,25 @@ impl<'a> InstructionVerifier<'a> {
         Ok(())
     }
 
+    fn verify_binary_op(&self, arg1: &Value, arg2: &Value) -> Result<(), IrError> {
+        // Arithmetic is only between integers of the same width.
+        match (arg1.get_type(self.context), arg2.get_type(self.context)) {
+            (Some(Type::Uint(nbits1)), Some(Type::Uint(nbits2))) if nbits1 == nbits2 => Ok(()),
+            (Some(Type::BigUint(nbits1)), Some(Type::BigUint(nbits2))) if nbits1 == nbits2 => {
+                Ok(())
+            }
+            (Some(ty1), Some(ty2)) => Err(IrError::VerifyBinaryOpIncorrectArgTypes(
+                ty1.as_string(self.context),
+                ty2.as_string(self.context),
+            )),
+            _otherwise => Err(IrError::VerifyBinaryOpUnknownArgTypes),
+        }
+    }
+
     fn verify_bitcast(&self, value: &Value, ty: &Type) -> Result<(), IrError> {
         // The to and from types must be copy-types, excluding short strings, and the same size.
+        // Integers narrower than a word are held in a whole word though, so they may be cast
+        // between any of those widths.
         let val_ty = value
             .get_type(self.context)
             .ok_or(IrError::VerifyBitcastUnknownSourceType)?;
@@ -188,14 +213,17 @@ impl<'a> InstructionVerifier<'a> {
         }
         let is_valid = match val_ty {
             Type::Unit | Type::Bool => true, // Unit or bool to any copy type works.
-            Type::Uint(from_nbits) => match ty {
+            Type::Uint(_) => match ty {
                 Type::Unit | Type::Bool => true, // We can construct a unit or bool from any sized integer.
-                Type::Uint(to_nbits) => from_nbits == *to_nbits,
+                Type::Uint(_) => true,           // Any integer fits in the word it's held in.
                 _otherwise => false,
             },
-            Type::B256 | Type::String(_) | Type::Array(_) | Type::Union(_) | Type::Struct(_) => {
-                false
-            }
+            Type::BigUint(_)
+            | Type::B256
+            | Type::String(_)
+            | Type::Array(_)
+            | Type::Union(_)
+            | Type::Struct(_) => false,
         };
         if !is_valid {
             Err(IrError::VerifyBitcastBetweenInvalidTypes(
@@ -304,6 +332,16 @@ impl<'a> InstructionVerifier<'a> {
                         Ok(())
                     }
                 }
+                (Type::BigUint(lhs_nbits), Type::BigUint(rhs_nbits)) => {
+                    if lhs_nbits != rhs_nbits {
+                        Err(IrError::VerifyCmpTypeMismatch(
+                            lhs_ty.as_string(self.context),
+                            rhs_ty.as_string(self.context),
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                }
                 (Type::Bool, Type::Bool) => Ok(()),
                 _otherwise => Err(IrError::VerifyCmpBadTypes(
                     lhs_ty.as_string(self.context),
@@ -483,6 +521,18 @@ impl<'a> InstructionVerifier<'a> {
         }
     }
 
+    fn verify_log(&self, log_val: &Value, log_ty: &Type, log_id: &Value) -> Result<(), IrError> {
+        if !matches!(log_id.get_type(self.context), Some(Type::Uint(64))) {
+            return Err(IrError::VerifyLogId);
+        }
+
+        if self.opt_ty_not_eq(&log_val.get_type(self.context), &Some(*log_ty)) {
+            return Err(IrError::VerifyMismatchedLoggedTypes);
+        }
+
+        Ok(())
+    }
+
     fn verify_phi(&self, pairs: &[(Block, Value)]) -> Result<(), IrError> {
         if pairs.is_empty() {
             Ok(())
@@ -1,53 +1,96 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // -------------------------------------------------------------------------------------------------
 // Utility for finding test files and running FileCheck.  See actual pass invocations below.
 
 fn run_tests<F: Fn(&mut sway_ir::Context) -> bool>(sub_dir: &str, opt_fn: F) {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
-    let dir: PathBuf = format!("{}/tests/{}", manifest_dir, sub_dir).into();
-    for entry in std::fs::read_dir(dir).unwrap() {
-        let path = entry.unwrap().path();
-
+    for path in test_paths(sub_dir) {
         let input_bytes = std::fs::read(&path).unwrap();
         let input = String::from_utf8_lossy(&input_bytes);
 
-        let chkr = filecheck::CheckerBuilder::new()
-            .text(&input)
-            .unwrap()
-            .finish();
-        assert!(
-            !chkr.is_empty(),
-            "No filecheck directives found in test: {}",
-            path.display()
-        );
-
-        let mut ir = match sway_ir::parser::parse(&input) {
-            Ok(ir) => ir,
-            Err(parse_err) => {
-                tracing::error!("{parse_err}");
-                panic!()
-            }
-        };
+        let mut ir = parse(&input);
 
         assert!(opt_fn(&mut ir));
 
         let output = sway_ir::printer::to_string(&ir);
 
-        match chkr.explain(&output, filecheck::NO_VARIABLES) {
-            Ok((success, report)) if !success => {
-                tracing::error!("--- FILECHECK FAILED FOR {}", path.display());
-                tracing::error!("{report}");
-                panic!()
-            }
-            Err(e) => {
-                panic!("filecheck directive error while checking: {e}");
-            }
-            _ => (),
+        check_output(&path, &input, &output);
+    }
+}
+
+// Run the passes via the `opt` binary instead, and confirm that its output can be parsed again
+// before checking it.
+fn run_opt_tests(sub_dir: &str, passes: &[&str]) {
+    for path in test_paths(sub_dir) {
+        let input_bytes = std::fs::read(&path).unwrap();
+        let input = String::from_utf8_lossy(&input_bytes);
+
+        let output_path = std::env::temp_dir().join(format!(
+            "sway-ir-{}-{}-{}",
+            sub_dir,
+            std::process::id(),
+            path.file_name().unwrap().to_string_lossy()
+        ));
+        let status = std::process::Command::new(env!("CARGO_BIN_EXE_opt"))
+            .arg("-i")
+            .arg(&path)
+            .args(passes)
+            .arg("-o")
+            .arg(&output_path)
+            .status()
+            .unwrap();
+        assert!(status.success(), "opt failed for test: {}", path.display());
+
+        let output_bytes = std::fs::read(&output_path).unwrap();
+        std::fs::remove_file(&output_path).unwrap();
+        let output = sway_ir::printer::to_string(&parse(&String::from_utf8_lossy(&output_bytes)));
+
+        check_output(&path, &input, &output);
+    }
+}
+
+fn test_paths(sub_dir: &str) -> impl Iterator<Item = PathBuf> {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let dir: PathBuf = format!("{}/tests/{}", manifest_dir, sub_dir).into();
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+}
+
+fn parse(input: &str) -> sway_ir::Context {
+    match sway_ir::parser::parse(input) {
+        Ok(ir) => ir,
+        Err(parse_err) => {
+            tracing::error!("{parse_err}");
+            panic!()
         }
     }
 }
 
+fn check_output(path: &Path, input: &str, output: &str) {
+    let chkr = filecheck::CheckerBuilder::new()
+        .text(input)
+        .unwrap()
+        .finish();
+    assert!(
+        !chkr.is_empty(),
+        "No filecheck directives found in test: {}",
+        path.display()
+    );
+
+    match chkr.explain(output, filecheck::NO_VARIABLES) {
+        Ok((success, report)) if !success => {
+            tracing::error!("--- FILECHECK FAILED FOR {}", path.display());
+            tracing::error!("{report}");
+            panic!()
+        }
+        Err(e) => {
+            panic!("filecheck directive error while checking: {e}");
+        }
+        _ => (),
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 #[test]
@@ -80,6 +123,174 @@ fn constants() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn sroa() {
+    run_opt_tests("sroa", &["sroa"])
+}
+
+#[test]
+fn mem2reg() {
+    run_opt_tests("mem2reg", &["mem2reg"])
+}
+
+#[test]
+fn cse() {
+    run_opt_tests("cse", &["cse"])
+}
+
+#[test]
+fn storage() {
+    run_opt_tests("storage", &["storage"])
+}
+
+#[test]
+fn licm() {
+    run_opt_tests("licm", &["licm"])
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn analysis() {
+    // The analyses are printed as comments, so confirm the output can still be parsed too.
+    for path in test_paths("analysis") {
+        let input_bytes = std::fs::read(&path).unwrap();
+        let input = String::from_utf8_lossy(&input_bytes);
+
+        let output = sway_ir::printer::to_string_with_analyses(&parse(&input));
+        parse(&output);
+
+        check_output(&path, &input, &output);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn interpreter() {
+    // Run `main()` with `opt --run` and check what it prints.
+    for path in test_paths("interpreter") {
+        let input_bytes = std::fs::read(&path).unwrap();
+        let input = String::from_utf8_lossy(&input_bytes);
+
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_opt"))
+            .arg("-i")
+            .arg(&path)
+            .args(["--run", "main"])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "opt failed for test: {}\n{}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        check_output(&path, &input, &String::from_utf8_lossy(&output.stdout));
+    }
+}
+
+// Run every function in the tests before and after each pass, with all zero arguments, and
+// confirm that the results, the logs and the storage written are all unchanged.
+#[test]
+fn interpreter_differential() {
+    let passes: &[(&str, &[&str])] = &[
+        ("constants", &["constcombine"]),
+        ("cse", &["cse"]),
+        ("inline", &["inline"]),
+        (
+            "interpreter",
+            &["inline", "sroa", "mem2reg", "cse", "storage", "licm"],
+        ),
+        ("licm", &["licm"]),
+        ("mem2reg", &["mem2reg"]),
+        ("sroa", &["sroa"]),
+        ("storage", &["storage"]),
+    ];
+    let mut num_runs = 0;
+    for (sub_dir, pass_names) in passes {
+        for path in test_paths(sub_dir) {
+            let input_bytes = std::fs::read(&path).unwrap();
+            let input = String::from_utf8_lossy(&input_bytes);
+            let before = parse(&input);
+            let mut after = parse(&input);
+            for pass_name in *pass_names {
+                run_pass(&mut after, pass_name);
+            }
+
+            for (_, fc) in before.functions.iter() {
+                let find_function = |ir: &sway_ir::Context| {
+                    ir.functions
+                        .iter()
+                        .find_map(|(idx, other_fc)| {
+                            if other_fc.name == fc.name {
+                                Some(sway_ir::function::Function(idx))
+                            } else {
+                                None
+                            }
+                        })
+                        .unwrap()
+                };
+                let (before_result, before_hooks) = run_zero_args(&before, find_function(&before));
+                // Some functions can't run until after a pass, e.g., those which only get a state
+                // index for `get_storage_key` once inlined.
+                if matches!(
+                    before_result,
+                    Err(sway_ir::InterpError::Invalid(_) | sway_ir::InterpError::Unsupported(_))
+                ) {
+                    continue;
+                }
+                let (after_result, after_hooks) = run_zero_args(&after, find_function(&after));
+                assert_eq!(
+                    (before_result, before_hooks),
+                    (after_result, after_hooks),
+                    "Different behaviour for {} in test: {}",
+                    fc.name,
+                    path.display()
+                );
+                num_runs += 1;
+            }
+        }
+    }
+    assert!(num_runs > 0);
+}
+
+fn run_pass(ir: &mut sway_ir::Context, pass_name: &str) {
+    let fn_idcs: Vec<_> = ir.functions.iter().map(|func| func.0).collect();
+    for fn_idx in fn_idcs {
+        let function = sway_ir::function::Function(fn_idx);
+        match pass_name {
+            "constcombine" => sway_ir::optimize::combine_constants(ir, &function),
+            "cse" => sway_ir::optimize::cse(ir, &function),
+            "inline" => sway_ir::optimize::inline_all_function_calls(ir, &function),
+            "licm" => sway_ir::optimize::licm(ir, &function, usize::MAX),
+            "mem2reg" => sway_ir::optimize::mem2reg(ir, &function, usize::MAX),
+            "sroa" => sway_ir::optimize::sroa(ir, &function),
+            "storage" => sway_ir::optimize::optimize_storage_accesses(ir, &function),
+            _otherwise => unreachable!("Unknown pass name: {pass_name}"),
+        }
+        .unwrap();
+    }
+}
+
+fn run_zero_args(
+    ir: &sway_ir::Context,
+    function: sway_ir::function::Function,
+) -> (
+    Result<sway_ir::InterpValue, sway_ir::InterpError>,
+    sway_ir::DefaultHooks,
+) {
+    let args: Vec<_> = function
+        .args_iter(ir)
+        .map(|(_, arg_val)| sway_ir::InterpValue::zero(ir, &arg_val.get_type(ir).unwrap()))
+        .collect();
+    let mut hooks = sway_ir::DefaultHooks::default();
+    let result = sway_ir::Interpreter::new(ir, &mut hooks).run(&function, &args);
+    (result, hooks)
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[test]
 fn serialize() {
     // This isn't running a pass, it's just confirming that the IR can be loaded and printed, and
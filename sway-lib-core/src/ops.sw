@@ -6,37 +6,37 @@ pub trait Add {
 
 impl Add for u64 {
     fn add(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            add r3 r2 r1;
-            r3: u64
-        }
+        __add(self, other)
     }
 }
 
 impl Add for u32 {
     fn add(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            add r3 r2 r1;
-            r3: u32
-        }
+        __add(self, other)
     }
 }
 
 impl Add for u16 {
     fn add(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            add r3 r2 r1;
-            r3: u16
-        }
+        __add(self, other)
     }
 }
 
 impl Add for u8 {
     fn add(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            add r3 r2 r1;
-            r3: u8
-        }
+        __add(self, other)
+    }
+}
+
+impl Add for u128 {
+    fn add(self, other: Self) -> Self {
+        __add(self, other)
+    }
+}
+
+impl Add for u256 {
+    fn add(self, other: Self) -> Self {
+        __add(self, other)
     }
 }
 
@@ -46,37 +46,37 @@ pub trait Subtract {
 
 impl Subtract for u64 {
     fn subtract(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            sub r3 r1 r2;
-            r3
-        }
+        __sub(self, other)
     }
 }
 
 impl Subtract for u32 {
     fn subtract(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            sub r3 r1 r2;
-            r3: u32
-        }
+        __sub(self, other)
     }
 }
 
 impl Subtract for u16 {
     fn subtract(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            sub r3 r1 r2;
-            r3: u16
-        }
+        __sub(self, other)
     }
 }
 
 impl Subtract for u8 {
     fn subtract(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            sub r3 r1 r2;
-            r3: u8
-        }
+        __sub(self, other)
+    }
+}
+
+impl Subtract for u128 {
+    fn subtract(self, other: Self) -> Self {
+        __sub(self, other)
+    }
+}
+
+impl Subtract for u256 {
+    fn subtract(self, other: Self) -> Self {
+        __sub(self, other)
     }
 }
 
@@ -86,37 +86,37 @@ pub trait Multiply {
 
 impl Multiply for u64 {
     fn multiply(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            mul r3 r1 r2;
-            r3
-        }
+        __mul(self, other)
     }
 }
 
 impl Multiply for u32 {
     fn multiply(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            mul r3 r1 r2;
-            r3: u32
-        }
+        __mul(self, other)
     }
 }
 
 impl Multiply for u16 {
     fn multiply(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            mul r3 r1 r2;
-            r3: u16
-        }
+        __mul(self, other)
     }
 }
 
 impl Multiply for u8 {
     fn multiply(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            mul r3 r1 r2;
-            r3: u8
-        }
+        __mul(self, other)
+    }
+}
+
+impl Multiply for u128 {
+    fn multiply(self, other: Self) -> Self {
+        __mul(self, other)
+    }
+}
+
+impl Multiply for u256 {
+    fn multiply(self, other: Self) -> Self {
+        __mul(self, other)
     }
 }
 
@@ -126,37 +126,37 @@ pub trait Divide {
 
 impl Divide for u64 {
     fn divide(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            div r3 r1 r2;
-            r3
-        }
+        __div(self, other)
     }
 }
 
 impl Divide for u32 {
     fn divide(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            div r3 r1 r2;
-            r3: u32
-        }
+        __div(self, other)
     }
 }
 
 impl Divide for u16 {
     fn divide(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            div r3 r1 r2;
-            r3: u16
-        }
+        __div(self, other)
     }
 }
 
 impl Divide for u8 {
     fn divide(self, other: Self) -> Self {
-        asm(r1: self, r2: other, r3) {
-            div r3 r1 r2;
-            r3: u8
-        }
+        __div(self, other)
+    }
+}
+
+impl Divide for u128 {
+    fn divide(self, other: Self) -> Self {
+        __div(self, other)
+    }
+}
+
+impl Divide for u256 {
+    fn divide(self, other: Self) -> Self {
+        __div(self, other)
     }
 }
 
@@ -238,6 +238,18 @@ impl Eq for u8 {
     }
 }
 
+impl Eq for u128 {
+    fn eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
+impl Eq for u256 {
+    fn eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
 impl Eq for b256 {
     fn eq(self, other: Self) -> bool {
         // Both self and other are addresses of the values, so we can use MEQ.
@@ -314,6 +326,24 @@ impl Ord for u8 {
     }
 }
 
+impl Ord for u128 {
+    fn gt(self, other: Self) -> bool {
+        __gt(self, other)
+    }
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
+impl Ord for u256 {
+    fn gt(self, other: Self) -> bool {
+        __gt(self, other)
+    }
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
 impl Ord for b256 {
     fn gt(self, other: Self) -> bool {
         let(self_word_1, self_word_2, self_word_3, self_word_4) = decompose(self);
@@ -467,6 +497,10 @@ impl OrdEq for u16 {
 }
 impl OrdEq for u8 {
 }
+impl OrdEq for u128 {
+}
+impl OrdEq for u256 {
+}
 impl OrdEq for b256 {
 }
 
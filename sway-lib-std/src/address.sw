@@ -6,7 +6,7 @@ use ::mem::{addr_of, eq};
 
 /// The Address type, a struct wrappper around the inner `value`.
 pub struct Address {
-    value: b256,
+    pub value: b256,
 }
 
 impl core::ops::Eq for Address {
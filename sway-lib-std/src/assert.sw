@@ -1,9 +1,6 @@
 library assert;
 
-use ::logging::log;
-use ::revert::revert;
-
-const FAILED_REQUIRE_SIGNAL = 42;
+use ::revert::{revert, revert_with_log};
 
 /// Assert that a value is true
 pub fn assert(a: bool) {
@@ -15,10 +12,10 @@ pub fn assert(a: bool) {
 }
 
 /// A wrapper for `assert` that allows logging a custom value `v` if condition `c` is not true.
+/// The value is typically a variant of an error enum, see `revert_with_log`.
 pub fn require<T>(c: bool, v: T) {
     if !c {
-        log(v);
-        revert(FAILED_REQUIRE_SIGNAL)
+        revert_with_log(v)
     } else {
         ()
     }
@@ -4,3 +4,5 @@ use ::contract_id::ContractId;
 
 pub const BASE_ASSET_ID = ~ContractId::from(ZERO_B256);
 pub const ZERO_B256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+/// The code a program reverts with when integer arithmetic overflows and overflow checks are enabled.
+pub const ARITHMETIC_OVERFLOW_SIGNAL = 43;
@@ -6,7 +6,7 @@ use ::mem::{addr_of, eq};
 
 /// The ContractId type, a struct wrappper around the inner `value`.
 pub struct ContractId {
-    value: b256,
+    pub value: b256,
 }
 
 impl core::ops::Eq for ContractId {
@@ -0,0 +1,9 @@
+library convert;
+
+/// Conversion between the implementing type and values of type `T`.
+pub trait From<T> {
+    /// Creates a value of the implementing type from `b`.
+    fn from(b: T) -> Self;
+    /// Converts the value back into a value of type `T`.
+    fn into(self) -> T;
+}
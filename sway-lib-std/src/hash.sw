@@ -43,3 +43,56 @@ pub fn keccak256<T>(param: T) -> b256 {
         }
     }
 }
+
+/// A type whose values can be hashed into a `b256` digest.
+pub trait Hash {
+    fn hash(self) -> b256;
+}
+
+impl Hash for bool {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
+
+impl Hash for u8 {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
+
+impl Hash for u16 {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
+
+impl Hash for u32 {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
+
+impl Hash for u64 {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
+
+impl Hash for u128 {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
+
+impl Hash for u256 {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
+
+impl Hash for b256 {
+    fn hash(self) -> b256 {
+        sha256(self)
+    }
+}
@@ -1,8 +1,8 @@
 library std;
 
 dep intrinsics;
-dep revert;
 dep logging;
+dep revert;
 dep assert;
 dep option;
 dep result;
@@ -25,6 +25,7 @@ dep ecr;
 dep reentrancy;
 dep vm/mod;
 dep flags;
+dep convert;
 dep u128;
 dep u256;
 dep vec;
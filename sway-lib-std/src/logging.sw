@@ -1,19 +1,12 @@
 //! Allows logging of arbitrary types, emitted as either `Log` or `Logd` receipts.
 library logging;
-use ::intrinsics::{is_reference_type, size_of};
 
 /// Log any stack type.
-/// If the type is a reference type, `log` is used.
-/// Otherwise `logd` is used.'
+/// If the type is a reference type, `logd` is used.
+/// Otherwise `log` is used.
+///
+/// Each call site is given a log ID by the compiler, which is placed in `rb` of the receipt.
+/// The JSON ABI lists the type logged under each ID in its `loggedTypes` table.
 pub fn log<T>(value: T) {
-    if !is_reference_type::<T>() {
-        asm(r1: value) {
-            log r1 zero zero zero;
-        }
-    } else {
-        let size = size_of::<T>();
-        asm(r1: value, r2: size) {
-            logd zero zero r1 r2;
-        };
-    }
+    __log(value);
 }
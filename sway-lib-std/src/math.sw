@@ -1,5 +1,8 @@
 library math;
 
+use core::num::*;
+use ::option::Option;
+
 pub trait Root {
     fn sqrt(self) -> Self;
 }
@@ -83,3 +86,280 @@ impl Exponentiate for u8 {
         }
     }
 }
+
+impl u64 {
+    /// Adds `other` to `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        asm(r1: self, r2: other, r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            add r3 r1 r2;
+            flag saved;
+            r3: u64
+        }
+    }
+
+    /// Subtracts `other` from `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        asm(r1: self, r2: other, r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            sub r3 r1 r2;
+            flag saved;
+            r3: u64
+        }
+    }
+
+    /// Multiplies `self` by `other`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        asm(r1: self, r2: other, r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            mul r3 r1 r2;
+            flag saved;
+            r3: u64
+        }
+    }
+
+    /// Adds `other` to `self`, returning `None` if the result overflows.
+    pub fn checked_add(self, other: Self) -> Option<u64> {
+        let result = self.wrapping_add(other);
+        if result < self {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+
+    /// Subtracts `other` from `self`, returning `None` if the result underflows.
+    pub fn checked_sub(self, other: Self) -> Option<u64> {
+        if self < other {
+            Option::None
+        } else {
+            Option::Some(self.wrapping_sub(other))
+        }
+    }
+
+    /// Multiplies `self` by `other`, returning `None` if the result overflows.
+    pub fn checked_mul(self, other: Self) -> Option<u64> {
+        let result = self.wrapping_mul(other);
+        if self != 0 && result / self != other {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+}
+
+impl u32 {
+    /// Adds `other` to `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u32::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            add r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u32
+        }
+    }
+
+    /// Subtracts `other` from `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u32::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            sub r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u32
+        }
+    }
+
+    /// Multiplies `self` by `other`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u32::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            mul r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u32
+        }
+    }
+
+    /// Adds `other` to `self`, returning `None` if the result overflows.
+    pub fn checked_add(self, other: Self) -> Option<u32> {
+        let result = self.wrapping_add(other);
+        if result < self {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+
+    /// Subtracts `other` from `self`, returning `None` if the result underflows.
+    pub fn checked_sub(self, other: Self) -> Option<u32> {
+        if self < other {
+            Option::None
+        } else {
+            Option::Some(self.wrapping_sub(other))
+        }
+    }
+
+    /// Multiplies `self` by `other`, returning `None` if the result overflows.
+    pub fn checked_mul(self, other: Self) -> Option<u32> {
+        let result = self.wrapping_mul(other);
+        if self != 0 && result / self != other {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+}
+
+impl u16 {
+    /// Adds `other` to `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u16::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            add r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u16
+        }
+    }
+
+    /// Subtracts `other` from `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u16::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            sub r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u16
+        }
+    }
+
+    /// Multiplies `self` by `other`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u16::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            mul r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u16
+        }
+    }
+
+    /// Adds `other` to `self`, returning `None` if the result overflows.
+    pub fn checked_add(self, other: Self) -> Option<u16> {
+        let result = self.wrapping_add(other);
+        if result < self {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+
+    /// Subtracts `other` from `self`, returning `None` if the result underflows.
+    pub fn checked_sub(self, other: Self) -> Option<u16> {
+        if self < other {
+            Option::None
+        } else {
+            Option::Some(self.wrapping_sub(other))
+        }
+    }
+
+    /// Multiplies `self` by `other`, returning `None` if the result overflows.
+    pub fn checked_mul(self, other: Self) -> Option<u16> {
+        let result = self.wrapping_mul(other);
+        if self != 0 && result / self != other {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+}
+
+impl u8 {
+    /// Adds `other` to `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u8::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            add r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u8
+        }
+    }
+
+    /// Subtracts `other` from `self`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u8::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            sub r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u8
+        }
+    }
+
+    /// Multiplies `self` by `other`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        asm(r1: self, r2: other, mask: ~u8::max(), r3, saved, wrapping) {
+            move saved flag;
+            ori wrapping saved i2;
+            flag wrapping;
+            mul r3 r1 r2;
+            flag saved;
+            and r3 r3 mask;
+            r3: u8
+        }
+    }
+
+    /// Adds `other` to `self`, returning `None` if the result overflows.
+    pub fn checked_add(self, other: Self) -> Option<u8> {
+        let result = self.wrapping_add(other);
+        if result < self {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+
+    /// Subtracts `other` from `self`, returning `None` if the result underflows.
+    pub fn checked_sub(self, other: Self) -> Option<u8> {
+        if self < other {
+            Option::None
+        } else {
+            Option::Some(self.wrapping_sub(other))
+        }
+    }
+
+    /// Multiplies `self` by `other`, returning `None` if the result overflows.
+    pub fn checked_mul(self, other: Self) -> Option<u8> {
+        let result = self.wrapping_mul(other);
+        if self != 0 && result / self != other {
+            Option::None
+        } else {
+            Option::Some(result)
+        }
+    }
+}
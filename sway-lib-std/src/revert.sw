@@ -1,5 +1,10 @@
 library revert;
 
+use ::logging::log;
+
+/// The revert code used when reverting with a logged error value.
+pub const FAILED_REQUIRE_SIGNAL = 42;
+
 /// Context-dependent:
 /// will panic if used in a predicate
 /// will revert if used in a contract
@@ -8,3 +13,11 @@ pub fn revert(code: u64) {
         rvrt r1;
     }
 }
+
+/// Revert with a typed error value, e.g. a variant of an error enum.
+/// The value is logged before reverting with `FAILED_REQUIRE_SIGNAL`, so its type is described in
+/// the `loggedTypes` table of the JSON ABI and tools can decode it from the receipts.
+pub fn revert_with_log<T>(value: T) {
+    log(value);
+    revert(FAILED_REQUIRE_SIGNAL)
+}
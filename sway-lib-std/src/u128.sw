@@ -8,8 +8,8 @@ use ::result::Result;
 /// The 128-bit unsigned integer type.
 /// Represented as two 64-bit components: `(upper, lower)`, where `value = (upper << 64) + lower`.
 pub struct U128 {
-    upper: u64,
-    lower: u64,
+    pub upper: u64,
+    pub lower: u64,
 }
 
 pub enum U128Error {
@@ -1,29 +1,26 @@
 library u256;
 
 use core::num::*;
+use ::convert::From;
 use ::result::Result;
 
 /// The 256-bit unsigned integer type.
 /// Represented as four 64-bit components: `(a, b, c, d)`, where `value = (a << 192) + (b << 128) + (c << 64) + d`.
 pub struct U256 {
-    a: u64,
-    b: u64,
-    c: u64,
-    d: u64,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub d: u64,
 }
 
 pub enum U256Error {
     LossOfPrecision: (),
 }
 
-pub trait From {
+impl From<(u64, u64, u64, u64)> for U256 {
     /// Function for creating a U256 from its u64 components.
-    pub fn from(a: u64, b: u64, c: u64, d: u64) -> Self;
-    fn into(self) -> (u64, u64, u64, u64);
-}
-
-impl From for U256 {
-    pub fn from(a: u64, b: u64, c: u64, d: u64) -> U256 {
+    fn from(components: (u64, u64, u64, u64)) -> U256 {
+        let (a, b, c, d) = components;
         U256 {
             a, b, c, d,
         }
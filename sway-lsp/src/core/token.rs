@@ -1,14 +1,14 @@
 use crate::{
     core::token_type::{
         get_const_details, get_enum_details, get_function_details, get_struct_details,
-        get_struct_field_details, get_trait_details, TokenType, VariableDetails,
+        get_struct_field_details, get_trait_details, TokenType, VarBody, VariableDetails,
     },
     utils::common::{extract_var_body, get_range_from_span},
 };
 use sway_core::{
     constants::TUPLE_NAME_PREFIX, parse_tree::MethodName, type_engine::TypeInfo, AstNode,
-    AstNodeContent, Declaration, Expression, FunctionDeclaration, FunctionParameter,
-    VariableDeclaration, WhileLoop,
+    AstNodeContent, Declaration, Expression, FunctionDeclaration, FunctionParameter, Scrutinee,
+    StructScrutineeField, VariableDeclaration, WhileLoop,
 };
 use sway_types::{ident::Ident, span::Span, Spanned};
 use tower_lsp::lsp_types::Range;
@@ -363,10 +363,20 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
         } => {
             handle_expression(*value, tokens);
             for branch in branches {
-                // TODO: handle_scrutinee(branch.scrutinee, tokens);
+                handle_scrutinee(branch.scrutinee, tokens);
                 handle_expression(branch.result, tokens);
             }
         }
+        Expression::IrrefutableMatchExp {
+            value,
+            scrutinee,
+            result,
+            ..
+        } => {
+            handle_expression(*value, tokens);
+            handle_scrutinee(scrutinee, tokens);
+            handle_expression(*result, tokens);
+        }
         Expression::AsmExpression { .. } => {
             //TODO handle asm expressions
         }
@@ -445,9 +455,83 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
         Expression::IntrinsicFunction { arguments, .. } => {
             handle_intrinsic_function(arguments, tokens);
         }
+        Expression::Closure { function, .. } => {
+            // the closure's name is synthesized, so only its parameters and body are collected
+            for param in function.parameters {
+                handle_function_parameter(&param, tokens);
+            }
+            for node in function.body.contents {
+                traverse_node(node, tokens);
+            }
+        }
+    }
+}
+
+fn handle_scrutinee(scrutinee: Scrutinee, tokens: &mut Vec<Token>) {
+    match scrutinee {
+        Scrutinee::CatchAll { .. } | Scrutinee::Literal { .. } => {}
+        Scrutinee::Variable { name, .. } => {
+            tokens.push(Token::from_ident(&name, pattern_binding()));
+        }
+        Scrutinee::StructScrutinee {
+            struct_name,
+            fields,
+            ..
+        } => {
+            let token = Token::from_ident(&struct_name, TokenType::Struct);
+            tokens.push(token);
+
+            for field in fields {
+                if let StructScrutineeField::Field {
+                    field, scrutinee, ..
+                } = field
+                {
+                    match scrutinee {
+                        Some(scrutinee) => {
+                            let token = Token::from_ident(
+                                &field,
+                                TokenType::StructExpressionField(get_struct_field_details(
+                                    &struct_name,
+                                )),
+                            );
+                            tokens.push(token);
+                            handle_scrutinee(scrutinee, tokens);
+                        }
+                        // `Foo { a }` binds the field to a variable of the same name.
+                        None => tokens.push(Token::from_ident(&field, pattern_binding())),
+                    }
+                }
+            }
+        }
+        Scrutinee::EnumScrutinee {
+            call_path, value, ..
+        } => {
+            for prefix in call_path.prefixes {
+                let token = Token::from_ident(&prefix, TokenType::DelineatedPath);
+                tokens.push(token);
+            }
+
+            let token = Token::from_ident(&call_path.suffix, TokenType::DelineatedPath);
+            tokens.push(token);
+
+            handle_scrutinee(*value, tokens);
+        }
+        Scrutinee::Tuple { elems, .. } => {
+            for elem in elems {
+                handle_scrutinee(elem, tokens);
+            }
+        }
     }
 }
 
+// Variables bound by a pattern are immutable and have no body of their own.
+fn pattern_binding() -> TokenType {
+    TokenType::VariableDeclaration(VariableDetails {
+        is_mutable: false,
+        var_body: VarBody::Other,
+    })
+}
+
 fn handle_intrinsic_function(arguments: Vec<Expression>, tokens: &mut Vec<Token>) {
     for arg in arguments {
         handle_expression(arg, tokens);
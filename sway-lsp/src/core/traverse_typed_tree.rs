@@ -225,7 +225,8 @@ fn handle_expression(expression: &TypedExpression, tokens: &mut TokenMap) {
             handle_expression(lhs, tokens);
             handle_expression(rhs, tokens);
         }
-        TypedExpressionVariant::VariableExpression { ref name } => {
+        TypedExpressionVariant::VariableExpression { ref name }
+        | TypedExpressionVariant::VariableReference { ref name } => {
             tokens.insert(
                 to_ident_key(name),
                 TokenType::TypedExpression(expression.clone()),
@@ -331,6 +332,20 @@ fn handle_expression(expression: &TypedExpression, tokens: &mut TokenMap) {
                 TokenType::TypedExpression(expression.clone()),
             );
         }
+        TypedExpressionVariant::FunctionValue { function } => {
+            for node in &function.body.contents {
+                traverse_node(node, tokens);
+            }
+        }
+        TypedExpressionVariant::IndirectFunctionApplication { callee, arguments } => {
+            tokens.insert(
+                to_ident_key(callee),
+                TokenType::TypedExpression(expression.clone()),
+            );
+            for exp in arguments {
+                handle_expression(exp, tokens);
+            }
+        }
     }
 }
 
@@ -168,6 +168,7 @@ pub enum Expr {
         reassignment_op: ReassignmentOp,
         expr: Box<Expr>,
     },
+    Closure(ExprClosure),
 }
 
 impl Spanned for Expr {
@@ -233,6 +234,7 @@ impl Spanned for Expr {
             Expr::Reassignment {
                 assignable, expr, ..
             } => Span::join(assignable.span(), expr.span()),
+            Expr::Closure(closure) => closure.span(),
         }
     }
 }
@@ -382,6 +384,112 @@ impl Parse for IfCondition {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct ExprClosure {
+    pub params: ClosureParams,
+    pub body: Box<Expr>,
+}
+
+#[derive(Clone, Debug)]
+pub enum ClosureParams {
+    Empty {
+        double_pipe_token: DoublePipeToken,
+    },
+    List {
+        open_pipe_token: PipeToken,
+        params: Punctuated<ClosureParam, CommaToken>,
+        close_pipe_token: PipeToken,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct ClosureParam {
+    pub name: Ident,
+    pub ty_opt: Option<(ColonToken, Ty)>,
+}
+
+impl Spanned for ExprClosure {
+    fn span(&self) -> Span {
+        Span::join(self.params.span(), self.body.span())
+    }
+}
+
+impl Spanned for ClosureParams {
+    fn span(&self) -> Span {
+        match self {
+            ClosureParams::Empty { double_pipe_token } => double_pipe_token.span(),
+            ClosureParams::List {
+                open_pipe_token,
+                close_pipe_token,
+                ..
+            } => Span::join(open_pipe_token.span(), close_pipe_token.span()),
+        }
+    }
+}
+
+impl Spanned for ClosureParam {
+    fn span(&self) -> Span {
+        match &self.ty_opt {
+            Some((_colon_token, ty)) => Span::join(self.name.span(), ty.span()),
+            None => self.name.span(),
+        }
+    }
+}
+
+impl Parse for ExprClosure {
+    fn parse(parser: &mut Parser) -> ParseResult<ExprClosure> {
+        let params = parser.parse()?;
+        let body = parser.parse()?;
+        Ok(ExprClosure { params, body })
+    }
+}
+
+impl Parse for ClosureParams {
+    fn parse(parser: &mut Parser) -> ParseResult<ClosureParams> {
+        if let Some(double_pipe_token) = parser.take() {
+            return Ok(ClosureParams::Empty { double_pipe_token });
+        }
+        // the parameters are not delimited by a token tree group, so collect them by hand until
+        // the closing pipe
+        let open_pipe_token = parser.parse()?;
+        let mut value_separator_pairs = Vec::new();
+        let mut final_value_opt = None;
+        let close_pipe_token = loop {
+            if let Some(close_pipe_token) = parser.take() {
+                break close_pipe_token;
+            }
+            let param = parser.parse()?;
+            match parser.take() {
+                Some(comma_token) => value_separator_pairs.push((param, comma_token)),
+                None => {
+                    final_value_opt = Some(Box::new(param));
+                    break parser.parse()?;
+                }
+            }
+        };
+        let params = Punctuated {
+            value_separator_pairs,
+            final_value_opt,
+        };
+        Ok(ClosureParams::List {
+            open_pipe_token,
+            params,
+            close_pipe_token,
+        })
+    }
+}
+
+impl Parse for ClosureParam {
+    fn parse(parser: &mut Parser) -> ParseResult<ClosureParam> {
+        let name = parser.parse()?;
+        let ty_opt = match parser.take() {
+            Some(colon_token) => Some((colon_token, parser.parse()?)),
+            None => None,
+        };
+        Ok(ClosureParam { name, ty_opt })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum ExprTupleDescriptor {
     Nil,
@@ -1096,6 +1204,10 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             expr_opt: Some(expr),
         });
     }
+    if parser.peek::<PipeToken>().is_some() || parser.peek::<DoublePipeToken>().is_some() {
+        let closure = parser.parse()?;
+        return Ok(Expr::Closure(closure));
+    }
     if parser.peek::<IfToken>().is_some() {
         let if_expr = parser.parse()?;
         return Ok(Expr::If(if_expr));
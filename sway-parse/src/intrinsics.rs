@@ -7,6 +7,13 @@ pub enum Intrinsic {
     SizeOfType,
     SizeOfVal,
     Eq,
+    Gt,
+    Lt,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Log,
 }
 
 impl fmt::Display for Intrinsic {
@@ -17,6 +24,13 @@ impl fmt::Display for Intrinsic {
             Intrinsic::SizeOfType => "size_of",
             Intrinsic::SizeOfVal => "size_of_val",
             Intrinsic::Eq => "eq",
+            Intrinsic::Gt => "gt",
+            Intrinsic::Lt => "lt",
+            Intrinsic::Add => "add",
+            Intrinsic::Sub => "sub",
+            Intrinsic::Mul => "mul",
+            Intrinsic::Div => "div",
+            Intrinsic::Log => "log",
         };
         write!(f, "{}", s)
     }
@@ -31,6 +45,13 @@ impl Intrinsic {
             "__size_of" => SizeOfType,
             "__size_of_val" => SizeOfVal,
             "__eq" => Eq,
+            "__gt" => Gt,
+            "__lt" => Lt,
+            "__add" => Add,
+            "__sub" => Sub,
+            "__mul" => Mul,
+            "__div" => Div,
+            "__log" => Log,
             _ => return None,
         })
     }
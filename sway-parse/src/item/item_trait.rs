@@ -5,6 +5,7 @@ pub struct ItemTrait {
     pub visibility: Option<PubToken>,
     pub trait_token: TraitToken,
     pub name: Ident,
+    pub generics: Option<GenericParams>,
     pub super_traits: Option<(ColonToken, Traits)>,
     pub trait_items: Braces<Vec<(Annotated<FnSignature>, SemicolonToken)>>,
     pub trait_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>>,
@@ -35,6 +36,13 @@ impl Parse for ItemTrait {
         let visibility = parser.take();
         let trait_token = parser.parse()?;
         let name = parser.parse()?;
+        let generics = match parser.peek::<OpenAngleBracketToken>() {
+            Some(_open_angle_bracket_token) => {
+                let generic_params = parser.parse()?;
+                Some(generic_params)
+            }
+            None => None,
+        };
         let super_traits = match parser.take() {
             Some(colon_token) => {
                 let traits = parser.parse()?;
@@ -48,6 +56,7 @@ impl Parse for ItemTrait {
             visibility,
             trait_token,
             name,
+            generics,
             super_traits,
             trait_items,
             trait_defs_opt,
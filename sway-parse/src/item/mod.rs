@@ -112,6 +112,7 @@ impl Parse for ItemKind {
 
 #[derive(Clone, Debug)]
 pub struct TypeField {
+    pub visibility: Option<PubToken>,
     pub name: Ident,
     pub colon_token: ColonToken,
     pub ty: Ty,
@@ -119,16 +120,22 @@ pub struct TypeField {
 
 impl Spanned for TypeField {
     fn span(&self) -> Span {
-        Span::join(self.name.span(), self.ty.span())
+        let start = match &self.visibility {
+            Some(pub_token) => pub_token.span(),
+            None => self.name.span(),
+        };
+        Span::join(start, self.ty.span())
     }
 }
 
 impl Parse for TypeField {
     fn parse(parser: &mut Parser) -> ParseResult<TypeField> {
+        let visibility = parser.take();
         let name = parser.parse()?;
         let colon_token = parser.parse()?;
         let ty = parser.parse()?;
         Ok(TypeField {
+            visibility,
             name,
             colon_token,
             ty,
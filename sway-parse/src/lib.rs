@@ -30,8 +30,9 @@ pub use crate::{
     expr::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
-        AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        AbiCastArgs, ClosureParam, ClosureParams, CodeBlockContents, Expr, ExprArrayDescriptor,
+        ExprClosure, ExprStructField, ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch,
+        MatchBranchKind,
     },
     generics::{GenericArgs, GenericParams},
     intrinsics::*,
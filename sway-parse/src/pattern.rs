@@ -6,6 +6,7 @@ pub enum Pattern {
         underscore_token: UnderscoreToken,
     },
     Var {
+        reference: Option<RefToken>,
         mutable: Option<MutToken>,
         name: Ident,
     },
@@ -26,9 +27,14 @@ impl Spanned for Pattern {
     fn span(&self) -> Span {
         match self {
             Pattern::Wildcard { underscore_token } => underscore_token.span(),
-            Pattern::Var { mutable, name } => match mutable {
-                Some(mut_token) => Span::join(mut_token.span(), name.span()),
-                None => name.span(),
+            Pattern::Var {
+                reference,
+                mutable,
+                name,
+            } => match (reference, mutable) {
+                (Some(ref_token), _) => Span::join(ref_token.span(), name.span()),
+                (None, Some(mut_token)) => Span::join(mut_token.span(), name.span()),
+                (None, None) => name.span(),
             },
             Pattern::Literal(literal) => literal.span(),
             Pattern::Constant(path_expr) => path_expr.span(),
@@ -41,10 +47,15 @@ impl Spanned for Pattern {
 
 impl Parse for Pattern {
     fn parse(parser: &mut Parser) -> ParseResult<Pattern> {
-        if let Some(mut_token) = parser.take() {
-            let mutable = Some(mut_token);
+        let reference = parser.take();
+        let mutable = parser.take();
+        if reference.is_some() || mutable.is_some() {
             let name = parser.parse()?;
-            return Ok(Pattern::Var { mutable, name });
+            return Ok(Pattern::Var {
+                reference,
+                mutable,
+                name,
+            });
         }
         if parser.peek::<TrueToken>().is_some() {
             let ident = parser.parse::<TrueToken>()?;
@@ -92,6 +103,7 @@ impl Parse for Pattern {
         }
         match path.try_into_ident() {
             Ok(name) => Ok(Pattern::Var {
+                reference: None,
                 mutable: None,
                 name,
             }),
@@ -799,6 +799,8 @@ pub fn lex_commented(
                         "u16" => LitIntType::U16,
                         "u32" => LitIntType::U32,
                         "u64" => LitIntType::U64,
+                        "u128" => LitIntType::U128,
+                        "u256" => LitIntType::U256,
                         "i8" => LitIntType::I8,
                         "i16" => LitIntType::I16,
                         "i32" => LitIntType::I32,
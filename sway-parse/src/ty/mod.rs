@@ -13,6 +13,11 @@ pub enum Ty {
     Infer {
         underscore_token: UnderscoreToken,
     },
+    Fn {
+        fn_token: FnToken,
+        arguments: Parens<Punctuated<Ty, CommaToken>>,
+        return_type_opt: Option<(RightArrowToken, Box<Ty>)>,
+    },
 }
 
 impl Spanned for Ty {
@@ -23,6 +28,17 @@ impl Spanned for Ty {
             Ty::Array(array_type) => array_type.span(),
             Ty::Str { str_token, length } => Span::join(str_token.span(), length.span()),
             Ty::Infer { underscore_token } => underscore_token.span(),
+            Ty::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            } => {
+                let end = match return_type_opt {
+                    Some((_right_arrow_token, return_type)) => return_type.span(),
+                    None => arguments.span(),
+                };
+                Span::join(fn_token.span(), end)
+            }
         }
     }
 }
@@ -99,6 +115,18 @@ impl Parse for Ty {
         if let Some(underscore_token) = parser.take() {
             return Ok(Ty::Infer { underscore_token });
         }
+        if let Some(fn_token) = parser.take() {
+            let arguments = parser.parse()?;
+            let return_type_opt = match parser.take() {
+                Some(right_arrow_token) => Some((right_arrow_token, parser.parse()?)),
+                None => None,
+            };
+            return Ok(Ty::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
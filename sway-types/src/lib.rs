@@ -351,7 +351,12 @@ impl Context {
 /// This type may be used by compilers (e.g. Sway) and related tooling to convert an ABI
 /// representation into native Rust structs and vice-versa.
 
-pub type JsonABI = Vec<Function>;
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonABI {
+    pub functions: Vec<Function>,
+    pub logged_types: Vec<LoggedType>,
+}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -372,3 +377,57 @@ pub struct Property {
     pub components: Option<Vec<Property>>, // Used for custom types
     pub type_arguments: Option<Vec<Property>>, // Used for generic types. Not yet supported in fuels-rs.
 }
+
+/// The type of the value logged by the `log` call site with ID `log_id`. The ID is found in `rb`
+/// of the `Log` or `LogData` receipt emitted by that call site.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggedType {
+    pub log_id: u64,
+    pub logged_type: Property,
+}
+
+/// The effects of calling an ABI method of a contract.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodEffects {
+    pub name: String,
+    /// The storage fields read by the method, by path, e.g. `config.owner`. Storage accessed
+    /// through a computed key, e.g. by `std::storage::get`, is recorded as `*`.
+    pub reads: Vec<String>,
+    /// The storage fields written by the method, recorded as for `reads`.
+    pub writes: Vec<String>,
+    /// The names of the methods of other contracts called by the method.
+    pub contract_calls: Vec<String>,
+    /// Whether a contract called by the method may call back into this contract before the method
+    /// returns, i.e. whether it calls other contracts without calling
+    /// `std::reentrancy::reentrancy_guard` before the first of them.
+    pub can_reenter: bool,
+}
+
+/// The locations in contract storage of the fields of a contract's `storage` declaration.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageLayout {
+    pub fields: Vec<StorageFieldLayout>,
+}
+
+/// The location in storage of a storage field. Fields of struct type are stored field by field,
+/// so each of their fields gets its own entry, named by its path, e.g. `config.owner`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageFieldLayout {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    /// The keys of the slots holding the value, in order. Values larger than a slot continue in
+    /// the slots following the first one.
+    pub slots: Vec<String>,
+    /// The offset in bytes of the value within its first slot.
+    pub offset: u64,
+    /// The size in bytes of the value.
+    pub size: u64,
+    /// How the slot of each value of a `StorageMap` is derived from its key. `None` for fields
+    /// which are not maps.
+    pub key_derivation: Option<String>,
+}
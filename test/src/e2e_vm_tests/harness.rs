@@ -63,8 +63,11 @@ pub(crate) fn runs_on_node(
 }
 
 /// Very basic check that code does indeed run in the VM.
-/// `true` if it does, `false` if not.
-pub(crate) fn runs_in_vm(file_name: &str, locked: bool) -> (ProgramState, Compiled) {
+/// Returns the final state of the VM along with the receipts of the run.
+pub(crate) fn runs_in_vm(
+    file_name: &str,
+    locked: bool,
+) -> (ProgramState, Vec<fuel_tx::Receipt>, Compiled) {
     let storage = MemoryStorage::default();
 
     let script = compile_to_bytes(file_name, locked).unwrap();
@@ -92,7 +95,8 @@ pub(crate) fn runs_in_vm(file_name: &str, locked: bool) -> (ProgramState, Compil
         .validate(block_height, &Default::default())
         .unwrap();
     let mut i = Interpreter::with_storage(storage, Default::default());
-    (*i.transact(tx_to_test).unwrap().state(), script)
+    let transition = i.transact(tx_to_test).unwrap();
+    (*transition.state(), transition.receipts().to_vec(), script)
 }
 
 /// Returns Err(()) if code _does_ compile, used for test cases where the source
@@ -127,6 +131,31 @@ pub(crate) fn does_not_compile(file_name: &str, locked: bool) -> Result<String,
     }
 }
 
+/// Compiles the test verbosely, returning the compiled program along with the captured stdout, used
+/// for tests which check the warnings the compiler emits.
+pub(crate) fn compile_and_capture_output(
+    file_name: &str,
+    locked: bool,
+) -> Result<(Compiled, String)> {
+    use std::io::Read;
+
+    tracing::info!(" Compiling {}", file_name);
+
+    // Capture stdout to a buffer, compile the test and save stdout to a string.
+    let mut buf = gag::BufferRedirect::stdout().unwrap();
+    let result = compile_to_bytes_verbose(file_name, locked, true);
+    let mut output = String::new();
+    buf.read_to_string(&mut output).unwrap();
+    drop(buf);
+
+    // If verbosity is requested then print it out.
+    if get_test_config_from_env() {
+        tracing::info!("{output}");
+    }
+
+    result.map(|compiled| (compiled, output))
+}
+
 /// Returns `true` if a file compiled without any errors or warnings,
 /// and `false` if it did not.
 pub(crate) fn compile_to_bytes(file_name: &str, locked: bool) -> Result<Compiled> {
@@ -231,6 +260,86 @@ fn emit_json_storage_slots(file_name: &str, compiled: &Compiled) -> Result<()> {
     Ok(())
 }
 
+pub(crate) fn test_json_storage_layout(file_name: &str, compiled: &Compiled) -> Result<()> {
+    emit_json_storage_layout(file_name, compiled)?;
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let oracle_path = format!(
+        "{}/src/e2e_vm_tests/test_programs/{}/{}",
+        manifest_dir, file_name, "json_storage_layout_oracle.json"
+    );
+    let output_path = format!(
+        "{}/src/e2e_vm_tests/test_programs/{}/{}",
+        manifest_dir, file_name, "json_storage_layout_output.json"
+    );
+    if fs::metadata(oracle_path.clone()).is_err() {
+        bail!("JSON storage layout oracle file does not exist for this test.");
+    }
+    if fs::metadata(output_path.clone()).is_err() {
+        bail!("JSON storage layout output file does not exist for this test.");
+    }
+    let oracle_contents =
+        fs::read_to_string(oracle_path).expect("Something went wrong reading the file.");
+    let output_contents =
+        fs::read_to_string(output_path).expect("Something went wrong reading the file.");
+    if oracle_contents != output_contents {
+        bail!("Mismatched storage layout JSON output.");
+    }
+    Ok(())
+}
+
+fn emit_json_storage_layout(file_name: &str, compiled: &Compiled) -> Result<()> {
+    tracing::info!("   storage layout JSON gen {}", file_name);
+    let json_storage_layout = serde_json::json!(compiled.storage_layout);
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let file = std::fs::File::create(format!(
+        "{}/src/e2e_vm_tests/test_programs/{}/{}",
+        manifest_dir, file_name, "json_storage_layout_output.json"
+    ))?;
+    let res = serde_json::to_writer_pretty(&file, &json_storage_layout);
+    res?;
+    Ok(())
+}
+
+pub(crate) fn test_json_method_effects(file_name: &str, compiled: &Compiled) -> Result<()> {
+    emit_json_method_effects(file_name, compiled)?;
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let oracle_path = format!(
+        "{}/src/e2e_vm_tests/test_programs/{}/{}",
+        manifest_dir, file_name, "json_method_effects_oracle.json"
+    );
+    let output_path = format!(
+        "{}/src/e2e_vm_tests/test_programs/{}/{}",
+        manifest_dir, file_name, "json_method_effects_output.json"
+    );
+    if fs::metadata(oracle_path.clone()).is_err() {
+        bail!("JSON method effects oracle file does not exist for this test.");
+    }
+    if fs::metadata(output_path.clone()).is_err() {
+        bail!("JSON method effects output file does not exist for this test.");
+    }
+    let oracle_contents =
+        fs::read_to_string(oracle_path).expect("Something went wrong reading the file.");
+    let output_contents =
+        fs::read_to_string(output_path).expect("Something went wrong reading the file.");
+    if oracle_contents != output_contents {
+        bail!("Mismatched method effects JSON output.");
+    }
+    Ok(())
+}
+
+fn emit_json_method_effects(file_name: &str, compiled: &Compiled) -> Result<()> {
+    tracing::info!("   method effects JSON gen {}", file_name);
+    let json_method_effects = serde_json::json!(compiled.method_effects);
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let file = std::fs::File::create(format!(
+        "{}/src/e2e_vm_tests/test_programs/{}/{}",
+        manifest_dir, file_name, "json_method_effects_output.json"
+    ))?;
+    let res = serde_json::to_writer_pretty(&file, &json_method_effects);
+    res?;
+    Ok(())
+}
+
 fn get_test_config_from_env() -> bool {
     let var_exists = |key| std::env::var(key).map(|_| true).unwrap_or(false);
     var_exists("SWAY_TEST_VERBOSE")
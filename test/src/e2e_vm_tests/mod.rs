@@ -2,6 +2,7 @@
 
 mod harness;
 
+use forc::utils::logs::decode_revert_error;
 use forc_util::init_tracing_subscriber;
 use fuel_vm::prelude::*;
 use regex::Regex;
@@ -33,8 +34,11 @@ struct TestDescription {
     category: TestCategory,
     expected_result: Option<TestResult>,
     contract_paths: Vec<String>,
+    expected_error: Option<String>,
     validate_abi: bool,
     validate_storage_slots: bool,
+    validate_storage_layout: bool,
+    validate_method_effects: bool,
     checker: filecheck::Checker,
 }
 
@@ -56,8 +60,11 @@ pub fn run(locked: bool, filter_regex: Option<&regex::Regex>) {
         category,
         expected_result,
         contract_paths,
+        expected_error,
         validate_abi,
         validate_storage_slots,
+        validate_storage_layout,
+        validate_method_effects,
         checker,
     } in configured_tests
     {
@@ -81,18 +88,48 @@ pub fn run(locked: bool, filter_regex: Option<&regex::Regex>) {
                     ),
                 };
 
-                let result = crate::e2e_vm_tests::harness::runs_in_vm(&name, locked);
-                assert_eq!(result.0, res);
+                let (state, receipts, compiled) =
+                    crate::e2e_vm_tests::harness::runs_in_vm(&name, locked);
+                let error = decode_revert_error(&receipts, &compiled.json_abi.logged_types);
+                if let Some(error) = &error {
+                    tracing::info!("{name} reverted with error: {error}");
+                }
+                assert_eq!(state, res);
+                if let Some(expected_error) = expected_error {
+                    assert_eq!(
+                        error.map(|error| error.to_string()),
+                        Some(expected_error),
+                        "For {name}:\nUnexpected revert error."
+                    );
+                }
                 if validate_abi {
-                    assert!(crate::e2e_vm_tests::harness::test_json_abi(&name, &result.1).is_ok());
+                    assert!(crate::e2e_vm_tests::harness::test_json_abi(&name, &compiled).is_ok());
                 }
                 number_of_tests_executed += 1;
             }
 
             TestCategory::Compiles => {
-                let result = crate::e2e_vm_tests::harness::compile_to_bytes(&name, locked);
-                assert!(result.is_ok());
-                let compiled = result.unwrap();
+                let compiled = if checker.is_empty() {
+                    let result = crate::e2e_vm_tests::harness::compile_to_bytes(&name, locked);
+                    assert!(result.is_ok());
+                    result.unwrap()
+                } else {
+                    // Check the warnings in the compiler output.
+                    let result =
+                        crate::e2e_vm_tests::harness::compile_and_capture_output(&name, locked);
+                    assert!(result.is_ok());
+                    let (compiled, output) = result.unwrap();
+                    match checker.explain(&output, filecheck::NO_VARIABLES) {
+                        Ok((success, report)) if !success => {
+                            panic!("For {name}:\nFilecheck failed:\n{report}");
+                        }
+                        Err(e) => {
+                            panic!("For {name}:\nFilecheck directive error: {e}");
+                        }
+                        _ => (),
+                    }
+                    compiled
+                };
                 if validate_abi {
                     assert!(crate::e2e_vm_tests::harness::test_json_abi(&name, &compiled).is_ok());
                 }
@@ -102,6 +139,18 @@ pub fn run(locked: bool, filter_regex: Option<&regex::Regex>) {
                     )
                     .is_ok());
                 }
+                if validate_storage_layout {
+                    assert!(crate::e2e_vm_tests::harness::test_json_storage_layout(
+                        &name, &compiled
+                    )
+                    .is_ok());
+                }
+                if validate_method_effects {
+                    assert!(crate::e2e_vm_tests::harness::test_json_method_effects(
+                        &name, &compiled
+                    )
+                    .is_ok());
+                }
                 number_of_tests_executed += 1;
             }
 
@@ -289,6 +338,16 @@ fn parse_test_toml(path: &Path) -> Result<TestDescription, String> {
             })?,
     };
 
+    let expected_error = toml_content
+        .get("expected_error")
+        .map(|error| {
+            error
+                .as_str()
+                .map(|error| error.to_owned())
+                .ok_or_else(|| "Expected error must be a JSON string.".to_owned())
+        })
+        .transpose()?;
+
     let validate_abi = toml_content
         .get("validate_abi")
         .map(|v| v.as_bool().unwrap_or(false))
@@ -299,6 +358,16 @@ fn parse_test_toml(path: &Path) -> Result<TestDescription, String> {
         .map(|v| v.as_bool().unwrap_or(false))
         .unwrap_or(false);
 
+    let validate_storage_layout = toml_content
+        .get("validate_storage_layout")
+        .map(|v| v.as_bool().unwrap_or(false))
+        .unwrap_or(false);
+
+    let validate_method_effects = toml_content
+        .get("validate_method_effects")
+        .map(|v| v.as_bool().unwrap_or(false))
+        .unwrap_or(false);
+
     // We need to adjust the path to start relative to `test_programs`.
     let name = path
         .iter()
@@ -319,8 +388,11 @@ fn parse_test_toml(path: &Path) -> Result<TestDescription, String> {
         category,
         expected_result,
         contract_paths,
+        expected_error,
         validate_abi,
         validate_storage_slots,
+        validate_storage_layout,
+        validate_method_effects,
         checker,
     })
 }
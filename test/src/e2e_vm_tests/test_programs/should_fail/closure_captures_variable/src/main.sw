@@ -0,0 +1,20 @@
+script;
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn identity<T>(x: T) -> T {
+    x
+}
+
+fn make_adder() -> fn(u64) -> u64 {
+    |x: u64| x + 1
+}
+
+fn main() -> u64 {
+    let y = 5;
+    let a = apply(|x| x + y, 1);
+    let b = apply(identity, 2);
+    a + b
+}
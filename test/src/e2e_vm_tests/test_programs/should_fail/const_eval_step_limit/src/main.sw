@@ -0,0 +1,15 @@
+script;
+
+const SPIN: u64 = spin(1);
+
+fn spin(x: u64) -> u64 {
+    let mut y = x;
+    while y > 0 {
+        y = y + 1;
+    }
+    y
+}
+
+fn main() -> u64 {
+    SPIN
+}
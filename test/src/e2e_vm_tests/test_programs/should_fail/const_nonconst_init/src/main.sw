@@ -1,7 +1,10 @@
 script;
 
 fn bla(x: u64) -> u64 {
-    x + 1
+    asm(r1: x, r2) {
+        addi r2 r1 i1;
+        r2: u64
+    }
 }
 
 fn main() -> u64 {
@@ -1,4 +1,4 @@
 library foo;
 dep bar;
 dep inner/bar;
-pub struct Foo { foo: str[3] } 
+pub struct Foo { pub foo: str[3] } 
@@ -3,4 +3,4 @@ dep double_inner/double_bar;
 
 use double_bar::DoubleBar;
 
-pub struct Bar { a: u32 } 
+pub struct Bar { pub a: u32 } 
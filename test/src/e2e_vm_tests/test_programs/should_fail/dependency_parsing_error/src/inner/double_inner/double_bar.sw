@@ -1,6 +1,6 @@
 library double_bar;
 //use ::foo::Foo;
 
-pub struct DoubleBar { a: u32 } 
+pub struct DoubleBar { pub a: u32 } 
 
 let baz = 43;
\ No newline at end of file
@@ -2,5 +2,5 @@ library bar;
 use ::foo::Foo;
 
 pub struct NestedDependency {
-  num: u64
+  pub num: u64
 }
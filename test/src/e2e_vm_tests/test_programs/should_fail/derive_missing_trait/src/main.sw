@@ -0,0 +1,21 @@
+script;
+
+#[derive(Ord)]
+struct Flags {
+    count: u64,
+    enabled: bool,
+}
+
+#[derive(Eq)]
+enum Pair {
+    Numbers: (u64, u64),
+    Nothing: (),
+}
+
+#[derive(Eq)]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn main() {
+}
@@ -0,0 +1,34 @@
+script;
+
+trait Convert<T> {
+    fn convert(self) -> T;
+}
+
+trait Plain {
+    fn plain(self) -> u64;
+}
+
+struct Meters {
+    value: u64,
+}
+
+impl Convert for Meters {
+    fn convert(self) -> u64 {
+        self.value
+    }
+}
+
+impl Convert<u64, bool> for Meters {
+    fn convert(self) -> u64 {
+        self.value
+    }
+}
+
+impl Plain<u64> for Meters {
+    fn plain(self) -> u64 {
+        self.value
+    }
+}
+
+fn main() {
+}
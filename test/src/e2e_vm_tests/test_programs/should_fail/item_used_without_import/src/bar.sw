@@ -1,5 +1,5 @@
 library bar;
 
 pub struct Bar {
-    a: u32,
+    pub a: u32,
 }
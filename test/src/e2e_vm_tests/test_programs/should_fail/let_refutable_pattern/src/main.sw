@@ -0,0 +1,25 @@
+script;
+
+enum Result {
+    Ok: u64,
+    Err: bool,
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn main() -> u64 {
+    let r = Result::Ok(5);
+    // should fail
+    let Result::Ok(a) = r;
+
+    // should fail
+    let (0, b) = (1, 2);
+
+    // should fail
+    let Point { x: 3, y } = Point { x: 3, y: 4 };
+
+    a + b + y
+}
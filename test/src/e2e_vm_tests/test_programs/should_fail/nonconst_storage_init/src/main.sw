@@ -3,8 +3,14 @@ contract;
 use core::*;
 
 storage {
-    x: u64 = 5 + 5,
-    y: u64 = 5 + 5,
+    x: u64 = opaque(5) + 5,
+    y: u64 = opaque(5) + 5,
+}
+
+fn opaque(x: u64) -> u64 {
+    asm(r1: x) {
+        r1: u64
+    }
 }
 
 abi Test {
@@ -0,0 +1,16 @@
+predicate;
+
+abi Oracle {
+    fn price() -> u64;
+}
+
+fn main() -> bool {
+    let oracle = abi(Oracle, 0x0000000000000000000000000000000000000000000000000000000000000001);
+    let mut total = 0;
+    let mut i = 0;
+    while i < 3 {
+        total = total + oracle.price();
+        i = i + 1;
+    }
+    total > 100
+}
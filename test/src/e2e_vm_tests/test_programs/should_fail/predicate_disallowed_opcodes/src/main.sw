@@ -0,0 +1,10 @@
+predicate;
+
+use std::{context::balance_of, contract_id::*, logging::log};
+
+fn main() -> bool {
+    let asset = ~ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+    let balance = balance_of(asset, asset);
+    log(balance);
+    balance > 0
+}
@@ -0,0 +1,26 @@
+library fixed;
+
+pub struct Fixed {
+    pub value: u64,
+    scale: u64,
+}
+
+impl Fixed {
+    pub fn new(value: u64) -> Fixed {
+        Fixed {
+            value,
+            scale: 1,
+        }
+    }
+
+    pub fn with_scale(self, scale: u64) -> Fixed {
+        let mut fixed = self;
+        fixed.scale = scale;
+        fixed
+    }
+
+    pub fn scaled(self) -> u64 {
+        let Fixed { value, scale } = self;
+        value * scale
+    }
+}
@@ -0,0 +1,19 @@
+script;
+
+dep fixed;
+
+use fixed::Fixed;
+
+fn main() -> u64 {
+    let mut a = Fixed {
+        value: 1,
+        scale: 2,
+    };
+    let b = ~Fixed::new(1);
+    let c = b.scale;
+    a.scale = 3;
+    let d = match b {
+        Fixed { value: _, scale } => scale,
+    };
+    0
+}
@@ -0,0 +1,19 @@
+script;
+
+fn increment(ref mut n: u64) {
+    n = n + 1;
+}
+
+fn add_to(ref mut n: u64, m: u64) {
+    n = n + m;
+}
+
+fn main() -> u64 {
+    let y = 1;
+    increment(y);
+    increment(2);
+    let mut z = 3;
+    add_to(z, z);
+    let f = increment;
+    z
+}
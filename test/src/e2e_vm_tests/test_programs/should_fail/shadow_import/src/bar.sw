@@ -1,13 +1,13 @@
 library bar;
 
 pub struct Bar1 {
-    a: u32,
+    pub a: u32,
 }
 
 pub struct Bar2 {
-    b: u32,
+    pub b: u32,
 }
 
 pub struct Bar3 {
-    c: u32,
+    pub c: u32,
 }
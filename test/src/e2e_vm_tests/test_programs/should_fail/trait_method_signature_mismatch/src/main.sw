@@ -8,7 +8,7 @@ trait Foo {
 }
 
 struct S {
-    x: u64,
+    pub x: u64,
 }
 
 impl Foo for S {
@@ -0,0 +1,74 @@
+script;
+
+use std::assert::assert;
+
+const DECIMALS: u64 = 9;
+const SCALE: u64 = pow10(DECIMALS);
+const MAX: u64 = 10 * SCALE;
+const N: u64 = 1 + 2;
+const IS_BIG: bool = MAX > 1000 && !(N == 0);
+
+struct Limits {
+    lo: u64,
+    hi: u64,
+}
+
+const LIMITS: Limits = make_limits(N);
+
+enum Size {
+    Small: (),
+    Large: u64,
+}
+
+const SIZE: Size = classify(MAX);
+const SIZE_VALUE: u64 = size_value(SIZE);
+const WIDE: u128 = 18446744073709551616u128 * 3;
+
+fn pow10(n: u64) -> u64 {
+    let mut res = 1;
+    let mut i = 0;
+    while i < n {
+        res = res * 10;
+        i = i + 1;
+    }
+    res
+}
+
+fn make_limits(n: u64) -> Limits {
+    if n > 2 {
+        Limits {
+            lo: n,
+            hi: n * n,
+        }
+    } else {
+        Limits { lo: 0, hi: 0 }
+    }
+}
+
+fn classify(n: u64) -> Size {
+    if n < 100 {
+        return Size::Small;
+    }
+    Size::Large(n / SCALE)
+}
+
+fn size_value(s: Size) -> u64 {
+    match s {
+        Size::Small => 0,
+        Size::Large(n) => n,
+    }
+}
+
+fn main() -> u64 {
+    assert(SCALE == 1_000_000_000);
+    assert(MAX == 10_000_000_000);
+    assert(IS_BIG);
+    assert(LIMITS.lo == 3 && LIMITS.hi == 9);
+    assert(SIZE_VALUE == 10);
+    assert(WIDE == 55340232221128654848u128);
+
+    let a: [u64; N] = [10, 20, 12];
+    let b: [u64; N + 1] = [1, 2, 3, 4];
+    assert(b[3] == 4);
+    a[0] + a[1] + a[2]
+}
@@ -4,5 +4,5 @@ dep double_inner/double_bar;
 use double_bar::DoubleBar;
 
 pub struct Bar {
-    a: u32,
+    pub a: u32,
 }
@@ -0,0 +1,76 @@
+script;
+
+use std::{assert::assert, hash::Hash};
+
+#[derive(Eq, Ord, Hash)]
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+#[derive(Eq, Ord, Hash)]
+struct Empty {}
+
+#[derive(Eq, Ord, Hash)]
+enum Shape {
+    Dot: (),
+    Circle: u64,
+    Line: Point,
+}
+
+#[derive(Eq, Hash)]
+struct Labelled {
+    label: b256,
+    point: Point,
+    marker: (),
+}
+
+fn main() -> bool {
+    let a = Point { x: 1, y: 2 };
+    let b = Point { x: 1, y: 3 };
+    let c = Point { x: 2, y: 0 };
+
+    // Eq
+    assert(a == Point { x: 1, y: 2 });
+    assert(a != b);
+    assert(Empty {} == Empty {});
+
+    // Ord compares fields in declaration order.
+    assert(a < b);
+    assert(b < c);
+    assert(c > a);
+    assert(!(a < a));
+    assert(!(a > a));
+    assert(!(Empty {} < Empty {}));
+
+    // Hash
+    assert(a.hash() == Point { x: 1, y: 2 }.hash());
+    assert(a.hash() != b.hash());
+    let label = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    let l1 = Labelled { label, point: a, marker: () };
+    let l2 = Labelled { label, point: b, marker: () };
+    assert(l1 == Labelled { label, point: a, marker: () });
+    assert(l1 != l2);
+    assert(l1.hash() != l2.hash());
+
+    // Enums compare by variant first and by value second.
+    let dot = Shape::Dot;
+    let small = Shape::Circle(1);
+    let big = Shape::Circle(5);
+    let line = Shape::Line(a);
+    assert(dot == Shape::Dot);
+    assert(small == Shape::Circle(1));
+    assert(small != big);
+    assert(small != dot);
+    assert(line == Shape::Line(a));
+    assert(dot < small);
+    assert(small < big);
+    assert(big < line);
+    assert(line > dot);
+    assert(!(dot < dot));
+    assert(dot.hash() == Shape::Dot.hash());
+    assert(small.hash() != big.hash());
+    assert(line.hash() != Shape::Line(b).hash());
+
+    true
+}
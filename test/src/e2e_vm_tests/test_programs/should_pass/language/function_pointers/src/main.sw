@@ -0,0 +1,56 @@
+script;
+
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn apply_twice(f: fn(u64) -> u64, x: u64) -> u64 {
+    apply(f, apply(f, x))
+}
+
+fn compose(f: fn(u64) -> u64, g: fn(u64) -> u64, x: u64) -> u64 {
+    g(f(x))
+}
+
+fn count_matching(pred: fn(u64) -> bool, a: u64, b: u64, c: u64) -> u64 {
+    let mut count = 0;
+    if pred(a) {
+        count = count + 1;
+    }
+    if pred(b) {
+        count = count + 1;
+    }
+    if pred(c) {
+        count = count + 1;
+    }
+    count
+}
+
+fn main() -> u64 {
+    // A named function passed as an argument.
+    let a = apply(double, 5);
+
+    // A named function bound to a variable and called through it.
+    let f = double;
+    let b = f(6);
+
+    // Closure parameter types are inferred from the expected function type.
+    let c = apply(|x| x + 1, 7);
+    let d = apply_twice(|x| x * 3, 2);
+
+    // An annotated closure bound to a variable.
+    let add_ten = |x: u64| x + 10;
+    let e = compose(add_ten, double, 1);
+
+    // A closure with no parameters.
+    let zero = || 0;
+    let g = zero();
+
+    let h = count_matching(|x| x > 3, 1, 4, 9);
+
+    a + b + c + d + e + g + h
+}
@@ -0,0 +1,79 @@
+script;
+
+trait Convert<T> {
+    fn convert(self) -> T;
+} {
+    fn convert_pair(self) -> (T, T) {
+        (self.convert(), self.convert())
+    }
+}
+
+trait Scale<T> {
+    fn scale(self, factor: T) -> Self;
+}
+
+struct Meters {
+    value: u64,
+}
+
+impl Convert<u64> for Meters {
+    fn convert(self) -> u64 {
+        self.value
+    }
+}
+
+impl Convert<bool> for Meters {
+    fn convert(self) -> bool {
+        self.value != 0
+    }
+}
+
+impl Scale<u64> for Meters {
+    fn scale(self, factor: u64) -> Self {
+        Meters {
+            value: self.value * factor,
+        }
+    }
+}
+
+impl Scale<bool> for Meters {
+    fn scale(self, keep: bool) -> Self {
+        if keep {
+            self
+        } else {
+            Meters { value: 0 }
+        }
+    }
+}
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+impl<T> Convert<T> for Wrapper<T> {
+    fn convert(self) -> T {
+        self.inner
+    }
+}
+
+fn main() -> u64 {
+    let meters = Meters { value: 7 };
+
+    // The expected type picks the implementation.
+    let as_u64: u64 = meters.convert();
+    let as_bool: bool = meters.convert();
+    let pair: (bool, bool) = meters.convert_pair();
+    if !as_bool || !pair.0 || !pair.1 {
+        return 0;
+    }
+
+    // The argument types pick the implementation.
+    let doubled = meters.scale(2);
+    let kept = meters.scale(true);
+    let zeroed = meters.scale(false);
+
+    let wrapper = Wrapper { inner: 35 };
+    let inner: u64 = wrapper.convert();
+
+    as_u64 + doubled.value + kept.value + zeroed.value + inner
+}
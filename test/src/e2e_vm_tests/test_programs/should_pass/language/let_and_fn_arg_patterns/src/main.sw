@@ -0,0 +1,39 @@
+script;
+
+// Tests destructuring patterns in `let` bindings and function parameters
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Wrapper {
+    Inner: Point,
+}
+
+fn sum_point(Point { x, y }: Point) -> u64 {
+    x + y
+}
+
+fn sum_pair((a, b): (u64, u64), _: bool) -> u64 {
+    a + b
+}
+
+fn unwrap(Wrapper::Inner(p): Wrapper) -> Point {
+    p
+}
+
+fn main() -> u64 {
+    let p = Point { x: 1, y: 2 };
+    let Point { x, y: mut y } = p;
+    y = y + x;
+
+    // single variant enums can be destructured irrefutably
+    let Wrapper::Inner(Point { x: inner_x, y: inner_y }) = Wrapper::Inner(Point { x: 10, y: 20 });
+
+    let (Wrapper::Inner(q), (mut count, _)) = (Wrapper::Inner(Point { x: 3, y: 4 }), (0, true));
+    count = count + q.x;
+
+    // 3 + 10 + 20 + 3 + 3 + 3 + 0
+    y + inner_x + inner_y + count + sum_point(p) + sum_pair((1, 2), false) + unwrap(Wrapper::Inner(Point { x: 0, y: 0 })).x
+}
@@ -0,0 +1,93 @@
+contract;
+
+use std::{reentrancy::reentrancy_guard, storage::{get, StorageMap}};
+
+struct Config {
+    owner: b256,
+    fee: u64,
+}
+
+storage {
+    deposits: u64 = 0,
+    config: Config = Config {
+        owner: 0x0000000000000000000000000000000000000000000000000000000000000001,
+        fee: 3,
+    },
+    balances: StorageMap<b256, u64>,
+}
+
+abi Token {
+    fn transfer(to: b256, amount: u64);
+}
+
+abi Bank {
+    #[storage(read, write)]
+    fn deposit(account: b256, amount: u64);
+
+    #[storage(read, write)]
+    fn withdraw(account: b256, token: b256);
+
+    #[storage(read, write)]
+    fn withdraw_guarded(account: b256, token: b256);
+
+    #[storage(read, write)]
+    fn withdraw_guarded_late(account: b256, token: b256);
+
+    #[storage(read, write)]
+    fn withdraw_maybe_guarded(account: b256, token: b256, guard: bool);
+
+    #[storage(read)]
+    fn raw_read(key: b256) -> u64;
+}
+
+impl Bank for Contract {
+    #[storage(read, write)]
+    fn deposit(account: b256, amount: u64) {
+        storage.deposits = storage.deposits + 1;
+        storage.balances.insert(account, storage.balances.get(account) + amount - storage.config.fee);
+    }
+
+    #[storage(read, write)]
+    fn withdraw(account: b256, token: b256) {
+        let amount = storage.balances.get(account);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+        storage.balances.insert(account, 0);
+    }
+
+    #[storage(read, write)]
+    fn withdraw_guarded(account: b256, token: b256) {
+        reentrancy_guard();
+        let amount = storage.balances.get(account);
+        storage.balances.insert(account, 0);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+    }
+
+    // The guard only runs after the transfer, which may already have re-entered.
+    #[storage(read, write)]
+    fn withdraw_guarded_late(account: b256, token: b256) {
+        let amount = storage.balances.get(account);
+        storage.balances.insert(account, 0);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+        reentrancy_guard();
+    }
+
+    // The guard doesn't run on every path to the transfer.
+    #[storage(read, write)]
+    fn withdraw_maybe_guarded(account: b256, token: b256, guard: bool) {
+        if guard {
+            reentrancy_guard();
+        }
+        let amount = storage.balances.get(account);
+        storage.balances.insert(account, 0);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+    }
+
+    #[storage(read)]
+    fn raw_read(key: b256) -> u64 {
+        get(key)
+    }
+}
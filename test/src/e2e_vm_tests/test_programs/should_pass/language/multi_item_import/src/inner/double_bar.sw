@@ -1,13 +1,13 @@
 library double_bar;
 
 pub struct DoubleBar1 {
-    a: u32,
+    pub a: u32,
 }
 
 pub struct DoubleBar2 {
-    a: u64,
+    pub a: u64,
 }
 
 pub struct DoubleBar3 {
-    a: u64,
+    pub a: u64,
 }
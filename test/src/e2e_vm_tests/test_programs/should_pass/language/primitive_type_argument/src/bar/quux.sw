@@ -1,9 +1,9 @@
 library quux;
 pub struct Quux<A, B, C, D, E, F> {
-  a: A,
-  b: B,
-  c: C,
-  d: D,
-  e: E,
-  f: F
+  pub a: A,
+  pub b: B,
+  pub c: C,
+  pub d: D,
+  pub e: E,
+  pub f: F
 }
@@ -0,0 +1,94 @@
+contract;
+
+use std::{reentrancy::reentrancy_guard, storage::StorageMap};
+
+storage {
+    withdrawals: u64 = 0,
+    balances: StorageMap<b256, u64>,
+}
+
+abi Token {
+    fn transfer(to: b256, amount: u64);
+}
+
+abi Bank {
+    #[storage(read, write)]
+    fn withdraw(account: b256, token: b256);
+
+    #[storage(read, write)]
+    fn withdraw_in_parts(account: b256, token: b256, parts: u64);
+
+    #[storage(read, write)]
+    fn withdraw_checked(account: b256, token: b256);
+
+    #[storage(read, write)]
+    fn withdraw_guarded(account: b256, token: b256);
+
+    #[storage(read, write)]
+    fn pay_or_record(account: b256, token: b256, pay: bool);
+
+    #[storage(read, write)]
+    fn withdraw_guarded_late(account: b256, token: b256);
+}
+
+impl Bank for Contract {
+    // The balance is cleared after the transfer, so the token may re-enter and withdraw again.
+    #[storage(read, write)]
+    fn withdraw(account: b256, token: b256) {
+        let amount = storage.balances.get(account);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+        storage.balances.insert(account, 0);
+    }
+
+    // The write precedes the transfer in each iteration, but follows the previous iteration's.
+    #[storage(read, write)]
+    fn withdraw_in_parts(account: b256, token: b256, parts: u64) {
+        let token = abi(Token, token);
+        let mut i = 0;
+        while i < parts {
+            storage.withdrawals = storage.withdrawals + 1;
+            token.transfer(account, 1);
+            i = i + 1;
+        }
+    }
+
+    // Checks-effects-interactions: the balance is cleared before the transfer.
+    #[storage(read, write)]
+    fn withdraw_checked(account: b256, token: b256) {
+        let amount = storage.balances.get(account);
+        storage.balances.insert(account, 0);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+    }
+
+    #[storage(read, write)]
+    fn withdraw_guarded(account: b256, token: b256) {
+        reentrancy_guard();
+        let amount = storage.balances.get(account);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+        storage.balances.insert(account, 0);
+    }
+
+    // The write and the transfer are on different paths.
+    #[storage(read, write)]
+    fn pay_or_record(account: b256, token: b256, pay: bool) {
+        if pay {
+            let token = abi(Token, token);
+            token.transfer(account, 1);
+        } else {
+            storage.withdrawals = storage.withdrawals + 1;
+        }
+    }
+
+    // The guard only runs once the transfer has returned, too late to stop a re-entry.
+    #[storage(read, write)]
+    fn withdraw_guarded_late(account: b256, token: b256) {
+        let amount = storage.balances.get(account);
+        let token = abi(Token, token);
+        token.transfer(account, amount);
+        reentrancy_guard();
+        storage.balances.insert(account, 0);
+    }
+}
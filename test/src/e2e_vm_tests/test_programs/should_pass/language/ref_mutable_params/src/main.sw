@@ -0,0 +1,48 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn increment(ref mut n: u64) {
+    n = n + 1;
+}
+
+fn increment_twice(ref mut n: u64) {
+    increment(n);
+    increment(n);
+}
+
+fn translate(ref mut p: Point, dx: u64, dy: u64) {
+    p.x = p.x + dx;
+    p.y = p.y + dy;
+}
+
+fn reset(ref mut p: Point) {
+    p = Point { x: 0, y: 0 };
+}
+
+fn swap(ref mut a: u64, ref mut b: u64) {
+    let tmp = a;
+    a = b;
+    b = tmp;
+}
+
+fn main() -> u64 {
+    let mut count = 1;
+    increment(count);
+    increment_twice(count);
+
+    let mut p = Point { x: 1, y: 2 };
+    translate(p, 10, 20);
+    let moved = p.x + p.y;
+    reset(p);
+
+    let mut a = 5;
+    let mut b = 7;
+    swap(a, b);
+
+    // 4 + 33 + 0 + 0 + 70 + 5
+    count + moved + p.x + p.y + a * 10 + b
+}
@@ -0,0 +1,39 @@
+contract;
+
+use std::storage::StorageMap;
+
+struct Config {
+    owner: b256,
+    fee: u64,
+}
+
+enum Status {
+    Open: (),
+    Frozen: u64,
+    Moved: Config,
+}
+
+storage {
+    counter: u64 = 0,
+    config: Config = Config {
+        owner: 0x0000000000000000000000000000000000000000000000000000000000000001,
+        fee: 3,
+    },
+    name: str[40] = "the name of a bank that spans two slots.",
+    balances: StorageMap<b256, u64>,
+    status: Status,
+    pair: (u64, b256),
+}
+
+abi Bank {
+    #[storage(read, write)]
+    fn deposit(account: b256, amount: u64);
+}
+
+impl Bank for Contract {
+    #[storage(read, write)]
+    fn deposit(account: b256, amount: u64) {
+        storage.counter = storage.counter + 1;
+        storage.balances.insert(account, storage.balances.get(account) + amount + storage.config.fee);
+    }
+}
@@ -0,0 +1,21 @@
+script;
+
+dep fixed;
+
+use fixed::Fixed;
+
+fn main() -> u64 {
+    // Public fields can be read and reassigned from any module.
+    let mut a = ~Fixed::new(7);
+    a.value = a.value + 3;
+
+    // Private fields are only touched by `fixed` itself.
+    let scaled = a.with_scale(3).scaled();
+
+    // Patterns may skip the private fields with `..`.
+    let value = match a {
+        Fixed { value, .. } => value,
+    };
+
+    scaled + value
+}
@@ -0,0 +1,8 @@
+script;
+
+fn main() -> bool {
+    // The low word of 2^192 is zero, so only the discarded high word shows the overflow.
+    let two_pow_96: u128 = 79228162514264337593543950336u128;
+    let x = two_pow_96 * two_pow_96;
+    x == 0
+}
@@ -0,0 +1,41 @@
+script;
+
+use std::assert::assert;
+
+fn main() -> u64 {
+    // u128
+    let word_max: u128 = 18446744073709551615u128;
+    let one: u128 = 1;
+    let two_pow_64 = word_max + one;
+    assert(two_pow_64 == 18446744073709551616u128);
+    assert(two_pow_64 - one == word_max);
+    assert(two_pow_64 > word_max);
+    assert(word_max < two_pow_64);
+    assert(!(word_max < word_max));
+
+    let squared = word_max * word_max;
+    assert(squared == 340282366920938463426481119284349108225u128);
+    assert(squared / word_max == word_max);
+    assert(squared / two_pow_64 == word_max - one);
+
+    let c: u128 = 7;
+    assert(c * 6 == 42);
+    assert(__add(c, c) == 14);
+    assert(__gt(c, 6));
+    assert(__lt(c, 8));
+
+    // u256
+    let two_pow_128 = 340282366920938463463374607431768211456u256;
+    let two_pow_64 = 18446744073709551616u256;
+    let two_pow_192 = two_pow_128 * two_pow_64;
+    assert(two_pow_192 == 6277101735386680763835789423207666416102355444464034512896u256);
+    assert(two_pow_192 - 1 == 6277101735386680763835789423207666416102355444464034512895u256);
+    assert((two_pow_192 + 5) / two_pow_64 == two_pow_128);
+    assert(two_pow_192 > two_pow_128);
+    assert(two_pow_64 < two_pow_128);
+
+    let d: u256 = 6;
+    assert(d * 7 == 42);
+
+    2
+}
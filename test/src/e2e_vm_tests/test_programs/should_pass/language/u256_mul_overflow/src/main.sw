@@ -0,0 +1,8 @@
+script;
+
+fn main() -> bool {
+    // The low word of 2^448 is zero, so only the discarded high word shows the overflow.
+    let two_pow_224: u256 = 26959946667150639794667015087019630673637144422540572481103610249216u256;
+    let x = two_pow_224 * two_pow_224;
+    x == 0
+}
@@ -0,0 +1,35 @@
+script;
+
+use std::logging::log;
+
+struct Point {
+    x: u64,
+    y: bool,
+}
+
+enum Shape {
+    Dot: (),
+    Circle: u64,
+}
+
+fn log_twice(value: u64) {
+    log(value);
+    log(value + 1);
+}
+
+fn main() -> bool {
+    log(42);
+    log(true);
+    log(0x4242424242424242424242424242424242424242424242424242424242424242);
+    log(Point {
+        x: 1, y: false
+    });
+    log(Shape::Circle(7));
+    log((5, true));
+
+    // Each call site of a logging function gets its own log ID.
+    log_twice(10);
+    log_twice(20);
+
+    true
+}
@@ -0,0 +1,50 @@
+script;
+
+use std::{assert::assert, math::*, option::Option};
+use core::num::*;
+
+fn main() -> bool {
+    let max_u64 = ~u64::max();
+    let max_u32 = ~u32::max();
+    let max_u16 = ~u16::max();
+    let max_u8 = ~u8::max();
+
+    // Wrapping arithmetic never reverts.
+    assert(max_u64.wrapping_add(2) == 1);
+    assert(0u64.wrapping_sub(1) == max_u64);
+    assert(max_u64.wrapping_mul(2) == max_u64 - 1);
+    assert(max_u32.wrapping_add(2) == 1);
+    assert(0u32.wrapping_sub(1) == max_u32);
+    assert(max_u32.wrapping_mul(max_u32) == 1);
+    assert(max_u16.wrapping_add(1) == 0);
+    assert(max_u16.wrapping_mul(2) == max_u16 - 1);
+    assert(max_u8.wrapping_add(max_u8) == 254);
+    assert(3u8.wrapping_sub(4) == max_u8);
+    assert(16u8.wrapping_mul(16) == 0);
+
+    // Checked arithmetic returns `None` instead.
+    assert(max_u64.checked_add(1).is_none());
+    assert(max_u64.checked_add(0).unwrap() == max_u64);
+    assert(0u64.checked_sub(1).is_none());
+    assert(5u64.checked_sub(5).unwrap() == 0);
+    assert(max_u64.checked_mul(2).is_none());
+    assert(0u64.checked_mul(max_u64).unwrap() == 0);
+    assert(max_u32.checked_add(1).is_none());
+    assert(65536u32.checked_mul(65536).is_none());
+    assert(65535u32.checked_mul(65537).unwrap() == max_u32);
+    assert(max_u16.checked_add(1).is_none());
+    assert(255u16.checked_mul(257).unwrap() == max_u16);
+    assert(max_u8.checked_add(1).is_none());
+    assert(0u8.checked_sub(1).is_none());
+    assert(16u8.checked_mul(16).is_none());
+    assert(15u8.checked_mul(17).unwrap() == max_u8);
+
+    // In range operations are unaffected by the overflow checks.
+    assert(max_u8 - 1 + 1 == max_u8);
+    assert(max_u32 / 2 * 2 + 1 == max_u32);
+
+    // The debug profile has overflow checks enabled, so this reverts with
+    // `ARITHMETIC_OVERFLOW_SIGNAL` rather than producing 256.
+    let x = max_u8 + 1;
+    x == 0
+}
@@ -0,0 +1,21 @@
+script;
+
+use std::{assert::require, revert::revert_with_log};
+
+enum Error {
+    InsufficientFunds: u64,
+    NotOwner: (),
+}
+
+fn withdraw(balance: u64, amount: u64) -> u64 {
+    if amount > balance {
+        revert_with_log(Error::InsufficientFunds(amount - balance));
+    }
+    balance - amount
+}
+
+fn main() -> u64 {
+    require(true, Error::NotOwner);
+    let balance = withdraw(20, 15);
+    withdraw(balance, 15)
+}
@@ -0,0 +1,14 @@
+script;
+
+fn main() -> u8 {
+    let a: u8 = 200;
+    let b: u8 = 100;
+    __add(a, b)
+}
+
+// Arithmetic on integers narrower than a word is done at their own width.
+
+// check: $(a8=$VAL) = bitcast $VAL to u8
+// check: $(b8=$VAL) = bitcast $VAL to u8
+// check: $(sum=$VAL) = add $a8 $b8
+// check: bitcast $sum to u64